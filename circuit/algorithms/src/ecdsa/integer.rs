@@ -0,0 +1,502 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bits in a limb.
+const LIMB_SIZE_IN_BITS: usize = 64;
+/// The number of limbs in a 256-bit integer.
+const NUM_LIMBS: usize = 4;
+/// The number of bits in a column of an integer relation, which holds the terms of two adjacent limbs.
+const COLUMN_SIZE_IN_BITS: usize = 2 * LIMB_SIZE_IN_BITS;
+
+/// A non-negative integer less than `2^256`, represented as four 64-bit limbs in little-endian order.
+/// Each limb is enforced to be less than `2^64`.
+///
+/// Note: An integer is not necessarily reduced modulo the non-native modulus of its arithmetic.
+/// Unless both integers are known to be canonical, they must be compared with [`Uint256::is_congruent`].
+#[derive(Clone)]
+pub(super) struct Uint256<E: Environment> {
+    /// The little-endian limbs.
+    limbs: Vec<Field<E>>,
+}
+
+impl<E: Environment> Uint256<E> {
+    /// Initializes a constant integer.
+    pub(super) fn constant(value: &BigUint) -> Self {
+        let limbs = to_limbs(value, NUM_LIMBS).into_iter().map(|limb| Field::constant(console::Field::from_u64(limb)));
+        Self { limbs: limbs.collect() }
+    }
+
+    /// Initializes a private integer, which is enforced to be less than `2^num_bits`.
+    pub(super) fn new(value: &BigUint, num_bits: usize) -> Self {
+        debug_assert!(num_bits <= NUM_LIMBS * LIMB_SIZE_IN_BITS, "An integer must be at most 256 bits");
+        let mut limbs = witness_limbs(value, num_bits);
+        limbs.resize(NUM_LIMBS, Field::zero());
+        Self { limbs }
+    }
+
+    /// Initializes a private integer, which is enforced to be less than the given modulus.
+    pub(super) fn new_canonical(value: &BigUint, modulus: &BigUint) -> Self {
+        let integer = Self::new(value, NUM_LIMBS * LIMB_SIZE_IN_BITS);
+        E::assert(integer.is_less_than(modulus));
+        integer
+    }
+
+    /// Initializes an integer from 32 bytes in big-endian order.
+    pub(super) fn from_bytes_be(bytes: &[U8<E>]) -> Self {
+        debug_assert_eq!(bytes.len(), 32, "A 256-bit integer must be initialized from 32 bytes");
+        // Note: Each limb is the little-endian composition of its bytes, so it is less than `2^64` by construction.
+        let limbs = bytes.rchunks(LIMB_SIZE_IN_BITS / 8).map(|chunk| {
+            Field::from_bits_le(&chunk.iter().rev().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>())
+        });
+        Self { limbs: limbs.collect() }
+    }
+
+    /// Returns the integer as 32 bytes in big-endian order.
+    pub(super) fn to_bytes_be(&self) -> Vec<U8<E>> {
+        let mut bytes = self.to_bits_le().chunks(8).map(U8::from_bits_le).collect::<Vec<_>>();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Returns the integer as 256 bits in little-endian order.
+    pub(super) fn to_bits_le(&self) -> Vec<Boolean<E>> {
+        self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_SIZE_IN_BITS)).collect()
+    }
+
+    /// Returns `true` if the integer is odd.
+    pub(super) fn is_odd(&self) -> Boolean<E> {
+        self.limbs[0].to_lower_bits_le(LIMB_SIZE_IN_BITS)[0].clone()
+    }
+
+    /// Returns `true` if the integer is zero.
+    pub(super) fn is_zero(&self) -> Boolean<E> {
+        self.limbs.iter().fold(Boolean::constant(true), |is_zero, limb| is_zero & limb.is_zero())
+    }
+
+    /// Returns `true` if the integers are congruent modulo the given modulus, which must be in `(2^255, 2^256)`.
+    ///
+    /// As both integers are less than `2^256`, their difference `d` is a multiple of `m` if and only if `d ∈ {-m, 0, m}`.
+    /// The difference is split into `d = low + high * 2^128`, where `low, high ∈ (-2^128, 2^128)`. For each multiple
+    /// `c = c_low + c_high * 2^128` with `c_low ∈ [0, 2^128)`, `d == c` if and only if `(low, high) == (c_low, c_high)`
+    /// or `(low + 2^128, high - 1) == (c_low, c_high)`. As these values are small, they are compared in the base field.
+    pub(super) fn is_congruent(&self, other: &Self, modulus: &BigUint) -> Boolean<E> {
+        debug_assert_eq!(modulus.bits(), 256, "The modulus must be in (2^255, 2^256)");
+
+        let limb_shift = constant_field(&(BigInt::from(1u32) << LIMB_SIZE_IN_BITS));
+        let difference = |i: usize| &self.limbs[i] - &other.limbs[i];
+        let low = difference(0) + difference(1) * &limb_shift;
+        let high = difference(2) + difference(3) * &limb_shift;
+
+        let modulus = BigInt::from(modulus.clone());
+        let column = BigInt::from(1u32) << COLUMN_SIZE_IN_BITS;
+        let (carried_low, carried_high) = (&low + constant_field(&column), &high - Field::one());
+        [-&modulus, BigInt::default(), modulus].iter().fold(Boolean::constant(false), |is_congruent, multiple| {
+            let c_low = (multiple % &column + &column) % &column;
+            let c_high = (multiple - &c_low) >> COLUMN_SIZE_IN_BITS;
+            let (c_low, c_high) = (constant_field(&c_low), constant_field(&c_high));
+            is_congruent
+                | (low.is_equal(&c_low) & high.is_equal(&c_high))
+                | (carried_low.is_equal(&c_low) & carried_high.is_equal(&c_high))
+        })
+    }
+
+    /// Returns `true` if the integer is less than the given constant, which must be in `(0, 2^256]`.
+    pub(super) fn is_less_than(&self, constant: &BigUint) -> Boolean<E> {
+        // Let `k := 2^256 - constant`. Then `self < constant` if and only if `self + k < 2^256`.
+        let modulus = BigUint::from(1u32) << (NUM_LIMBS * LIMB_SIZE_IN_BITS);
+        let k = &modulus - constant;
+        let sum = self.eject_value() + &k;
+
+        // Witness the lower 256 bits of the sum, and whether the sum overflows.
+        let overflows = Boolean::new(Mode::Private, sum >= modulus);
+        let truncated = Self::new(&(sum % &modulus), NUM_LIMBS * LIMB_SIZE_IN_BITS);
+
+        // Enforce `self + k == truncated + overflows * 2^256`.
+        let mut columns = Columns::default();
+        columns.add_integer(false, 1, self);
+        columns.add_constant(false, &k);
+        columns.add_integer(true, 1, &truncated);
+        columns.add(
+            NUM_LIMBS,
+            true,
+            Field::from_boolean(&overflows),
+            (overflows.eject_value() as u64).into(),
+            1u32.into(),
+        );
+        columns.assert_zero();
+
+        !overflows
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        let limbs = first.limbs.iter().zip_eq(&second.limbs).map(|(a, b)| Field::ternary(condition, a, b));
+        Self { limbs: limbs.collect() }
+    }
+
+    /// Returns the integer selected by the given one-hot indicators.
+    pub(super) fn select(indicators: &[Field<E>], integers: &[&Self]) -> Self {
+        let limbs = (0..NUM_LIMBS).map(|i| {
+            indicators
+                .iter()
+                .zip_eq(integers)
+                .fold(Field::zero(), |limb, (indicator, integer)| limb + indicator * &integer.limbs[i])
+        });
+        Self { limbs: limbs.collect() }
+    }
+
+    /// Ejects the value of the integer.
+    pub(super) fn eject_value(&self) -> BigUint {
+        from_limbs(&eject_limbs(&self.limbs))
+    }
+
+    /// Returns an upper bound on the value of each limb.
+    fn limb_bounds(&self) -> Vec<u64> {
+        let values = eject_limbs(&self.limbs);
+        self.limbs
+            .iter()
+            .zip_eq(values)
+            .map(|(limb, value)| if limb.is_constant() { value } else { u64::MAX })
+            .collect()
+    }
+
+    /// Returns an upper bound on the value of the integer.
+    fn bound(&self) -> BigUint {
+        from_limbs(&self.limb_bounds())
+    }
+}
+
+/// Returns the one-hot encoding of the given little-endian bits,
+/// i.e. the `i`-th indicator is `1` if and only if the bits encode `i`.
+pub(super) fn one_hot<E: Environment>(bits: &[Boolean<E>]) -> Vec<Field<E>> {
+    match bits {
+        [] => vec![Field::one()],
+        [bit] => vec![Field::from_boolean(&!bit), Field::from_boolean(bit)],
+        _ => {
+            let (lower, upper) = bits.split_at(bits.len() / 2);
+            let (lower, upper) = (one_hot(lower), one_hot(upper));
+            upper.iter().flat_map(|upper| lower.iter().map(move |lower| lower * upper)).collect()
+        }
+    }
+}
+
+/// A term in a modular relation.
+pub(super) enum Term<'a, E: Environment> {
+    /// The product of two integers, scaled by a coefficient.
+    Product(u64, &'a Uint256<E>, &'a Uint256<E>),
+    /// An integer, scaled by a coefficient.
+    Integer(u64, &'a Uint256<E>),
+    /// A constant.
+    Constant(BigUint),
+}
+
+impl<'a, E: Environment> Term<'a, E> {
+    /// Returns a copy of the term.
+    fn copy(&self) -> Term<'a, E> {
+        match self {
+            Self::Product(coefficient, a, b) => Term::Product(*coefficient, *a, *b),
+            Self::Integer(coefficient, a) => Term::Integer(*coefficient, *a),
+            Self::Constant(constant) => Term::Constant(constant.clone()),
+        }
+    }
+
+    /// Returns the value of the term.
+    fn eject_value(&self) -> BigUint {
+        match self {
+            Self::Product(coefficient, a, b) => a.eject_value() * b.eject_value() * *coefficient,
+            Self::Integer(coefficient, a) => a.eject_value() * *coefficient,
+            Self::Constant(constant) => constant.clone(),
+        }
+    }
+
+    /// Returns an upper bound on the value of the term.
+    fn bound(&self) -> BigUint {
+        match self {
+            Self::Product(coefficient, a, b) => a.bound() * b.bound() * *coefficient,
+            Self::Integer(coefficient, a) => a.bound() * *coefficient,
+            Self::Constant(constant) => constant.clone(),
+        }
+    }
+
+    /// Adds (or subtracts) the term to the given columns.
+    fn add_to(&self, columns: &mut Columns<E>, is_negative: bool) {
+        match self {
+            Self::Product(coefficient, a, b) => columns.add_product(is_negative, *coefficient, a, b),
+            Self::Integer(coefficient, a) => columns.add_integer(is_negative, *coefficient, a),
+            Self::Constant(constant) => columns.add_constant(is_negative, constant),
+        }
+    }
+}
+
+/// Enforces that `Σ positive - Σ negative = 0 (mod modulus)`.
+pub(super) fn assert_congruent<E: Environment>(modulus: &BigUint, positive: &[Term<E>], negative: &[Term<E>]) {
+    let offset = underflow_offset(modulus, negative);
+    let quotient_bound = (positive.iter().map(Term::bound).sum::<BigUint>() + &offset) / modulus;
+
+    // Witness the quotient `q`, such that `Σ positive - Σ negative + offset = q * modulus`.
+    let minuend = positive.iter().map(Term::eject_value).sum::<BigUint>() + &offset;
+    let subtrahend = negative.iter().map(Term::eject_value).sum::<BigUint>();
+    let quotient = match minuend >= subtrahend {
+        true => (minuend - subtrahend) / modulus,
+        // Note: This case only occurs for an invalid assignment, which will not satisfy the relation.
+        false => BigUint::default(),
+    };
+    let quotient = witness_limbs(&quotient, quotient_bound.bits() as usize);
+
+    // Enforce `Σ positive - Σ negative + offset - q * modulus == 0`.
+    let mut columns = Columns::default();
+    positive.iter().for_each(|term| term.add_to(&mut columns, false));
+    negative.iter().for_each(|term| term.add_to(&mut columns, true));
+    columns.add_constant(false, &offset);
+    let modulus_limbs = to_limbs(modulus, NUM_LIMBS);
+    let quotient_values = eject_limbs(&quotient);
+    for (i, (limb, value)) in quotient.iter().zip_eq(&quotient_values).enumerate() {
+        for (j, modulus_limb) in modulus_limbs.iter().enumerate() {
+            let product = limb * Field::constant(console::Field::from_u64(*modulus_limb));
+            columns.add(i + j, true, product, BigUint::from(*value) * *modulus_limb, max_limb() * *modulus_limb);
+        }
+    }
+    columns.assert_zero();
+}
+
+/// Returns a multiple of the modulus that is at least the sum of the bounds of the given terms,
+/// which offsets a relation so that it does not underflow.
+fn underflow_offset<E: Environment>(modulus: &BigUint, terms: &[Term<E>]) -> BigUint {
+    modulus * ((terms.iter().map(Term::bound).sum::<BigUint>() + modulus - 1u32) / modulus)
+}
+
+/// Returns an integer that is congruent to `Σ positive - Σ negative` modulo `modulus`.
+///
+/// Note: The result is less than `2^256`, but is not enforced to be less than `modulus`.
+pub(super) fn reduce<E: Environment>(modulus: &BigUint, positive: &[Term<E>], negative: &[Term<E>]) -> Uint256<E> {
+    reduce_with(modulus, positive, negative, |value| Uint256::new(value, NUM_LIMBS * LIMB_SIZE_IN_BITS))
+}
+
+/// Returns the integer less than `modulus` that is congruent to `Σ positive - Σ negative`.
+pub(super) fn reduce_canonical<E: Environment>(
+    modulus: &BigUint,
+    positive: &[Term<E>],
+    negative: &[Term<E>],
+) -> Uint256<E> {
+    reduce_with(modulus, positive, negative, |value| Uint256::new_canonical(value, modulus))
+}
+
+/// Witnesses the result of `Σ positive - Σ negative (mod modulus)` with the given initializer,
+/// and enforces it is congruent to `Σ positive - Σ negative`.
+fn reduce_with<E: Environment>(
+    modulus: &BigUint,
+    positive: &[Term<E>],
+    negative: &[Term<E>],
+    witness: impl FnOnce(&BigUint) -> Uint256<E>,
+) -> Uint256<E> {
+    // Compute the result.
+    let offset = underflow_offset(modulus, negative);
+    let minuend = positive.iter().map(Term::eject_value).sum::<BigUint>() + offset;
+    let subtrahend = negative.iter().map(Term::eject_value).sum::<BigUint>();
+    let value = match minuend >= subtrahend {
+        true => (minuend - subtrahend) % modulus,
+        false => BigUint::default(),
+    };
+
+    // Witness the result, and enforce it is congruent to `Σ positive - Σ negative`.
+    let result = witness(&value);
+    let negative = negative.iter().map(Term::copy).chain([Term::Integer(1, &result)]).collect::<Vec<_>>();
+    assert_congruent(modulus, positive, &negative);
+    result
+}
+
+/// The columns of an integer relation, where column `i` holds the terms with weight `2^(128 * i)`.
+struct Columns<E: Environment> {
+    /// The circuit value of each column.
+    values: Vec<Field<E>>,
+    /// The native value of each column.
+    natives: Vec<BigInt>,
+    /// An upper bound on the absolute value of each column.
+    bounds: Vec<BigUint>,
+}
+
+impl<E: Environment> Default for Columns<E> {
+    fn default() -> Self {
+        Self { values: Vec::new(), natives: Vec::new(), bounds: Vec::new() }
+    }
+}
+
+impl<E: Environment> Columns<E> {
+    /// Adds (or subtracts) the given term of weight `2^(64 * position)`.
+    fn add(&mut self, position: usize, is_negative: bool, value: Field<E>, native: BigUint, bound: BigUint) {
+        // Place the term in column `position / 2`, shifted by the position of its limb within the column.
+        let (index, shift) = (position / 2, (position % 2) * LIMB_SIZE_IN_BITS);
+        let value = match shift {
+            0 => value,
+            _ => value * constant_field(&(BigInt::from(1u32) << shift)),
+        };
+        let (native, bound) = (BigInt::from(native << shift), bound << shift);
+
+        if index >= self.values.len() {
+            self.values.resize(index + 1, Field::zero());
+            self.natives.resize(index + 1, BigInt::default());
+            self.bounds.resize(index + 1, BigUint::default());
+        }
+        match is_negative {
+            true => {
+                self.values[index] -= value;
+                self.natives[index] -= native;
+            }
+            false => {
+                self.values[index] += value;
+                self.natives[index] += native;
+            }
+        }
+        self.bounds[index] += bound;
+    }
+
+    /// Adds (or subtracts) the given integer, scaled by a coefficient.
+    fn add_integer(&mut self, is_negative: bool, coefficient: u64, integer: &Uint256<E>) {
+        let (values, bounds) = (eject_limbs(&integer.limbs), integer.limb_bounds());
+        for (i, ((limb, value), bound)) in integer.limbs.iter().zip_eq(values).zip_eq(bounds).enumerate() {
+            let limb = scale(limb.clone(), coefficient);
+            self.add(i, is_negative, limb, BigUint::from(value) * coefficient, BigUint::from(bound) * coefficient);
+        }
+    }
+
+    /// Adds (or subtracts) the product of the given integers, scaled by a coefficient.
+    fn add_product(&mut self, is_negative: bool, coefficient: u64, a: &Uint256<E>, b: &Uint256<E>) {
+        let (a_values, b_values) = (eject_limbs(&a.limbs), eject_limbs(&b.limbs));
+        let (a_bounds, b_bounds) = (a.limb_bounds(), b.limb_bounds());
+        for (i, a_limb) in a.limbs.iter().enumerate() {
+            for (j, b_limb) in b.limbs.iter().enumerate() {
+                let product = scale(a_limb * b_limb, coefficient);
+                let native = BigUint::from(a_values[i]) * b_values[j] * coefficient;
+                let bound = BigUint::from(a_bounds[i]) * b_bounds[j] * coefficient;
+                self.add(i + j, is_negative, product, native, bound);
+            }
+        }
+    }
+
+    /// Adds (or subtracts) the given constant.
+    fn add_constant(&mut self, is_negative: bool, constant: &BigUint) {
+        let num_limbs = (constant.bits() as usize).div_ceil(LIMB_SIZE_IN_BITS);
+        for (i, limb) in to_limbs(constant, num_limbs).into_iter().enumerate() {
+            self.add(i, is_negative, Field::constant(console::Field::from_u64(limb)), limb.into(), limb.into());
+        }
+    }
+
+    /// Enforces that the integer `Σ_i column_i * 2^(128 * i)` is zero.
+    ///
+    /// This witnesses a signed carry `c_i` for each column, and enforces
+    /// `column_0 == c_0 * 2^128`, `column_i + c_{i-1} == c_i * 2^128`, and `column_last + c_{last-1} == 0`.
+    /// Each carry is range-checked to its bound, so no relation can wrap around the base field.
+    fn assert_zero(self) {
+        let shift = constant_field(&(BigInt::from(1u32) << COLUMN_SIZE_IN_BITS));
+        let num_columns = self.values.len();
+
+        let (mut carry, mut carry_native, mut carry_bound) = (Field::zero(), BigInt::default(), BigUint::default());
+        for (i, ((value, native), bound)) in
+            self.values.into_iter().zip_eq(self.natives).zip_eq(self.bounds).enumerate()
+        {
+            let column = value + &carry;
+            let native = native + &carry_native;
+
+            // Enforce the last column, including its carry, is zero.
+            if i + 1 == num_columns {
+                E::assert_eq(column, Field::<E>::zero());
+                break;
+            }
+
+            // Witness the carry, offset by its bound to be non-negative.
+            carry_bound = (bound + &carry_bound) >> COLUMN_SIZE_IN_BITS;
+            carry_native = native >> COLUMN_SIZE_IN_BITS;
+            let offset_carry = (&carry_native + BigInt::from(carry_bound.clone())).to_biguint().unwrap_or_default();
+            let offset_carry = Field::new(Mode::Private, console::Field::from_u128(to_u128(&offset_carry)));
+            offset_carry.to_lower_bits_le((&carry_bound << 1u32).bits().max(1) as usize);
+            carry = offset_carry - Field::constant(console::Field::from_u128(to_u128(&carry_bound)));
+
+            // Enforce `column == carry * 2^128`.
+            E::assert_eq(column, &carry * &shift);
+        }
+    }
+}
+
+/// Returns the maximum value of a limb.
+fn max_limb() -> BigUint {
+    BigUint::from(u64::MAX)
+}
+
+/// Returns the given field element scaled by a constant coefficient.
+fn scale<E: Environment>(field: Field<E>, coefficient: u64) -> Field<E> {
+    match coefficient {
+        1 => field,
+        _ => field * Field::constant(console::Field::from_u64(coefficient)),
+    }
+}
+
+/// Returns the given integer, whose absolute value must be less than the base field modulus, as a constant.
+fn constant_field<E: Environment>(value: &BigInt) -> Field<E> {
+    let limb_shift = console::Field::<E::Network>::from_u128(1u128 << LIMB_SIZE_IN_BITS);
+    let magnitude = value
+        .magnitude()
+        .to_u64_digits()
+        .iter()
+        .rev()
+        .fold(console::Field::zero(), |magnitude, limb| magnitude * limb_shift + console::Field::from_u64(*limb));
+    match value.sign() {
+        num_bigint::Sign::Minus => Field::constant(-magnitude),
+        _ => Field::constant(magnitude),
+    }
+}
+
+/// Returns the given number of little-endian 64-bit limbs of the given integer.
+fn to_limbs(value: &BigUint, num_limbs: usize) -> Vec<u64> {
+    let mut limbs = value.to_u64_digits();
+    limbs.resize(num_limbs, 0);
+    limbs
+}
+
+/// Returns the integer with the given little-endian 64-bit limbs.
+fn from_limbs(limbs: &[u64]) -> BigUint {
+    limbs.iter().rev().fold(BigUint::default(), |value, limb| (value << LIMB_SIZE_IN_BITS) + *limb)
+}
+
+/// Returns the value of the given integer, which must be less than `2^128`.
+fn to_u128(value: &BigUint) -> u128 {
+    let limbs = to_limbs(value, 2);
+    (limbs[1] as u128) << LIMB_SIZE_IN_BITS | limbs[0] as u128
+}
+
+/// Returns the values of the given limbs.
+fn eject_limbs<E: Environment>(limbs: &[Field<E>]) -> Vec<u64> {
+    limbs
+        .iter()
+        .map(|limb| {
+            let bits_le = limb.eject_value().to_bits_le();
+            bits_le.iter().take(LIMB_SIZE_IN_BITS).rev().fold(0u64, |value, bit| value << 1 | *bit as u64)
+        })
+        .collect()
+}
+
+/// Returns private limbs for the given integer, which are enforced to compose an integer less than `2^num_bits`.
+fn witness_limbs<E: Environment>(value: &BigUint, num_bits: usize) -> Vec<Field<E>> {
+    let num_limbs = num_bits.div_ceil(LIMB_SIZE_IN_BITS).max(1);
+    to_limbs(value, num_limbs)
+        .into_iter()
+        .enumerate()
+        .map(|(i, limb)| {
+            let limb = Field::new(Mode::Private, console::Field::from_u64(limb));
+            limb.to_lower_bits_le(num_bits.saturating_sub(i * LIMB_SIZE_IN_BITS).min(LIMB_SIZE_IN_BITS));
+            limb
+        })
+        .collect()
+}
@@ -0,0 +1,471 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod integer;
+mod native;
+mod point;
+
+use integer::{assert_congruent, one_hot, reduce, reduce_canonical, Term, Uint256};
+use native::{NativePoint, Parameters, FIXED_BASE_WINDOW_SIZE, HALF_SCALAR_SIZE_IN_BITS};
+use point::Point;
+
+use crate::{Hash, Keccak256};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U8};
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint};
+
+/// The secp256k1 ECDSA signature scheme.
+///
+/// The signature, verifier, and digest follow the encodings of `console::ECDSASecp256k1`,
+/// and the output matches the console verification for every input.
+///
+/// Note: For adversarially-chosen inputs (e.g. a public key that is a known multiple of an offset point,
+/// so that the scalar multiplication reaches an exceptional case of the incomplete addition formulas),
+/// the circuit is unsatisfiable, rather than `false`.
+/// These cases occur with negligible probability for honestly-generated signatures and keys.
+pub struct ECDSASecp256k1<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> ECDSASecp256k1<E> {
+    /// Returns `true` if the signature is valid for the given verifier and message digest.
+    pub fn verify_digest(signature: &[U8<E>], verifier: &[U8<E>], digest: &[U8<E>]) -> Boolean<E> {
+        // Ensure the inputs are well-formed.
+        if !console::ECDSASecp256k1::is_valid_signature_size(signature.len()) {
+            E::halt(format!("Invalid ECDSA signature size: expected 64 or 65 bytes, found {} bytes", signature.len()))
+        }
+        if !console::ECDSASecp256k1::is_valid_verifier_size(verifier.len()) {
+            E::halt(format!(
+                "Invalid ECDSA verifier size: expected 20, 33, or 65 bytes, found {} bytes",
+                verifier.len()
+            ))
+        }
+        if digest.len() != console::ECDSASecp256k1::DIGEST_SIZE_IN_BYTES {
+            E::halt(format!("Invalid ECDSA digest size: expected 32 bytes, found {} bytes", digest.len()))
+        }
+
+        // If all of the inputs are constant, return the console verification as a constant.
+        if signature.iter().chain(verifier).chain(digest).all(|byte| byte.is_constant()) {
+            let eject = |bytes: &[U8<E>]| bytes.iter().map(|byte| *byte.eject_value()).collect::<Vec<_>>();
+            return match console::ECDSASecp256k1::verify_digest(&eject(signature), &eject(verifier), &eject(digest)) {
+                Ok(is_valid) => Boolean::constant(is_valid),
+                Err(error) => E::halt(error.to_string()),
+            };
+        }
+
+        let Parameters { p, n, .. } = Parameters::get();
+
+        // Parse the signature, substituting `1` for an invalid `r` or `s`.
+        let r = Uint256::from_bytes_be(&signature[..32]);
+        let s = Uint256::from_bytes_be(&signature[32..64]);
+        // Ensure `0 < r < n`.
+        let is_r_valid = !r.is_zero() & r.is_less_than(n);
+        // Ensure `0 < s <= (n - 1) / 2`, as high-`s` signatures are rejected.
+        let is_s_valid = !s.is_zero() & s.is_less_than(&((n >> 1) + 1u32));
+        let one = Uint256::constant(&BigUint::from(1u32));
+        let r = Uint256::ternary(&is_r_valid, &r, &one);
+        let s = Uint256::ternary(&is_s_valid, &s, &one);
+
+        // Parse the digest `z`, which is only used modulo `n`.
+        let z = Uint256::from_bytes_be(digest);
+
+        let is_verified = match verifier.len() {
+            // If the verifier is an Ethereum address, recover the public key and compare the addresses.
+            console::ECDSASecp256k1::ETH_ADDRESS_SIZE_IN_BYTES => {
+                // Retrieve the recovery ID.
+                let recovery_id = match signature.get(console::ECDSASecp256k1::SIGNATURE_SIZE_IN_BYTES) {
+                    Some(recovery_id) => recovery_id,
+                    None => E::halt(
+                        "An ECDSA signature must contain a recovery ID to be verified against an Ethereum address",
+                    ),
+                };
+                // Normalize the recovery ID, as Ethereum adds an offset of 27.
+                let is_equal = |value: u8| recovery_id.is_equal(&U8::constant(console::U8::new(value)));
+                let is_y_odd = is_equal(1) | is_equal(28);
+                let is_recovery_id_valid = is_equal(0) | is_equal(27) | &is_y_odd;
+
+                // Recover the point `R`, whose x-coordinate is `r` and whose y-coordinate has the given parity.
+                let (point_r, is_r_on_curve) = Self::decompress(&r, &is_y_odd);
+
+                // Compute `u_1 := -z * r^-1 (mod n)` and `u_2 := s * r^-1 (mod n)`.
+                let r_inverse = Uint256::new(&native::inverse(&r.eject_value(), n), 256);
+                assert_congruent(n, &[Term::Product(1, &r, &r_inverse)], &[Term::Constant(BigUint::from(1u32))]);
+                let u_1 = reduce(n, &[], &[Term::Product(1, &z, &r_inverse)]);
+                let u_2 = reduce(n, &[Term::Product(1, &s, &r_inverse)], &[]);
+
+                // Compute the public key `Q := u_1 * G + u_2 * R`.
+                // Note: The recovered key verifies the signature by construction, as `z * s^-1 * G + r * s^-1 * Q == R`.
+                let (public_key, is_infinity) = Self::multi_scalar_mul(&u_1, &u_2, &point_r);
+
+                // Compute the Ethereum address, as the last 20 bytes of the Keccak-256 hash of the public key.
+                let public_key = public_key.to_canonical();
+                let preimage = public_key.x.to_bytes_be().into_iter().chain(public_key.y.to_bytes_be());
+                let hash = Keccak256::<E>::new().hash(&preimage.flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>());
+                let address = hash[96..].chunks(8).map(U8::from_bits_le);
+                let is_address_equal =
+                    address.zip_eq(verifier).fold(Boolean::constant(true), |is_equal, (a, b)| is_equal & a.is_equal(b));
+
+                is_recovery_id_valid & is_r_on_curve & !is_infinity & is_address_equal
+            }
+            // Otherwise, parse the public key and verify the signature.
+            _ => {
+                let (public_key, is_public_key_valid) = Self::parse_public_key(verifier);
+
+                // Compute `u_1 := z * s^-1 (mod n)` and `u_2 := r * s^-1 (mod n)`.
+                let s_inverse = Uint256::new(&native::inverse(&s.eject_value(), n), 256);
+                assert_congruent(n, &[Term::Product(1, &s, &s_inverse)], &[Term::Constant(BigUint::from(1u32))]);
+                let u_1 = reduce(n, &[Term::Product(1, &z, &s_inverse)], &[]);
+                let u_2 = reduce(n, &[Term::Product(1, &r, &s_inverse)], &[]);
+
+                // Compute `R := u_1 * G + u_2 * Q`.
+                let (point_r, is_infinity) = Self::multi_scalar_mul(&u_1, &u_2, &public_key);
+
+                // Ensure `R.x == r (mod n)`. As `R.x < p < 2n` and `r < n`, this holds if `R.x - r ∈ {0, n}`.
+                let x = reduce_canonical(p, &[Term::Integer(1, &point_r.x)], &[]);
+                let is_x_equal = x.is_congruent(&r, n);
+
+                is_public_key_valid & !is_infinity & is_x_equal
+            }
+        };
+
+        is_r_valid & is_s_valid & is_verified
+    }
+
+    /// Returns `true` if the signature is valid for the given verifier and the Keccak-256 digest of the message.
+    pub fn verify_keccak256(signature: &[U8<E>], verifier: &[U8<E>], message: &[U8<E>]) -> Boolean<E> {
+        // Compute the Keccak-256 digest of the message.
+        let hash = Keccak256::<E>::new().hash(&message.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>());
+        let digest = hash.chunks(8).map(U8::from_bits_le).collect::<Vec<_>>();
+        // Verify the signature.
+        Self::verify_digest(signature, verifier, &digest)
+    }
+}
+
+impl<E: Environment> ECDSASecp256k1<E> {
+    /// Returns the public key encoded in the given compressed (33 bytes) or uncompressed (65 bytes) SEC1 bytes,
+    /// and whether the encoding is valid. If the encoding is invalid, the point `2G` is returned.
+    fn parse_public_key(bytes: &[U8<E>]) -> (Point<E>, Boolean<E>) {
+        let parameters = Parameters::get();
+        let p = &parameters.p;
+
+        let prefix = &bytes[0];
+        let is_prefix = |value: u8| prefix.is_equal(&U8::constant(console::U8::new(value)));
+        let x = Uint256::from_bytes_be(&bytes[1..33]);
+
+        let (point, is_valid) = match bytes.len() {
+            console::ECDSASecp256k1::COMPRESSED_PUBLIC_KEY_SIZE_IN_BYTES => {
+                // Decompress the point, using the parity of the y-coordinate from the prefix.
+                let is_y_odd = is_prefix(3);
+                let (point, is_on_curve) = Self::decompress(&x, &is_y_odd);
+                (point, (is_prefix(2) | is_y_odd) & is_on_curve)
+            }
+            _ => {
+                let y = Uint256::from_bytes_be(&bytes[33..65]);
+                // Ensure `y^2 == x^3 + 7 (mod p)`.
+                let y_squared = reduce(p, &[Term::Product(1, &y, &y)], &[]);
+                let is_on_curve = y_squared.is_congruent(&Self::curve_equation(&x), p);
+                let is_valid = is_prefix(4) & y.is_less_than(p) & is_on_curve;
+                (Point { x: x.clone(), y }, is_valid)
+            }
+        };
+
+        // Ensure the x-coordinate is less than `p`.
+        let is_valid = is_valid & x.is_less_than(p);
+        (Point::ternary(&is_valid, &point, &Point::constant(&parameters.substitute)), is_valid)
+    }
+
+    /// Returns the point with the given x-coordinate and y-coordinate parity,
+    /// and whether such a point exists. If it does not exist, the point `2G` is returned.
+    fn decompress(x: &Uint256<E>, is_y_odd: &Boolean<E>) -> (Point<E>, Boolean<E>) {
+        let parameters = Parameters::get();
+        let p = &parameters.p;
+
+        // Compute `x^3 + 7 (mod p)`, and its negation.
+        let rhs = Self::curve_equation(x);
+        let neg_rhs = reduce(p, &[], &[Term::Integer(1, &rhs)]);
+
+        // Witness `y`, such that `y^2 == x^3 + 7` if it is a square, and otherwise `y^2 == -(x^3 + 7)`.
+        // Note: As `p = 3 (mod 4)`, `-1` is not a square, so exactly one of `x^3 + 7` and `-(x^3 + 7)` is a square.
+        let rhs_value = rhs.eject_value() % p;
+        let (y, is_square) = match native::sqrt(&rhs_value, p) {
+            Some(y) => (y, true),
+            None => (native::sqrt(&native::sub(p, &rhs_value, p), p).unwrap_or_default(), false),
+        };
+        // Select the root with the given parity.
+        let y = match y.bit(0) == is_y_odd.eject_value() {
+            true => y,
+            false => native::sub(p, &y, p),
+        };
+        let y = Uint256::new_canonical(&y, p);
+        let is_square = Boolean::new(Mode::Private, is_square);
+
+        // Enforce `y^2 == ±(x^3 + 7) (mod p)`, and that `y` has the given parity.
+        let square = Uint256::ternary(&is_square, &rhs, &neg_rhs);
+        assert_congruent(p, &[Term::Product(1, &y, &y)], &[Term::Integer(1, &square)]);
+        E::assert_eq(y.is_odd(), is_y_odd);
+
+        let point = Point { x: x.clone(), y };
+        (Point::ternary(&is_square, &point, &Point::constant(&parameters.substitute)), is_square)
+    }
+
+    /// Returns `x^3 + 7 (mod p)`.
+    fn curve_equation(x: &Uint256<E>) -> Uint256<E> {
+        let p = &Parameters::get().p;
+        let x_squared = reduce(p, &[Term::Product(1, x, x)], &[]);
+        reduce(p, &[Term::Product(1, &x_squared, x), Term::Constant(BigUint::from(7u32))], &[])
+    }
+
+    /// Returns `u_1 * G + u_2 * Q`, and whether the result is the point at infinity.
+    ///
+    /// The product `u_1 * G` sums one entry of a constant table for each 8-bit window of `u_1`.
+    /// The product `u_2 * Q` decomposes `u_2 == k_1 + k_2 * λ (mod n)` into 128-bit halves, using the endomorphism
+    /// `φ(Q) = λ * Q`, and computes `k_1 * Q + k_2 * φ(Q)` with a joint double-and-add over 2-bit windows.
+    /// Each table entry is offset by a multiple of an offset point `A`, so that the incomplete addition formulas
+    /// do not encounter the point at infinity. The sum `K` of these offsets is subtracted at the end.
+    fn multi_scalar_mul(u_1: &Uint256<E>, u_2: &Uint256<E>, q: &Point<E>) -> (Point<E>, Boolean<E>) {
+        let parameters = Parameters::get();
+        let (p, n) = (&parameters.p, &parameters.n);
+        let to_points = |table: &[NativePoint]| table.iter().map(Point::constant).collect::<Vec<_>>();
+
+        // Compute `u_1 * G + (2^32 - 1) * A`, where table `i` holds `d * 2^(8i) * G + 2^i * A` at index `d`.
+        let mut windows = u_1.to_bits_le().chunks(FIXED_BASE_WINDOW_SIZE).map(<[_]>::to_vec).collect::<Vec<_>>();
+        let tables = &parameters.fixed_base_tables;
+        let mut fixed_base_product = Point::select(&windows.remove(0), &to_points(&tables[0]));
+        for (window, table) in windows.iter().zip_eq(&tables[1..]) {
+            fixed_base_product = fixed_base_product.add(&Point::select(window, &to_points(table)));
+        }
+
+        // Decompose `u_2 == k_1 + k_2 * λ (mod n)`, where `|k_1|, |k_2| < 2^128`.
+        let [(k_1, is_k_1_negative), (k_2, is_k_2_negative)] = parameters.decompose(&(u_2.eject_value() % n));
+        let k_1 = Uint256::new(&k_1, HALF_SCALAR_SIZE_IN_BITS);
+        let k_2 = Uint256::new(&k_2, HALF_SCALAR_SIZE_IN_BITS);
+        let is_k_1_negative = Boolean::new(Mode::Private, is_k_1_negative);
+        let is_k_2_negative = Boolean::new(Mode::Private, is_k_2_negative);
+
+        // Enforce `|k_1| + |k_2| * λ - 2 * (|k_1| if k_1 < 0) - 2 * (|k_2| if k_2 < 0) * λ == u_2 (mod n)`.
+        let zero = Uint256::constant(&BigUint::default());
+        let negative_k_1 = Uint256::ternary(&is_k_1_negative, &k_1, &zero);
+        let negative_k_2 = Uint256::ternary(&is_k_2_negative, &k_2, &zero);
+        let lambda = Uint256::constant(&parameters.lambda);
+        assert_congruent(n, &[Term::Integer(1, &k_1), Term::Product(1, &lambda, &k_2)], &[
+            Term::Integer(2, &negative_k_1),
+            Term::Product(2, &lambda, &negative_k_2),
+            Term::Integer(1, u_2),
+        ]);
+
+        // Compute `P_1 := ±Q` and `P_2 := ±φ(Q)`, where `φ(Q) = (β * Q.x, Q.y)`, so that `u_2 * Q == |k_1| * P_1 + |k_2| * P_2`.
+        let neg_y = reduce(p, &[], &[Term::Integer(1, &q.y)]);
+        let p_1 = Point { x: q.x.clone(), y: Uint256::ternary(&is_k_1_negative, &neg_y, &q.y) };
+        let p_2 = Point {
+            x: reduce(p, &[Term::Product(1, &Uint256::constant(&parameters.beta), &q.x)], &[]),
+            y: Uint256::ternary(&is_k_2_negative, &neg_y, &q.y),
+        };
+
+        // Compute the table of `B + i * P_1 + j * P_2` at index `i + 4 * j`, for `i, j ∈ [0, 4)`.
+        let mut table = vec![Point::constant(&parameters.table_offset)];
+        for i in 1..4 {
+            table.push(table[i - 1].add(&p_1));
+        }
+        for index in 4..16 {
+            table.push(table[index - 4].add(&p_2));
+        }
+
+        // Compute `|k_1| * P_1 + |k_2| * P_2 + (4^64 - 1) / 3 * B`, from the most significant window.
+        let (bits_1, bits_2) = (k_1.to_bits_le(), k_2.to_bits_le());
+        let window = |i: usize| [&bits_1[2 * i..2 * i + 2], &bits_2[2 * i..2 * i + 2]].concat();
+        let num_windows = HALF_SCALAR_SIZE_IN_BITS / 2;
+        let mut variable_base_product = Point::select(&window(num_windows - 1), &table);
+        for i in (0..num_windows - 1).rev() {
+            variable_base_product = variable_base_product.double().double_and_add(&Point::select(&window(i), &table));
+        }
+
+        // Compute `u_1 * G + u_2 * Q + K`.
+        let sum = fixed_base_product.add(&variable_base_product);
+
+        // Subtract `K`. If the sum has the same x-coordinate as `K`,
+        // the result is either the point at infinity, or `2 * sum` (as `sum == -K`).
+        // Note: In this case, `A` is added instead of `-K`, so that the addition is well-defined.
+        let accumulated_offset = Point::constant(&parameters.accumulated_offset);
+        let is_x_equal = sum.x.is_congruent(&accumulated_offset.x, p);
+        let is_infinity = &is_x_equal & sum.y.is_congruent(&accumulated_offset.y, p);
+        let addend = Point::ternary(
+            &is_x_equal,
+            &Point::constant(&parameters.offset),
+            &Point::constant(&parameters.neg_accumulated_offset),
+        );
+        let difference = sum.add(&addend);
+        (Point::ternary(&is_x_equal, &sum.double(), &difference), is_infinity)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use k256::ecdsa::SigningKey;
+
+    const ITERATIONS: usize = 100;
+
+    /// Samples a random signing key.
+    fn sample_signing_key(rng: &mut TestRng) -> SigningKey {
+        loop {
+            if let Ok(signing_key) = SigningKey::from_slice(&<[u8; 32]>::rand(rng)) {
+                return signing_key;
+            }
+        }
+    }
+
+    /// Signs the given digest, returning the signature as `r || s || v`.
+    fn sign(signing_key: &SigningKey, digest: &[u8; 32]) -> Vec<u8> {
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(digest).unwrap();
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte());
+        bytes
+    }
+
+    /// Injects the given bytes in the given mode.
+    fn inject(mode: Mode, bytes: &[u8]) -> Vec<U8<Circuit>> {
+        bytes.iter().map(|byte| U8::new(mode, console::U8::new(*byte))).collect()
+    }
+
+    fn check_verify_digest(mode: Mode, signature: &[u8], verifier: &[u8], digest: &[u8]) {
+        let expected = console::ECDSASecp256k1::verify_digest(signature, verifier, digest).unwrap();
+
+        let (signature, verifier, digest) = (inject(mode, signature), inject(mode, verifier), inject(mode, digest));
+        let candidate = ECDSASecp256k1::<Circuit>::verify_digest(&signature, &verifier, &digest);
+        assert_eq!(expected, candidate.eject_value());
+        assert_eq!(mode.is_constant(), candidate.is_constant());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_digest() {
+        let mut rng = TestRng::default();
+
+        let signing_key = sample_signing_key(&mut rng);
+        let verifying_key = signing_key.verifying_key();
+        let digest = <[u8; 32]>::rand(&mut rng);
+        let signature = sign(&signing_key, &digest);
+
+        let compressed = verifying_key.to_encoded_point(true);
+        let uncompressed = verifying_key.to_encoded_point(false);
+        let address = console::ECDSASecp256k1::to_eth_address(verifying_key);
+
+        for mode in [Mode::Constant, Mode::Private] {
+            // Check the signature verifies against all verifier encodings.
+            check_verify_digest(mode, &signature[..64], compressed.as_bytes(), &digest);
+            check_verify_digest(mode, &signature, uncompressed.as_bytes(), &digest);
+            check_verify_digest(mode, &signature, &address, &digest);
+
+            // Check the signature fails for a different digest.
+            let other_digest = <[u8; 32]>::rand(&mut rng);
+            check_verify_digest(mode, &signature, compressed.as_bytes(), &other_digest);
+            check_verify_digest(mode, &signature, &address, &other_digest);
+        }
+    }
+
+    #[test]
+    fn test_verify_digest_with_small_keys() {
+        let mut rng = TestRng::default();
+
+        // Check the signatures of the keys `G`, `2G`, and `-G` verify.
+        let n_minus_one = (&Parameters::get().n - 1u32).to_bytes_be();
+        for secret_key in [BigUint::from(1u32).to_bytes_be(), BigUint::from(2u32).to_bytes_be(), n_minus_one] {
+            let mut bytes = [0u8; 32];
+            bytes[32 - secret_key.len()..].copy_from_slice(&secret_key);
+            let signing_key = SigningKey::from_slice(&bytes).unwrap();
+
+            let digest = <[u8; 32]>::rand(&mut rng);
+            let signature = sign(&signing_key, &digest);
+            let compressed = signing_key.verifying_key().to_encoded_point(true);
+            check_verify_digest(Mode::Private, &signature, compressed.as_bytes(), &digest);
+        }
+    }
+
+    #[test]
+    fn test_decompose() {
+        let mut rng = TestRng::default();
+        let parameters = Parameters::get();
+
+        for _ in 0..ITERATIONS {
+            let k = BigUint::from_bytes_be(&<[u8; 32]>::rand(&mut rng)) % &parameters.n;
+            let [(k_1, is_k_1_negative), (k_2, is_k_2_negative)] = parameters.decompose(&k);
+            assert!(k_1.bits() <= HALF_SCALAR_SIZE_IN_BITS as u64);
+            assert!(k_2.bits() <= HALF_SCALAR_SIZE_IN_BITS as u64);
+
+            // Check `k == k_1 + k_2 * λ (mod n)`.
+            let signed = |k: BigUint, is_negative: bool| match is_negative {
+                true => native::sub(&parameters.n, &k, &parameters.n),
+                false => k,
+            };
+            let candidate = signed(k_1, is_k_1_negative) + signed(k_2, is_k_2_negative) * &parameters.lambda;
+            assert_eq!(k, candidate % &parameters.n);
+        }
+    }
+
+    #[test]
+    fn test_verify_digest_rejects_invalid_inputs() {
+        let mut rng = TestRng::default();
+
+        let signing_key = sample_signing_key(&mut rng);
+        let digest = <[u8; 32]>::rand(&mut rng);
+        let signature = sign(&signing_key, &digest);
+        let compressed = signing_key.verifying_key().to_encoded_point(true);
+        let address = console::ECDSASecp256k1::to_eth_address(signing_key.verifying_key());
+
+        // Check a high-`s` signature is rejected.
+        let mut high_s = signature.clone();
+        let s = BigUint::from_bytes_be(&signature[32..64]);
+        let high = (&Parameters::get().n - s).to_bytes_be();
+        high_s[32..64].fill(0);
+        high_s[64 - high.len()..64].copy_from_slice(&high);
+        check_verify_digest(Mode::Private, &high_s, compressed.as_bytes(), &digest);
+
+        // Check a zero signature is rejected.
+        check_verify_digest(Mode::Private, &[0u8; 65], compressed.as_bytes(), &digest);
+        check_verify_digest(Mode::Private, &[0u8; 65], &address, &digest);
+
+        // Check an invalid recovery ID is rejected.
+        let mut invalid_recovery_id = signature.clone();
+        invalid_recovery_id[64] = 5;
+        check_verify_digest(Mode::Private, &invalid_recovery_id, &address, &digest);
+
+        // Check an invalid public key is rejected.
+        check_verify_digest(Mode::Private, &signature, &[0u8; 33], &digest);
+        let mut invalid_prefix = compressed.as_bytes().to_vec();
+        invalid_prefix[0] = 5;
+        check_verify_digest(Mode::Private, &signature, &invalid_prefix, &digest);
+    }
+
+    #[test]
+    fn test_verify_keccak256() {
+        let mut rng = TestRng::default();
+
+        let signing_key = sample_signing_key(&mut rng);
+        let message = (0..u8::rand(&mut rng)).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+        let signature = sign(&signing_key, &console::ECDSASecp256k1::keccak256(&message));
+        let address = console::ECDSASecp256k1::to_eth_address(signing_key.verifying_key());
+
+        let candidate = ECDSASecp256k1::<Circuit>::verify_keccak256(
+            &inject(Mode::Private, &signature),
+            &inject(Mode::Public, &address),
+            &inject(Mode::Private, &message),
+        );
+        assert!(candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::sync::OnceLock;
+
+/// An affine point on secp256k1, as a pair of canonical coordinates.
+pub(super) type NativePoint = (BigUint, BigUint);
+
+/// The number of bits in a window of the fixed-base scalar multiplication.
+pub(super) const FIXED_BASE_WINDOW_SIZE: usize = 8;
+/// The number of windows in the fixed-base scalar multiplication.
+pub(super) const NUM_FIXED_BASE_WINDOWS: usize = 256 / FIXED_BASE_WINDOW_SIZE;
+/// The number of bits in each half of a scalar, after the endomorphism decomposition.
+pub(super) const HALF_SCALAR_SIZE_IN_BITS: usize = 128;
+
+/// The native secp256k1 parameters, used to compute the constants and witnesses of the circuit.
+pub(super) struct Parameters {
+    /// The base field modulus `p`.
+    pub(super) p: BigUint,
+    /// The scalar field modulus (i.e. the group order) `n`.
+    pub(super) n: BigUint,
+    /// The generator `G`.
+    pub(super) generator: NativePoint,
+    /// The point `2G`, which is substituted for an invalid public key.
+    pub(super) substitute: NativePoint,
+    /// The eigenvalue `λ` of the endomorphism `φ(x, y) = (β * x, y)`, such that `φ(P) = λ * P`.
+    pub(super) lambda: BigUint,
+    /// The cube root of unity `β` of the endomorphism.
+    pub(super) beta: BigUint,
+    /// The offset point `A`, from which the offsets of the scalar multiplication are derived.
+    pub(super) offset: NativePoint,
+    /// The fixed-base tables, where entry `d` of table `i` is `d * 2^(8i) * G + 2^i * A`.
+    pub(super) fixed_base_tables: Vec<Vec<NativePoint>>,
+    /// The point `B := 2^32 * A`, which offsets each entry of the variable-base table.
+    pub(super) table_offset: NativePoint,
+    /// The point `-K`, where `K` is the sum of the offsets that remain after the scalar multiplication.
+    pub(super) neg_accumulated_offset: NativePoint,
+    /// The point `K`.
+    pub(super) accumulated_offset: NativePoint,
+}
+
+impl Parameters {
+    /// The domain used to derive the offset point.
+    const OFFSET_DOMAIN: &'static [u8] = b"snarkVM.ECDSA.secp256k1.offset";
+
+    /// Returns the secp256k1 parameters.
+    pub(super) fn get() -> &'static Self {
+        static PARAMETERS: OnceLock<Parameters> = OnceLock::new();
+        PARAMETERS.get_or_init(Self::new)
+    }
+
+    /// Initializes the secp256k1 parameters.
+    fn new() -> Self {
+        let p = from_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let n = from_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+        let generator = (
+            from_hex("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+            from_hex("483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"),
+        );
+        let substitute = double(&generator, &p);
+        let lambda = from_hex("5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72");
+        let beta = from_hex("7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee");
+
+        // Derive the offset point from the smallest valid x-coordinate, starting at the hash of the domain.
+        let mut x = BigUint::from_bytes_be(&console::ECDSASecp256k1::keccak256(Self::OFFSET_DOMAIN)) % &p;
+        let offset = loop {
+            if let Some(y) = sqrt(&((x.modpow(&BigUint::from(3u32), &p) + 7u32) % &p), &p) {
+                break (x, y);
+            }
+            x = (x + 1u32) % &p;
+        };
+
+        // Compute the fixed-base tables. Each table is offset by a distinct multiple of `A`,
+        // so that no partial sum of the scalar multiplication is equal to the next entry.
+        let mut fixed_base_tables = Vec::with_capacity(NUM_FIXED_BASE_WINDOWS);
+        let (mut base, mut table_offset) = (generator.clone(), offset.clone());
+        for _ in 0..NUM_FIXED_BASE_WINDOWS {
+            let mut table = vec![table_offset.clone()];
+            for _ in 1..1 << FIXED_BASE_WINDOW_SIZE {
+                table.push(add(table.last().unwrap(), &base, &p));
+            }
+            fixed_base_tables.push(table);
+            base = (0..FIXED_BASE_WINDOW_SIZE).fold(base, |point, _| double(&point, &p));
+            table_offset = double(&table_offset, &p);
+        }
+
+        // Compute `K := (2^32 - 1) * A + (4^64 - 1) / 3 * B`, which is the sum of the offsets
+        // of the fixed-base tables, and the offset `B` accumulated in each window of the variable-base table.
+        let num_windows = HALF_SCALAR_SIZE_IN_BITS / 2;
+        let multiple = (BigUint::from(1u32) << NUM_FIXED_BASE_WINDOWS) - 1u32
+            + ((((BigUint::from(1u32) << (2 * num_windows)) - 1u32) / 3u32) << NUM_FIXED_BASE_WINDOWS);
+        let accumulated_offset = mul(&multiple, &offset, &p);
+        let neg_accumulated_offset = neg(&accumulated_offset, &p);
+        debug_assert!(offset.0 != accumulated_offset.0, "The offset point must not be a multiple of its sum");
+
+        Self {
+            p,
+            n,
+            generator,
+            substitute,
+            lambda,
+            beta,
+            offset,
+            fixed_base_tables,
+            table_offset,
+            neg_accumulated_offset,
+            accumulated_offset,
+        }
+    }
+
+    /// Returns `(|k_1|, k_1 < 0)` and `(|k_2|, k_2 < 0)`, such that `k == k_1 + k_2 * λ (mod n)`,
+    /// where `|k_1|` and `|k_2|` are less than `2^128`.
+    pub(super) fn decompose(&self, k: &BigUint) -> [(BigUint, bool); 2] {
+        // The short basis `{(a_1, b_1), (a_2, b_2)}` of the lattice `{(x, y) : x + y * λ == 0 (mod n)}`,
+        // where `b_1 = -|b_1|` and `b_2 = a_1`.
+        let a_1 = BigInt::from(from_hex("3086d221a7d46bcde86c90e49284eb15"));
+        let b_1 = BigInt::from(from_hex("e4437ed6010e88286f547fa90abfe4c3"));
+        let a_2 = BigInt::from(from_hex("114ca50f7a8e2f3f657c1108d9d44cfd8"));
+
+        // Round `k` to the nearest lattice point, i.e. `c_1 := round(b_2 * k / n)` and `c_2 := round(|b_1| * k / n)`.
+        let (k, n) = (BigInt::from(k.clone()), BigInt::from(self.n.clone()));
+        let c_1 = (&a_1 * &k + (&n >> 1)) / &n;
+        let c_2 = (&b_1 * &k + (&n >> 1)) / &n;
+
+        let k_1 = k - &c_1 * &a_1 - &c_2 * &a_2;
+        let k_2 = c_1 * &b_1 - c_2 * &a_1;
+        [k_1, k_2].map(|k| (k.magnitude().clone(), k.sign() == num_bigint::Sign::Minus))
+    }
+}
+
+/// Returns the integer with the given hexadecimal encoding.
+fn from_hex(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.as_bytes(), 16).expect("Invalid secp256k1 constant")
+}
+
+/// Returns `a - b` modulo `modulus`.
+pub(super) fn sub(a: &BigUint, b: &BigUint, modulus: &BigUint) -> BigUint {
+    (a % modulus + modulus - b % modulus) % modulus
+}
+
+/// Returns the inverse of `a` modulo the prime `modulus`, or zero if `a` is zero.
+pub(super) fn inverse(a: &BigUint, modulus: &BigUint) -> BigUint {
+    a.modpow(&(modulus - 2u32), modulus)
+}
+
+/// Returns the square root of `a` modulo the prime `modulus`, if it exists.
+/// This assumes `modulus = 3 (mod 4)`, which holds for the secp256k1 base field.
+pub(super) fn sqrt(a: &BigUint, modulus: &BigUint) -> Option<BigUint> {
+    let root = a.modpow(&((modulus + 1u32) >> 2), modulus);
+    match (&root * &root) % modulus == a % modulus {
+        true => Some(root),
+        false => None,
+    }
+}
+
+/// Returns the slope of the line through two points with distinct x-coordinates.
+pub(super) fn add_slope(a: &NativePoint, b: &NativePoint, p: &BigUint) -> BigUint {
+    sub(&b.1, &a.1, p) * inverse(&sub(&b.0, &a.0, p), p) % p
+}
+
+/// Returns the slope of the tangent line at a point with a non-zero y-coordinate.
+pub(super) fn double_slope(a: &NativePoint, p: &BigUint) -> BigUint {
+    (&a.0 * &a.0 * 3u32 % p) * inverse(&((&a.1 << 1) % p), p) % p
+}
+
+/// Returns `a + b` for two points with distinct x-coordinates.
+pub(super) fn add(a: &NativePoint, b: &NativePoint, p: &BigUint) -> NativePoint {
+    finish(&add_slope(a, b, p), a, &b.0, p)
+}
+
+/// Returns `2 * a` for a point with a non-zero y-coordinate.
+pub(super) fn double(a: &NativePoint, p: &BigUint) -> NativePoint {
+    finish(&double_slope(a, p), a, &a.0, p)
+}
+
+/// Returns `-a`.
+pub(super) fn neg(a: &NativePoint, p: &BigUint) -> NativePoint {
+    (a.0.clone(), sub(p, &a.1, p))
+}
+
+/// Returns `k * a` for a non-zero scalar `k`, assuming no partial sum has the same x-coordinate as `a`.
+fn mul(k: &BigUint, a: &NativePoint, p: &BigUint) -> NativePoint {
+    let bits = (0..k.bits()).rev().map(|i| k.bit(i)).skip(1);
+    bits.fold(a.clone(), |point, bit| {
+        let point = double(&point, p);
+        match bit {
+            true => add(&point, a, p),
+            false => point,
+        }
+    })
+}
+
+/// Returns the sum of the point `a` and a point with the given x-coordinate, on the line of slope `lambda`.
+pub(super) fn finish(lambda: &BigUint, a: &NativePoint, x: &BigUint, p: &BigUint) -> NativePoint {
+    let x3 = sub(&sub(&(lambda * lambda % p), &a.0, p), x, p);
+    let y3 = sub(&(lambda * sub(&a.0, &x3, p) % p), &a.1, p);
+    (x3, y3)
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An affine point on secp256k1.
+///
+/// Note: The coordinates are less than `2^256`, but are not necessarily reduced modulo the base field modulus `p`.
+#[derive(Clone)]
+pub(super) struct Point<E: Environment> {
+    /// The x-coordinate.
+    pub(super) x: Uint256<E>,
+    /// The y-coordinate.
+    pub(super) y: Uint256<E>,
+}
+
+impl<E: Environment> Point<E> {
+    /// Initializes a constant point.
+    pub(super) fn constant((x, y): &NativePoint) -> Self {
+        Self { x: Uint256::constant(x), y: Uint256::constant(y) }
+    }
+
+    /// Returns `self + other`.
+    ///
+    /// This method enforces `self.x != other.x (mod p)`, i.e. the points are not equal and are not inverses.
+    pub(super) fn add(&self, other: &Self) -> Self {
+        let p = &Parameters::get().p;
+
+        // Enforce `self.x != other.x`.
+        E::assert(!self.x.is_congruent(&other.x, p));
+
+        // Witness the slope `λ`, and enforce `λ * (other.x - self.x) == other.y - self.y (mod p)`.
+        let lambda = Self::witness_add_slope(self, other);
+
+        let x_3 = self.x_3(&lambda, &other.x);
+        let y_3 = self.y_3(&lambda, &x_3);
+        Self { x: x_3, y: y_3 }
+    }
+
+    /// Returns `2 * self`.
+    ///
+    /// Note: As the order of secp256k1 is odd, a point on the curve does not have a y-coordinate of zero.
+    pub(super) fn double(&self) -> Self {
+        let p = &Parameters::get().p;
+
+        // Witness the slope `λ`, and enforce `λ * 2 * self.y == 3 * self.x^2 (mod p)`.
+        let lambda = Uint256::new(&native::double_slope(&self.eject_value(), p), 256);
+        assert_congruent(p, &[Term::Product(2, &lambda, &self.y)], &[Term::Product(3, &self.x, &self.x)]);
+
+        let x_3 = self.x_3(&lambda, &self.x);
+        let y_3 = self.y_3(&lambda, &x_3);
+        Self { x: x_3, y: y_3 }
+    }
+
+    /// Returns `2 * self + other`.
+    ///
+    /// This computes `(self + other) + self`, without the y-coordinate of `self + other`,
+    /// and enforces that neither addition is exceptional.
+    pub(super) fn double_and_add(&self, other: &Self) -> Self {
+        let p = &Parameters::get().p;
+
+        // Compute the x-coordinate of `self + other`.
+        E::assert(!self.x.is_congruent(&other.x, p));
+        let lambda = Self::witness_add_slope(self, other);
+        let x_3 = self.x_3(&lambda, &other.x);
+
+        // Witness the slope `λ'` of the line through `self` and `self + other`, which is `-λ - 2 * self.y / (x_3 - self.x)`,
+        // and enforce `(λ + λ') * (x_3 - self.x) == -2 * self.y (mod p)`.
+        E::assert(!x_3.is_congruent(&self.x, p));
+        let (a, lambda_value) = (self.eject_value(), lambda.eject_value());
+        let x_3_value = x_3.eject_value() % p;
+        let quotient = (&a.1 << 1) * native::inverse(&native::sub(&x_3_value, &a.0, p), p);
+        let lambda_prime = Uint256::new(&native::sub(&native::sub(p, &lambda_value, p), &quotient, p), 256);
+        assert_congruent(
+            p,
+            &[Term::Product(1, &lambda, &x_3), Term::Product(1, &lambda_prime, &x_3), Term::Integer(2, &self.y)],
+            &[Term::Product(1, &lambda, &self.x), Term::Product(1, &lambda_prime, &self.x)],
+        );
+
+        let x_4 = self.x_3(&lambda_prime, &x_3);
+        let y_4 = self.y_3(&lambda_prime, &x_4);
+        Self { x: x_4, y: y_4 }
+    }
+
+    /// Witnesses the slope `λ` of the line through `self` and `other`,
+    /// and enforces `λ * (other.x - self.x) == other.y - self.y (mod p)`.
+    fn witness_add_slope(&self, other: &Self) -> Uint256<E> {
+        let p = &Parameters::get().p;
+        let lambda = Uint256::new(&native::add_slope(&self.eject_value(), &other.eject_value(), p), 256);
+        assert_congruent(p, &[Term::Product(1, &lambda, &other.x), Term::Integer(1, &self.y)], &[
+            Term::Product(1, &lambda, &self.x),
+            Term::Integer(1, &other.y),
+        ]);
+        lambda
+    }
+
+    /// Returns the x-coordinate `λ^2 - self.x - x (mod p)` of the sum of `self` and the point with the given x-coordinate,
+    /// on the line through `self` of slope `λ`.
+    fn x_3(&self, lambda: &Uint256<E>, x: &Uint256<E>) -> Uint256<E> {
+        let p = &Parameters::get().p;
+        reduce(p, &[Term::Product(1, lambda, lambda)], &[Term::Integer(1, &self.x), Term::Integer(1, x)])
+    }
+
+    /// Returns the y-coordinate `λ * (self.x - x_3) - self.y (mod p)` of the sum with the given x-coordinate `x_3`,
+    /// on the line through `self` of slope `λ`.
+    fn y_3(&self, lambda: &Uint256<E>, x_3: &Uint256<E>) -> Uint256<E> {
+        let p = &Parameters::get().p;
+        reduce(p, &[Term::Product(1, lambda, &self.x)], &[Term::Product(1, lambda, x_3), Term::Integer(1, &self.y)])
+    }
+
+    /// Returns the point with canonical coordinates.
+    pub(super) fn to_canonical(&self) -> Self {
+        let p = &Parameters::get().p;
+        Self {
+            x: reduce_canonical(p, &[Term::Integer(1, &self.x)], &[]),
+            y: reduce_canonical(p, &[Term::Integer(1, &self.y)], &[]),
+        }
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: Uint256::ternary(condition, &first.x, &second.x),
+            y: Uint256::ternary(condition, &first.y, &second.y),
+        }
+    }
+
+    /// Returns the point at the index encoded by the given little-endian bits.
+    pub(super) fn select(bits: &[Boolean<E>], points: &[Self]) -> Self {
+        let indicators = one_hot(bits);
+        Self {
+            x: Uint256::select(&indicators, &points.iter().map(|point| &point.x).collect::<Vec<_>>()),
+            y: Uint256::select(&indicators, &points.iter().map(|point| &point.y).collect::<Vec<_>>()),
+        }
+    }
+
+    /// Ejects the value of the point, with canonical coordinates.
+    pub(super) fn eject_value(&self) -> NativePoint {
+        let p = &Parameters::get().p;
+        (self.x.eject_value() % p, self.y.eject_value() % p)
+    }
+}
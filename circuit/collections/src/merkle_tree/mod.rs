@@ -29,6 +29,24 @@ pub struct MerklePath<E: Environment, const DEPTH: u8> {
     siblings: Vec<Field<E>>,
 }
 
+impl<E: Environment, const DEPTH: u8> TryFrom<(U64<E>, Vec<Field<E>>)> for MerklePath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle path.
+    fn try_from((leaf_index, siblings): (U64<E>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the leaf index is within the tree depth.
+        ensure!((*leaf_index.eject_value() as u128) < (1u128 << DEPTH), "Found an out of bounds Merkle leaf index");
+        // Ensure the Merkle path is the correct length.
+        ensure!(siblings.len() == DEPTH as usize, "Found an incorrect Merkle path length");
+        // Return the Merkle path.
+        Ok(Self { leaf_index, siblings })
+    }
+}
+
 #[cfg(console)]
 impl<E: Environment, const DEPTH: u8> Inject for MerklePath<E, DEPTH> {
     type Primitive = console::merkle_tree::MerklePath<E::Network, DEPTH>;
@@ -41,6 +41,7 @@ impl<A: Aleo> Inject for Access<A> {
         match plaintext {
             Self::Primitive::Member(identifier) => Self::Member(Identifier::new(_m, identifier)),
             Self::Primitive::Index(index) => Self::Index(U32::new(_m, index)),
+            Self::Primitive::Dynamic(..) => A::halt("Dynamic accesses are only supported in a finalize scope"),
         }
     }
 }
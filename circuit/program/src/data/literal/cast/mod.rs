@@ -28,11 +28,13 @@ use snarkvm_circuit_types::prelude::{
     Boolean,
     Environment,
     Field,
+    Fixed,
     FromBits,
     FromField,
     FromGroup,
     Group,
     IntegerType,
+    MulChecked,
     One,
     Result,
     Scalar,
@@ -40,11 +42,12 @@ use snarkvm_circuit_types::prelude::{
     ToField,
     ToGroup,
     Zero,
+    I128,
     MSB,
 };
 
 #[cfg(test)]
-use snarkvm_circuit_types::prelude::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+use snarkvm_circuit_types::prelude::{I16, I32, I64, I8, U128, U16, U32, U64, U8};
 
 /// Unary operator for casting values of one type to another.
 pub trait Cast<T: Sized = Self> {
@@ -62,6 +65,7 @@ impl<A: Aleo> Literal<A> {
     ///
     /// The hierarchy of casting is as follows:
     ///  - (`Address`, `Group`) <-> `Field` <-> `Scalar` <-> `Integer` <-> `Boolean`
+    ///  - `Fixed` <-> `Integer` (whole values only)
     ///  - `Signature` (not supported)
     ///  - `String` (not supported)
     /// Note that casting to left along the hierarchy always preserves information.
@@ -84,6 +88,7 @@ impl<A: Aleo> Literal<A> {
             Self::Scalar(scalar) => cast_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
+            Self::Fixed(fixed) => cast_fixed_to_type(fixed, to_type),
         }
     }
 }
@@ -113,6 +118,9 @@ macro_rules! impl_cast_body {
             LiteralType::String => {
                 bail!(concat!("Cannot cast a ", stringify!($type_name), " literal to a string type."))
             }
+            LiteralType::Fixed => {
+                bail!(concat!("Cannot cast a ", stringify!($type_name), " literal to a fixed type."))
+            }
         }
     };
 }
@@ -138,7 +146,15 @@ fn cast_group_to_type<A: Aleo>(input: &Group<A>, to_type: LiteralType) -> Result
 
 /// Casts an integer literal to the given literal type.
 fn cast_integer_to_type<A: Aleo, I: IntegerType>(input: &Integer<A, I>, to_type: LiteralType) -> Result<Literal<A>> {
-    impl_cast_body!(integer, cast, input, to_type)
+    match to_type {
+        LiteralType::Fixed => {
+            // Scale the integer into a fixed-point number, ensuring it is in range.
+            let integer: I128<A> = input.cast();
+            let scaling_factor = I128::constant(console::I128::new(console::Fixed::<A::Network>::SCALING_FACTOR));
+            Ok(Literal::Fixed(Fixed::from_integer(integer.mul_checked(&scaling_factor))))
+        }
+        _ => impl_cast_body!(integer, cast, input, to_type),
+    }
 }
 
 /// Casts a scalar literal to the given literal type.
@@ -146,6 +162,18 @@ fn cast_scalar_to_type<A: Aleo>(input: &Scalar<A>, to_type: LiteralType) -> Resu
     impl_cast_body!(scalar, cast, input, to_type)
 }
 
+/// Casts a fixed-point literal to the given literal type.
+fn cast_fixed_to_type<A: Aleo>(input: &Fixed<A>, to_type: LiteralType) -> Result<Literal<A>> {
+    match to_type {
+        LiteralType::Fixed => Ok(Literal::Fixed(input.clone())),
+        // Note: Only whole fixed-point values are cast, as the fractional part would otherwise be lost.
+        _ => {
+            A::assert(input.is_whole());
+            cast_integer_to_type(&input.to_whole(), to_type)
+        }
+    }
+}
+
 #[cfg(test)]
 macro_rules! impl_check_cast {
     ($fun:ident, $circuit_type:ty, $console_type:ty) => {
@@ -28,12 +28,14 @@ use snarkvm_circuit_types::prelude::{
     Boolean,
     Environment,
     Field,
+    Fixed,
     FromBits,
     FromBoolean,
     FromGroup,
     Group,
     Inject,
     IntegerType,
+    MulWrapped,
     One,
     Result,
     Scalar,
@@ -42,11 +44,12 @@ use snarkvm_circuit_types::prelude::{
     ToField,
     ToGroup,
     Zero,
+    I128,
     MSB,
 };
 
 #[cfg(test)]
-use snarkvm_circuit_types::prelude::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+use snarkvm_circuit_types::prelude::{I16, I32, I64, I8, U128, U16, U32, U64, U8};
 
 /// Unary operator for casting values of one type to another, with lossy truncation.
 pub trait CastLossy<T: Sized = Self> {
@@ -65,6 +68,7 @@ impl<A: Aleo> Literal<A> {
     ///
     /// The hierarchy of casting is as follows:
     ///  - (`Address`, `Group`) <-> `Field` <-> `Scalar` <-> `Integer` <-> `Boolean`
+    ///  - `Fixed` <-> `Integer` (the fractional part is truncated toward zero)
     ///  - `Signature` (not supported)
     ///  - `String` (not supported)
     /// Note that casting to left along the hierarchy always preserves information.
@@ -87,6 +91,7 @@ impl<A: Aleo> Literal<A> {
             Self::Scalar(scalar) => cast_lossy_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
+            Self::Fixed(fixed) => cast_lossy_fixed_to_type(fixed, to_type),
         }
     }
 }
@@ -116,6 +121,9 @@ macro_rules! impl_cast_body {
             LiteralType::String => {
                 bail!(concat!("Cannot cast (lossy) a ", stringify!($type_name), " literal to a string type."))
             }
+            LiteralType::Fixed => {
+                bail!(concat!("Cannot cast (lossy) a ", stringify!($type_name), " literal to a fixed type."))
+            }
         }
     };
 }
@@ -144,7 +152,15 @@ fn cast_lossy_integer_to_type<A: Aleo, I: IntegerType>(
     input: &Integer<A, I>,
     to_type: LiteralType,
 ) -> Result<Literal<A>> {
-    impl_cast_body!(integer, cast_lossy, input, to_type)
+    match to_type {
+        // Note: The integer is scaled with wrapping semantics, as it may exceed the range of a fixed-point number.
+        LiteralType::Fixed => {
+            let integer: I128<A> = input.cast_lossy();
+            let scaling_factor = I128::constant(console::I128::new(console::Fixed::<A::Network>::SCALING_FACTOR));
+            Ok(Literal::Fixed(Fixed::from_integer(integer.mul_wrapped(&scaling_factor))))
+        }
+        _ => impl_cast_body!(integer, cast_lossy, input, to_type),
+    }
 }
 
 /// Casts a scalar literal to the given literal type, with lossy truncation.
@@ -152,6 +168,15 @@ fn cast_lossy_scalar_to_type<A: Aleo>(input: &Scalar<A>, to_type: LiteralType) -
     impl_cast_body!(scalar, cast_lossy, input, to_type)
 }
 
+/// Casts a fixed-point literal to the given literal type, with lossy truncation.
+fn cast_lossy_fixed_to_type<A: Aleo>(input: &Fixed<A>, to_type: LiteralType) -> Result<Literal<A>> {
+    match to_type {
+        LiteralType::Fixed => Ok(Literal::Fixed(input.clone())),
+        // Note: The fractional part is truncated toward zero.
+        _ => cast_lossy_integer_to_type(&input.to_whole(), to_type),
+    }
+}
+
 #[cfg(test)]
 macro_rules! check_cast_lossy {
     ($fun:ident, $circuit_type:ty, $console_type:ty) => {
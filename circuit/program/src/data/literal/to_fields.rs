@@ -46,6 +46,7 @@ impl<A: Aleo> ToFields for &Literal<A> {
             Literal::Scalar(literal) => vec![literal.to_field()],
             Literal::Signature(literal) => literal.to_fields(),
             Literal::String(literal) => literal.to_fields(),
+            Literal::Fixed(literal) => vec![literal.to_field()],
         }
     }
 }
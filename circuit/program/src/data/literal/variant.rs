@@ -35,6 +35,7 @@ impl<A: Aleo> Literal<A> {
             Self::Scalar(..) => console::U8::new(14),
             Self::Signature(..) => console::U8::new(15),
             Self::String(..) => console::U8::new(16),
+            Self::Fixed(..) => console::U8::new(17),
         })
     }
 }
@@ -43,7 +43,13 @@ impl<A: Aleo> Equal<Self> for Plaintext<A> {
                 }
                 false => Boolean::constant(false),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::constant(false),
+            (Self::Option(is_some_a, a, _), Self::Option(is_some_b, b, _)) => {
+                // Check the flags and the values for equality.
+                is_some_a.is_equal(is_some_b) & a.is_equal(b)
+            }
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Option(..), _) => {
+                Boolean::constant(false)
+            }
         }
     }
 
@@ -73,7 +79,13 @@ impl<A: Aleo> Equal<Self> for Plaintext<A> {
                 }
                 false => Boolean::constant(true),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::constant(true),
+            (Self::Option(is_some_a, a, _), Self::Option(is_some_b, b, _)) => {
+                // Check the flags and the values for inequality.
+                is_some_a.is_not_equal(is_some_b) | a.is_not_equal(b)
+            }
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Option(..), _) => {
+                Boolean::constant(true)
+            }
         }
     }
 }
@@ -86,6 +86,16 @@ impl<A: Aleo> FromBits for Plaintext<A> {
             // Cache the plaintext bits, and return the array.
             Self::Array(elements, OnceCell::with_value(bits_le.to_vec()))
         }
+        // Option
+        else if variant == [true, true] {
+            let is_some = next_bits(1)[0].clone();
+
+            let value_size = U16::from_bits_le(next_bits(16)).eject_value();
+            let value = Plaintext::from_bits_le(next_bits(*value_size as usize));
+
+            // Cache the plaintext bits, and return the option.
+            Self::Option(is_some, Box::new(value), OnceCell::with_value(bits_le.to_vec()))
+        }
         // Unknown variant.
         else {
             A::halt("Unknown plaintext variant.")
@@ -161,6 +171,16 @@ impl<A: Aleo> FromBits for Plaintext<A> {
             // Cache the plaintext bits, and return the array.
             Self::Array(elements, OnceCell::with_value(bits_be.to_vec()))
         }
+        // Option
+        else if variant == [true, true] {
+            let is_some = next_bits(1)[0].clone();
+
+            let value_size = U16::from_bits_be(next_bits(16)).eject_value();
+            let value = Plaintext::from_bits_be(next_bits(*value_size as usize));
+
+            // Cache the plaintext bits, and return the option.
+            Self::Option(is_some, Box::new(value), OnceCell::with_value(bits_be.to_vec()))
+        }
         // Unknown variant.
         else {
             A::halt("Unknown plaintext variant.")
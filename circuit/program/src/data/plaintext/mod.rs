@@ -37,6 +37,8 @@ pub enum Plaintext<A: Aleo> {
     Struct(IndexMap<Identifier<A>, Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
     /// A plaintext array.
     Array(Vec<Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext option, containing a flag that is `true` if the option is `some`, and its value.
+    Option(Boolean<A>, Box<Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
 }
 
 #[cfg(console)]
@@ -49,6 +51,9 @@ impl<A: Aleo> Inject for Plaintext<A> {
             Self::Primitive::Literal(literal, _) => Self::Literal(Literal::new(mode, literal), Default::default()),
             Self::Primitive::Struct(struct_, _) => Self::Struct(Inject::new(mode, struct_), Default::default()),
             Self::Primitive::Array(array, _) => Self::Array(Inject::new(mode, array), Default::default()),
+            Self::Primitive::Option(is_some, value, _) => {
+                Self::Option(Boolean::new(mode, *is_some), Box::new(Plaintext::new(mode, *value)), Default::default())
+            }
         }
     }
 }
@@ -67,6 +72,7 @@ impl<A: Aleo> Eject for Plaintext<A> {
                 .collect::<Vec<_>>()
                 .eject_mode(),
             Self::Array(array, _) => array.iter().map(Eject::eject_mode).collect::<Vec<_>>().eject_mode(),
+            Self::Option(is_some, value, _) => vec![is_some.eject_mode(), value.eject_mode()].eject_mode(),
         }
     }
 
@@ -80,6 +86,11 @@ impl<A: Aleo> Eject for Plaintext<A> {
             Self::Array(array, _) => {
                 console::Plaintext::Array(array.iter().map(Eject::eject_value).collect(), Default::default())
             }
+            Self::Option(is_some, value, _) => console::Plaintext::Option(
+                console::Boolean::new(is_some.eject_value()),
+                Box::new(value.eject_value()),
+                Default::default(),
+            ),
         }
     }
 }
@@ -368,6 +379,34 @@ mod tests {
             OnceCell::new(),
         ));
 
+        // Test options of a field element.
+        for is_some in [true, false] {
+            run_test(Plaintext::<Circuit>::Option(
+                Boolean::new(Mode::Private, is_some),
+                Box::new(Plaintext::<Circuit>::Literal(
+                    Literal::Field(Field::new(Mode::Private, Uniform::rand(&mut rng))),
+                    OnceCell::new(),
+                )),
+                OnceCell::new(),
+            ));
+        }
+
+        // Test an option of an array.
+        run_test(Plaintext::<Circuit>::Option(
+            Boolean::new(Mode::Private, true),
+            Box::new(Plaintext::<Circuit>::Array(
+                vec![
+                    Plaintext::<Circuit>::Literal(Literal::Boolean(Boolean::new(Mode::Private, true)), OnceCell::new()),
+                    Plaintext::<Circuit>::Literal(
+                        Literal::Boolean(Boolean::new(Mode::Private, false)),
+                        OnceCell::new(),
+                    ),
+                ],
+                OnceCell::new(),
+            )),
+            OnceCell::new(),
+        ));
+
         Ok(())
     }
 }
@@ -64,6 +64,19 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                 // Extend the vector with the bits of the array.
                 vec.extend_from_slice(bits);
             }
+            Self::Option(is_some, value, bits_le) => {
+                // Compute the bits of the option.
+                let bits = bits_le.get_or_init(|| {
+                    let mut bits_le = vec![Boolean::constant(true), Boolean::constant(true)]; // Variant bit.
+                    bits_le.push(is_some.clone());
+                    let value_bits = value.to_bits_le();
+                    U16::constant(console::U16::new(value_bits.len() as u16)).write_bits_le(&mut bits_le);
+                    bits_le.extend(value_bits);
+                    bits_le
+                });
+                // Extend the vector with the bits of the option.
+                vec.extend_from_slice(bits);
+            }
         }
     }
 
@@ -114,6 +127,19 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                 // Extend the vector with the bits of the array.
                 vec.extend_from_slice(bits)
             }
+            Self::Option(is_some, value, bits_be) => {
+                // Compute the bits of the option.
+                let bits = bits_be.get_or_init(|| {
+                    let mut bits_be = vec![Boolean::constant(true), Boolean::constant(true)]; // Variant bit.
+                    bits_be.push(is_some.clone());
+                    let value_bits = value.to_bits_be();
+                    U16::constant(console::U16::new(value_bits.len() as u16)).write_bits_be(&mut bits_be);
+                    bits_be.extend(value_bits);
+                    bits_be
+                });
+                // Extend the vector with the bits of the option.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 }
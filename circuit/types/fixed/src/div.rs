@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Div<Fixed<E>> for Fixed<E> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment> Div<&Fixed<E>> for Fixed<E> {
+    type Output = Self;
+
+    /// Returns the `quotient` of `self` and `other`, rounded toward zero, halting on division by zero or overflow.
+    fn div(self, other: &Self) -> Self::Output {
+        self.div_with_rounding(other, console::Rounding::TowardZero)
+    }
+}
+
+impl<E: Environment> DivRounded<Self> for Fixed<E> {
+    type Output = Self;
+
+    /// Returns the `quotient` of `self` and `other`, rounded to the nearest value (ties away from zero),
+    /// halting on division by zero or overflow.
+    fn div_rounded(&self, other: &Self) -> Self::Output {
+        self.div_with_rounding(other, console::Rounding::HalfAwayFromZero)
+    }
+}
+
+impl<E: Environment> Fixed<E> {
+    /// Returns the `quotient` of `self` and `other`, rounded with the given rounding mode,
+    /// halting if `other` is zero or the magnitude of the quotient exceeds `i128::MAX`.
+    fn div_with_rounding(&self, other: &Self, rounding: console::Rounding) -> Self {
+        // Halt on division by a constant zero.
+        if other.is_constant() && other.eject_value().is_zero() {
+            E::halt("Attempted to divide by zero.")
+        }
+
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the quotient and return the new constant.
+            match self.eject_value().checked_div_with_rounding(&other.eject_value(), rounding) {
+                Some(value) => Fixed::new(Mode::Constant, value),
+                None => E::halt("Fixed-point overflow on division of two constants"),
+            }
+        } else {
+            // Witness the quotient and remainder of the scaled magnitude of `self`, divided by the magnitude of `other`.
+            // Note: If `other` is zero or the quotient does not fit in 128 bits,
+            // the witnesses are zero and the constraints are unsatisfied.
+            let (this, that) = (self, other);
+            let quotient_lower: U64<E> = witness!(|this, that| {
+                console::Integer::new(this.div_magnitude_with_remainder(&that).unwrap_or_default().0 as u64)
+            });
+            let quotient_upper: U64<E> = witness!(|this, that| {
+                console::Integer::new((this.div_magnitude_with_remainder(&that).unwrap_or_default().0 >> 64) as u64)
+            });
+            let remainder: U128<E> = witness!(|this, that| {
+                console::Integer::new(this.div_magnitude_with_remainder(&that).unwrap_or_default().1)
+            });
+
+            // Ensure the quotient is less than 2^127, and the remainder is less than the divisor.
+            // Note: This also ensures the divisor is nonzero.
+            let divisor = other.magnitude();
+            E::assert(!quotient_upper.msb());
+            E::assert(remainder.is_less_than(&divisor));
+
+            // Split the magnitudes into 64-bit limbs.
+            // Note: The full product of the quotient and divisor exceeds the base field, so it is checked limb by limb.
+            let (x_0, x_1) = Self::to_limbs(&self.magnitude());
+            let (y_0, y_1) = Self::to_limbs(&divisor);
+            let (q_0, q_1) = (quotient_lower.to_field(), quotient_upper.to_field());
+
+            // Check the lower limb of `x * scaling_factor == quotient * y + remainder`.
+            // The offset of 2^124 ensures the difference is non-negative, and is removed from the carry below.
+            let offset = Field::constant(console::Field::from_u128(1u128 << 124));
+            let lower = &q_0 * &y_0 + remainder.to_field() + &offset - &x_0 * Self::scaling_factor();
+            let lower_bits_le = lower.to_lower_bits_le(130);
+            Boolean::assert_bits_are_zero(&lower_bits_le[..64]);
+            let carry = Field::from_bits_le(&lower_bits_le[64..]);
+
+            // Check the upper limbs of `x * scaling_factor == quotient * y + remainder`.
+            let two_pow_64 = Field::constant(console::Field::from_u128(1u128 << 64));
+            let upper = &q_1 * &y_1 * &two_pow_64 + &q_0 * &y_1 + &q_1 * &y_0 + &carry;
+            let offset = Field::constant(console::Field::from_u128(1u128 << 60));
+            E::assert_eq(upper, &x_1 * Self::scaling_factor() + &offset);
+
+            // Round the quotient.
+            let quotient = U128::from_bits_le(&[quotient_lower.to_bits_le(), quotient_upper.to_bits_le()].concat());
+            let round_up = match rounding {
+                console::Rounding::TowardZero => Boolean::constant(false),
+                // Note: `remainder < divisor`, so this subtraction does not underflow.
+                console::Rounding::HalfAwayFromZero => !remainder.is_less_than(&divisor.sub_wrapped(&remainder)),
+            };
+            let magnitude = quotient.add_wrapped(&U128::from_bits_le(&[round_up]));
+
+            // Return the quotient with the appropriate sign.
+            Self::from_magnitude(magnitude, &self.integer.msb().is_equal(other.integer.msb()))
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::test_utilities::check_operation;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_div(
+        name: &str,
+        first: console::Fixed<<Circuit as Environment>::Network>,
+        second: console::Fixed<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        // Division by a constant zero halts, regardless of the mode of the dividend.
+        if mode_b.is_constant() && second.is_zero() {
+            let a = Fixed::<Circuit>::new(mode_a, first);
+            let b = Fixed::<Circuit>::new(mode_b, second);
+            let result = std::panic::catch_unwind(move || a / b);
+            assert!(result.is_err());
+            Circuit::reset();
+            return;
+        }
+
+        let a = Fixed::<Circuit>::new(mode_a, first);
+        let b = Fixed::<Circuit>::new(mode_b, second);
+        let expected = first.checked_div_with_rounding(&second, console::Rounding::TowardZero);
+        check_operation(name, expected, a, b, |a, b| a / &b);
+
+        let a = Fixed::<Circuit>::new(mode_a, first);
+        let b = Fixed::<Circuit>::new(mode_b, second);
+        let expected = first.checked_div_with_rounding(&second, console::Rounding::HalfAwayFromZero);
+        check_operation(name, expected, a, b, |a, b| a.div_rounded(&b));
+    }
+
+    #[test]
+    fn test_div() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for i in 0..ITERATIONS {
+                    // Sample values whose quotient is likely representable.
+                    let first = console::Fixed::new(i64::rand(&mut rng) as i128);
+                    let second = Uniform::rand(&mut rng);
+                    check_div(&format!("Div: {mode_a} {mode_b} {i}"), first, second, mode_a, mode_b);
+
+                    // Sample values whose quotient is likely to overflow.
+                    let first = Uniform::rand(&mut rng);
+                    let second = console::Fixed::new(i64::rand(&mut rng) as i128);
+                    check_div(&format!("Div: {mode_a} {mode_b} {i} (overflow)"), first, second, mode_a, mode_b);
+                }
+                // Check the boundary values.
+                let one = console::Fixed::from_whole(1).unwrap();
+                check_div("Div: MAX / 1", console::Fixed::MAX, one, mode_a, mode_b);
+                check_div("Div: MIN / 1", console::Fixed::MIN, one, mode_a, mode_b);
+                check_div("Div: MAX / MIN", console::Fixed::MAX, console::Fixed::MIN, mode_a, mode_b);
+                check_div("Div: 1 / 0", one, console::Fixed::zero(), mode_a, mode_b);
+                // Check the rounding of a tie, i.e. -1e-18 / 2 = -5e-19.
+                check_div("Div: ties", console::Fixed::new(-1), console::Fixed::from_whole(2).unwrap(), mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Equal<Self> for Fixed<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        self.integer.is_equal(&other.integer)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_is_equal() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for i in 0..ITERATIONS {
+                    let first: console::Fixed<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                    let second: console::Fixed<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+
+                    let a = Fixed::<Circuit>::new(mode_a, first);
+                    let b = Fixed::<Circuit>::new(mode_b, second);
+                    let c = Fixed::<Circuit>::new(mode_b, first);
+
+                    Circuit::scope(format!("Equal: {mode_a} {mode_b} {i}"), || {
+                        assert_eq!(first == second, a.is_equal(&b).eject_value());
+                        assert_eq!(first != second, a.is_not_equal(&b).eject_value());
+                        assert!(a.is_equal(&c).eject_value());
+                        assert!(!a.is_not_equal(&c).eject_value());
+                        assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                    });
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> FromBits for Fixed<E> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes a new fixed-point number from a list of little-endian bits *with* trailing zeros.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        Self { integer: I128::from_bits_le(bits_le) }
+    }
+
+    /// Initializes a new fixed-point number from a list of big-endian bits *with* leading zeros.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        Self { integer: I128::from_bits_be(bits_be) }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bits() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let expected: console::Fixed<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let given = Fixed::<Circuit>::new(mode, expected);
+
+                Circuit::scope(format!("{mode}"), || {
+                    assert_eq!(expected, Fixed::from_bits_le(&given.to_bits_le()).eject_value());
+                    assert_eq!(expected, Fixed::from_bits_be(&given.to_bits_be()).eject_value());
+                    assert_scope!(0, 0, 0, 0);
+                });
+            }
+            Circuit::reset();
+        }
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![forbid(unsafe_code)]
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+
+mod helpers;
+
+pub mod add;
+pub mod compare;
+pub mod div;
+pub mod equal;
+pub mod mul;
+pub mod sub;
+pub mod ternary;
+
+#[cfg(test)]
+use console::{TestRng, Uniform};
+#[cfg(test)]
+use snarkvm_circuit_environment::assert_scope;
+
+use snarkvm_circuit_environment::prelude::*;
+use snarkvm_circuit_types_boolean::Boolean;
+use snarkvm_circuit_types_field::Field;
+use snarkvm_circuit_types_integers::{I128, U128, U64};
+
+/// A signed fixed-point decimal number, represented as a 128-bit integer scaled by `10^SCALE`.
+#[derive(Clone)]
+pub struct Fixed<E: Environment> {
+    /// The underlying integer, scaled by `10^SCALE`.
+    integer: I128<E>,
+}
+
+impl<E: Environment> Fixed<E> {
+    /// Initializes a new fixed-point number from its scaled integer representation.
+    pub fn from_integer(integer: I128<E>) -> Self {
+        Self { integer }
+    }
+
+    /// Returns the scaled integer representation of the fixed-point number.
+    pub fn to_integer(&self) -> &I128<E> {
+        &self.integer
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Fixed<E> {
+    /// Returns the whole part of the fixed-point number, rounded toward zero.
+    pub fn to_whole(&self) -> I128<E> {
+        self.integer.div_wrapped(&I128::constant(console::Integer::new(console::Fixed::<E::Network>::SCALING_FACTOR)))
+    }
+
+    /// Returns `true` if the fixed-point number has no fractional part.
+    pub fn is_whole(&self) -> Boolean<E> {
+        let scaling_factor = I128::constant(console::Integer::new(console::Fixed::<E::Network>::SCALING_FACTOR));
+        self.integer.rem_wrapped(&scaling_factor).is_equal(&I128::zero())
+    }
+
+    /// Returns the scaling factor, `10^SCALE`, as a constant field element.
+    fn scaling_factor() -> Field<E> {
+        Field::constant(console::Field::from_u128(console::Fixed::<E::Network>::SCALING_FACTOR as u128))
+    }
+
+    /// Returns the magnitude of the fixed-point number, as an unsigned integer.
+    fn magnitude(&self) -> U128<E> {
+        // Note: It is safe to use `abs_wrapped` as we want `I128::MIN` to be interpreted as an unsigned number.
+        self.integer.abs_wrapped().cast_as_dual()
+    }
+
+    /// Returns the lower and upper 64-bit limbs of the given magnitude, as field elements.
+    fn to_limbs(magnitude: &U128<E>) -> (Field<E>, Field<E>) {
+        let bits_le = magnitude.to_bits_le();
+        (Field::from_bits_le(&bits_le[..64]), Field::from_bits_le(&bits_le[64..]))
+    }
+
+    /// Returns the fixed-point number with the given magnitude and sign, halting if the magnitude exceeds `i128::MAX`.
+    fn from_magnitude(magnitude: U128<E>, is_positive: &Boolean<E>) -> Self {
+        // Ensure the magnitude does not exceed `i128::MAX`.
+        E::assert(!magnitude.msb());
+        // Restore the sign of the magnitude.
+        let magnitude = magnitude.cast_as_dual();
+        Self { integer: I128::ternary(is_positive, &magnitude, &I128::zero().sub_wrapped(&magnitude)) }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for Fixed<E> {
+    type Primitive = console::Fixed<E::Network>;
+
+    /// Initializes a new fixed-point number.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        Self { integer: I128::new(mode, value.to_integer()) }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for Fixed<E> {
+    type Primitive = console::Fixed<E::Network>;
+
+    /// Ejects the mode of the fixed-point number.
+    fn eject_mode(&self) -> Mode {
+        self.integer.eject_mode()
+    }
+
+    /// Ejects the fixed-point circuit as a console fixed-point value.
+    fn eject_value(&self) -> Self::Primitive {
+        console::Fixed::new(*self.integer.eject_value())
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Parser for Fixed<E> {
+    /// Parses a string into a fixed-point circuit.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the fixed-point number from the string.
+        let (string, fixed) = console::Fixed::parse(string)?;
+        // Parse the mode from the string.
+        let (string, mode) = opt(pair(tag("."), Mode::parse))(string)?;
+
+        match mode {
+            Some((_, mode)) => Ok((string, Fixed::new(mode, fixed))),
+            None => Ok((string, Fixed::new(Mode::Constant, fixed))),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> FromStr for Fixed<E> {
+    type Err = Error;
+
+    /// Parses a string into a fixed-point circuit.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> TypeName for Fixed<E> {
+    /// Returns the type name of the circuit as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        console::Fixed::<E::Network>::type_name()
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Debug for Fixed<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Display for Fixed<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(all(test, console))]
+mod test_utilities {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::UnwindSafe;
+
+    /// Checks the given `operation` against the `expected` console result.
+    /// If `expected` is `None`, ensures the operation halts on constants and is unsatisfied otherwise.
+    pub fn check_operation(
+        name: &str,
+        expected: Option<console::Fixed<<Circuit as Environment>::Network>>,
+        a: Fixed<Circuit>,
+        b: Fixed<Circuit>,
+        operation: impl FnOnce(Fixed<Circuit>, Fixed<Circuit>) -> Fixed<Circuit> + UnwindSafe,
+    ) {
+        match expected {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = operation(a, b);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            }),
+            None => match a.is_constant() && b.is_constant() {
+                true => {
+                    let result = std::panic::catch_unwind(move || operation(a, b));
+                    assert!(result.is_err());
+                }
+                false => Circuit::scope(name, || {
+                    let _candidate = operation(a, b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_new_and_parse() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let expected: console::Fixed<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+
+                let candidate = Fixed::<Circuit>::new(mode, expected);
+                assert_eq!(mode, candidate.eject_mode());
+                assert_eq!(expected, candidate.eject_value());
+
+                let candidate = Fixed::<Circuit>::from_str(&format!("{expected}.{mode}"))?;
+                assert_eq!(mode, candidate.eject_mode());
+                assert_eq!(expected, candidate.eject_value());
+            }
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Mul<Fixed<E>> for Fixed<E> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment> Mul<&Fixed<E>> for Fixed<E> {
+    type Output = Self;
+
+    /// Returns the `product` of `self` and `other`, rounded toward zero, halting if an overflow occurs.
+    fn mul(self, other: &Self) -> Self::Output {
+        self.mul_with_rounding(other, console::Rounding::TowardZero)
+    }
+}
+
+impl<E: Environment> MulRounded<Self> for Fixed<E> {
+    type Output = Self;
+
+    /// Returns the `product` of `self` and `other`, rounded to the nearest value (ties away from zero),
+    /// halting if an overflow occurs.
+    fn mul_rounded(&self, other: &Self) -> Self::Output {
+        self.mul_with_rounding(other, console::Rounding::HalfAwayFromZero)
+    }
+}
+
+impl<E: Environment> Fixed<E> {
+    /// Returns the `product` of `self` and `other`, rounded with the given rounding mode,
+    /// halting if the magnitude of the product exceeds `i128::MAX`.
+    fn mul_with_rounding(&self, other: &Self, rounding: console::Rounding) -> Self {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the product and return the new constant.
+            match self.eject_value().checked_mul_with_rounding(&other.eject_value(), rounding) {
+                Some(value) => Fixed::new(Mode::Constant, value),
+                None => E::halt("Fixed-point overflow on multiplication of two constants"),
+            }
+        } else {
+            // Witness the quotient and remainder of the product of the magnitudes, divided by the scaling factor.
+            // Note: If the quotient does not fit in 128 bits, the witnesses are zero and the constraints are unsatisfied.
+            let (this, that) = (self, other);
+            let quotient_lower: U64<E> = witness!(|this, that| {
+                console::Integer::new(this.mul_magnitude_with_remainder(&that).unwrap_or_default().0 as u64)
+            });
+            let quotient_upper: U64<E> = witness!(|this, that| {
+                console::Integer::new((this.mul_magnitude_with_remainder(&that).unwrap_or_default().0 >> 64) as u64)
+            });
+            let remainder: U64<E> = witness!(|this, that| {
+                console::Integer::new(this.mul_magnitude_with_remainder(&that).unwrap_or_default().1 as u64)
+            });
+
+            // Ensure the quotient is less than 2^127, and the remainder is less than the scaling factor.
+            let scaling_factor = console::Fixed::<E::Network>::SCALING_FACTOR as u64;
+            E::assert(!quotient_upper.msb());
+            E::assert(remainder.is_less_than(&U64::constant(console::Integer::new(scaling_factor))));
+
+            // Split the magnitudes into 64-bit limbs.
+            // Note: The full product of the magnitudes exceeds the base field, so it is checked limb by limb.
+            let (x_0, x_1) = Self::to_limbs(&self.magnitude());
+            let (y_0, y_1) = Self::to_limbs(&other.magnitude());
+
+            // Check the lower limb of `x * y - remainder == quotient * scaling_factor`.
+            // The offset of 2^125 is a multiple of 2^64 that ensures the difference is non-negative,
+            // and is removed from the carry below.
+            let offset = Field::constant(console::Field::from_u128(1u128 << 125));
+            let lower =
+                &x_0 * &y_0 - remainder.to_field() - quotient_lower.to_field() * Self::scaling_factor() + &offset;
+            let lower_bits_le = lower.to_lower_bits_le(129);
+            Boolean::assert_bits_are_zero(&lower_bits_le[..64]);
+            let carry = Field::from_bits_le(&lower_bits_le[64..]);
+
+            // Check the upper limbs of `x * y - remainder == quotient * scaling_factor`.
+            let two_pow_64 = Field::constant(console::Field::from_u128(1u128 << 64));
+            let upper = &x_1 * &y_1 * &two_pow_64 + &x_0 * &y_1 + &x_1 * &y_0 + &carry;
+            let offset = Field::constant(console::Field::from_u128(1u128 << 61));
+            E::assert_eq(upper, quotient_upper.to_field() * Self::scaling_factor() + &offset);
+
+            // Round the quotient.
+            let quotient = U128::from_bits_le(&[quotient_lower.to_bits_le(), quotient_upper.to_bits_le()].concat());
+            let round_up = match rounding {
+                console::Rounding::TowardZero => Boolean::constant(false),
+                console::Rounding::HalfAwayFromZero => {
+                    !remainder.is_less_than(&U64::constant(console::Integer::new(scaling_factor / 2)))
+                }
+            };
+            let magnitude = quotient.add_wrapped(&U128::from_bits_le(&[round_up]));
+
+            // Return the product with the appropriate sign.
+            Self::from_magnitude(magnitude, &self.integer.msb().is_equal(other.integer.msb()))
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::test_utilities::check_operation;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_mul(
+        name: &str,
+        first: console::Fixed<<Circuit as Environment>::Network>,
+        second: console::Fixed<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Fixed::<Circuit>::new(mode_a, first);
+        let b = Fixed::<Circuit>::new(mode_b, second);
+        let expected = first.checked_mul_with_rounding(&second, console::Rounding::TowardZero);
+        check_operation(name, expected, a, b, |a, b| a * &b);
+
+        let a = Fixed::<Circuit>::new(mode_a, first);
+        let b = Fixed::<Circuit>::new(mode_b, second);
+        let expected = first.checked_mul_with_rounding(&second, console::Rounding::HalfAwayFromZero);
+        check_operation(name, expected, a, b, |a, b| a.mul_rounded(&b));
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for i in 0..ITERATIONS {
+                    // Sample values whose product is likely representable.
+                    let first = console::Fixed::new(i64::rand(&mut rng) as i128 * 1_000);
+                    let second = console::Fixed::new(i64::rand(&mut rng) as i128);
+                    check_mul(&format!("Mul: {mode_a} {mode_b} {i}"), first, second, mode_a, mode_b);
+
+                    // Sample values whose product is likely to overflow.
+                    let first = Uniform::rand(&mut rng);
+                    let second = Uniform::rand(&mut rng);
+                    check_mul(&format!("Mul: {mode_a} {mode_b} {i} (overflow)"), first, second, mode_a, mode_b);
+                }
+                // Check the boundary values.
+                let one = console::Fixed::from_whole(1).unwrap();
+                check_mul("Mul: MAX * 1", console::Fixed::MAX, one, mode_a, mode_b);
+                check_mul("Mul: MIN * 1", console::Fixed::MIN, one, mode_a, mode_b);
+                check_mul(
+                    "Mul: MIN * -1",
+                    console::Fixed::MIN,
+                    console::Fixed::from_whole(-1).unwrap(),
+                    mode_a,
+                    mode_b,
+                );
+                check_mul("Mul: MAX * MAX", console::Fixed::MAX, console::Fixed::MAX, mode_a, mode_b);
+                // Check the rounding of a tie, i.e. -5e-18 * 0.1 = -5e-19.
+                check_mul(
+                    "Mul: ties",
+                    console::Fixed::new(-5),
+                    console::Fixed::new(100_000_000_000_000_000),
+                    mode_a,
+                    mode_b,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Sub<Fixed<E>> for Fixed<E> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment> Sub<&Fixed<E>> for Fixed<E> {
+    type Output = Self;
+
+    /// Returns the `difference` of `self` and `other`, halting if an overflow occurs.
+    fn sub(self, other: &Self) -> Self::Output {
+        // Note: The scaled integers share the same scale, so the difference is computed on the scaled integers directly.
+        Self { integer: self.integer.sub_checked(&other.integer) }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::test_utilities::check_operation;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_sub(
+        name: &str,
+        first: console::Fixed<<Circuit as Environment>::Network>,
+        second: console::Fixed<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Fixed::<Circuit>::new(mode_a, first);
+        let b = Fixed::<Circuit>::new(mode_b, second);
+        check_operation(name, first.checked_sub(&second), a, b, |a, b| a - &b);
+    }
+
+    #[test]
+    fn test_sub() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for i in 0..ITERATIONS {
+                    let first = Uniform::rand(&mut rng);
+                    let second = Uniform::rand(&mut rng);
+                    check_sub(&format!("Sub: {mode_a} {mode_b} {i}"), first, second, mode_a, mode_b);
+                }
+                // Check the boundary values.
+                check_sub("Sub: MAX", console::Fixed::MAX, console::Fixed::MIN, mode_a, mode_b);
+                check_sub("Sub: MIN", console::Fixed::MIN, console::Fixed::MAX, mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Ternary for Fixed<E> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        Self { integer: I128::ternary(condition, &first.integer, &second.integer) }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_ternary() {
+        let mut rng = TestRng::default();
+
+        for mode_condition in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                for i in 0..ITERATIONS {
+                    let flag = bool::rand(&mut rng);
+                    let first: console::Fixed<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                    let second: console::Fixed<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+
+                    let condition = Boolean::<Circuit>::new(mode_condition, flag);
+                    let a = Fixed::<Circuit>::new(mode, first);
+                    let b = Fixed::<Circuit>::new(mode, second);
+
+                    Circuit::scope(format!("Ternary: {mode_condition} {mode} {i}"), || {
+                        let candidate = Fixed::ternary(&condition, &a, &b);
+                        assert_eq!(if flag { first } else { second }, candidate.eject_value());
+                        assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                    });
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+}
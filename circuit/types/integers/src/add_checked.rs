@@ -110,6 +110,45 @@ impl<E: Environment, I: IntegerType> AddChecked<Self> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> AddOverflowing<Self> for Integer<E, I> {
+    type Output = (Self, Boolean<E>);
+
+    #[inline]
+    fn add_overflowing(&self, other: &Integer<E, I>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the wrapped sum and the overflow flag, and return the new constants.
+            let (first, second) = (self.eject_value(), other.eject_value());
+            let is_overflow = first.checked_add(&second).is_none();
+            (Integer::constant(first.add_wrapped(&second)), Boolean::constant(is_overflow))
+        } else {
+            // Instead of adding the bits of `self` and `other` directly, the integers are
+            // converted into a field elements, and summed, before converting back to integers.
+            // Note: This is safe as the field is larger than the maximum integer type supported.
+            let sum = self.to_field() + other.to_field();
+
+            // Extract the integer bits from the field element, with a carry bit.
+            let (sum, carry) = match sum.to_lower_bits_le(I::BITS as usize + 1).split_last() {
+                Some((carry, bits_le)) => (Integer::from_bits_le(bits_le), carry.clone()),
+                // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
+                None => E::halt("Malformed sum detected during integer addition"),
+            };
+
+            // Determine whether the sum overflowed, using the same conditions as `add_checked`.
+            let is_overflow = match I::is_signed() {
+                true => {
+                    let is_same_sign = self.msb().is_equal(other.msb());
+                    is_same_sign & sum.msb().is_not_equal(self.msb())
+                }
+                // For unsigned addition, the carry bit is set if and only if an overflow occurred.
+                false => carry,
+            };
+
+            (sum, is_overflow)
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Metrics<dyn Add<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
     type Case = (Mode, Mode);
 
@@ -196,6 +235,15 @@ mod tests {
             },
         }
         Circuit::reset();
+
+        // Ensure the overflowing variant returns the wrapped sum and flags the overflow, without halting.
+        Circuit::scope(name, || {
+            let (candidate, is_overflow) = a.add_overflowing(&b);
+            assert_eq!(first.add_wrapped(&second), candidate.eject_value());
+            assert_eq!(first.checked_add(&second).is_none(), is_overflow.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
     }
 
     fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
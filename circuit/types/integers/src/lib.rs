@@ -27,16 +27,20 @@ pub mod compare;
 pub mod div_checked;
 pub mod div_wrapped;
 pub mod equal;
+pub mod leading_zeros;
 pub mod modulo;
 pub mod mul_checked;
 pub mod mul_wrapped;
 pub mod neg;
 pub mod not;
 pub mod or;
+pub mod pop_count;
 pub mod pow_checked;
 pub mod pow_wrapped;
 pub mod rem_checked;
 pub mod rem_wrapped;
+pub mod rotate_left;
+pub mod rotate_right;
 pub mod shl_checked;
 pub mod shl_wrapped;
 pub mod shr_checked;
@@ -44,6 +48,7 @@ pub mod shr_wrapped;
 pub mod sub_checked;
 pub mod sub_wrapped;
 pub mod ternary;
+pub mod trailing_zeros;
 pub mod xor;
 
 pub type I8<E> = Integer<E, i8>;
@@ -119,6 +119,45 @@ impl<E: Environment, I: IntegerType> MulChecked<Self> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> MulOverflowing<Self> for Integer<E, I> {
+    type Output = (Self, Boolean<E>);
+
+    #[inline]
+    fn mul_overflowing(&self, other: &Integer<E, I>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the wrapped product and the overflow flag, and return the new constants.
+            let (first, second) = (self.eject_value(), other.eject_value());
+            let is_overflow = first.checked_mul(&second).is_none();
+            (Integer::constant(first.mul_wrapped(&second)), Boolean::constant(is_overflow))
+        } else if I::is_signed() {
+            // Compute the product of `abs(self)` and `abs(other)`, while flagging an overflow.
+            // Note: it is safe to use `abs_wrapped` as we want `Integer::MIN` to be interpreted as an unsigned number.
+            let (product, is_unsigned_overflow) = Self::mul_and_flag(&self.abs_wrapped(), &other.abs_wrapped());
+
+            // If the product should be positive, then it cannot exceed the signed maximum.
+            let operands_same_sign = &self.msb().is_equal(other.msb());
+            let positive_product_overflows = operands_same_sign & product.msb();
+
+            // If the product should be negative, then it cannot exceed the absolute value of the signed minimum.
+            let negative_product_underflows = {
+                let lower_product_bits_nonzero =
+                    product.bits_le[..(I::BITS as usize - 1)].iter().fold(Boolean::constant(false), |a, b| a | b);
+                let negative_product_lt_or_eq_signed_min =
+                    !product.msb() | (product.msb() & !lower_product_bits_nonzero);
+                !operands_same_sign & !negative_product_lt_or_eq_signed_min
+            };
+
+            // Return the wrapped product of `self` and `other` with the appropriate sign, and the overflow flag.
+            let is_overflow = is_unsigned_overflow | positive_product_overflows | negative_product_underflows;
+            (Self::ternary(operands_same_sign, &product, &Self::zero().sub_wrapped(&product)), is_overflow)
+        } else {
+            // Compute the product of `self` and `other`, while flagging an overflow.
+            Self::mul_and_flag(self, other)
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Integer<E, I> {
     /// Multiply the integer bits of `this` and `that`, while checking for an overflow.
     /// This function assumes that `this` and `that` are non-negative.
@@ -154,6 +193,41 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Multiply the integer bits of `this` and `that`, returning the wrapped product and whether an overflow occurred.
+    /// This function assumes that `this` and `that` are non-negative.
+    #[inline]
+    fn mul_and_flag(this: &Integer<E, I>, that: &Integer<E, I>) -> (Integer<E, I>, Boolean<E>) {
+        // Case 1 - 2 integers fit in 1 field element (u8, u16, u32, u64, i8, i16, i32, i64).
+        if 2 * I::BITS < (E::BaseField::size_in_bits() - 1) as u64 {
+            // Compute the full product of `this` and `that`, in the base field.
+            // Note: The multiplication is safe as the field twice as large as the maximum integer type supported.
+            let product = this.to_field() * that.to_field();
+
+            // Split the product into the integer bits and the carry bits.
+            let bits_le = product.to_lower_bits_le(2 * I::BITS as usize);
+            let (bits_le, carry) = bits_le.split_at(I::BITS as usize);
+
+            // The product overflowed if any of the carry bits are set.
+            let is_overflow = carry.iter().fold(Boolean::constant(false), |a, b| a | b);
+
+            (Integer::from_bits_le(bits_le), is_overflow)
+        }
+        // Case 2 - 1.5 integers fit in 1 field element (u128, i128).
+        else if (I::BITS + I::BITS / 2) < (E::BaseField::size_in_bits() - 1) as u64 {
+            // Use Karatsuba multiplication to compute the product of `self` and `other`.
+            let (product, z_1_upper_bits, z2) = Self::karatsuba_multiply(this, that);
+
+            // The product overflowed if any of the upper bits of z1 are set, or if `z2` is nonzero.
+            let is_overflow = z_1_upper_bits.iter().fold(z2.is_not_equal(&Field::zero()), |a, b| a | b);
+
+            (product, is_overflow)
+        } else {
+            E::halt(format!("Multiplication of integers of size {} is not supported", I::BITS))
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Integer<E, I> {
     /// Multiply the integer bits of `this` and `that`, using Karatsuba multiplication.
     ///
@@ -301,6 +375,15 @@ mod tests {
             },
         }
         Circuit::reset();
+
+        // Ensure the overflowing variant returns the wrapped product and flags the overflow, without halting.
+        Circuit::scope(name, || {
+            let (candidate, is_overflow) = a.mul_overflowing(&b);
+            assert_eq!(first.mul_wrapped(&second), candidate.eject_value());
+            assert_eq!(first.checked_mul(&second).is_none(), is_overflow.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
     }
 
     fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
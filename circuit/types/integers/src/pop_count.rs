@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> PopCount for Integer<E, I> {
+    type Output = Integer<E, u8>;
+
+    /// Returns the number of ones in the binary representation of `self`.
+    #[inline]
+    fn pop_count(&self) -> Self::Output {
+        // Sum the bits of `self` in the base field.
+        // Note: The sum is at most `I::BITS`, which is less than `2^8`, so it always fits within a `u8`.
+        let count = self.bits_le.iter().fold(Field::zero(), |count, bit| count + Field::from_boolean(bit));
+        // Return the count as a `u8`.
+        Integer::from_field(count)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn PopCount<Output = Integer<E, u8>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(8, 0, 0, 0),
+            _ => Count::is(0, 0, 8, 9),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn PopCount<Output = Integer<E, u8>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_pop_count<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = value.pop_count();
+
+        Circuit::scope(name, || {
+            let candidate = a.pop_count();
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(PopCount(Integer<I>) => Integer<u8>, &mode);
+            assert_output_mode!(PopCount(Integer<I>) => Integer<u8>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("PopCount: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_pop_count::<I>(&name, value, mode);
+        }
+
+        // Check cases common to signed and unsigned integers.
+        check_pop_count::<I>(&format!("PopCount: {mode} zero"), console::Integer::zero(), mode);
+        check_pop_count::<I>(&format!("PopCount: {mode} one"), console::Integer::one(), mode);
+        check_pop_count::<I>(&format!("PopCount: {mode} MAX"), console::Integer::MAX, mode);
+        check_pop_count::<I>(&format!("PopCount: {mode} MIN"), console::Integer::MIN, mode);
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("PopCount: {mode}");
+            check_pop_count::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, pop_count);
+    test_integer_unary!(run_test, i16, pop_count);
+    test_integer_unary!(run_test, i32, pop_count);
+    test_integer_unary!(run_test, i64, pop_count);
+    test_integer_unary!(run_test, i128, pop_count);
+
+    test_integer_unary!(run_test, u8, pop_count);
+    test_integer_unary!(run_test, u16, pop_count);
+    test_integer_unary!(run_test, u32, pop_count);
+    test_integer_unary!(run_test, u64, pop_count);
+    test_integer_unary!(run_test, u128, pop_count);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, pop_count, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, pop_count, exhaustive);
+}
@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateRight<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates `self` to the right by `rhs` bits, modulo the number of bits in `self`.
+    #[inline]
+    fn rotate_right(&self, rhs: &Integer<E, M>) -> Self::Output {
+        // Retrieve the index for the first upper bit from the RHS that we mask.
+        // Since `I::BITS` is a power of two, the masked RHS is the rotation amount modulo `I::BITS`.
+        let first_upper_bit_index = I::BITS.trailing_zeros() as usize;
+
+        // Perform the rotation with a barrel shifter, by conditionally rotating by `2^i` for each bit `i` of the RHS.
+        // Note: If the bit is a constant, `Boolean::ternary` selects a branch without introducing any constraints.
+        let mut bits_le = self.bits_le.clone();
+        for (i, bit) in rhs.bits_le[..first_upper_bit_index].iter().enumerate() {
+            // In little-endian order, rotating the integer right moves each bit to a lower index.
+            let mut rotated = bits_le.clone();
+            rotated.rotate_left(1 << i);
+            bits_le = rotated.iter().zip_eq(&bits_le).map(|(a, b)| Boolean::ternary(bit, a, b)).collect();
+        }
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn RotateRight<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, bool);
+
+    fn count(case: &Self::Case) -> Count {
+        // The number of layers in the barrel shifter.
+        let num_layers = I::BITS.trailing_zeros() as u64;
+
+        match (case.0, case.1, case.2) {
+            (_, Mode::Constant, _) => Count::is(0, 0, 0, 0),
+            (Mode::Constant, _, true) => Count::is(0, 0, 0, 0),
+            (Mode::Constant, _, false) => Count::less_than(0, 0, num_layers * I::BITS, num_layers * I::BITS),
+            (_, _, _) => Count::is(0, 0, num_layers * I::BITS, num_layers * I::BITS),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn RotateRight<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, bool);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1, case.2) {
+            (mode_a, Mode::Constant, _) => mode_a,
+            (Mode::Constant, _, true) => Mode::Constant,
+            (_, _, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rotate_right<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let expected = first.rotate_right(&second);
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+        // Determine if all of the bits of `a` are the same.
+        let a_is_uniform = first.is_zero() || (!first).is_zero();
+        Circuit::scope(name, || {
+            let candidate = a.rotate_right(&b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(RotateRight(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b, a_is_uniform));
+            assert_output_mode!(RotateRight(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b, a_is_uniform), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("RotateRight: {mode_a} rotr {mode_b} {i}");
+            check_rotate_right::<I, M>(&name, first, second, mode_a, mode_b);
+
+            // Check that rotating right by one is computed correctly.
+            let name = format!("RotateRight by one: {mode_a} rotr {mode_b} {i}");
+            check_rotate_right::<I, M>(&name, first, console::Integer::one(), mode_a, mode_b);
+        }
+
+        // Check cases common to signed and unsigned integers.
+        check_rotate_right::<I, M>("0 rotr 1", console::Integer::zero(), console::Integer::one(), mode_a, mode_b);
+        check_rotate_right::<I, M>("MAX rotr 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_rotate_right::<I, M>("MIN rotr 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_rotate_right::<I, M>("1 rotr 0", console::Integer::one(), console::Integer::zero(), mode_a, mode_b);
+        check_rotate_right::<I, M>("1 rotr MAX", console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+        RangeInclusive<M>: Iterator<Item = M>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in M::MIN..=M::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, M>::new(second);
+
+                let name = format!("RotateRight: ({first} rotr {second})");
+                check_rotate_right::<I, M>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, u8, rotate_right);
+    test_integer_binary!(run_test, i8, u16, rotate_right);
+    test_integer_binary!(run_test, i8, u32, rotate_right);
+
+    test_integer_binary!(run_test, i16, u8, rotate_right);
+    test_integer_binary!(run_test, i16, u16, rotate_right);
+    test_integer_binary!(run_test, i16, u32, rotate_right);
+
+    test_integer_binary!(run_test, i32, u8, rotate_right);
+    test_integer_binary!(run_test, i32, u16, rotate_right);
+    test_integer_binary!(run_test, i32, u32, rotate_right);
+
+    test_integer_binary!(run_test, i64, u8, rotate_right);
+    test_integer_binary!(run_test, i64, u16, rotate_right);
+    test_integer_binary!(run_test, i64, u32, rotate_right);
+
+    test_integer_binary!(run_test, i128, u8, rotate_right);
+    test_integer_binary!(run_test, i128, u16, rotate_right);
+    test_integer_binary!(run_test, i128, u32, rotate_right);
+
+    test_integer_binary!(run_test, u8, u8, rotate_right);
+    test_integer_binary!(run_test, u8, u16, rotate_right);
+    test_integer_binary!(run_test, u8, u32, rotate_right);
+
+    test_integer_binary!(run_test, u16, u8, rotate_right);
+    test_integer_binary!(run_test, u16, u16, rotate_right);
+    test_integer_binary!(run_test, u16, u32, rotate_right);
+
+    test_integer_binary!(run_test, u32, u8, rotate_right);
+    test_integer_binary!(run_test, u32, u16, rotate_right);
+    test_integer_binary!(run_test, u32, u32, rotate_right);
+
+    test_integer_binary!(run_test, u64, u8, rotate_right);
+    test_integer_binary!(run_test, u64, u16, rotate_right);
+    test_integer_binary!(run_test, u64, u32, rotate_right);
+
+    test_integer_binary!(run_test, u128, u8, rotate_right);
+    test_integer_binary!(run_test, u128, u16, rotate_right);
+    test_integer_binary!(run_test, u128, u32, rotate_right);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, rotate_right, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, rotate_right, exhaustive);
+}
@@ -108,6 +108,45 @@ impl<E: Environment, I: IntegerType> SubChecked<Self> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> SubOverflowing<Self> for Integer<E, I> {
+    type Output = (Self, Boolean<E>);
+
+    #[inline]
+    fn sub_overflowing(&self, other: &Integer<E, I>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the wrapped difference and the underflow flag, and return the new constants.
+            let (first, second) = (self.eject_value(), other.eject_value());
+            let is_underflow = first.checked_sub(&second).is_none();
+            (Integer::constant(first.sub_wrapped(&second)), Boolean::constant(is_underflow))
+        } else {
+            // Instead of subtracting the bits of `self` and `other` directly, the integers are
+            // converted into a field elements, and subtracted, before converting back to integers.
+            // Note: This is safe as the field is larger than the maximum integer type supported.
+            let difference = self.to_field() + (!other).to_field() + Field::one();
+
+            // Extract the integer bits from the field element, with a carry bit.
+            let (difference, carry) = match difference.to_lower_bits_le(I::BITS as usize + 1).split_last() {
+                Some((carry, bits_le)) => (Integer::from_bits_le(bits_le), carry.clone()),
+                // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
+                None => E::halt("Malformed difference detected during integer subtraction"),
+            };
+
+            // Determine whether the difference underflowed, using the same conditions as `sub_checked`.
+            let is_underflow = match I::is_signed() {
+                true => {
+                    let is_different_signs = self.msb().is_not_equal(other.msb());
+                    is_different_signs & difference.msb().is_equal(other.msb())
+                }
+                // For unsigned subtraction, the carry bit is unset if and only if an underflow occurred.
+                false => !carry,
+            };
+
+            (difference, is_underflow)
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Metrics<dyn Sub<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
     type Case = (Mode, Mode);
 
@@ -193,6 +232,15 @@ mod tests {
             },
         }
         Circuit::reset();
+
+        // Ensure the overflowing variant returns the wrapped difference and flags the overflow, without halting.
+        Circuit::scope(name, || {
+            let (candidate, is_overflow) = a.sub_overflowing(&b);
+            assert_eq!(first.sub_wrapped(&second), candidate.eject_value());
+            assert_eq!(first.checked_sub(&second).is_none(), is_overflow.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
     }
 
     fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
@@ -28,6 +28,9 @@ pub mod modules {
     pub use snarkvm_circuit_types_field as field;
     pub use snarkvm_circuit_types_field::Field;
 
+    pub use snarkvm_circuit_types_fixed as fixed;
+    pub use snarkvm_circuit_types_fixed::Fixed;
+
     pub use snarkvm_circuit_types_group as group;
     pub use snarkvm_circuit_types_group::Group;
 
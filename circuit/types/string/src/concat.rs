@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the concatenation of `self` and `other`.
+    pub fn concat(&self, other: &Self) -> Self {
+        // Ensure the concatenated string is within the allowed capacity.
+        let num_bytes = self.bytes.len().saturating_add(other.bytes.len());
+        if num_bytes > E::MAX_STRING_BYTES as usize {
+            E::halt(format!("Attempted to concatenate into a string of size {num_bytes}"))
+        }
+
+        Self {
+            mode: Mode::combine(self.mode, [other.mode]),
+            bytes: self.bytes.iter().chain(&other.bytes).cloned().collect(),
+            size_in_bytes: &self.size_in_bytes + &other.size_in_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_concat(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        // Sample two strings. Take 1/8th to ensure the concatenation fits for all code points.
+        let given_a = rng.next_string(Circuit::MAX_STRING_BYTES / 8, true);
+        let given_b = rng.next_string(Circuit::MAX_STRING_BYTES / 8, true);
+        let string_a = StringType::<Circuit>::new(mode_a, console::StringType::new(&given_a));
+        let string_b = StringType::<Circuit>::new(mode_b, console::StringType::new(&given_b));
+
+        Circuit::scope(format!("{mode_a} {mode_b}"), || {
+            let candidate = string_a.concat(&string_b);
+            assert_eq!(format!("{given_a}{given_b}"), *candidate.eject_value());
+            assert_eq!(Mode::combine(mode_a, [mode_b]), candidate.eject_mode());
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_concat() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_concat(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_concat_halts_on_overflow() {
+        let string = StringType::<Circuit>::new(
+            Mode::Private,
+            console::StringType::new(&"a".repeat(Circuit::MAX_STRING_BYTES as usize)),
+        );
+        let result = std::panic::catch_unwind(|| string.concat(&string));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
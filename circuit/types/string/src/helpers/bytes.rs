@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Initializes a new string from the given bytes.
+    ///
+    /// Note: As with `from_bits_le`, the bytes are not constrained to be valid UTF-8.
+    pub fn from_bytes(bytes: &[U8<E>]) -> Self {
+        Self::from_bits_le(&bytes.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>())
+    }
+
+    /// Returns the bytes of the string.
+    pub fn to_bytes(&self) -> Vec<U8<E>> {
+        self.bytes.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random string. Take 1/4th to ensure we fit for all code points.
+            let given = rng.next_string(Circuit::MAX_STRING_BYTES / 4, true);
+            let string = StringType::<Circuit>::new(mode, console::StringType::new(&given));
+
+            Circuit::scope(format!("{mode}"), || {
+                let bytes = string.to_bytes();
+                assert_eq!(given.as_bytes(), bytes.iter().map(|byte| *byte.eject_value()).collect::<Vec<_>>());
+                let candidate = StringType::from_bytes(&bytes);
+                assert_eq!(given, *candidate.eject_value());
+                match mode.is_constant() {
+                    true => assert_scope!(1, 0, 0, 0),
+                    false => assert_scope!(1, 0, 1, 1),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+}
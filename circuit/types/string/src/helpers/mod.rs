@@ -14,6 +14,7 @@
 
 use super::*;
 
+pub mod bytes;
 pub mod from_bits;
 pub mod to_bits;
 pub mod to_fields;
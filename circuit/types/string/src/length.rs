@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the number of bytes in the string.
+    ///
+    /// Note: The number of bytes in a string is fixed by the circuit, and is therefore returned as a constant.
+    pub fn length(&self) -> U32<E> {
+        U32::constant(console::Integer::new(
+            u32::try_from(self.bytes.len()).unwrap_or_else(|error| E::halt(error.to_string())),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    #[test]
+    fn test_length() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random string. Take 1/4th to ensure we fit for all code points.
+            let given = rng.next_string(Circuit::MAX_STRING_BYTES / 4, true);
+            let string = StringType::<Circuit>::new(mode, console::StringType::new(&given));
+
+            Circuit::scope(format!("{mode}"), || {
+                let candidate = string.length();
+                assert_eq!(given.len() as u32, *candidate.eject_value());
+                assert!(candidate.eject_mode().is_constant());
+                assert_scope!(32, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+}
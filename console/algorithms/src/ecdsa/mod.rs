@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use snarkvm_console_types::environment::prelude::*;
+
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use tiny_keccak::{Hasher, Keccak as TinyKeccak};
+
+/// The ECDSA signature scheme over the secp256k1 curve, as used by Bitcoin and Ethereum.
+///
+/// Signatures are encoded as `r || s` (64 bytes), or as `r || s || v` (65 bytes),
+/// where `r` and `s` are big-endian scalars and `v` is the recovery ID (`0`, `1`, `27`, or `28`).
+/// Only low-`s` signatures are accepted, in order to prevent signature malleability.
+///
+/// The verifier is one of:
+///   - a SEC1-encoded public key, in compressed (33 bytes) or uncompressed (65 bytes) form, or
+///   - an Ethereum address (20 bytes), in which case the signature must contain a recovery ID.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ECDSASecp256k1;
+
+impl ECDSASecp256k1 {
+    /// The number of bytes in a compressed public key.
+    pub const COMPRESSED_PUBLIC_KEY_SIZE_IN_BYTES: usize = 33;
+    /// The number of bytes in a message digest.
+    pub const DIGEST_SIZE_IN_BYTES: usize = 32;
+    /// The number of bytes in an Ethereum address.
+    pub const ETH_ADDRESS_SIZE_IN_BYTES: usize = 20;
+    /// The number of bytes in a signature, with a recovery ID.
+    pub const RECOVERABLE_SIGNATURE_SIZE_IN_BYTES: usize = 65;
+    /// The number of bytes in a signature, without a recovery ID.
+    pub const SIGNATURE_SIZE_IN_BYTES: usize = 64;
+    /// The number of bytes in an uncompressed public key.
+    pub const UNCOMPRESSED_PUBLIC_KEY_SIZE_IN_BYTES: usize = 65;
+
+    /// Returns `true` if the given number of bytes is a valid signature size.
+    pub const fn is_valid_signature_size(num_bytes: usize) -> bool {
+        num_bytes == Self::SIGNATURE_SIZE_IN_BYTES || num_bytes == Self::RECOVERABLE_SIGNATURE_SIZE_IN_BYTES
+    }
+
+    /// Returns `true` if the given number of bytes is a valid verifier size.
+    pub const fn is_valid_verifier_size(num_bytes: usize) -> bool {
+        num_bytes == Self::COMPRESSED_PUBLIC_KEY_SIZE_IN_BYTES
+            || num_bytes == Self::UNCOMPRESSED_PUBLIC_KEY_SIZE_IN_BYTES
+            || num_bytes == Self::ETH_ADDRESS_SIZE_IN_BYTES
+    }
+
+    /// Returns the Ethereum address for the given public key,
+    /// defined as the last 20 bytes of the Keccak-256 hash of the uncompressed public key (without its prefix).
+    pub fn to_eth_address(verifying_key: &VerifyingKey) -> [u8; 20] {
+        // Encode the public key in uncompressed form.
+        let encoded = verifying_key.to_encoded_point(false);
+        // Hash the public key, skipping the `0x04` prefix.
+        let digest = Self::keccak256(&encoded.as_bytes()[1..]);
+        // Return the last 20 bytes of the digest.
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&digest[12..]);
+        address
+    }
+
+    /// Returns the Keccak-256 digest of the given message.
+    pub fn keccak256(message: &[u8]) -> [u8; 32] {
+        let mut keccak = TinyKeccak::v256();
+        keccak.update(message);
+
+        let mut digest = [0u8; 32];
+        keccak.finalize(&mut digest);
+        digest
+    }
+}
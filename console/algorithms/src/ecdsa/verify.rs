@@ -0,0 +1,205 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use k256::ecdsa::signature::hazmat::PrehashVerifier;
+
+impl ECDSASecp256k1 {
+    /// Returns `true` if the signature is valid for the given verifier and message digest.
+    /// Returns an error if the signature, verifier, or digest is not of a valid size.
+    pub fn verify_digest(signature: &[u8], verifier: &[u8], digest: &[u8]) -> Result<bool> {
+        // Ensure the inputs are well-formed.
+        ensure!(
+            Self::is_valid_signature_size(signature.len()),
+            "Invalid ECDSA signature size: expected 64 or 65 bytes, found {} bytes",
+            signature.len()
+        );
+        ensure!(
+            Self::is_valid_verifier_size(verifier.len()),
+            "Invalid ECDSA verifier size: expected 20, 33, or 65 bytes, found {} bytes",
+            verifier.len()
+        );
+        ensure!(
+            digest.len() == Self::DIGEST_SIZE_IN_BYTES,
+            "Invalid ECDSA digest size: expected {} bytes, found {} bytes",
+            Self::DIGEST_SIZE_IN_BYTES,
+            digest.len()
+        );
+
+        // Parse the signature. An invalid encoding (i.e. `r` or `s` is zero or out of range) is an invalid signature.
+        let Ok(sig) = Signature::from_slice(&signature[..Self::SIGNATURE_SIZE_IN_BYTES]) else {
+            return Ok(false);
+        };
+
+        match verifier.len() {
+            // If the verifier is an Ethereum address, recover the public key and compare the addresses.
+            Self::ETH_ADDRESS_SIZE_IN_BYTES => {
+                // Retrieve the recovery ID.
+                let Some(recovery_id) = signature.get(Self::SIGNATURE_SIZE_IN_BYTES) else {
+                    bail!("An ECDSA signature must contain a recovery ID to be verified against an Ethereum address")
+                };
+                // Normalize the recovery ID, as Ethereum adds an offset of 27.
+                let recovery_id = match recovery_id {
+                    0 | 1 => *recovery_id,
+                    27 | 28 => recovery_id - 27,
+                    _ => return Ok(false),
+                };
+                let Some(recovery_id) = RecoveryId::from_byte(recovery_id) else {
+                    return Ok(false);
+                };
+                // Recover the public key. Note: This also checks the signature is valid for the recovered key.
+                match VerifyingKey::recover_from_prehash(digest, &sig, recovery_id) {
+                    Ok(verifying_key) => Ok(Self::to_eth_address(&verifying_key) == verifier),
+                    Err(_) => Ok(false),
+                }
+            }
+            // Otherwise, parse the public key and verify the signature.
+            _ => {
+                // Ensure the public key is in compressed or uncompressed SEC1 form, as expected by the circuit.
+                let is_valid_prefix = match verifier.len() {
+                    Self::COMPRESSED_PUBLIC_KEY_SIZE_IN_BYTES => matches!(verifier[0], 0x02 | 0x03),
+                    _ => verifier[0] == 0x04,
+                };
+                if !is_valid_prefix {
+                    return Ok(false);
+                }
+                match VerifyingKey::from_sec1_bytes(verifier) {
+                    Ok(verifying_key) => Ok(verifying_key.verify_prehash(digest, &sig).is_ok()),
+                    Err(_) => Ok(false),
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the signature is valid for the given verifier and the Keccak-256 digest of the message.
+    /// Returns an error if the signature or verifier is not of a valid size.
+    pub fn verify_keccak256(signature: &[u8], verifier: &[u8], message: &[u8]) -> Result<bool> {
+        Self::verify_digest(signature, verifier, &Self::keccak256(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use k256::ecdsa::SigningKey;
+
+    const ITERATIONS: usize = 100;
+
+    /// Samples a random signing key.
+    fn sample_signing_key(rng: &mut TestRng) -> SigningKey {
+        loop {
+            if let Ok(signing_key) = SigningKey::from_slice(&<[u8; 32]>::rand(rng)) {
+                return signing_key;
+            }
+        }
+    }
+
+    /// Signs the given digest, returning the signature as `r || s || v`.
+    fn sign(signing_key: &SigningKey, digest: &[u8; 32]) -> Vec<u8> {
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(digest).unwrap();
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte());
+        bytes
+    }
+
+    #[test]
+    fn test_verify_digest() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let signing_key = sample_signing_key(&mut rng);
+            let verifying_key = signing_key.verifying_key();
+            let digest = <[u8; 32]>::rand(&mut rng);
+            let signature = sign(&signing_key, &digest);
+
+            let compressed = verifying_key.to_encoded_point(true);
+            let uncompressed = verifying_key.to_encoded_point(false);
+            let address = ECDSASecp256k1::to_eth_address(verifying_key);
+
+            // Check the signature verifies against all verifier encodings.
+            assert!(ECDSASecp256k1::verify_digest(&signature, compressed.as_bytes(), &digest).unwrap());
+            assert!(ECDSASecp256k1::verify_digest(&signature[..64], compressed.as_bytes(), &digest).unwrap());
+            assert!(ECDSASecp256k1::verify_digest(&signature, uncompressed.as_bytes(), &digest).unwrap());
+            assert!(ECDSASecp256k1::verify_digest(&signature, &address, &digest).unwrap());
+
+            // Check the Ethereum-style recovery ID is accepted.
+            let mut eth_signature = signature.clone();
+            eth_signature[64] += 27;
+            assert!(ECDSASecp256k1::verify_digest(&eth_signature, &address, &digest).unwrap());
+
+            // Check the signature fails for a different digest.
+            let other_digest = <[u8; 32]>::rand(&mut rng);
+            assert!(!ECDSASecp256k1::verify_digest(&signature, compressed.as_bytes(), &other_digest).unwrap());
+            assert!(!ECDSASecp256k1::verify_digest(&signature, &address, &other_digest).unwrap());
+
+            // Check the signature fails for a different verifier.
+            let other_key = sample_signing_key(&mut rng);
+            let other_address = ECDSASecp256k1::to_eth_address(other_key.verifying_key());
+            let other_compressed = other_key.verifying_key().to_encoded_point(true);
+            assert!(!ECDSASecp256k1::verify_digest(&signature, other_compressed.as_bytes(), &digest).unwrap());
+            assert!(!ECDSASecp256k1::verify_digest(&signature, &other_address, &digest).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verify_keccak256() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let signing_key = sample_signing_key(&mut rng);
+            let message = (0..u8::rand(&mut rng)).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+            let signature = sign(&signing_key, &ECDSASecp256k1::keccak256(&message));
+            let address = ECDSASecp256k1::to_eth_address(signing_key.verifying_key());
+
+            assert!(ECDSASecp256k1::verify_keccak256(&signature, &address, &message).unwrap());
+
+            let mut other_message = message.clone();
+            other_message.push(0);
+            assert!(!ECDSASecp256k1::verify_keccak256(&signature, &address, &other_message).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_malformed_inputs() {
+        let mut rng = TestRng::default();
+
+        let signing_key = sample_signing_key(&mut rng);
+        let digest = <[u8; 32]>::rand(&mut rng);
+        let signature = sign(&signing_key, &digest);
+        let address = ECDSASecp256k1::to_eth_address(signing_key.verifying_key());
+        let compressed = signing_key.verifying_key().to_encoded_point(true);
+
+        // Check invalid sizes are rejected.
+        assert!(ECDSASecp256k1::verify_digest(&signature[..63], &address, &digest).is_err());
+        assert!(ECDSASecp256k1::verify_digest(&signature, &address[..19], &digest).is_err());
+        assert!(ECDSASecp256k1::verify_digest(&signature, &address, &digest[..31]).is_err());
+        // Check an Ethereum address requires a recovery ID.
+        assert!(ECDSASecp256k1::verify_digest(&signature[..64], &address, &digest).is_err());
+        // Check an invalid recovery ID is an invalid signature.
+        let mut invalid_signature = signature.clone();
+        invalid_signature[64] = 5;
+        assert!(!ECDSASecp256k1::verify_digest(&invalid_signature, &address, &digest).unwrap());
+        // Check a zero signature is an invalid signature.
+        assert!(!ECDSASecp256k1::verify_digest(&[0u8; 64], compressed.as_bytes(), &digest).unwrap());
+        // Check an invalid public key is an invalid signature.
+        assert!(!ECDSASecp256k1::verify_digest(&signature, &[0u8; 33], &digest).unwrap());
+        // Check a public key that is not in compressed or uncompressed form is an invalid signature.
+        let mut compact = compressed.as_bytes().to_vec();
+        compact[0] = 0x05;
+        assert!(!ECDSASecp256k1::verify_digest(&signature, &compact, &digest).unwrap());
+    }
+}
@@ -21,6 +21,13 @@ pub trait AddChecked<Rhs: ?Sized = Self> {
     fn add_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for adding two values, wrapping the sum and returning whether an overflow occurred.
+pub trait AddOverflowing<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn add_overflowing(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for adding two values, bounding the sum to `MAX` if an overflow occurs.
 pub trait AddSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -49,6 +56,13 @@ pub trait DivChecked<Rhs: ?Sized = Self> {
     fn div_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for dividing two values, rounding the quotient to the nearest value (ties away from zero).
+pub trait DivRounded<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn div_rounded(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for dividing two values, bounding the quotient to `MAX` or `MIN` if an overflow occurs.
 pub trait DivSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -77,6 +91,20 @@ pub trait MulChecked<Rhs: ?Sized = Self> {
     fn mul_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for multiplying two values, wrapping the product and returning whether an overflow occurred.
+pub trait MulOverflowing<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn mul_overflowing(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for multiplying two values, rounding the product to the nearest value (ties away from zero).
+pub trait MulRounded<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn mul_rounded(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for multiplying two values, bounding the product to `MAX` if an overflow occurs.
 pub trait MulSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -163,6 +191,13 @@ pub trait SubChecked<Rhs: ?Sized = Self> {
     fn sub_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for subtracting two values, wrapping the difference and returning whether an underflow occurred.
+pub trait SubOverflowing<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn sub_overflowing(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for subtracting two values, bounding the difference to `MIN` if an underflow occurs.
 pub trait SubSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -56,6 +56,46 @@ pub trait Nor<Rhs: ?Sized = Self> {
     fn nor(&self, other: &Rhs) -> Self::Output;
 }
 
+/// Unary operator for counting the number of set bits.
+pub trait PopCount {
+    type Output;
+
+    /// Returns the number of ones in the binary representation of `self`.
+    fn pop_count(&self) -> Self::Output;
+}
+
+/// Unary operator for counting the number of leading zero bits.
+pub trait LeadingZeros {
+    type Output;
+
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    fn leading_zeros(&self) -> Self::Output;
+}
+
+/// Unary operator for counting the number of trailing zero bits.
+pub trait TrailingZeros {
+    type Output;
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    fn trailing_zeros(&self) -> Self::Output;
+}
+
+/// Binary operator for rotating the bits of a value to the left, wrapping the shifted bits around.
+pub trait RotateLeft<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self` rotated to the left by `rhs` bits, modulo the number of bits in `self`.
+    fn rotate_left(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for rotating the bits of a value to the right, wrapping the shifted bits around.
+pub trait RotateRight<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `self` rotated to the right by `rhs` bits, modulo the number of bits in `self`.
+    fn rotate_right(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Trait for ternary operations.
 pub trait Ternary {
     type Boolean;
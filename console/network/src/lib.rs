@@ -179,6 +179,10 @@ pub trait Network:
     const MAX_RECORDS: usize = 10 * Self::MAX_FUNCTIONS;
     /// The maximum number of closures in a program.
     const MAX_CLOSURES: usize = 2 * Self::MAX_FUNCTIONS;
+    /// The maximum number of constants in a program.
+    const MAX_CONSTANTS: usize = 10 * Self::MAX_FUNCTIONS;
+    /// The maximum number of views in a program.
+    const MAX_VIEWS: usize = Self::MAX_FUNCTIONS;
     /// The maximum number of operands in an instruction.
     const MAX_OPERANDS: usize = Self::MAX_INPUTS;
     /// The maximum number of instructions in a closure or function.
@@ -21,7 +21,8 @@ impl<N: Network> FromBytes for Access<N> {
         match variant {
             0 => Ok(Self::Member(Identifier::read_le(&mut reader)?)),
             1 => Ok(Self::Index(U32::read_le(&mut reader)?)),
-            2.. => Err(error(format!("Failed to deserialize access variant {variant}"))),
+            2 => Ok(Self::Dynamic(u64::read_le(&mut reader)?)),
+            3.. => Err(error(format!("Failed to deserialize access variant {variant}"))),
         }
     }
 }
@@ -38,6 +39,10 @@ impl<N: Network> ToBytes for Access<N> {
                 1u8.write_le(&mut writer)?;
                 index.write_le(&mut writer)
             }
+            Access::Dynamic(locator) => {
+                2u8.write_le(&mut writer)?;
+                locator.write_le(&mut writer)
+            }
         }
     }
 }
@@ -71,6 +76,10 @@ mod tests {
             // Index
             let index = U32::<CurrentNetwork>::rand(rng);
             check_bytes(Access::Index(index))?;
+
+            // Dynamic
+            let locator = rng.gen::<u64>();
+            check_bytes(Access::Dynamic(locator))?;
         }
         Ok(())
     }
@@ -26,6 +26,17 @@ pub enum Access<N: Network> {
     Member(Identifier<N>),
     /// Access an element of an array.
     Index(U32<N>),
+    /// Access an element of an array, at the `u32` index stored in the given register locator.
+    /// Note: Dynamic accesses are only supported in a finalize scope.
+    Dynamic(u64),
+}
+
+impl<N: Network> Access<N> {
+    /// Returns `true` if the access is a dynamic access.
+    #[inline]
+    pub const fn is_dynamic(&self) -> bool {
+        matches!(self, Self::Dynamic(..))
+    }
 }
 
 impl<N: Network> From<Identifier<N>> for Access<N> {
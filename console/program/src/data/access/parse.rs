@@ -16,8 +16,15 @@ use super::*;
 
 impl<N: Network> Parser for Access<N> {
     fn parse(string: &str) -> ParserResult<Self> {
+        // Parses a register locator, i.e. `r1`.
+        let parse_locator = map(
+            pair(tag("r"), map_res(recognize(many1(one_of("0123456789"))), |locator: &str| locator.parse::<u64>())),
+            |(_, locator)| locator,
+        );
+
         alt((
             map(pair(tag("["), pair(U32::parse, tag("]"))), |(_, (index, _))| Self::Index(index)),
+            map(pair(tag("["), pair(parse_locator, tag("]"))), |(_, (locator, _))| Self::Dynamic(locator)),
             map(pair(tag("."), Identifier::parse), |(_, identifier)| Self::Member(identifier)),
         ))(string)
     }
@@ -56,6 +63,8 @@ impl<N: Network> Display for Access<N> {
             Self::Member(identifier) => write!(f, ".{}", identifier),
             // Prints the access index, i.e. `[0u32]`
             Self::Index(index) => write!(f, "[{}]", index),
+            // Prints the dynamic access, i.e. `[r1]`
+            Self::Dynamic(locator) => write!(f, "[r{}]", locator),
         }
     }
 }
@@ -71,6 +80,7 @@ mod tests {
     fn test_parse() -> Result<()> {
         assert_eq!(Access::parse(".data"), Ok(("", Access::<CurrentNetwork>::Member(Identifier::from_str("data")?))));
         assert_eq!(Access::parse("[0u32]"), Ok(("", Access::<CurrentNetwork>::Index(U32::new(0)))));
+        assert_eq!(Access::parse("[r1]"), Ok(("", Access::<CurrentNetwork>::Dynamic(1))));
         Ok(())
     }
 
@@ -86,6 +96,9 @@ mod tests {
         assert!(Access::<CurrentNetwork>::parse("[index]").is_err());
         assert!(Access::<CurrentNetwork>::parse("[0.0]").is_err());
         assert!(Access::<CurrentNetwork>::parse("[999999999999]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[r]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[r0.foo]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[r0[0u32]]").is_err());
 
         // Must fit within the data capacity of a base field element.
         let access =
@@ -99,6 +112,7 @@ mod tests {
     fn test_display() -> Result<()> {
         assert_eq!(Access::<CurrentNetwork>::Member(Identifier::from_str("foo")?).to_string(), ".foo");
         assert_eq!(Access::<CurrentNetwork>::Index(U32::new(0)).to_string(), "[0u32]");
+        assert_eq!(Access::<CurrentNetwork>::Dynamic(1).to_string(), "[r1]");
         Ok(())
     }
 }
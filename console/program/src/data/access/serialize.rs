@@ -76,6 +76,7 @@ mod tests {
         for i in 0..1000 {
             check_serde_json(Access::<CurrentNetwork>::from_str(&format!(".owner_{i}")).unwrap());
             check_serde_json(Access::<CurrentNetwork>::from_str(&format!("[{i}u32]")).unwrap());
+            check_serde_json(Access::<CurrentNetwork>::from_str(&format!("[r{i}]")).unwrap());
         }
     }
 
@@ -84,6 +85,7 @@ mod tests {
         for i in 0..1000 {
             check_bincode(Access::<CurrentNetwork>::from_str(&format!(".owner_{i}")).unwrap());
             check_bincode(Access::<CurrentNetwork>::from_str(&format!("[{i}u32]")).unwrap());
+            check_bincode(Access::<CurrentNetwork>::from_str(&format!("[r{i}]")).unwrap());
         }
     }
 }
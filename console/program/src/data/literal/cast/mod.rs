@@ -38,6 +38,7 @@ impl<N: Network> Literal<N> {
     ///
     /// The hierarchy of casting is as follows:
     ///  - (`Address`, `Group`) <-> `Field` <-> `Scalar` <-> `Integer` <-> `Boolean`
+    ///  - `Fixed` <-> `Integer` (whole values only)
     ///  - `Signature` (not supported)
     ///  - `String` (not supported)
     /// Note that casting to left along the hierarchy always preserves information.
@@ -60,6 +61,7 @@ impl<N: Network> Literal<N> {
             Self::Scalar(scalar) => cast_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
+            Self::Fixed(fixed) => cast_fixed_to_type(fixed, to_type),
         }
     }
 }
@@ -89,6 +91,9 @@ macro_rules! impl_cast_body {
             LiteralType::String => {
                 bail!(concat!("Cannot cast a ", stringify!($type_name), " literal to a string type."))
             }
+            LiteralType::Fixed => {
+                bail!(concat!("Cannot cast a ", stringify!($type_name), " literal to a fixed type."))
+            }
         }
     };
 }
@@ -129,10 +134,32 @@ where
     u64: TryFrom<I>,
     u128: TryFrom<I>,
 {
-    impl_cast_body!(integer, cast, input, to_type)
+    match to_type {
+        LiteralType::Fixed => {
+            // Scale the integer into a fixed-point number, ensuring it is in range.
+            let fixed = i128::try_from(**input).ok().and_then(Fixed::from_whole);
+            match fixed {
+                Some(fixed) => Ok(Literal::Fixed(fixed)),
+                None => bail!("Failed to cast {input} to a fixed type: value is out of range."),
+            }
+        }
+        _ => impl_cast_body!(integer, cast, input, to_type),
+    }
 }
 
 /// Casts a scalar literal to the given literal type.
 fn cast_scalar_to_type<N: Network>(input: &Scalar<N>, to_type: LiteralType) -> Result<Literal<N>> {
     impl_cast_body!(scalar, cast, input, to_type)
 }
+
+/// Casts a fixed-point literal to the given literal type.
+fn cast_fixed_to_type<N: Network>(input: &Fixed<N>, to_type: LiteralType) -> Result<Literal<N>> {
+    match to_type {
+        LiteralType::Fixed => Ok(Literal::Fixed(*input)),
+        // Note: Only whole fixed-point values are cast, as the fractional part would otherwise be lost.
+        _ => match input.is_whole() {
+            true => cast_integer_to_type(&I128::new(input.to_whole()), to_type),
+            false => bail!("Failed to cast {input} to a {to_type} type: value has a fractional part."),
+        },
+    }
+}
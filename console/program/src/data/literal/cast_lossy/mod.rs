@@ -39,6 +39,7 @@ impl<N: Network> Literal<N> {
     ///
     /// The hierarchy of casting is as follows:
     ///  - (`Address`, `Group`) <-> `Field` <-> `Scalar` <-> `Integer` <-> `Boolean`
+    ///  - `Fixed` <-> `Integer` (the fractional part is truncated toward zero)
     ///  - `Signature` (not supported)
     ///  - `String` (not supported)
     /// Note that casting to left along the hierarchy always preserves information.
@@ -61,6 +62,7 @@ impl<N: Network> Literal<N> {
             Self::Scalar(scalar) => cast_lossy_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
+            Self::Fixed(fixed) => cast_lossy_fixed_to_type(fixed, to_type),
         }
     }
 }
@@ -90,6 +92,9 @@ macro_rules! impl_cast_lossy_body {
             LiteralType::String => {
                 bail!(concat!("Cannot cast (lossy) a ", stringify!($type_name), " literal to a string type."))
             }
+            LiteralType::Fixed => {
+                bail!(concat!("Cannot cast (lossy) a ", stringify!($type_name), " literal to a fixed type."))
+            }
         }
     };
 }
@@ -130,10 +135,26 @@ where
         + AsPrimitive<i64>
         + AsPrimitive<i128>,
 {
-    impl_cast_lossy_body!(integer, cast_lossy, input, to_type)
+    match to_type {
+        // Note: The integer is scaled with wrapping semantics, as it may exceed the range of a fixed-point number.
+        LiteralType::Fixed => {
+            let integer: i128 = (**input).as_();
+            Ok(Literal::Fixed(Fixed::new(integer.wrapping_mul(Fixed::<N>::SCALING_FACTOR))))
+        }
+        _ => impl_cast_lossy_body!(integer, cast_lossy, input, to_type),
+    }
 }
 
 /// Casts a scalar literal to the given literal type, with lossy truncation.
 fn cast_lossy_scalar_to_type<N: Network>(input: &Scalar<N>, to_type: LiteralType) -> Result<Literal<N>> {
     impl_cast_lossy_body!(scalar, cast_lossy, input, to_type)
 }
+
+/// Casts a fixed-point literal to the given literal type, with lossy truncation.
+fn cast_lossy_fixed_to_type<N: Network>(input: &Fixed<N>, to_type: LiteralType) -> Result<Literal<N>> {
+    match to_type {
+        LiteralType::Fixed => Ok(Literal::Fixed(*input)),
+        // Note: The fractional part is truncated toward zero.
+        _ => cast_lossy_integer_to_type(&I128::new(input.to_whole()), to_type),
+    }
+}
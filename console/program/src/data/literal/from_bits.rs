@@ -48,7 +48,8 @@ impl<N: Network> Literal<N> {
                     false => bail!("String literal exceeds maximum length of {} bytes.", N::MAX_STRING_BYTES),
                 }
             }
-            17.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
+            17 => Literal::Fixed(Fixed::from_bits_le(literal)?),
+            18.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
         };
         Ok(literal)
     }
@@ -86,7 +87,8 @@ impl<N: Network> Literal<N> {
                     false => bail!("String literal exceeds maximum length of {} bytes.", N::MAX_STRING_BYTES),
                 }
             }
-            17.. => bail!("Failed to initialize literal variant {} from bits (BE)", variant),
+            17 => Literal::Fixed(Fixed::from_bits_be(literal)?),
+            18.. => bail!("Failed to initialize literal variant {} from bits (BE)", variant),
         };
         Ok(literal)
     }
@@ -151,6 +153,8 @@ mod tests {
             // Sample a random string. Take 1/4th to ensure we fit for all code points.
             let string = rng.next_string(CurrentNetwork::MAX_STRING_BYTES / 4, false);
             check_serialization(Literal::<CurrentNetwork>::String(StringType::new(&string)))?;
+            // Fixed
+            check_serialization(Literal::<CurrentNetwork>::Fixed(Uniform::rand(rng)))?;
         }
         Ok(())
     }
@@ -36,6 +36,7 @@ impl<N: Network> Parser for Literal<N> {
             map(Scalar::<N>::parse, |literal| Self::Scalar(literal)),
             map(Signature::<N>::parse, |literal| Self::Signature(Box::new(literal))),
             map(StringType::<N>::parse, |literal| Self::String(literal)),
+            map(Fixed::<N>::parse, |literal| Self::Fixed(literal)),
             // This allows users to implicitly declare program IDs as literals.
             map_res(ProgramID::<N>::parse, |program_id| Ok::<Self, Error>(Self::Address(program_id.to_address()?))),
         ))(string)
@@ -86,6 +87,7 @@ impl<N: Network> Display for Literal<N> {
             Self::Scalar(literal) => Display::fmt(literal, f),
             Self::Signature(literal) => Display::fmt(literal, f),
             Self::String(literal) => Display::fmt(literal, f),
+            Self::Fixed(literal) => Display::fmt(literal, f),
         }
     }
 }
@@ -65,7 +65,24 @@ impl<N: Network> FromBytes for Plaintext<N> {
                 // Return the array.
                 Self::Array(elements, Default::default())
             }
-            3.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
+            3 => {
+                // Read the flag indicating whether the option is `some`.
+                let is_some = Boolean::read_le(&mut reader)?;
+                // Read the plaintext value (in 2 steps to prevent infinite recursion).
+                let num_bytes = u16::read_le(&mut reader)?;
+                // Read the plaintext bytes.
+                let mut bytes = Vec::new();
+                (&mut reader).take(num_bytes as u64).read_to_end(&mut bytes)?;
+                // Recover the plaintext value.
+                let plaintext = Plaintext::read_le(&mut bytes.as_slice())?;
+                // Ensure the value of a `none` option is the placeholder for its type.
+                if !*is_some && !plaintext.is_placeholder() {
+                    return Err(error("Found a 'none' option with a value that is not a placeholder"));
+                }
+                // Return the option.
+                Self::Option(is_some, Box::new(plaintext), Default::default())
+            }
+            4.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
         };
         Ok(plaintext)
     }
@@ -116,6 +133,19 @@ impl<N: Network> ToBytes for Plaintext<N> {
                 }
                 Ok(())
             }
+            Self::Option(is_some, value, ..) => {
+                3u8.write_le(&mut writer)?;
+
+                // Write the flag indicating whether the option is `some`.
+                is_some.write_le(&mut writer)?;
+
+                // Write the value (performed in 2 steps to prevent infinite recursion).
+                let bytes = value.to_bytes_le().map_err(error)?;
+                // Write the number of bytes.
+                u16::try_from(bytes.len()).map_err(error)?.write_le(&mut writer)?;
+                // Write the bytes.
+                bytes.write_le(&mut writer)
+            }
         }
     }
 }
@@ -232,6 +262,26 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
 
+        // Check the options manually.
+        for string in [
+            "some(100u64)",
+            "none(0u64)",
+            "some({ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, token_amount: 100u64 })",
+        ] {
+            let expected = Plaintext::<CurrentNetwork>::from_str(string)?;
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
+        }
+
+        // Ensure a `none` option that does not contain the placeholder for its type is rejected.
+        let value = Box::new(Plaintext::<CurrentNetwork>::from_str("1u8")?);
+        let candidate = Plaintext::<CurrentNetwork>::Option(Boolean::new(false), value, Default::default());
+        assert!(Plaintext::<CurrentNetwork>::read_le(&candidate.to_bytes_le()?[..]).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_le(&candidate.to_bits_le()).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_be(&candidate.to_bits_be()).is_err());
+
         Ok(())
     }
 }
@@ -48,7 +48,12 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(false),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::new(false),
+            (Self::Option(is_some_a, a, _), Self::Option(is_some_b, b, _)) => {
+                Boolean::new(*is_some_a.is_equal(is_some_b) && *a.is_equal(b))
+            }
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Option(..), _) => {
+                Boolean::new(false)
+            }
         }
     }
 
@@ -76,7 +81,12 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(true),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::new(true),
+            (Self::Option(is_some_a, a, _), Self::Option(is_some_b, b, _)) => {
+                Boolean::new(*is_some_a.is_not_equal(is_some_b) || *a.is_not_equal(b))
+            }
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Option(..), _) => {
+                Boolean::new(true)
+            }
         }
     }
 }
@@ -90,6 +90,18 @@ impl<N: Network> FromBits for Plaintext<N> {
             // Cache the plaintext bits, and return the array.
             Ok(Self::Array(elements, OnceCell::with_value(bits_le.to_vec())))
         }
+        // Option
+        else if variant == [true, true] {
+            let is_some = Boolean::new(next_bits(1)?[0]);
+
+            let value_size = u16::from_bits_le(next_bits(16)?)?;
+            let value = Plaintext::from_bits_le(next_bits(value_size as usize)?)?;
+            // Ensure the value of a `none` option is the placeholder for its type.
+            ensure!(*is_some || value.is_placeholder(), "Found a 'none' option with a value that is not a placeholder");
+
+            // Cache the plaintext bits, and return the option.
+            Ok(Self::Option(is_some, Box::new(value), OnceCell::with_value(bits_le.to_vec())))
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant - {variant:?}");
@@ -171,6 +183,18 @@ impl<N: Network> FromBits for Plaintext<N> {
             // Cache the plaintext bits, and return the array.
             Ok(Self::Array(elements, OnceCell::with_value(bits_be.to_vec())))
         }
+        // Option
+        else if variant == [true, true] {
+            let is_some = Boolean::new(next_bits(1)?[0]);
+
+            let value_size = u16::from_bits_be(next_bits(16)?)?;
+            let value = Plaintext::from_bits_be(next_bits(value_size as usize)?)?;
+            // Ensure the value of a `none` option is the placeholder for its type.
+            ensure!(*is_some || value.is_placeholder(), "Found a 'none' option with a value that is not a placeholder");
+
+            // Cache the plaintext bits, and return the option.
+            Ok(Self::Option(is_some, Box::new(value), OnceCell::with_value(bits_be.to_vec())))
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant - {variant:?}");
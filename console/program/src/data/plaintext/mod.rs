@@ -20,6 +20,7 @@ mod from_bits;
 mod from_fields;
 mod num_randomizers;
 mod parse;
+mod placeholder;
 mod serialize;
 mod size_in_fields;
 mod to_bits;
@@ -40,6 +41,10 @@ pub enum Plaintext<N: Network> {
     Struct(IndexMap<Identifier<N>, Plaintext<N>>, OnceCell<Vec<bool>>),
     /// An array.
     Array(Vec<Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// An option, containing a flag that is `true` if the option is `some`, and its value.
+    /// Note that a `none` option still holds a value of the element type, so that an option
+    /// always has the same shape (and size in a circuit) regardless of whether it is `some`.
+    Option(Boolean<N>, Box<Plaintext<N>>, OnceCell<Vec<bool>>),
 }
 
 impl<N: Network> From<Literal<N>> for Plaintext<N> {
@@ -198,6 +203,13 @@ mod tests {
             OnceCell::new(),
         ));
 
+        // Test options.
+        run_test(Plaintext::<CurrentNetwork>::from_str("some(5u8)")?);
+        run_test(Plaintext::<CurrentNetwork>::from_str("none(0u8)")?);
+        run_test(Plaintext::<CurrentNetwork>::from_str("some({ x: 0field, y: 1field })")?);
+        run_test(Plaintext::<CurrentNetwork>::from_str("none([0field, 0field])")?);
+        run_test(Plaintext::<CurrentNetwork>::from_str("{ a: some(true), b: none(false) }")?);
+
         // Test a non-uniform array.
         run_test(Plaintext::<CurrentNetwork>::Array(
             vec![
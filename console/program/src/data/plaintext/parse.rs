@@ -78,6 +78,30 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Array(members, Default::default())))
         }
 
+        /// Parses a plaintext as an option: `some(plaintext)` or `none(plaintext)`.
+        fn parse_option<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the variant from the string.
+            let (string, is_some) = alt((map(tag("some"), |_| true), map(tag("none"), |_| false)))(string)?;
+            // Parse the "(" from the string.
+            let (string, _) = tag("(")(string)?;
+            // Parse the value from the string.
+            let (string, value) = map_res(Plaintext::parse, |value: Plaintext<N>| {
+                // Ensure the value of a `none` option is the placeholder for its type.
+                match is_some || value.is_placeholder() {
+                    true => Ok(value),
+                    false => Err(error("Found a 'none' option with a value that is not a placeholder")),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ')' from the string.
+            let (string, _) = tag(")")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::Option(Boolean::new(is_some), Box::new(value), Default::default())))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
@@ -88,6 +112,8 @@ impl<N: Network> Parser for Plaintext<N> {
             parse_struct,
             // Parse a plaintext array.
             parse_array,
+            // Parse a plaintext option.
+            parse_option,
         ))(string)
     }
 }
@@ -149,7 +175,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) | Self::Array(..) => {
+                        Self::Struct(..) | Self::Array(..) | Self::Option(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -182,7 +208,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) | Self::Array(..) => {
+                        Self::Struct(..) | Self::Array(..) | Self::Option(..) => {
                             // Print a newline.
                             write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -198,6 +224,21 @@ impl<N: Network> Plaintext<N> {
                     }
                 })
             }
+            // Prints the option, i.e. some(10u64)
+            Self::Option(is_some, value, ..) => {
+                // Print the variant.
+                match **is_some {
+                    true => write!(f, "some(")?,
+                    false => write!(f, "none(")?,
+                }
+                // Print the value.
+                match &**value {
+                    Self::Literal(literal, ..) => write!(f, "{literal}")?,
+                    Self::Struct(..) | Self::Array(..) | Self::Option(..) => value.fmt_internal(f, depth)?,
+                }
+                // Print the closing parenthesis.
+                write!(f, ")")
+            }
         }
     }
 }
@@ -474,4 +515,42 @@ mod tests {
         assert_eq!(expected, candidate.to_string());
         assert_eq!("", remainder);
     }
+
+    #[test]
+    fn test_option() {
+        // Test options of literals.
+        for expected in
+            ["some(1u8)", "none(0u8)", "some(aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah)"]
+        {
+            let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse(expected).unwrap();
+            assert_eq!(expected, candidate.to_string());
+            assert_eq!("", remainder);
+        }
+
+        // Test a struct with option members.
+        let expected = r"{
+  foo: some(1u8),
+  bar: none({
+    baz: 0u8
+  }),
+  qux: some([
+    2u8,
+    3u8
+  ])
+}";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse(expected).unwrap();
+        println!("\nExpected: {expected}\n\nFound: {candidate}\n");
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Ensure an option must contain a value.
+        assert!(Plaintext::<CurrentNetwork>::parse("some()").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("none").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("some 1u8").is_err());
+
+        // Ensure a `none` option must contain the placeholder for its type.
+        assert!(Plaintext::<CurrentNetwork>::parse("none(1u8)").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("none([0field, 1field])").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("{ a: none(some(0u8)) }").is_err());
+    }
 }
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the placeholder for the type of this plaintext, which is the value held by a `none` option.
+    /// The placeholder is the default value of the type: each literal is `zero`, and each option is `none`.
+    pub fn to_placeholder(&self) -> Result<Self> {
+        match self {
+            Self::Literal(literal, ..) => Ok(Self::from(Literal::zero(literal.to_type())?)),
+            Self::Struct(members, ..) => Ok(Self::Struct(
+                members
+                    .iter()
+                    .map(|(identifier, member)| Ok((*identifier, member.to_placeholder()?)))
+                    .collect::<Result<IndexMap<_, _>>>()?,
+                Default::default(),
+            )),
+            Self::Array(elements, ..) => Ok(Self::Array(
+                elements.iter().map(|element| element.to_placeholder()).collect::<Result<Vec<_>>>()?,
+                Default::default(),
+            )),
+            Self::Option(_, value, ..) => Ok(Self::new_none(value)?),
+        }
+    }
+
+    /// Returns `true` if this plaintext is the placeholder for its type.
+    pub fn is_placeholder(&self) -> bool {
+        match self {
+            Self::Literal(literal, ..) => Literal::zero(literal.to_type()).is_ok_and(|zero| *literal == zero),
+            Self::Struct(members, ..) => members.values().all(|member| member.is_placeholder()),
+            Self::Array(elements, ..) => elements.iter().all(|element| element.is_placeholder()),
+            Self::Option(is_some, value, ..) => !**is_some && value.is_placeholder(),
+        }
+    }
+
+    /// Returns a `none` option for the type of the given value, which holds the placeholder for the type.
+    pub fn new_none(value: &Plaintext<N>) -> Result<Self> {
+        Ok(Self::Option(Boolean::new(false), Box::new(value.to_placeholder()?), Default::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_placeholder() -> Result<()> {
+        let check = |value: &str, expected: &str| -> Result<()> {
+            let value = Plaintext::<CurrentNetwork>::from_str(value)?;
+            let expected = Plaintext::<CurrentNetwork>::from_str(expected)?;
+            assert_eq!(value.to_placeholder()?, expected);
+            assert!(expected.is_placeholder());
+            assert_eq!(
+                Plaintext::new_none(&value)?,
+                Plaintext::Option(Boolean::new(false), Box::new(expected), Default::default())
+            );
+            Ok(())
+        };
+
+        check("5u8", "0u8")?;
+        check("true", "false")?;
+        check("\"hello\"", "\"\"")?;
+        check("[1field, 2field]", "[0field, 0field]")?;
+        check("{ a: 1i64, b: [true, false] }", "{ a: 0i64, b: [false, false] }")?;
+        check("some(5u8)", "none(0u8)")?;
+        check("{ a: some({ b: 3u32 }) }", "{ a: none({ b: 0u32 }) }")?;
+
+        // Ensure a value that is not the default value is not a placeholder.
+        assert!(!Plaintext::<CurrentNetwork>::from_str("1u8")?.is_placeholder());
+        assert!(!Plaintext::<CurrentNetwork>::from_str("[0u8, 1u8]")?.is_placeholder());
+        assert!(!Plaintext::<CurrentNetwork>::from_str("some(0u8)")?.is_placeholder());
+        Ok(())
+    }
+}
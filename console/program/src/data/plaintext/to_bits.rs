@@ -85,6 +85,25 @@ impl<N: Network> ToBits for Plaintext<N> {
                 // Extend the vector with the bits.
                 vec.extend_from_slice(bits)
             }
+            Self::Option(is_some, value, bits_le) => {
+                // Compute the bits.
+                let bits = bits_le.get_or_init(|| {
+                    let mut bits_le = vec![true, true]; // Variant bits.
+
+                    // Write the flag indicating whether the option is `some`.
+                    bits_le.push(**is_some);
+
+                    // Write the value of the option.
+                    let value_bits = value.to_bits_le();
+                    u16::try_from(value_bits.len())
+                        .or_halt_with::<N>("Plaintext option value exceeds u16::MAX bits")
+                        .write_bits_le(&mut bits_le);
+                    bits_le.extend(value_bits);
+                    bits_le
+                });
+                // Extend the vector with the bits.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 
@@ -159,6 +178,25 @@ impl<N: Network> ToBits for Plaintext<N> {
                 // Extend the vector with the bits.
                 vec.extend_from_slice(bits)
             }
+            Self::Option(is_some, value, bits_be) => {
+                // Compute the bits.
+                let bits = bits_be.get_or_init(|| {
+                    let mut bits_be = vec![true, true]; // Variant bits.
+
+                    // Write the flag indicating whether the option is `some`.
+                    bits_be.push(**is_some);
+
+                    // Write the value of the option.
+                    let value_bits = value.to_bits_be();
+                    u16::try_from(value_bits.len())
+                        .or_halt_with::<N>("Plaintext option value exceeds u16::MAX bits")
+                        .write_bits_be(&mut bits_be);
+                    bits_be.extend(value_bits);
+                    bits_be
+                });
+                // Extend the vector with the bits.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 }
@@ -46,6 +46,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
                 parse_struct,
                 // Parse an array.
                 parse_array,
+                // Parse an option.
+                parse_option,
             ))(string)?;
             // Parse the whitespace from the string.
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
@@ -111,7 +113,7 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             let (string, (elements, mode)) = map_res(
                 separated_list1(
                     pair(Sanitizer::parse_whitespaces, pair(tag(","), Sanitizer::parse_whitespaces)),
-                    alt((parse_literal, parse_struct, parse_array)),
+                    alt((parse_literal, parse_struct, parse_array, parse_option)),
                 ),
                 |members: Vec<(Plaintext<N>, Mode)>| {
                     // Ensure the members all have the same visibility.
@@ -136,6 +138,36 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             Ok((string, (Plaintext::Array(elements, Default::default()), mode)))
         }
 
+        /// Parses an entry as an option: `some(plaintext.visibility)` or `none(plaintext.visibility)`.
+        /// Observe the `visibility` of the option is the visibility of its value.
+        fn parse_option<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the variant from the string.
+            let (string, is_some) = alt((map(tag("some"), |_| true), map(tag("none"), |_| false)))(string)?;
+            // Parse the "(" from the string.
+            let (string, _) = tag("(")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the value and visibility from the string.
+            let (string, (value, mode)) = map_res(
+                alt((parse_literal, parse_struct, parse_array, parse_option)),
+                |(value, mode): (Plaintext<N>, Mode)| {
+                    // Ensure the value of a `none` option is the placeholder for its type.
+                    match is_some || value.is_placeholder() {
+                        true => Ok((value, mode)),
+                        false => Err(error("Found a 'none' option with a value that is not a placeholder")),
+                    }
+                },
+            )(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ')' from the string.
+            let (string, _) = tag(")")(string)?;
+            // Output the plaintext and visibility.
+            Ok((string, (Plaintext::Option(Boolean::new(is_some), Box::new(value), Default::default()), mode)))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the entry (order matters).
@@ -146,6 +178,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             parse_struct,
             // Parse an array.
             parse_array,
+            // Parse an option.
+            parse_option,
         ))(string)?;
 
         // Return the entry.
@@ -223,7 +257,7 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal}.{visibility},", "", indent = (depth + 1) * INDENT),
                         },
-                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                        Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Option(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -261,7 +295,7 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{literal}.{visibility},", "", indent = (depth + 1) * INDENT),
                         },
-                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                        Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Option(..) => {
                             // Print a new line.
                             write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -281,6 +315,25 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                     }
                 })
             }
+            // Prints the option, i.e. some(10u64.public)
+            Plaintext::Option(is_some, value, ..) => {
+                // Print the variant.
+                match **is_some {
+                    true => write!(f, "some(")?,
+                    false => write!(f, "none(")?,
+                }
+                // Print the value.
+                match &**value {
+                    Plaintext::Literal(literal, ..) => write!(f, "{literal}.{visibility}")?,
+                    Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Option(..) => match self {
+                        Self::Constant(..) => Self::Constant((**value).clone()).fmt_internal(f, depth)?,
+                        Self::Public(..) => Self::Public((**value).clone()).fmt_internal(f, depth)?,
+                        Self::Private(..) => Self::Private((**value).clone()).fmt_internal(f, depth)?,
+                    },
+                }
+                // Print the closing parenthesis.
+                write!(f, ")")
+            }
         }
     }
 }
@@ -334,6 +387,24 @@ mod tests {
         assert_eq!(expected, candidate.to_string());
         assert_eq!("", remainder);
 
+        // Test options.
+        let expected = r"{
+  foo: some(5u8.private),
+  bar: none({
+    baz: 0field.private
+  })
+}";
+        let (remainder, candidate) = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(
+            "{ foo: some(5u8.private), bar: none({ baz: 0field.private }) }",
+        )?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Ensure a `none` option must contain the placeholder for its type.
+        assert!(
+            Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse("{ foo: none({ baz: 1field.private }) }").is_err()
+        );
+
         // Test an array of literals.
         let expected = r"[
   5u8.private,
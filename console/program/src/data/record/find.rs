@@ -26,7 +26,7 @@ impl<N: Network> Record<N, Plaintext<N>> {
         if let Some((first, rest)) = path.split_first() {
             let first = match (*first).into() {
                 Access::Member(identifier) => identifier,
-                Access::Index(_) => bail!("Attempted to index into a record"),
+                Access::Index(_) | Access::Dynamic(_) => bail!("Attempted to index into a record"),
             };
             // Retrieve the top-level entry.
             match self.data.get(&first) {
@@ -157,13 +157,16 @@ impl<N: Network> Record<N, Plaintext<N>> {
                 Entry::Constant(Plaintext::Literal(..))
                 | Entry::Public(Plaintext::Literal(..))
                 | Entry::Private(Plaintext::Literal(..)) => write!(f, "{entry}")?,
-                // If the entry is a struct or an array, print the entry with indentation.
+                // If the entry is a struct, an array, or an option, print the entry with indentation.
                 Entry::Constant(Plaintext::Struct(..))
                 | Entry::Public(Plaintext::Struct(..))
                 | Entry::Private(Plaintext::Struct(..))
                 | Entry::Constant(Plaintext::Array(..))
                 | Entry::Public(Plaintext::Array(..))
-                | Entry::Private(Plaintext::Array(..)) => entry.fmt_internal(f, depth + 1)?,
+                | Entry::Private(Plaintext::Array(..))
+                | Entry::Constant(Plaintext::Option(..))
+                | Entry::Public(Plaintext::Option(..))
+                | Entry::Private(Plaintext::Option(..)) => entry.fmt_internal(f, depth + 1)?,
             }
             // Print the comma.
             write!(f, ",")?;
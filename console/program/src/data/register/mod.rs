@@ -37,6 +37,24 @@ impl<N: Network> Register<N> {
             Self::Access(locator, _) => *locator,
         }
     }
+
+    /// Returns the register, with its locator (and any dynamic access locators) remapped by the given function.
+    #[inline]
+    pub fn map_locator(&self, map: impl Fn(u64) -> u64) -> Self {
+        match self {
+            Self::Locator(locator) => Self::Locator(map(*locator)),
+            Self::Access(locator, accesses) => Self::Access(
+                map(*locator),
+                accesses
+                    .iter()
+                    .map(|access| match access {
+                        Access::Dynamic(index) => Access::Dynamic(map(*index)),
+                        access => *access,
+                    })
+                    .collect(),
+            ),
+        }
+    }
 }
 
 impl<N: Network> Ord for Register<N> {
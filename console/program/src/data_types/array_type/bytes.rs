@@ -57,7 +57,7 @@ impl<N: Network> ToBytes for ArrayType<N> {
         // Note that the lengths are in the order of the outermost dimension to the innermost dimension.
         for _ in 1..N::MAX_DATA_DEPTH {
             element_type = match element_type {
-                PlaintextType::Literal(_) | PlaintextType::Struct(_) => break,
+                PlaintextType::Literal(_) | PlaintextType::Struct(_) | PlaintextType::Option(_) => break,
                 PlaintextType::Array(array_type) => {
                     lengths.push(*array_type.length());
                     array_type.next_element_type().clone()
@@ -80,6 +80,9 @@ impl<N: Network> ToBytes for ArrayType<N> {
                 1u8.write_le(&mut writer)?;
                 identifier.write_le(&mut writer)?;
             }
+            PlaintextType::Option(_) => {
+                return Err(error("Array type cannot contain an option type."));
+            }
             PlaintextType::Array(_) => {
                 // This is technically unreachable by definition, however we return an error
                 // out of an abundance of caution.
@@ -63,6 +63,8 @@ pub enum LiteralType {
     Signature,
     /// The string type.
     String,
+    /// The fixed-point decimal type.
+    Fixed,
 }
 
 impl LiteralType {
@@ -86,6 +88,7 @@ impl LiteralType {
             Self::Scalar => "scalar",
             Self::Signature => "signature",
             Self::String => "string",
+            Self::Fixed => "fixed",
         }
     }
 
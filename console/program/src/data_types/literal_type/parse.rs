@@ -23,6 +23,7 @@ impl Parser for LiteralType {
             map(tag("address"), |_| Self::Address),
             map(tag("boolean"), |_| Self::Boolean),
             map(tag("field"), |_| Self::Field),
+            map(tag("fixed"), |_| Self::Fixed),
             map(tag("group"), |_| Self::Group),
             map(tag("i8"), |_| Self::I8),
             map(tag("i16"), |_| Self::I16),
@@ -21,6 +21,9 @@ pub use finalize_type::FinalizeType;
 mod literal_type;
 pub use literal_type::LiteralType;
 
+mod option_type;
+pub use option_type::OptionType;
+
 mod plaintext_type;
 pub use plaintext_type::PlaintextType;
 
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{ArrayType, Identifier, LiteralType};
+
+impl<N: Network> FromBytes for OptionType<N> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the element type.
+        let variant = u8::read_le(&mut reader)?;
+        let element_type = match variant {
+            0 => PlaintextType::Literal(LiteralType::read_le(&mut reader)?),
+            1 => PlaintextType::Struct(Identifier::read_le(&mut reader)?),
+            2 => PlaintextType::Array(ArrayType::read_le(&mut reader)?),
+            3.. => return Err(error(format!("Failed to deserialize element type {variant}"))),
+        };
+        // Construct the option type.
+        OptionType::new(element_type).map_err(|e| error(format!("{e}")))
+    }
+}
+
+impl<N: Network> ToBytes for OptionType<N> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the element type.
+        match self.element_type() {
+            PlaintextType::Literal(literal_type) => {
+                0u8.write_le(&mut writer)?;
+                literal_type.write_le(&mut writer)
+            }
+            PlaintextType::Struct(identifier) => {
+                1u8.write_le(&mut writer)?;
+                identifier.write_le(&mut writer)
+            }
+            PlaintextType::Array(array_type) => {
+                2u8.write_le(&mut writer)?;
+                array_type.write_le(&mut writer)
+            }
+            PlaintextType::Option(_) => {
+                // This is technically unreachable by definition, however we return an error
+                // out of an abundance of caution.
+                Err(error("An option type cannot contain an option type"))
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+pub(crate) mod serialize;
+
+use crate::PlaintextType;
+use snarkvm_console_network::prelude::*;
+
+use core::fmt::{Debug, Display};
+
+/// An `OptionType` defines the type of a value that is either `some` value of the element type, or `none`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct OptionType<N: Network> {
+    /// The element type.
+    element_type: Box<PlaintextType<N>>,
+}
+
+impl<N: Network> OptionType<N> {
+    /// Initializes a new option type.
+    pub fn new(element_type: PlaintextType<N>) -> Result<Self> {
+        // Ensure the element type is not an option type.
+        ensure!(!matches!(element_type, PlaintextType::Option(..)), "An option type cannot contain an option type");
+        // Construct the option type.
+        Ok(Self { element_type: Box::new(element_type) })
+    }
+}
+
+impl<N: Network> OptionType<N> {
+    /// Returns the element type.
+    pub const fn element_type(&self) -> &PlaintextType<N> {
+        &self.element_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArrayType, Identifier, LiteralType};
+    use snarkvm_console_network::MainnetV0;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_option_type() -> Result<()> {
+        // Test literal option types.
+        let option = OptionType::<CurrentNetwork>::from_str("option<u64>")?;
+        assert_eq!(option, OptionType::<CurrentNetwork>::new(PlaintextType::from_str("u64")?)?);
+        assert_eq!(
+            option.to_bytes_le()?,
+            OptionType::<CurrentNetwork>::from_bytes_le(&option.to_bytes_le()?)?.to_bytes_le()?
+        );
+        assert_eq!(option.element_type(), &PlaintextType::Literal(LiteralType::U64));
+        assert_eq!(option.to_string(), "option<u64>");
+
+        // Test struct option types.
+        let option = OptionType::<CurrentNetwork>::from_str("option<foo>")?;
+        assert_eq!(option.element_type(), &PlaintextType::Struct(Identifier::from_str("foo")?));
+        assert_eq!(
+            option.to_bytes_le()?,
+            OptionType::<CurrentNetwork>::from_bytes_le(&option.to_bytes_le()?)?.to_bytes_le()?
+        );
+
+        // Test array option types.
+        let option = OptionType::<CurrentNetwork>::from_str("option<[field; 4u32]>")?;
+        assert_eq!(option.element_type(), &PlaintextType::Array(ArrayType::from_str("[field; 4u32]")?));
+        assert_eq!(
+            option.to_bytes_le()?,
+            OptionType::<CurrentNetwork>::from_bytes_le(&option.to_bytes_le()?)?.to_bytes_le()?
+        );
+        assert_eq!(option.to_string(), "option<[field; 4u32]>");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_option_type_fails() {
+        assert!(OptionType::<CurrentNetwork>::from_str("option<>").is_err());
+        assert!(OptionType::<CurrentNetwork>::from_str("option<u8").is_err());
+        assert!(OptionType::<CurrentNetwork>::from_str("option<option<u8>>").is_err());
+        assert!(OptionType::<CurrentNetwork>::from_str("option<u8.public>").is_err());
+        assert!(OptionType::<CurrentNetwork>::new(PlaintextType::from_str("option<u8>").unwrap()).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{ArrayType, Identifier, LiteralType};
+
+impl<N: Network> Parser for OptionType<N> {
+    /// Parses a string into an option type.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // A helper function to parse the element type.
+        // Note that the element type is parsed without recursing into `PlaintextType::parse`,
+        // as it cannot be an option type.
+        fn parse_element_type<N: Network>(string: &str) -> ParserResult<PlaintextType<N>> {
+            // Parse to determine the element type (order matters).
+            alt((
+                map(ArrayType::parse, PlaintextType::from),
+                map(Identifier::parse, PlaintextType::from),
+                map(LiteralType::parse, PlaintextType::from),
+            ))(string)
+        }
+
+        // Parse the keyword and the opening angle bracket from the string.
+        let (string, _) = tag("option<")(string)?;
+        // Parse the whitespaces from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the element type from the string.
+        let (string, element_type) = map_res(parse_element_type, OptionType::new)(string)?;
+        // Parse the whitespaces from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the closing angle bracket from the string.
+        let (string, _) = tag(">")(string)?;
+        // Return the option type.
+        Ok((string, element_type))
+    }
+}
+
+impl<N: Network> FromStr for OptionType<N> {
+    type Err = Error;
+
+    /// Returns an option type from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for OptionType<N> {
+    /// Prints the option type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for OptionType<N> {
+    /// Prints the option type as a string.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "option<{}>", self.element_type())
+    }
+}
@@ -20,6 +20,7 @@ impl<N: Network> Parser for PlaintextType<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse to determine the plaintext type (order matters).
         alt((
+            map(OptionType::parse, |type_| Self::Option(type_)),
             map(ArrayType::parse, |type_| Self::Array(type_)),
             map(Identifier::parse, |identifier| Self::Struct(identifier)),
             map(LiteralType::parse, |type_| Self::Literal(type_)),
@@ -61,6 +62,8 @@ impl<N: Network> Display for PlaintextType<N> {
             Self::Struct(struct_) => Display::fmt(struct_, f),
             // Prints the array type, i.e. [field; 2u32]
             Self::Array(array) => Display::fmt(array, f),
+            // Prints the option type, i.e. option<field>
+            Self::Option(option) => Display::fmt(option, f),
         }
     }
 }
@@ -94,6 +97,14 @@ mod tests {
             PlaintextType::parse("[field; 1u32]"),
             Ok(("", PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[field; 1u32]")?)))
         );
+        assert_eq!(
+            PlaintextType::parse("option<field>"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Option(OptionType::from_str("option<field>")?)))
+        );
+        assert_eq!(
+            PlaintextType::parse("optional"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("optional")?)))
+        );
         Ok(())
     }
 
@@ -184,6 +195,10 @@ mod tests {
             PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[field; 8u32]")?).to_string(),
             "[field; 8u32]"
         );
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::Option(OptionType::from_str("option<field>")?).to_string(),
+            "option<field>"
+        );
         Ok(())
     }
 }
@@ -101,14 +101,22 @@ mod tests {
 
     #[test]
     fn test_serde_json() {
-        for case in TEST_CASES.iter().chain(crate::data_types::array_type::serialize::tests::TEST_CASES) {
+        for case in TEST_CASES
+            .iter()
+            .chain(crate::data_types::array_type::serialize::tests::TEST_CASES)
+            .chain(crate::data_types::option_type::serialize::tests::TEST_CASES)
+        {
             check_serde_json(PlaintextType::<CurrentNetwork>::from_str(case).unwrap());
         }
     }
 
     #[test]
     fn test_bincode() {
-        for case in TEST_CASES.iter().chain(crate::data_types::array_type::serialize::tests::TEST_CASES) {
+        for case in TEST_CASES
+            .iter()
+            .chain(crate::data_types::array_type::serialize::tests::TEST_CASES)
+            .chain(crate::data_types::option_type::serialize::tests::TEST_CASES)
+        {
             check_bincode(PlaintextType::<CurrentNetwork>::from_str(case).unwrap());
         }
     }
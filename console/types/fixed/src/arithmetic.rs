@@ -0,0 +1,340 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Fixed<E> {
+    /// Returns the `sum` of `self` and `other`, or `None` if an overflow occurs.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        (*self.integer).checked_add(*other.integer).map(Self::new)
+    }
+
+    /// Returns the `difference` of `self` and `other`, or `None` if an overflow occurs.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        (*self.integer).checked_sub(*other.integer).map(Self::new)
+    }
+
+    /// Returns the `product` of `self` and `other`, rounded with the given rounding mode,
+    /// or `None` if the magnitude of the product exceeds `i128::MAX`.
+    pub fn checked_mul_with_rounding(&self, other: &Self, rounding: Rounding) -> Option<Self> {
+        let (quotient, remainder) = self.mul_magnitude_with_remainder(other)?;
+        // Round the quotient, and restore the sign.
+        let is_negative = (*self.integer).is_negative() != (*other.integer).is_negative();
+        apply_rounding(quotient, remainder, Self::SCALING_FACTOR as u128, rounding, is_negative)
+    }
+
+    /// Returns the `quotient` of `self` and `other`, rounded with the given rounding mode,
+    /// or `None` if `other` is zero or the magnitude of the quotient exceeds `i128::MAX`.
+    pub fn checked_div_with_rounding(&self, other: &Self, rounding: Rounding) -> Option<Self> {
+        let (quotient, remainder) = self.div_magnitude_with_remainder(other)?;
+        // Round the quotient, and restore the sign.
+        let is_negative = (*self.integer).is_negative() != (*other.integer).is_negative();
+        apply_rounding(quotient, remainder, (*other.integer).unsigned_abs(), rounding, is_negative)
+    }
+
+    /// Returns the magnitude of the product of `self` and `other` divided by the scaling factor, with its remainder,
+    /// or `None` if the quotient does not fit in 128 bits.
+    pub fn mul_magnitude_with_remainder(&self, other: &Self) -> Option<(u128, u128)> {
+        // Compute the full product of the magnitudes, and remove one factor of the scale.
+        let (high, low) = widening_mul((*self.integer).unsigned_abs(), (*other.integer).unsigned_abs());
+        divide_wide(high, low, Self::SCALING_FACTOR as u128)
+    }
+
+    /// Returns the magnitude of `self` times the scaling factor divided by the magnitude of `other`, with its remainder,
+    /// or `None` if `other` is zero or the quotient does not fit in 128 bits.
+    pub fn div_magnitude_with_remainder(&self, other: &Self) -> Option<(u128, u128)> {
+        // Ensure the divisor is nonzero.
+        if other.is_zero() {
+            return None;
+        }
+        // Scale the magnitude of the dividend, and divide it by the magnitude of the divisor.
+        let (high, low) = widening_mul((*self.integer).unsigned_abs(), Self::SCALING_FACTOR as u128);
+        divide_wide(high, low, (*other.integer).unsigned_abs())
+    }
+}
+
+/// Returns the 256-bit product of `a` and `b`, as the `(high, low)` 128-bit halves.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    // Split the operands into 64-bit limbs.
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    // Compute the partial products, none of which overflow.
+    let z0 = a_lo * b_lo;
+    let (z1, z1_carry) = (a_lo * b_hi).overflowing_add(a_hi * b_lo);
+    let z2 = a_hi * b_hi;
+
+    // Combine the partial products.
+    let (low, low_carry) = z0.overflowing_add(z1 << 64);
+    let high = z2 + (z1 >> 64) + ((z1_carry as u128) << 64) + (low_carry as u128);
+    (high, low)
+}
+
+/// Divides the 256-bit value `(high, low)` by the nonzero `divisor`, returning the quotient and remainder,
+/// or `None` if the quotient does not fit in 128 bits.
+fn divide_wide(high: u128, low: u128, divisor: u128) -> Option<(u128, u128)> {
+    // If the high half is not less than the divisor, the quotient does not fit in 128 bits.
+    if high >= divisor {
+        return None;
+    }
+    // Perform binary long division over the bits of the low half.
+    let (mut quotient, mut remainder) = (0u128, high);
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> i) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+    Some((quotient, remainder))
+}
+
+/// Rounds the unsigned `quotient` given its `remainder` and `divisor`, and applies the sign,
+/// returning `None` if the magnitude of the result exceeds `i128::MAX`.
+fn apply_rounding<E: Environment>(
+    quotient: u128,
+    remainder: u128,
+    divisor: u128,
+    rounding: Rounding,
+    is_negative: bool,
+) -> Option<Fixed<E>> {
+    // Note: `remainder < divisor`, so this subtraction does not underflow.
+    let round_up = match rounding {
+        Rounding::TowardZero => false,
+        Rounding::HalfAwayFromZero => remainder >= divisor - remainder,
+    };
+    let magnitude = i128::try_from(quotient.checked_add(round_up as u128)?).ok()?;
+    Some(Fixed::new(if is_negative { -magnitude } else { magnitude }))
+}
+
+impl<E: Environment> Add<Fixed<E>> for Fixed<E> {
+    type Output = Fixed<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: Fixed<E>) -> Self::Output {
+        match self.checked_add(&other) {
+            Some(fixed) => fixed,
+            None => E::halt(format!("Fixed addition failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment> Add<&Fixed<E>> for Fixed<E> {
+    type Output = Fixed<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: &Fixed<E>) -> Self::Output {
+        self + *other
+    }
+}
+
+impl<E: Environment> Sub<Fixed<E>> for Fixed<E> {
+    type Output = Fixed<E>;
+
+    /// Returns the `difference` of `self` and `other`.
+    #[inline]
+    fn sub(self, other: Fixed<E>) -> Self::Output {
+        match self.checked_sub(&other) {
+            Some(fixed) => fixed,
+            None => E::halt(format!("Fixed subtraction failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment> Sub<&Fixed<E>> for Fixed<E> {
+    type Output = Fixed<E>;
+
+    /// Returns the `difference` of `self` and `other`.
+    #[inline]
+    fn sub(self, other: &Fixed<E>) -> Self::Output {
+        self - *other
+    }
+}
+
+impl<E: Environment> Mul<Fixed<E>> for Fixed<E> {
+    type Output = Fixed<E>;
+
+    /// Returns the `product` of `self` and `other`, rounded toward zero.
+    #[inline]
+    fn mul(self, other: Fixed<E>) -> Self::Output {
+        match self.checked_mul_with_rounding(&other, Rounding::TowardZero) {
+            Some(fixed) => fixed,
+            None => E::halt(format!("Fixed multiplication failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment> Mul<&Fixed<E>> for Fixed<E> {
+    type Output = Fixed<E>;
+
+    /// Returns the `product` of `self` and `other`, rounded toward zero.
+    #[inline]
+    fn mul(self, other: &Fixed<E>) -> Self::Output {
+        self * *other
+    }
+}
+
+impl<E: Environment> MulRounded<Fixed<E>> for Fixed<E> {
+    type Output = Fixed<E>;
+
+    /// Returns the `product` of `self` and `other`, rounded to the nearest value (ties away from zero).
+    #[inline]
+    fn mul_rounded(&self, other: &Fixed<E>) -> Self::Output {
+        match self.checked_mul_with_rounding(other, Rounding::HalfAwayFromZero) {
+            Some(fixed) => fixed,
+            None => E::halt(format!("Fixed multiplication failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment> Div<Fixed<E>> for Fixed<E> {
+    type Output = Fixed<E>;
+
+    /// Returns the `quotient` of `self` and `other`, rounded toward zero.
+    #[inline]
+    fn div(self, other: Fixed<E>) -> Self::Output {
+        match self.checked_div_with_rounding(&other, Rounding::TowardZero) {
+            Some(fixed) => fixed,
+            None => E::halt(format!("Fixed division failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment> Div<&Fixed<E>> for Fixed<E> {
+    type Output = Fixed<E>;
+
+    /// Returns the `quotient` of `self` and `other`, rounded toward zero.
+    #[inline]
+    fn div(self, other: &Fixed<E>) -> Self::Output {
+        self / *other
+    }
+}
+
+impl<E: Environment> DivRounded<Fixed<E>> for Fixed<E> {
+    type Output = Fixed<E>;
+
+    /// Returns the `quotient` of `self` and `other`, rounded to the nearest value (ties away from zero).
+    #[inline]
+    fn div_rounded(&self, other: &Fixed<E>) -> Self::Output {
+        match self.checked_div_with_rounding(other, Rounding::HalfAwayFromZero) {
+            Some(fixed) => fixed,
+            None => E::halt(format!("Fixed division failed on: {self} and {other}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    /// Returns the fixed-point number for the given string.
+    fn fixed(string: &str) -> Fixed<CurrentEnvironment> {
+        Fixed::from_str(string).unwrap()
+    }
+
+    #[test]
+    fn test_widening_mul() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (a, b) = (u64::rand(&mut rng) as u128, u128::rand(&mut rng) >> 64);
+            assert_eq!(widening_mul(a, b), (0, a * b));
+
+            let (a, b) = (u128::rand(&mut rng), u128::rand(&mut rng));
+            let (high, low) = widening_mul(a, b);
+            // Ensure the product is recovered by dividing by one of the operands.
+            if b != 0 {
+                assert_eq!(divide_wide(high, low, b), Some((a, 0)));
+            }
+        }
+        assert_eq!(widening_mul(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+    }
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(fixed("1.5fixed") * fixed("2.0fixed"), fixed("3.0fixed"));
+        assert_eq!(fixed("-1.5fixed") * fixed("2.0fixed"), fixed("-3.0fixed"));
+        assert_eq!(fixed("-0.5fixed") * fixed("-0.5fixed"), fixed("0.25fixed"));
+        assert_eq!(fixed("0.000000000000000001fixed") * fixed("0.5fixed"), fixed("0.0fixed"));
+        assert_eq!(
+            fixed("0.000000000000000001fixed").mul_rounded(&fixed("0.5fixed")),
+            fixed("0.000000000000000001fixed")
+        );
+        assert_eq!(
+            fixed("-0.000000000000000001fixed").mul_rounded(&fixed("0.5fixed")),
+            fixed("-0.000000000000000001fixed")
+        );
+        assert_eq!(fixed("0.000000000000000001fixed").mul_rounded(&fixed("0.4fixed")), fixed("0.0fixed"));
+        assert_eq!(Fixed::MAX * fixed("1.0fixed"), Fixed::MAX);
+
+        // Ensure the product halts if its magnitude exceeds `i128::MAX`.
+        assert!(fixed("1.0fixed").checked_mul_with_rounding(&Fixed::MIN, Rounding::TowardZero).is_none());
+        assert!(Fixed::MAX.checked_mul_with_rounding(&fixed("2.0fixed"), Rounding::TowardZero).is_none());
+        assert!(std::panic::catch_unwind(|| Fixed::<CurrentEnvironment>::MAX * fixed("1.5fixed")).is_err());
+    }
+
+    #[test]
+    fn test_div() {
+        assert_eq!(fixed("3.0fixed") / fixed("2.0fixed"), fixed("1.5fixed"));
+        assert_eq!(fixed("-3.0fixed") / fixed("2.0fixed"), fixed("-1.5fixed"));
+        assert_eq!(fixed("1.0fixed") / fixed("3.0fixed"), fixed("0.333333333333333333fixed"));
+        assert_eq!(fixed("2.0fixed") / fixed("3.0fixed"), fixed("0.666666666666666666fixed"));
+        assert_eq!(fixed("2.0fixed").div_rounded(&fixed("3.0fixed")), fixed("0.666666666666666667fixed"));
+        assert_eq!(fixed("-2.0fixed").div_rounded(&fixed("3.0fixed")), fixed("-0.666666666666666667fixed"));
+        assert_eq!(fixed("1.0fixed").div_rounded(&fixed("3.0fixed")), fixed("0.333333333333333333fixed"));
+
+        // Ensure division by zero halts.
+        assert!(fixed("1.0fixed").checked_div_with_rounding(&Fixed::zero(), Rounding::TowardZero).is_none());
+        assert!(std::panic::catch_unwind(|| fixed("1.0fixed") / Fixed::zero()).is_err());
+        // Ensure the quotient halts if its magnitude exceeds `i128::MAX`.
+        assert!(Fixed::MAX.checked_div_with_rounding(&fixed("0.5fixed"), Rounding::TowardZero).is_none());
+    }
+
+    #[test]
+    fn test_mul_div_roundtrip() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample values whose product is always in range.
+            let a = Fixed::<CurrentEnvironment>::new(i64::rand(&mut rng) as i128);
+            let b = Fixed::<CurrentEnvironment>::new(
+                i64::rand(&mut rng) as i128 | Fixed::<CurrentEnvironment>::SCALING_FACTOR,
+            );
+
+            // Ensure multiplying by a whole number is exact, and is undone by division.
+            let whole = Fixed::from_whole(i32::rand(&mut rng) as i128).unwrap();
+            assert_eq!(*(a * whole), *a * whole.to_whole());
+            if !whole.is_zero() {
+                assert_eq!((a * whole) / whole, a);
+            }
+
+            // Ensure rounding to the nearest value differs by at most one unit from truncation.
+            let (truncated, rounded) = (a * b, a.mul_rounded(&b));
+            assert!((*rounded - *truncated).abs() <= 1);
+            let (truncated, rounded) = (a / b, a.div_rounded(&b));
+            assert!((*rounded - *truncated).abs() <= 1);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Equal for Fixed<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        Boolean::new(self == other)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        Boolean::new(self != other)
+    }
+}
+
+impl<E: Environment> Compare for Fixed<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        Boolean::new(self.integer < other.integer)
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_greater_than_or_equal(self)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.is_less_than(other)
+    }
+}
+
+impl<E: Environment> Ternary for Fixed<E> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        match **condition {
+            true => *first,
+            false => *second,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> FromBits for Fixed<E> {
+    /// Initializes a new fixed-point number from a list of **little-endian** bits.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        Ok(Self { integer: I128::from_bits_le(bits_le)? })
+    }
+
+    /// Initializes a new fixed-point number from a list of **big-endian** bits.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        Ok(Self { integer: I128::from_bits_be(bits_be)? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random value.
+            let expected: Fixed<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            let given_bits = expected.to_bits_le();
+            assert_eq!(Fixed::<CurrentEnvironment>::size_in_bits(), given_bits.len());
+            assert_eq!(expected, Fixed::<CurrentEnvironment>::from_bits_le(&given_bits)?);
+            assert_eq!(expected, Fixed::<CurrentEnvironment>::from_bits_be(&expected.to_bits_be())?);
+
+            // Add excess zero bits.
+            let candidate = [given_bits, vec![false; i]].concat();
+            assert_eq!(expected, Fixed::<CurrentEnvironment>::from_bits_le(&candidate)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+#![warn(clippy::cast_possible_truncation)]
+
+mod arithmetic;
+mod bitwise;
+mod bytes;
+mod compare;
+mod from_bits;
+mod parse;
+mod random;
+mod serialize;
+mod size_in_bits;
+mod size_in_bytes;
+mod to_bits;
+mod zero;
+
+pub use snarkvm_console_network_environment::prelude::*;
+pub use snarkvm_console_types_boolean::Boolean;
+pub use snarkvm_console_types_field::Field;
+pub use snarkvm_console_types_integers::{Integer, I128};
+
+/// The rounding mode used when a fixed-point product or quotient is not exactly representable.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Discards the digits beyond the scale, rounding toward zero.
+    TowardZero,
+    /// Rounds to the nearest representable value, rounding ties away from zero.
+    HalfAwayFromZero,
+}
+
+/// A signed fixed-point decimal number, represented as a 128-bit integer scaled by `10^SCALE`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Fixed<E: Environment> {
+    /// The underlying integer, scaled by `10^SCALE`.
+    integer: I128<E>,
+}
+
+impl<E: Environment> Fixed<E> {
+    /// The number of decimal digits after the decimal point.
+    pub const SCALE: u32 = 18;
+    /// The scaling factor, `10^SCALE`.
+    pub const SCALING_FACTOR: i128 = 10i128.pow(Self::SCALE);
+    /// The largest representable value.
+    pub const MAX: Self = Self::new(i128::MAX);
+    /// The smallest representable value.
+    pub const MIN: Self = Self::new(i128::MIN);
+
+    /// Initializes a new fixed-point number from its scaled integer representation.
+    pub const fn new(integer: i128) -> Self {
+        Self { integer: I128::new(integer) }
+    }
+
+    /// Initializes a new fixed-point number from a whole number, returning `None` if it is out of range.
+    pub fn from_whole(whole: i128) -> Option<Self> {
+        whole.checked_mul(Self::SCALING_FACTOR).map(Self::new)
+    }
+
+    /// Returns the scaled integer representation of the fixed-point number.
+    pub const fn to_integer(&self) -> I128<E> {
+        self.integer
+    }
+
+    /// Returns the whole part of the fixed-point number, rounded toward zero.
+    pub fn to_whole(&self) -> i128 {
+        *self.integer / Self::SCALING_FACTOR
+    }
+
+    /// Returns `true` if the fixed-point number has no fractional part.
+    pub fn is_whole(&self) -> bool {
+        *self.integer % Self::SCALING_FACTOR == 0
+    }
+}
+
+impl<E: Environment> TypeName for Fixed<E> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "fixed"
+    }
+}
+
+impl<E: Environment> Deref for Fixed<E> {
+    type Target = i128;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &*self.integer
+    }
+}
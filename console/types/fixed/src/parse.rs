@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Parser for Fixed<E> {
+    /// Parses a string into a fixed-point number.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the negative sign '-' from the string.
+        let (string, negation) = opt(tag("-"))(string)?;
+        // Parse the whole digits from the string.
+        let (string, whole) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+        // Parse the fractional digits from the string.
+        let (string, fraction) = opt(pair(char('.'), recognize(many1(one_of("0123456789")))))(string)?;
+        // Parse the value from the string.
+        let (string, value) = map_res(tag(Self::type_name()), |_| {
+            Self::from_decimal(negation.is_some(), whole, fraction.map(|(_, fraction)| fraction).unwrap_or_default())
+        })(string)?;
+
+        Ok((string, value))
+    }
+}
+
+impl<E: Environment> Fixed<E> {
+    /// Initializes a new fixed-point number from the given sign, whole digits, and fractional digits.
+    fn from_decimal(is_negative: bool, whole: &str, fraction: &str) -> Result<Self> {
+        // Ensure the fractional digits do not exceed the scale.
+        let scale = Self::SCALE as usize;
+        ensure!(fraction.len() <= scale, "A fixed-point number supports at most {scale} fractional digits");
+
+        // Compute the magnitude of the scaled integer.
+        let whole: u128 = whole.replace('_', "").parse()?;
+        let fraction: u128 = format!("{fraction:0<scale$}").parse()?;
+        let magnitude = whole.checked_mul(Self::SCALING_FACTOR as u128).and_then(|whole| whole.checked_add(fraction));
+
+        // Apply the sign to the magnitude.
+        let integer = match (is_negative, magnitude) {
+            (false, Some(magnitude)) => i128::try_from(magnitude).ok(),
+            (true, Some(magnitude)) => 0i128.checked_sub_unsigned(magnitude),
+            (_, None) => None,
+        };
+        match integer {
+            Some(integer) => Ok(Self::new(integer)),
+            None => bail!("The fixed-point number is out of range"),
+        }
+    }
+}
+
+impl<E: Environment> FromStr for Fixed<E> {
+    type Err = Error;
+
+    /// Parses a string into a fixed-point number.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<E: Environment> Debug for Fixed<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<E: Environment> Display for Fixed<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Split the magnitude into its whole and fractional parts.
+        let magnitude = (*self.integer).unsigned_abs();
+        let (whole, fraction) = (magnitude / Self::SCALING_FACTOR as u128, magnitude % Self::SCALING_FACTOR as u128);
+        // Format the fractional part without trailing zeros, keeping at least one digit.
+        let fraction = format!("{fraction:0>width$}", width = Self::SCALE as usize);
+        let fraction = match fraction.trim_end_matches('0') {
+            "" => "0",
+            fraction => fraction,
+        };
+        let sign = if (*self.integer).is_negative() { "-" } else { "" };
+        write!(f, "{sign}{whole}.{fraction}{}", Self::type_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        // Ensure empty value fails.
+        assert!(Fixed::<CurrentEnvironment>::parse(Fixed::<CurrentEnvironment>::type_name()).is_err());
+        assert!(Fixed::<CurrentEnvironment>::parse("").is_err());
+        assert!(Fixed::<CurrentEnvironment>::parse(".5fixed").is_err());
+        assert!(Fixed::<CurrentEnvironment>::parse("1.fixed").is_err());
+        assert!(Fixed::<CurrentEnvironment>::parse("1.5").is_err());
+
+        // Ensure the scale is enforced.
+        assert!(Fixed::<CurrentEnvironment>::parse("0.1234567890123456789fixed").is_err());
+        assert_eq!(*Fixed::<CurrentEnvironment>::from_str("0.123456789012345678fixed")?, 123456789012345678);
+
+        // Ensure the sign and digits are parsed.
+        assert_eq!(*Fixed::<CurrentEnvironment>::from_str("1fixed")?, 1_000_000_000_000_000_000);
+        assert_eq!(*Fixed::<CurrentEnvironment>::from_str("1.5fixed")?, 1_500_000_000_000_000_000);
+        assert_eq!(*Fixed::<CurrentEnvironment>::from_str("-0.25fixed")?, -250_000_000_000_000_000);
+        assert_eq!(*Fixed::<CurrentEnvironment>::from_str("1_000.0fixed")?, 1_000_000_000_000_000_000_000);
+
+        // Ensure the range is enforced.
+        assert_eq!(Fixed::<CurrentEnvironment>::from_str(&Fixed::<CurrentEnvironment>::MAX.to_string())?, Fixed::MAX);
+        assert_eq!(Fixed::<CurrentEnvironment>::from_str(&Fixed::<CurrentEnvironment>::MIN.to_string())?, Fixed::MIN);
+        assert!(Fixed::<CurrentEnvironment>::from_str("170141183460469231732fixed").is_err());
+        assert!(Fixed::<CurrentEnvironment>::from_str("-170141183460469231732fixed").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        assert_eq!("0.0fixed", Fixed::<CurrentEnvironment>::zero().to_string());
+        assert_eq!("1.5fixed", Fixed::<CurrentEnvironment>::new(1_500_000_000_000_000_000).to_string());
+        assert_eq!("-0.000000000000000001fixed", Fixed::<CurrentEnvironment>::new(-1).to_string());
+        assert_eq!("-170141183460469231731.687303715884105728fixed", Fixed::<CurrentEnvironment>::MIN.to_string());
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value.
+            let expected: Fixed<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            let (remainder, candidate) = Fixed::<CurrentEnvironment>::parse(&expected.to_string()).unwrap();
+            assert_eq!(expected, candidate);
+            assert_eq!("", remainder);
+        }
+        Ok(())
+    }
+}
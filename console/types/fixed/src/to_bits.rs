@@ -0,0 +1,27 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> ToBits for Fixed<E> {
+    /// Outputs the little-endian bit representation of `self` *without* trailing zeros.
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        self.integer.write_bits_le(vec);
+    }
+
+    /// Outputs the big-endian bit representation of `self` *without* leading zeros.
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        self.integer.write_bits_be(vec);
+    }
+}
@@ -95,6 +95,17 @@ impl<E: Environment, I: IntegerType> AddWrapped<Integer<E, I>> for Integer<E, I>
     }
 }
 
+impl<E: Environment, I: IntegerType> AddOverflowing<Integer<E, I>> for Integer<E, I> {
+    type Output = (Integer<E, I>, Boolean<E>);
+
+    /// Returns the wrapped `sum` of `self` and `other`, and whether an overflow occurred.
+    #[inline]
+    fn add_overflowing(&self, other: &Integer<E, I>) -> Self::Output {
+        let is_overflow = self.integer.checked_add(&other.integer).is_none();
+        (Integer::new(self.integer.wrapping_add(&other.integer)), Boolean::new(is_overflow))
+    }
+}
+
 impl<E: Environment, I: IntegerType> AddAssign<Integer<E, I>> for Integer<E, I> {
     /// Adds `other` to `self`.
     #[inline]
@@ -153,6 +164,17 @@ impl<E: Environment, I: IntegerType> SubWrapped<Integer<E, I>> for Integer<E, I>
     }
 }
 
+impl<E: Environment, I: IntegerType> SubOverflowing<Integer<E, I>> for Integer<E, I> {
+    type Output = (Integer<E, I>, Boolean<E>);
+
+    /// Returns the wrapped `difference` of `self` and `other`, and whether an underflow occurred.
+    #[inline]
+    fn sub_overflowing(&self, other: &Integer<E, I>) -> Self::Output {
+        let is_overflow = self.integer.checked_sub(&other.integer).is_none();
+        (Integer::new(self.integer.wrapping_sub(&other.integer)), Boolean::new(is_overflow))
+    }
+}
+
 impl<E: Environment, I: IntegerType> SubAssign<Integer<E, I>> for Integer<E, I> {
     /// Subtracts `other` from `self`.
     #[inline]
@@ -211,6 +233,17 @@ impl<E: Environment, I: IntegerType> MulWrapped<Integer<E, I>> for Integer<E, I>
     }
 }
 
+impl<E: Environment, I: IntegerType> MulOverflowing<Integer<E, I>> for Integer<E, I> {
+    type Output = (Integer<E, I>, Boolean<E>);
+
+    /// Returns the wrapped `product` of `self` and `other`, and whether an overflow occurred.
+    #[inline]
+    fn mul_overflowing(&self, other: &Integer<E, I>) -> Self::Output {
+        let is_overflow = self.integer.checked_mul(&other.integer).is_none();
+        (Integer::new(self.integer.wrapping_mul(&other.integer)), Boolean::new(is_overflow))
+    }
+}
+
 impl<E: Environment, I: IntegerType> MulAssign<Integer<E, I>> for Integer<E, I> {
     /// Multiplies `self` by `other`.
     #[inline]
@@ -277,6 +277,66 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrAssign<Integer<E, M>> for
     }
 }
 
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateLeft<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates `self` to the left by `n` bits, modulo the number of bits in `self`.
+    #[inline]
+    fn rotate_left(&self, n: &Integer<E, M>) -> Self::Output {
+        let mut bits_le = self.to_bits_le();
+        // Note: Casting `n` to a `usize` is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+        let shift = n.integer.to_usize().unwrap() % bits_le.len();
+        // In little-endian order, rotating the integer left moves each bit to a higher index.
+        bits_le.rotate_right(shift);
+        Integer::from_bits_le(&bits_le).unwrap()
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateRight<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates `self` to the right by `n` bits, modulo the number of bits in `self`.
+    #[inline]
+    fn rotate_right(&self, n: &Integer<E, M>) -> Self::Output {
+        let mut bits_le = self.to_bits_le();
+        // Note: Casting `n` to a `usize` is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+        let shift = n.integer.to_usize().unwrap() % bits_le.len();
+        // In little-endian order, rotating the integer right moves each bit to a lower index.
+        bits_le.rotate_left(shift);
+        Integer::from_bits_le(&bits_le).unwrap()
+    }
+}
+
+impl<E: Environment, I: IntegerType> PopCount for Integer<E, I> {
+    type Output = Integer<E, u8>;
+
+    /// Returns the number of ones in the binary representation of `self`.
+    #[inline]
+    fn pop_count(&self) -> Self::Output {
+        Integer::new(self.to_bits_le().into_iter().fold(0u8, |count, bit| count + bit as u8))
+    }
+}
+
+impl<E: Environment, I: IntegerType> LeadingZeros for Integer<E, I> {
+    type Output = Integer<E, u8>;
+
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    #[inline]
+    fn leading_zeros(&self) -> Self::Output {
+        Integer::new(self.to_bits_le().into_iter().rev().take_while(|bit| !bit).fold(0u8, |count, _| count + 1))
+    }
+}
+
+impl<E: Environment, I: IntegerType> TrailingZeros for Integer<E, I> {
+    type Output = Integer<E, u8>;
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    #[inline]
+    fn trailing_zeros(&self) -> Self::Output {
+        Integer::new(self.to_bits_le().into_iter().take_while(|bit| !bit).fold(0u8, |count, _| count + 1))
+    }
+}
+
 impl<E: Environment, I: IntegerType> Ternary for Integer<E, I> {
     type Boolean = Boolean<E>;
     type Output = Self;
@@ -289,3 +349,56 @@ impl<E: Environment, I: IntegerType> Ternary for Integer<E, I> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1_000;
+
+    macro_rules! check_bit_manipulation {
+        ($type:ty, $rng:expr) => {
+            for _ in 0..ITERATIONS {
+                // Sample a random value and rotation amount.
+                let value: $type = Uniform::rand($rng);
+                let n: u32 = Uniform::rand($rng);
+
+                let integer = Integer::<CurrentEnvironment, $type>::new(value);
+                let magnitude = Integer::<CurrentEnvironment, u32>::new(n);
+
+                assert_eq!(value.rotate_left(n), *integer.rotate_left(&magnitude));
+                assert_eq!(value.rotate_right(n), *integer.rotate_right(&magnitude));
+                assert_eq!(value.count_ones(), *integer.pop_count() as u32);
+                assert_eq!(value.leading_zeros(), *integer.leading_zeros() as u32);
+                assert_eq!(value.trailing_zeros(), *integer.trailing_zeros() as u32);
+            }
+            // Check the boundary values.
+            for value in [<$type>::MIN, <$type>::MAX, 0, 1] {
+                let integer = Integer::<CurrentEnvironment, $type>::new(value);
+                assert_eq!(value.count_ones(), *integer.pop_count() as u32);
+                assert_eq!(value.leading_zeros(), *integer.leading_zeros() as u32);
+                assert_eq!(value.trailing_zeros(), *integer.trailing_zeros() as u32);
+            }
+        };
+    }
+
+    #[test]
+    fn test_bit_manipulation() {
+        let mut rng = TestRng::default();
+
+        check_bit_manipulation!(i8, &mut rng);
+        check_bit_manipulation!(i16, &mut rng);
+        check_bit_manipulation!(i32, &mut rng);
+        check_bit_manipulation!(i64, &mut rng);
+        check_bit_manipulation!(i128, &mut rng);
+
+        check_bit_manipulation!(u8, &mut rng);
+        check_bit_manipulation!(u16, &mut rng);
+        check_bit_manipulation!(u32, &mut rng);
+        check_bit_manipulation!(u64, &mut rng);
+        check_bit_manipulation!(u128, &mut rng);
+    }
+}
@@ -41,6 +41,11 @@ pub mod modules {
     #[cfg(feature = "field")]
     pub use snarkvm_console_types_field::Field;
 
+    #[cfg(feature = "fixed")]
+    pub use snarkvm_console_types_fixed as fixed;
+    #[cfg(feature = "fixed")]
+    pub use snarkvm_console_types_fixed::Fixed;
+
     #[cfg(feature = "group")]
     pub use snarkvm_console_types_group as group;
     #[cfg(feature = "group")]
@@ -100,11 +100,12 @@ impl<N: Network> ConfirmedTransaction<N> {
     ) -> Result<Self> {
         // Ensure the finalize operations contain the correct types.
         for operation in finalize_operations.iter() {
-            // Ensure the finalize operation is an insert or update key-value operation.
+            // Ensure the finalize operation is a key-value or emit event operation.
             match operation {
                 FinalizeOperation::InsertKeyValue(..)
                 | FinalizeOperation::UpdateKeyValue(..)
-                | FinalizeOperation::RemoveKeyValue(..) => (),
+                | FinalizeOperation::RemoveKeyValue(..)
+                | FinalizeOperation::EmitEvent(..) => (),
                 FinalizeOperation::InitializeMapping(..)
                 | FinalizeOperation::ReplaceMapping(..)
                 | FinalizeOperation::RemoveMapping(..) => {
@@ -137,7 +138,8 @@ impl<N: Network> ConfirmedTransaction<N> {
                 | FinalizeOperation::RemoveKeyValue(..) => (),
                 FinalizeOperation::InitializeMapping(..)
                 | FinalizeOperation::ReplaceMapping(..)
-                | FinalizeOperation::RemoveMapping(..) => {
+                | FinalizeOperation::RemoveMapping(..)
+                | FinalizeOperation::EmitEvent(..) => {
                     bail!("Transaction '{}' (fee) contains an invalid finalize operation type", transaction.id())
                 }
             }
@@ -167,7 +169,8 @@ impl<N: Network> ConfirmedTransaction<N> {
                 | FinalizeOperation::RemoveKeyValue(..) => (),
                 FinalizeOperation::InitializeMapping(..)
                 | FinalizeOperation::ReplaceMapping(..)
-                | FinalizeOperation::RemoveMapping(..) => {
+                | FinalizeOperation::RemoveMapping(..)
+                | FinalizeOperation::EmitEvent(..) => {
                     bail!("Transaction '{}' (fee) contains an invalid finalize operation type", transaction.id())
                 }
             }
@@ -458,6 +461,7 @@ mod test {
             FinalizeOperation::InsertKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng)),
             FinalizeOperation::UpdateKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng)),
             FinalizeOperation::RemoveKeyValue(Uniform::rand(rng), Uniform::rand(rng)),
+            FinalizeOperation::EmitEvent(Uniform::rand(rng), Uniform::rand(rng)),
         ];
         let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations.clone()).unwrap();
 
@@ -50,7 +50,7 @@ impl<N: Network> ToBytes for Rejected<N> {
                 // Write the deployment.
                 deployment.write_le(&mut writer)
             }
-            Self::Execution(execution) => {
+            Self::Execution(execution, _) => {
                 // Write the variant.
                 1u8.write_le(&mut writer)?;
                 // Write the execution.
@@ -72,4 +72,19 @@ mod tests {
             assert_eq!(expected, Rejected::read_le(&expected_bytes[..]).unwrap());
         }
     }
+
+    #[test]
+    fn test_bytes_exclude_reason() {
+        let rng = &mut TestRng::default();
+
+        // Sample a rejected execution with a reason.
+        let with_reason = crate::transactions::rejected::test_helpers::sample_rejected_execution_with_reason(rng);
+        assert!(with_reason.reason().is_some());
+        // Construct the same rejected execution without a reason.
+        let without_reason = Rejected::new_execution(with_reason.execution().unwrap().clone());
+
+        // Ensure the reason does not change the byte representation or equality.
+        assert_eq!(with_reason.to_bytes_le().unwrap(), without_reason.to_bytes_le().unwrap());
+        assert_eq!(with_reason, without_reason);
+    }
 }
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod reason;
+pub use reason::*;
+
 mod bytes;
 mod serialize;
 mod string;
@@ -21,12 +24,30 @@ use super::*;
 use crate::{Deployment, Execution, Fee};
 
 /// A wrapper around the rejected deployment or execution.
-#[derive(Clone, PartialEq, Eq)]
+///
+/// The reason of a rejected execution is local metadata from the finalize scope of this node.
+/// It is not part of the rejected transaction, so it is excluded from equality and serialization.
+#[derive(Clone)]
 pub enum Rejected<N: Network> {
     Deployment(ProgramOwner<N>, Box<Deployment<N>>),
-    Execution(Execution<N>),
+    Execution(Execution<N>, Option<RejectionReason<N>>),
+}
+
+impl<N: Network> PartialEq for Rejected<N> {
+    /// Returns `true` if the rejected transactions are equal, ignoring the rejection reason.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Deployment(owner, deployment), Self::Deployment(other_owner, other_deployment)) => {
+                owner == other_owner && deployment == other_deployment
+            }
+            (Self::Execution(execution, _), Self::Execution(other_execution, _)) => execution == other_execution,
+            _ => false,
+        }
+    }
 }
 
+impl<N: Network> Eq for Rejected<N> {}
+
 impl<N: Network> Rejected<N> {
     /// Initializes a rejected deployment.
     pub fn new_deployment(program_owner: ProgramOwner<N>, deployment: Deployment<N>) -> Self {
@@ -35,7 +56,12 @@ impl<N: Network> Rejected<N> {
 
     /// Initializes a rejected execution.
     pub fn new_execution(execution: Execution<N>) -> Self {
-        Self::Execution(execution)
+        Self::Execution(execution, None)
+    }
+
+    /// Initializes a rejected execution, with the reason its finalize scope failed.
+    pub fn new_execution_with_reason(execution: Execution<N>, reason: RejectionReason<N>) -> Self {
+        Self::Execution(execution, Some(reason))
     }
 
     /// Returns true if the rejected transaction is a deployment.
@@ -52,7 +78,7 @@ impl<N: Network> Rejected<N> {
     pub fn program_owner(&self) -> Option<&ProgramOwner<N>> {
         match self {
             Self::Deployment(program_owner, _) => Some(program_owner),
-            Self::Execution(_) => None,
+            Self::Execution(..) => None,
         }
     }
 
@@ -60,7 +86,7 @@ impl<N: Network> Rejected<N> {
     pub fn deployment(&self) -> Option<&Deployment<N>> {
         match self {
             Self::Deployment(_, deployment) => Some(deployment),
-            Self::Execution(_) => None,
+            Self::Execution(..) => None,
         }
     }
 
@@ -68,7 +94,15 @@ impl<N: Network> Rejected<N> {
     pub fn execution(&self) -> Option<&Execution<N>> {
         match self {
             Self::Deployment(_, _) => None,
-            Self::Execution(execution) => Some(execution),
+            Self::Execution(execution, _) => Some(execution),
+        }
+    }
+
+    /// Returns the reason the rejected execution failed to finalize, if it was recorded.
+    pub fn reason(&self) -> Option<&RejectionReason<N>> {
+        match self {
+            Self::Deployment(_, _) => None,
+            Self::Execution(_, reason) => reason.as_ref(),
         }
     }
 
@@ -76,7 +110,7 @@ impl<N: Network> Rejected<N> {
     pub fn to_id(&self) -> Result<Field<N>> {
         match self {
             Self::Deployment(_, deployment) => deployment.to_deployment_id(),
-            Self::Execution(execution) => execution.to_execution_id(),
+            Self::Execution(execution, _) => execution.to_execution_id(),
         }
     }
 
@@ -86,7 +120,7 @@ impl<N: Network> Rejected<N> {
     pub fn to_unconfirmed_id(&self, fee: &Option<Fee<N>>) -> Result<Field<N>> {
         match self {
             Self::Deployment(_, deployment) => Ok(*Transaction::deployment_tree(deployment, fee.as_ref())?.root()),
-            Self::Execution(execution) => Ok(*Transaction::execution_tree(execution, fee)?.root()),
+            Self::Execution(execution, _) => Ok(*Transaction::execution_tree(execution, fee)?.root()),
         }
     }
 }
@@ -128,6 +162,17 @@ pub mod test_helpers {
         Rejected::new_execution(execution)
     }
 
+    /// Samples a rejected execution, with the reason its finalize scope failed.
+    pub(crate) fn sample_rejected_execution_with_reason(rng: &mut TestRng) -> Rejected<CurrentNetwork> {
+        // Sample a rejected execution.
+        let execution = sample_rejected_execution(false, rng).execution().unwrap().clone();
+        // Sample a rejection reason.
+        let reason = crate::transactions::rejected::reason::test_helpers::sample_rejection_reasons().pop().unwrap();
+
+        // Return the rejected execution.
+        Rejected::new_execution_with_reason(execution, reason)
+    }
+
     /// Sample a list of randomly rejected transactions.
     pub(crate) fn sample_rejected_transactions() -> Vec<Rejected<CurrentNetwork>> {
         let rng = &mut TestRng::default();
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for RejectionReason<N> {
+    /// Reads the rejection reason from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid rejection reason version"));
+        }
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the function name.
+        let function_name = Identifier::read_le(&mut reader)?;
+        // Read the command index.
+        let command_index = u16::read_le(&mut reader)?;
+        // Read the opcode.
+        let opcode = read_string(&mut reader, Self::MAX_OPCODE_BYTES)?;
+        // Read the failure code.
+        let code = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(read_string(&mut reader, Self::MAX_CODE_BYTES)?),
+            variant => return Err(error(format!("Invalid failure code variant: {variant}"))),
+        };
+        // Return the rejection reason.
+        Self::new(program_id, function_name, command_index, opcode, code).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for RejectionReason<N> {
+    /// Writes the rejection reason to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the function name.
+        self.function_name.write_le(&mut writer)?;
+        // Write the command index.
+        self.command_index.write_le(&mut writer)?;
+        // Write the opcode.
+        write_string(&mut writer, &self.opcode)?;
+        // Write the failure code.
+        match &self.code {
+            None => 0u8.write_le(&mut writer),
+            Some(code) => {
+                1u8.write_le(&mut writer)?;
+                write_string(&mut writer, code)
+            }
+        }
+    }
+}
+
+/// Reads a length-prefixed UTF-8 string of at most `max_bytes` bytes from the buffer.
+fn read_string<R: Read>(mut reader: R, max_bytes: usize) -> IoResult<String> {
+    // Read the number of bytes.
+    let num_bytes = u8::read_le(&mut reader)? as usize;
+    // Ensure the number of bytes is within bounds.
+    if num_bytes > max_bytes {
+        return Err(error(format!("String exceeds the maximum of {max_bytes} bytes")));
+    }
+    // Read the bytes.
+    let mut bytes = vec![0u8; num_bytes];
+    reader.read_exact(&mut bytes)?;
+    // Return the string.
+    String::from_utf8(bytes).map_err(|e| error(e.to_string()))
+}
+
+/// Writes a length-prefixed string to the buffer.
+fn write_string<W: Write>(mut writer: W, string: &str) -> IoResult<()> {
+    // Write the number of bytes.
+    u8::try_from(string.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+    // Write the bytes.
+    writer.write_all(string.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        for expected in crate::transactions::rejected::reason::test_helpers::sample_rejection_reasons() {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, RejectionReason::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use synthesizer_program::AssertWithCode;
+
+/// A compact record of why the finalize scope of a rejected execution failed.
+///
+/// Note: The reason is informational, and is not committed to by the block header.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RejectionReason<N: Network> {
+    /// The program ID of the finalize scope that failed.
+    program_id: ProgramID<N>,
+    /// The function name of the finalize scope that failed.
+    function_name: Identifier<N>,
+    /// The index of the failing command in the finalize scope.
+    command_index: u16,
+    /// The opcode of the failing command.
+    opcode: String,
+    /// The failure code, if the failing command is an `assert` with a code.
+    code: Option<String>,
+}
+
+impl<N: Network> RejectionReason<N> {
+    /// The maximum number of bytes in an opcode.
+    pub const MAX_OPCODE_BYTES: usize = 32;
+    /// The maximum number of bytes in a failure code.
+    pub const MAX_CODE_BYTES: usize = AssertWithCode::<N>::MAX_CODE_BYTES;
+
+    /// Initializes a new rejection reason.
+    pub fn new(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        command_index: u16,
+        opcode: String,
+        code: Option<String>,
+    ) -> Result<Self> {
+        // Ensure the opcode is within bounds.
+        ensure!(!opcode.is_empty(), "The opcode in a rejection reason must not be empty");
+        ensure!(opcode.len() <= Self::MAX_OPCODE_BYTES, "The opcode in a rejection reason exceeds the maximum size");
+        // Ensure the failure code is within bounds.
+        if let Some(code) = &code {
+            ensure!(
+                code.len() <= Self::MAX_CODE_BYTES,
+                "The failure code in a rejection reason exceeds the maximum size"
+            );
+        }
+        // Return the rejection reason.
+        Ok(Self { program_id, function_name, command_index, opcode, code })
+    }
+
+    /// Returns the program ID of the finalize scope that failed.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name of the finalize scope that failed.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the index of the failing command in the finalize scope.
+    pub const fn command_index(&self) -> u16 {
+        self.command_index
+    }
+
+    /// Returns the opcode of the failing command.
+    pub fn opcode(&self) -> &str {
+        &self.opcode
+    }
+
+    /// Returns the failure code, if the failing command is an `assert` with a code.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a list of rejection reasons.
+    pub(crate) fn sample_rejection_reasons() -> Vec<RejectionReason<CurrentNetwork>> {
+        vec![
+            RejectionReason::new(
+                ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("transfer_public").unwrap(),
+                2,
+                "sub".to_string(),
+                None,
+            )
+            .unwrap(),
+            RejectionReason::new(
+                ProgramID::from_str("token.aleo").unwrap(),
+                Identifier::from_str("withdraw").unwrap(),
+                u16::MAX,
+                "assert".to_string(),
+                Some("E_INSUFFICIENT_BALANCE".to_string()),
+            )
+            .unwrap(),
+        ]
+    }
+}
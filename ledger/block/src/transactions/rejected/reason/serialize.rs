@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for RejectionReason<N> {
+    /// Serializes the rejection reason into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut object = serializer.serialize_struct("RejectionReason", 4 + self.code.is_some() as usize)?;
+                object.serialize_field("program_id", &self.program_id)?;
+                object.serialize_field("function_name", &self.function_name)?;
+                object.serialize_field("command_index", &self.command_index)?;
+                object.serialize_field("opcode", &self.opcode)?;
+                if let Some(code) = &self.code {
+                    object.serialize_field("code", code)?;
+                }
+                object.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RejectionReason<N> {
+    /// Deserializes the rejection reason from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the rejection reason from a string into a value.
+                let mut object = serde_json::Value::deserialize(deserializer)?;
+
+                // Parse the program ID.
+                let program_id = DeserializeExt::take_from_value::<D>(&mut object, "program_id")?;
+                // Parse the function name.
+                let function_name = DeserializeExt::take_from_value::<D>(&mut object, "function_name")?;
+                // Parse the command index.
+                let command_index = DeserializeExt::take_from_value::<D>(&mut object, "command_index")?;
+                // Parse the opcode.
+                let opcode = DeserializeExt::take_from_value::<D>(&mut object, "opcode")?;
+                // Parse the failure code, if it exists.
+                let code =
+                    serde_json::from_value(object.get_mut("code").unwrap_or(&mut serde_json::Value::Null).take())
+                        .map_err(de::Error::custom)?;
+
+                // Return the rejection reason.
+                Self::new(program_id, function_name, command_index, opcode, code).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "rejection reason"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        for expected in crate::transactions::rejected::reason::test_helpers::sample_rejection_reasons() {
+            // Serialize
+            let expected_string = expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected_string, candidate_string);
+
+            // Deserialize
+            assert_eq!(expected, RejectionReason::from_str(&expected_string).unwrap());
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        for expected in crate::transactions::rejected::reason::test_helpers::sample_rejection_reasons() {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, RejectionReason::read_le(&expected_bytes[..]).unwrap());
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+        }
+    }
+}
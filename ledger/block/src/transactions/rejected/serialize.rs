@@ -26,7 +26,7 @@ impl<N: Network> Serialize for Rejected<N> {
                     object.serialize_field("deployment", deployment)?;
                     object.end()
                 }
-                Self::Execution(execution) => {
+                Self::Execution(execution, _) => {
                     let mut object = serializer.serialize_struct("Rejected", 2)?;
                     object.serialize_field("type", "execution")?;
                     object.serialize_field("execution", execution)?;
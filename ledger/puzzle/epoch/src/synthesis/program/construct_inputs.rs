@@ -54,7 +54,8 @@ impl<N: Network> EpochProgram<N> {
                 | LiteralType::U128
                 | LiteralType::Scalar
                 | LiteralType::Signature
-                | LiteralType::String => {
+                | LiteralType::String
+                | LiteralType::Fixed => {
                     unreachable!("Invalid input literal type, malformed program");
                 }
             };
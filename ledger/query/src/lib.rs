@@ -23,5 +23,8 @@ mod query;
 #[cfg(feature = "query")]
 pub use query::*;
 
+mod static_query;
+pub use static_query::*;
+
 mod traits;
 pub use traits::*;
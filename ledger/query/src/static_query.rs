@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::QueryTrait;
+use console::{network::prelude::*, program::StatePath, types::Field};
+
+use std::collections::HashMap;
+
+/// A query for a fixed global state root and set of state paths, to prepare a trace without access to the ledger.
+#[derive(Clone)]
+pub struct StaticQuery<N: Network> {
+    /// The global state root.
+    state_root: N::StateRoot,
+    /// The mapping of commitments to their state paths.
+    state_paths: HashMap<Field<N>, StatePath<N>>,
+}
+
+impl<N: Network> StaticQuery<N> {
+    /// Initializes a new static query, given the global state root and the state paths for it.
+    pub fn new(state_root: N::StateRoot, state_paths: impl IntoIterator<Item = StatePath<N>>) -> Result<Self> {
+        // Map each commitment to its state path.
+        let state_paths = state_paths
+            .into_iter()
+            .map(|state_path| {
+                // Ensure the state path is for the global state root.
+                ensure!(
+                    state_path.global_state_root() == state_root,
+                    "The state path must be for the global state root '{state_root}'"
+                );
+                Ok((state_path.transition_leaf().id(), state_path))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        Ok(Self { state_root, state_paths })
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for StaticQuery<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.state_root)
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.current_state_root()
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        match self.state_paths.get(commitment) {
+            Some(state_path) => Ok(state_path.clone()),
+            None => bail!("Missing the state path for commitment '{commitment}'"),
+        }
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_state_path_for_commitment(commitment)
+    }
+}
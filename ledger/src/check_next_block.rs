@@ -63,6 +63,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -94,4 +94,54 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     pub fn transition_public_keys(&self) -> impl '_ + Iterator<Item = Cow<'_, Group<N>>> {
         self.vm.transition_store().tpks()
     }
+
+    /* Finalize */
+
+    /// Returns an iterator over the `(block height, transition ID, payload)` events emitted under the given
+    /// `program ID` and `topic`, for the blocks in the given block range, in confirmed order.
+    /// The range is inclusive of the start and exclusive of the end, and ends at the latest block.
+    ///
+    /// The events are ordered by block height, then by the position of the transaction in the block,
+    /// then by the position of the transition in the transaction, and then in emission order.
+    pub fn events(
+        &self,
+        program_id: ProgramID<N>,
+        topic: Identifier<N>,
+        heights: Range<u32>,
+    ) -> impl '_ + Iterator<Item = Result<(u32, N::TransitionID, Plaintext<N>)>> {
+        // Truncate the range to the latest block.
+        let end = heights.end.min(self.latest_height().saturating_add(1));
+        (heights.start..end).flat_map(move |height| match self.get_block_events(program_id, topic, height) {
+            Ok(events) => events.into_iter().map(Ok).collect::<Vec<_>>(),
+            Err(error) => vec![Err(error)],
+        })
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the `(block height, transition ID, payload)` events emitted under the given `program ID` and `topic`,
+    /// in the block at the given height, in confirmed order.
+    fn get_block_events(
+        &self,
+        program_id: ProgramID<N>,
+        topic: Identifier<N>,
+        height: u32,
+    ) -> Result<Vec<(u32, N::TransitionID, Plaintext<N>)>> {
+        // Retrieve the transactions of the block.
+        let block_hash = self.get_hash(height)?;
+        let Some(transactions) = self.vm.block_store().get_block_transactions(&block_hash)? else {
+            bail!("Missing transactions for block {height} ('{block_hash}')");
+        };
+
+        let mut events = Vec::new();
+        // Retrieve the events of each transition of the program, in the order of the accepted transactions.
+        // Note: The events of a rejected transaction are never stored, as its finalize scope is reverted.
+        for transaction in transactions.iter().filter(|transaction| transaction.is_accepted()) {
+            for transition in transaction.transitions().filter(|transition| transition.program_id() == &program_id) {
+                let payloads = self.vm.finalize_store().get_events_confirmed(program_id, topic, *transition.id())?;
+                events.extend(payloads.into_iter().map(|payload| (height, *transition.id(), payload)));
+            }
+        }
+        Ok(events)
+    }
 }
@@ -25,10 +25,14 @@ use console::{
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
     types::U16,
 };
-use ledger_block::{ConfirmedTransaction, Execution, Ratify, Rejected, Transaction};
+use ledger_block::{ConfirmedTransaction, Execution, Ratify, Rejected, RejectionReason, Transaction};
 use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
-use synthesizer::{program::Program, vm::VM, Stack};
+use synthesizer::{
+    program::{FinalizeOperation, Program},
+    vm::VM,
+    Stack,
+};
 
 use indexmap::IndexMap;
 use rand::seq::SliceRandom;
@@ -442,10 +446,24 @@ finalize failed_assert:
     assert!(confirmed_transaction.is_rejected());
     if let Transaction::Execute(_, execution, fee) = failed_assert_transaction {
         let fee_transaction = Transaction::from_fee(fee.unwrap()).unwrap();
-        let expected_confirmed_transaction =
-            ConfirmedTransaction::RejectedExecute(0, fee_transaction, Rejected::new_execution(execution), vec![]);
+        let reason = RejectionReason::new(
+            ProgramID::from_str(program_id).unwrap(),
+            Identifier::from_str("failed_assert").unwrap(),
+            0,
+            "assert.eq".to_string(),
+            None,
+        )
+        .unwrap();
+        let expected_confirmed_transaction = ConfirmedTransaction::RejectedExecute(
+            0,
+            fee_transaction,
+            Rejected::new_execution_with_reason(execution, reason.clone()),
+            vec![],
+        );
 
         assert_eq!(confirmed_transaction, &expected_confirmed_transaction);
+        // Note: The rejection reason is excluded from equality, so it is checked separately.
+        assert_eq!(confirmed_transaction.to_rejected().and_then(|rejected| rejected.reason()), Some(&reason));
     }
 
     // Check that the unconfirmed transaction ID of the rejected execution is correct.
@@ -456,6 +474,111 @@ finalize failed_assert:
 
     // Add the block with the rejected transaction to the ledger.
     ledger.advance_to_next_block(&next_block).unwrap();
+
+    // Ensure the rejection reason is retrievable from the ledger.
+    let candidate = ledger.get_confirmed_transaction(confirmed_transaction.transaction().id()).unwrap();
+    assert_eq!(&candidate, confirmed_transaction);
+    let reason = candidate.to_rejected().and_then(|rejected| rejected.reason()).unwrap();
+    assert_eq!(reason.command_index(), 0);
+    assert_eq!(reason.opcode(), "assert.eq");
+}
+
+#[test]
+fn test_emitted_events() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("test_emit.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+
+function transfer:
+    input r0 as u64.public;
+    async transfer r0 into r1;
+    output r1 as {program_id}/transfer.future;
+
+finalize transfer:
+    input r0 as u64.public;
+    emit transfer r0;
+    add r0 1u64 into r1;
+    emit transfer r1;"
+    ))
+    .unwrap();
+
+    // Deploy the program.
+    let transaction = ledger.vm().deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Execute the function twice, recording the events emitted by each transition.
+    let mut transactions = Vec::new();
+    let mut emitted = IndexMap::new();
+    for (input, output) in [("5u64", "6u64"), ("10u64", "11u64")] {
+        let transaction = ledger
+            .vm()
+            .execute(
+                &private_key,
+                (program_id, "transfer"),
+                [Value::from_str(input).unwrap()].into_iter(),
+                None,
+                0,
+                None,
+                rng,
+            )
+            .unwrap();
+        let transition_id =
+            *transaction.transitions().find(|transition| transition.program_id() == &program_id).unwrap().id();
+        let payloads = vec![Plaintext::from_str(input).unwrap(), Plaintext::from_str(output).unwrap()];
+        emitted.insert(transaction.id(), (transition_id, payloads));
+        transactions.push(transaction);
+    }
+
+    // Construct the next block containing the executions.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], transactions, rng).unwrap();
+    assert_eq!(block.transactions().len(), 2);
+
+    // Ensure each confirmed execution commits to both of its events.
+    for confirmed_transaction in block.transactions().iter() {
+        assert!(confirmed_transaction.is_accepted());
+        let num_events = confirmed_transaction
+            .finalize_operations()
+            .iter()
+            .filter(|operation| matches!(operation, FinalizeOperation::EmitEvent(..)))
+            .count();
+        assert_eq!(num_events, 2);
+    }
+
+    // Construct the expected events, in the order of the transactions in the block.
+    let expected = block
+        .transaction_ids()
+        .flat_map(|transaction_id| {
+            let (transition_id, payloads) = emitted[transaction_id].clone();
+            payloads.into_iter().map(move |payload| (block.height(), transition_id, payload))
+        })
+        .collect::<Vec<_>>();
+
+    // Ensure the events are not visible before the block is added.
+    let topic = Identifier::from_str("transfer").unwrap();
+    assert_eq!(ledger.events(program_id, topic, 0..u32::MAX).count(), 0);
+
+    // Add the block to the ledger.
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the events are retrievable from the ledger, in confirmed order.
+    let events = ledger.events(program_id, topic, 0..u32::MAX).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(events, expected);
+
+    // Ensure the events are only retrieved for the given block range.
+    assert_eq!(ledger.events(program_id, topic, 0..block.height()).count(), 0);
+    assert_eq!(ledger.events(program_id, topic, block.height() + 1..u32::MAX).count(), 0);
+    let events = ledger.events(program_id, topic, block.height()..block.height() + 1).collect::<Result<Vec<_>>>();
+    assert_eq!(events.unwrap(), expected);
 }
 
 #[test]
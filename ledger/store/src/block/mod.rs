@@ -37,6 +37,7 @@ use ledger_block::{
     Header,
     Ratifications,
     Rejected,
+    RejectionReason,
     Solutions,
     Transaction,
     Transactions,
@@ -139,6 +140,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of rejected `transaction ID` to `rejection reason`.
+    type RejectionReasonMap: for<'a> Map<'a, N::TransactionID, RejectionReason<N>>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -181,6 +184,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the rejection reason map.
+    fn rejection_reason_map(&self) -> &Self::RejectionReasonMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -213,6 +218,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.rejection_reason_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -235,6 +241,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.rejection_reason_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -257,6 +264,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.rejection_reason_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -279,6 +287,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.rejection_reason_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -301,6 +310,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.rejection_reason_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -323,6 +333,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.rejection_reason_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -345,6 +356,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.rejection_reason_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -451,6 +463,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 if let ConfirmedTxType::RejectedDeploy(_, rejected) | ConfirmedTxType::RejectedExecute(_, rejected) =
                     confirmed_type
                 {
+                    // Store the rejection reason, if it is known.
+                    if let Some(reason) = rejected.reason() {
+                        self.rejection_reason_map().insert(transaction.id(), reason.clone())?;
+                    }
                     self.rejected_deployment_or_execution_map().insert(rejected.to_id()?, rejected)?;
                 }
                 // Store the transaction.
@@ -582,6 +598,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             for transaction_id in transaction_ids.iter() {
                 // Remove the reverse transaction ID.
                 self.confirmed_transactions_map().remove(transaction_id)?;
+                // Remove the rejection reason, if it exists.
+                self.rejection_reason_map().remove(transaction_id)?;
                 // Remove the transaction.
                 self.transaction_store().remove(transaction_id)?;
             }
@@ -913,11 +931,19 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Err(err) => return Err(err),
         };
         // Retrieve the confirmed attributes.
-        let (_, confirmed_type, finalize_operations) =
+        let (_, mut confirmed_type, finalize_operations) =
             match self.confirmed_transactions_map().get_confirmed(&transaction.id())? {
                 Some(confirmed_attributes) => cow_to_cloned!(confirmed_attributes),
                 None => bail!("Missing confirmed transaction '{transaction_id}' in block storage"),
             };
+        // Attach the rejection reason, if it was recorded.
+        if let ConfirmedTxType::RejectedExecute(_, rejected) = &mut confirmed_type {
+            if let Some(reason) = self.rejection_reason_map().get_confirmed(&transaction.id())? {
+                if let Some(execution) = rejected.execution() {
+                    *rejected = Rejected::new_execution_with_reason(execution.clone(), cow_to_cloned!(reason));
+                }
+            }
+        }
         // Construct the confirmed transaction.
         to_confirmed_transaction(confirmed_type, transaction, finalize_operations).map(Some)
     }
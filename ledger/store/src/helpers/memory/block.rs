@@ -21,7 +21,7 @@ use crate::{
 };
 use console::{prelude::*, types::Field};
 use ledger_authority::Authority;
-use ledger_block::{Header, Ratifications, Rejected, Solutions};
+use ledger_block::{Header, Ratifications, Rejected, RejectionReason, Solutions};
 use ledger_puzzle::SolutionID;
 use synthesizer_program::FinalizeOperation;
 
@@ -66,6 +66,8 @@ pub struct BlockMemory<N: Network> {
         MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: MemoryMap<Field<N>, Rejected<N>>,
+    /// The rejection reason map.
+    rejection_reason_map: MemoryMap<N::TransactionID, RejectionReason<N>>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
 }
@@ -89,6 +91,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
+    type RejectionReasonMap = MemoryMap<N::TransactionID, RejectionReason<N>>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -117,6 +120,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
+            rejection_reason_map: MemoryMap::default(),
             transaction_store,
         })
     }
@@ -206,6 +210,11 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the rejection reason map.
+    fn rejection_reason_map(&self) -> &Self::RejectionReasonMap {
+        &self.rejection_reason_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
@@ -391,6 +391,20 @@ impl<
         Ok(key_values)
     }
 
+    ///
+    /// Returns up to `limit` confirmed keys for the given map, starting from the key at index `start`.
+    /// The keys are ordered by their serialized bytes, and at most `start + limit` keys are visited.
+    ///
+    fn get_keys_confirmed(&'a self, map: &M, start: usize, limit: usize) -> Result<Vec<K>> {
+        // Serialize 'm'.
+        let m = bincode::serialize(map)?;
+        // Retrieve the requested keys for the serialized map.
+        match self.map.read().get(&m) {
+            Some(keys) => keys.iter().skip(start).take(limit).map(|k| Ok(bincode::deserialize(k)?)).collect(),
+            None => Ok(Vec::new()),
+        }
+    }
+
     ///
     /// Returns the value for the given key from the map, if it exists.
     ///
@@ -647,6 +661,14 @@ mod tests {
         crate::helpers::test_helpers::nested_map::check_get_map(map);
     }
 
+    #[test]
+    fn test_get_keys() {
+        // Initialize a map.
+        let map: NestedMemoryMap<usize, usize, String> = Default::default();
+
+        crate::helpers::test_helpers::nested_map::check_get_keys(map);
+    }
+
     #[test]
     fn test_check_iterators_match() {
         // Initialize a map.
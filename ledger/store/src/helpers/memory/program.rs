@@ -38,6 +38,12 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The mapping length map.
+    mapping_len_map: MemoryMap<(ProgramID<N>, Identifier<N>), u64>,
+    /// The event map.
+    event_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), (N::TransitionID, u16), Plaintext<N>>,
+    /// The event topic map.
+    event_topic_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -47,6 +53,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type MappingLenMap = MemoryMap<(ProgramID<N>, Identifier<N>), u64>;
+    type EventMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), (N::TransitionID, u16), Plaintext<N>>;
+    type EventTopicMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -57,6 +66,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             committee_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
+            mapping_len_map: MemoryMap::default(),
+            event_map: NestedMemoryMap::default(),
+            event_topic_map: MemoryMap::default(),
             storage_mode: storage.into(),
         })
     }
@@ -82,6 +94,21 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the mapping length map.
+    fn mapping_len_map(&self) -> &Self::MappingLenMap {
+        &self.mapping_len_map
+    }
+
+    /// Returns the event map.
+    fn event_map(&self) -> &Self::EventMap {
+        &self.event_map
+    }
+
+    /// Returns the event topic map.
+    fn event_topic_map(&self) -> &Self::EventTopicMap {
+        &self.event_topic_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
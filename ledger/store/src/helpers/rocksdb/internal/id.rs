@@ -85,6 +85,7 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    RejectionReason = DataID::BlockRejectionReasonMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -198,6 +199,9 @@ pub enum TransitionMap {
 pub enum ProgramMap {
     ProgramID = DataID::ProgramIDMap as u16,
     KeyValueID = DataID::KeyValueMap as u16,
+    EventID = DataID::EventMap as u16,
+    MappingLenID = DataID::MappingLenMap as u16,
+    EventTopicID = DataID::EventTopicMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -292,6 +296,10 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    EventMap,
+    MappingLenMap,
+    BlockRejectionReasonMap,
+    EventTopicMap,
 
     // Testing
     #[cfg(test)]
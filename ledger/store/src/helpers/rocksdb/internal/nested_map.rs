@@ -510,6 +510,36 @@ impl<
         Ok(key_values)
     }
 
+    ///
+    /// Returns up to `limit` confirmed keys for the given map, starting from the key at index `start`.
+    /// The keys are ordered by their serialized bytes, and at most `start + limit` keys are visited.
+    ///
+    fn get_keys_confirmed(&'a self, map: &M, start: usize, limit: usize) -> Result<Vec<K>> {
+        // Obtain the nested map prefix.
+        let prefix = self.create_prefixed_map(map)?;
+
+        // A raw iterator doesn't allocate.
+        let mut iter = self.database.raw_iterator();
+        // Find the first key with the nested map prefix.
+        iter.seek(&prefix);
+
+        // Visit at most `start + limit` keys belonging to the nested map.
+        let mut keys = Vec::new();
+        for index in 0..start.saturating_add(limit) {
+            // If there are no more keys belonging to the nested map, it's the end of iteration.
+            let Some(map_key) = iter.key().filter(|map_key| map_key.starts_with(&prefix)) else {
+                break;
+            };
+            // Deserialize the key, if it is within the requested range.
+            if index >= start {
+                keys.push(bincode::deserialize(&map_key[prefix.len()..])?);
+            }
+            iter.next();
+        }
+
+        Ok(keys)
+    }
+
     ///
     /// Returns the value for the given map and key, if it exists.
     ///
@@ -1039,6 +1069,18 @@ mod tests {
         crate::helpers::test_helpers::nested_map::check_get_map(map);
     }
 
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_get_keys() {
+        // Initialize a map.
+        let map: NestedDataMap<usize, usize, String> =
+            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+                .expect("Failed to open data map");
+
+        crate::helpers::test_helpers::nested_map::check_get_keys(map);
+    }
+
     #[test]
     #[serial]
     #[traced_test]
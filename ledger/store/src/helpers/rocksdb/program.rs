@@ -38,6 +38,12 @@ pub struct FinalizeDB<N: Network> {
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The mapping length map.
+    mapping_len_map: DataMap<(ProgramID<N>, Identifier<N>), u64>,
+    /// The event map.
+    event_map: NestedDataMap<(ProgramID<N>, Identifier<N>), (N::TransitionID, u16), Plaintext<N>>,
+    /// The event topic map.
+    event_topic_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -47,6 +53,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type MappingLenMap = DataMap<(ProgramID<N>, Identifier<N>), u64>;
+    type EventMap = NestedDataMap<(ProgramID<N>, Identifier<N>), (N::TransitionID, u16), Plaintext<N>>;
+    type EventTopicMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -57,6 +66,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyValueID))?,
+            mapping_len_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::MappingLenID))?,
+            event_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::EventID))?,
+            event_topic_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::EventTopicID))?,
             storage_mode: storage.into(),
         })
     }
@@ -70,7 +82,10 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         Ok(Self {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
-            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir, dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            mapping_len_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::MappingLenID))?,
+            event_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::EventID))?,
+            event_topic_map: rocksdb::RocksDB::open_map_testing(temp_dir, dev, MapID::Program(ProgramMap::EventTopicID))?,
             storage_mode: dev.into(),
         })
     }
@@ -90,6 +105,21 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.key_value_map
     }
 
+    /// Returns the mapping length map.
+    fn mapping_len_map(&self) -> &Self::MappingLenMap {
+        &self.mapping_len_map
+    }
+
+    /// Returns the event map.
+    fn event_map(&self) -> &Self::EventMap {
+        &self.event_map
+    }
+
+    /// Returns the event topic map.
+    fn event_topic_map(&self) -> &Self::EventTopicMap {
+        &self.event_topic_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
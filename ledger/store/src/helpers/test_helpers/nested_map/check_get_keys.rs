@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ensure_map_is_empty;
+use crate::helpers::NestedMap;
+
+use std::collections::BTreeSet;
+
+const MAP: usize = 0;
+const OTHER_MAP: usize = 1;
+// Note: The keys are below 256, so that their serialized bytes are ordered as the keys.
+const NUM_ITEMS: usize = 20;
+
+fn check_pages(map: &impl for<'a> NestedMap<'a, usize, usize, String>, confirmed: &[usize], speculative: &[usize]) {
+    for start in 0..NUM_ITEMS + 2 {
+        for limit in 0..NUM_ITEMS + 2 {
+            let expected = |keys: &[usize]| keys.iter().copied().skip(start).take(limit).collect::<Vec<_>>();
+            assert_eq!(map.get_keys_confirmed(&MAP, start, limit).unwrap(), expected(confirmed));
+            assert_eq!(map.get_keys_speculative(&MAP, start, limit).unwrap(), expected(speculative));
+        }
+    }
+}
+
+pub fn check_get_keys(map: impl for<'a> NestedMap<'a, usize, usize, String>) {
+    ensure_map_is_empty(&map);
+
+    // Insert the even keys in reverse order, and a key into another map.
+    for i in (0..NUM_ITEMS).step_by(2).rev() {
+        map.insert(MAP, i, i.to_string()).unwrap();
+    }
+    map.insert(OTHER_MAP, 1, 1.to_string()).unwrap();
+
+    let mut confirmed = (0..NUM_ITEMS).step_by(2).collect::<BTreeSet<_>>();
+    check_pages(&map, &Vec::from_iter(confirmed.clone()), &Vec::from_iter(confirmed.clone()));
+
+    /* test atomic insertions and removals */
+
+    {
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        let mut speculative = confirmed.clone();
+        for i in 0..NUM_ITEMS {
+            // Insert the odd keys, and remove every fourth key.
+            match i % 4 {
+                0 => {
+                    map.remove_key(&MAP, &i).unwrap();
+                    speculative.remove(&i);
+                }
+                1 | 3 => {
+                    map.insert(MAP, i, i.to_string()).unwrap();
+                    speculative.insert(i);
+                }
+                _ => {}
+            }
+            check_pages(&map, &Vec::from_iter(confirmed.clone()), &Vec::from_iter(speculative.clone()));
+        }
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        confirmed = speculative;
+        check_pages(&map, &Vec::from_iter(confirmed.clone()), &Vec::from_iter(confirmed.clone()));
+    }
+
+    /* test an atomic removal of the map */
+
+    {
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Remove the map, and insert a single key.
+        map.remove_map(&MAP).unwrap();
+        check_pages(&map, &Vec::from_iter(confirmed.clone()), &[]);
+        map.insert(MAP, 7, 7.to_string()).unwrap();
+        check_pages(&map, &Vec::from_iter(confirmed.clone()), &[7]);
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        check_pages(&map, &[7], &[7]);
+    }
+
+    map.remove_map(&MAP).unwrap();
+    map.remove_map(&OTHER_MAP).unwrap();
+
+    ensure_map_is_empty(&map);
+}
@@ -21,6 +21,9 @@ pub use check_atomic_writes_can_be_aborted::*;
 mod check_contains_key;
 pub use check_contains_key::*;
 
+mod check_get_keys;
+pub use check_get_keys::*;
+
 mod check_get_map;
 pub use check_get_map::*;
 
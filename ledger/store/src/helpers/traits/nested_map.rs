@@ -15,7 +15,7 @@
 use console::network::prelude::{Deserialize, Result, Serialize};
 
 use core::hash::Hash;
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
 /// A trait representing 'nested map'-like storage operations with read-write capabilities.
 pub trait NestedMap<
@@ -127,6 +127,60 @@ pub trait NestedMapRead<
     ///
     fn get_map_speculative(&'a self, map: &M) -> Result<Vec<(K, V)>>;
 
+    ///
+    /// Returns up to `limit` confirmed keys for the given map, starting from the key at index `start`.
+    /// The keys are ordered by their serialized bytes, and at most `start + limit` keys are visited.
+    ///
+    fn get_keys_confirmed(&'a self, map: &M, start: usize, limit: usize) -> Result<Vec<K>>;
+
+    ///
+    /// Returns up to `limit` speculative keys for the given map, starting from the key at index `start`.
+    /// The keys are ordered by their serialized bytes, and at most `start + limit` confirmed keys
+    /// are visited, in addition to one confirmed key for each pending operation on the map.
+    ///
+    fn get_keys_speculative(&'a self, map: &M, start: usize, limit: usize) -> Result<Vec<K>> {
+        // Collect the pending operations for the map, keyed by the serialized key.
+        let mut is_cleared = false;
+        let mut pending = BTreeMap::new();
+        for (m, k, v) in self.iter_pending() {
+            // If the map does not match the given map, then continue.
+            if m.as_ref() != map {
+                continue;
+            }
+            match k {
+                // Insert or remove the key.
+                Some(k) => {
+                    pending.insert(bincode::serialize(k.as_ref())?, v.map(|_| k.into_owned()));
+                }
+                // Clear the map.
+                None => {
+                    is_cleared = true;
+                    pending.clear();
+                }
+            }
+        }
+
+        // Retrieve the confirmed keys that may precede the requested keys.
+        // Note: Each pending removal can shift at most one confirmed key into the requested keys.
+        let confirmed = match is_cleared {
+            true => Vec::new(),
+            false => self.get_keys_confirmed(map, 0, start.saturating_add(limit).saturating_add(pending.len()))?,
+        };
+
+        // Apply the pending operations to the confirmed keys, ordered by their serialized bytes.
+        let mut keys =
+            confirmed.into_iter().map(|k| Ok((bincode::serialize(&k)?, k))).collect::<Result<BTreeMap<_, _>>>()?;
+        for (bytes, k) in pending {
+            match k {
+                Some(k) => keys.insert(bytes, k),
+                None => keys.remove(&bytes),
+            };
+        }
+
+        // Return the requested keys.
+        Ok(keys.into_values().skip(start).take(limit).collect())
+    }
+
     ///
     /// Returns the value for the given key from the map, if it exists.
     ///
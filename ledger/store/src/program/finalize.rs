@@ -60,10 +60,33 @@ fn to_key_id<N: Network>(
     N::hash_bhp1024(&preimage)
 }
 
+/// Returns the event ID for the given `topic ID`, `transition ID`, `index`, and `payload`.
+fn to_event_id<N: Network>(
+    topic_id: Field<N>,
+    transition_id: &N::TransitionID,
+    index: u16,
+    payload: &Plaintext<N>,
+) -> Result<Field<N>> {
+    // Construct the preimage.
+    let mut preimage = Vec::new();
+    topic_id.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator
+    (**transition_id).write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator
+    index.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator
+    N::hash_bhp1024(&payload.to_bits_le())?.write_bits_le(&mut preimage);
+    // Compute the event ID.
+    N::hash_bhp1024(&preimage)
+}
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `key ID := Hash ( program ID || mapping name || Hash(key) )`
 /// and the `value ID := Hash ( key ID || Hash(value) )`.
+/// Events are indexed by `topic ID := Hash ( program ID || topic )`,
+/// with the `event ID := Hash ( topic ID || transition ID || index || Hash(payload) )`,
+/// where `index` is the position of the event among the events of the transition under the topic.
 ///
 /// `FinalizeStorage` emulates the following data structure:
 /// ```text
@@ -77,6 +100,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `(program ID, mapping name)` to `number of entries`.
+    type MappingLenMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>), u64>;
+    /// The mapping of `(program ID, topic)` to `[((transition ID, index), payload)]`.
+    type EventMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), (N::TransitionID, u16), Plaintext<N>>;
+    /// The mapping of `program ID` to `[topic]`.
+    type EventTopicMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
 
     /// Initializes the program state storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -91,6 +120,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the mapping length map.
+    fn mapping_len_map(&self) -> &Self::MappingLenMap;
+    /// Returns the event map.
+    fn event_map(&self) -> &Self::EventMap;
+    /// Returns the event topic map.
+    fn event_topic_map(&self) -> &Self::EventTopicMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode;
@@ -100,6 +135,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.mapping_len_map().start_atomic();
+        self.event_map().start_atomic();
+        self.event_topic_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -107,6 +145,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.mapping_len_map().is_atomic_in_progress()
+            || self.event_map().is_atomic_in_progress()
+            || self.event_topic_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -114,6 +155,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
+        self.mapping_len_map().atomic_checkpoint();
+        self.event_map().atomic_checkpoint();
+        self.event_topic_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -121,6 +165,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.mapping_len_map().clear_latest_checkpoint();
+        self.event_map().clear_latest_checkpoint();
+        self.event_topic_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -128,6 +175,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.mapping_len_map().atomic_rewind();
+        self.event_map().atomic_rewind();
+        self.event_topic_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -135,13 +185,47 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.mapping_len_map().abort_atomic();
+        self.event_map().abort_atomic();
+        self.event_topic_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.mapping_len_map().finish_atomic()?;
+        self.event_map().finish_atomic()?;
+        self.event_topic_map().finish_atomic()
+    }
+
+    /// Stores the number of entries for each mapping that is missing it,
+    /// such as the mappings initialized before the number of entries was tracked.
+    fn backfill_mapping_lens(&self) -> Result<()> {
+        // Determine the mappings that are missing their number of entries.
+        let mut mappings = Vec::new();
+        for (program_id, mapping_names) in self.program_id_map().iter_confirmed() {
+            for mapping_name in mapping_names.iter() {
+                if !self.mapping_len_map().contains_key_confirmed(&(*program_id, *mapping_name))? {
+                    mappings.push((*program_id, *mapping_name));
+                }
+            }
+        }
+        // If all mappings have their number of entries, return early.
+        if mappings.is_empty() {
+            return Ok(());
+        }
+
+        atomic_batch_scope!(self, {
+            for mapping in mappings {
+                // Count the entries in the mapping.
+                let mapping_len = u64::try_from(self.key_value_map().len_map_confirmed(&mapping)?)?;
+                // Store the number of entries in the mapping.
+                self.mapping_len_map().insert(mapping, mapping_len)?;
+            }
+            Ok(())
+        })
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -170,6 +254,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Update the program ID map with the new mapping name.
             self.program_id_map().insert(program_id, mapping_names)?;
+            // Initialize the number of entries in the mapping.
+            self.mapping_len_map().insert((program_id, mapping_name), 0)?;
 
             Ok(())
         })?;
@@ -199,6 +285,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             );
         }
 
+        // Retrieve the number of entries in the mapping.
+        let mapping_len = self.get_mapping_len_speculative(program_id, mapping_name)?;
+
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
@@ -207,6 +296,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
             self.key_value_map().insert((program_id, mapping_name), key, value)?;
+            // Increment the number of entries in the mapping.
+            self.mapping_len_map().insert((program_id, mapping_name), mapping_len.saturating_add(1))?;
 
             Ok(())
         })?;
@@ -230,6 +321,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
             bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot update key-value.")
         }
+        // Determine whether the key is new to the mapping.
+        let is_new_key = !self.contains_key_speculative(program_id, mapping_name, &key)?;
+        // Retrieve the number of entries in the mapping.
+        let mapping_len = self.get_mapping_len_speculative(program_id, mapping_name)?;
 
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
@@ -239,6 +334,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
             self.key_value_map().insert((program_id, mapping_name), key, value)?;
+            // If the key is new, increment the number of entries in the mapping.
+            if is_new_key {
+                self.mapping_len_map().insert((program_id, mapping_name), mapping_len.saturating_add(1))?;
+            }
 
             Ok(())
         })?;
@@ -263,6 +362,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         if !self.contains_key_speculative(program_id, mapping_name, key)? {
             return Ok(None);
         }
+        // Retrieve the number of entries in the mapping.
+        let mapping_len = self.get_mapping_len_speculative(program_id, mapping_name)?;
 
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, key)?;
@@ -270,6 +371,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key.
             self.key_value_map().remove_key(&(program_id, mapping_name), key)?;
+            // Decrement the number of entries in the mapping.
+            self.mapping_len_map().insert((program_id, mapping_name), mapping_len.saturating_sub(1))?;
 
             Ok(())
         })?;
@@ -290,6 +393,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
             bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot replace mapping.")
         }
+        // Count the distinct keys, as a repeated key overwrites the earlier entry.
+        let keys: std::collections::BTreeSet<_> = entries.iter().map(|(key, _)| key.to_bytes_le()).try_collect()?;
+        let mapping_len = u64::try_from(keys.len())?;
 
         atomic_batch_scope!(self, {
             // Remove the existing key-value entries.
@@ -300,6 +406,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
                 // Insert the key-value entry.
                 self.key_value_map().insert((program_id, mapping_name), key, value)?;
             }
+            // Update the number of entries in the mapping.
+            self.mapping_len_map().insert((program_id, mapping_name), mapping_len)?;
 
             Ok(())
         })?;
@@ -326,6 +434,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             self.program_id_map().insert(program_id, mapping_names)?;
             // Remove the mapping.
             self.key_value_map().remove_map(&(program_id, mapping_name))?;
+            // Remove the number of entries in the mapping.
+            self.mapping_len_map().remove(&(program_id, mapping_name))?;
 
             Ok(())
         })?;
@@ -334,13 +444,59 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(FinalizeOperation::RemoveMapping(to_mapping_id(&program_id, &mapping_name)?))
     }
 
+    /// Appends the given `payload` as an event under the given `program ID` and `topic` for the given `transition ID`.
+    fn emit_event(
+        &self,
+        program_id: ProgramID<N>,
+        topic: Identifier<N>,
+        transition_id: N::TransitionID,
+        payload: Plaintext<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        // Determine the index of the event, following the events already emitted under the topic by the transition.
+        // Note: The number of events per transition is bounded by the number of commands in a finalize scope.
+        let mut index = 0u16;
+        while self.event_map().contains_key_speculative(&(program_id, topic), &(transition_id, index))? {
+            index = match index.checked_add(1) {
+                Some(index) => index,
+                None => bail!("Illegal operation: too many events for '{program_id}/{topic}' in '{transition_id}'"),
+            };
+        }
+
+        // Compute the topic ID.
+        let topic_id = to_mapping_id(&program_id, &topic)?;
+        // Compute the event ID.
+        let event_id = to_event_id(topic_id, &transition_id, index, &payload)?;
+
+        // Retrieve the topics of the program.
+        let mut topics = match self.event_topic_map().get_speculative(&program_id)? {
+            Some(topics) => cow_to_cloned!(topics),
+            None => IndexSet::new(),
+        };
+
+        atomic_batch_scope!(self, {
+            // Update the topics, if this is the first event under the topic.
+            if topics.insert(topic) {
+                self.event_topic_map().insert(program_id, topics)?;
+            }
+            // Insert the event.
+            self.event_map().insert((program_id, topic), (transition_id, index), payload)?;
+
+            Ok(())
+        })?;
+
+        // Return the finalize operation.
+        Ok(FinalizeOperation::EmitEvent(topic_id, event_id))
+    }
+
     /// Removes the program for the given `program ID` from storage,
-    /// along with all associated mappings and key-value pairs in storage.
+    /// along with all associated mappings, key-value pairs, and events in storage.
     fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
         // Retrieve the mapping names.
         let Some(mapping_names) = self.program_id_map().get_speculative(program_id)? else {
             bail!("Illegal operation: program ID '{program_id}' is not initialized - cannot remove mapping.")
         };
+        // Retrieve the topics.
+        let topics = self.event_topic_map().get_speculative(program_id)?;
 
         atomic_batch_scope!(self, {
             // Update the mapping names.
@@ -350,6 +506,18 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             for mapping_name in mapping_names.iter() {
                 // Remove the mapping.
                 self.key_value_map().remove_map(&(*program_id, *mapping_name))?;
+                // Remove the number of entries in the mapping.
+                self.mapping_len_map().remove(&(*program_id, *mapping_name))?;
+            }
+
+            // Remove the events under each topic.
+            if let Some(topics) = topics {
+                // Update the topics.
+                self.event_topic_map().remove(program_id)?;
+                for topic in topics.iter() {
+                    // Remove the events.
+                    self.event_map().remove_map(&(*program_id, *topic))?;
+                }
             }
             Ok(())
         })
@@ -428,6 +596,66 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_map().get_map_speculative(&(program_id, mapping_name))
     }
 
+    /// Returns the confirmed number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_len_confirmed(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<u64> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get length (C).")
+        }
+        // Retrieve the number of entries in the mapping.
+        match self.mapping_len_map().get_confirmed(&(program_id, mapping_name))? {
+            Some(mapping_len) => Ok(cow_to_copied!(mapping_len)),
+            None => bail!("Missing the length of '{program_id}/{mapping_name}' in storage (C)."),
+        }
+    }
+
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_len_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<u64> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get length (S).")
+        }
+        // Retrieve the number of entries in the mapping.
+        match self.mapping_len_map().get_speculative(&(program_id, mapping_name))? {
+            Some(mapping_len) => Ok(cow_to_copied!(mapping_len)),
+            None => bail!("Missing the length of '{program_id}/{mapping_name}' in storage (S)."),
+        }
+    }
+
+    /// Returns up to `limit` confirmed keys for the given `program ID` and `mapping name`, skipping the first `start` keys.
+    /// The keys are returned in a stable order, which is the order of their serialized bytes.
+    fn get_keys_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<Plaintext<N>>> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get keys (C).")
+        }
+        // Retrieve the keys for the mapping.
+        self.key_value_map().get_keys_confirmed(&(program_id, mapping_name), start, limit)
+    }
+
+    /// Returns up to `limit` speculative keys for the given `program ID` and `mapping name`, skipping the first `start` keys.
+    /// The keys are returned in a stable order, which is the order of their serialized bytes.
+    fn get_keys_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<Plaintext<N>>> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get keys (S).")
+        }
+        // Retrieve the keys for the mapping.
+        self.key_value_map().get_keys_speculative(&(program_id, mapping_name), start, limit)
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     fn get_value_confirmed(
         &self,
@@ -454,6 +682,44 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the confirmed events for the given `program ID`, `topic`, and `transition ID`, in emission order.
+    fn get_events_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        topic: Identifier<N>,
+        transition_id: N::TransitionID,
+    ) -> Result<Vec<Plaintext<N>>> {
+        let mut events = Vec::new();
+        // Retrieve the events by index, as the indices of the events of a transition are contiguous.
+        // Note: The events are looked up by key, so that the order does not depend on the storage backend.
+        for index in 0..=u16::MAX {
+            match self.event_map().get_value_confirmed(&(program_id, topic), &(transition_id, index))? {
+                Some(payload) => events.push(cow_to_cloned!(payload)),
+                None => break,
+            }
+        }
+        Ok(events)
+    }
+
+    /// Returns the speculative events for the given `program ID`, `topic`, and `transition ID`, in emission order.
+    fn get_events_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        topic: Identifier<N>,
+        transition_id: N::TransitionID,
+    ) -> Result<Vec<Plaintext<N>>> {
+        let mut events = Vec::new();
+        // Retrieve the events by index, as the indices of the events of a transition are contiguous.
+        // Note: The events are looked up by key, so that the order does not depend on the storage backend.
+        for index in 0..=u16::MAX {
+            match self.event_map().get_value_speculative(&(program_id, topic), &(transition_id, index))? {
+                Some(payload) => events.push(cow_to_cloned!(payload)),
+                None => break,
+            }
+        }
+        Ok(events)
+    }
+
     /// Returns the confirmed checksum of the finalize storage.
     fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
@@ -546,6 +812,8 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
+        // Backfill the number of entries of the existing mappings.
+        storage.backfill_mapping_lens()?;
         // Return the finalize store.
         Ok(Self { storage, _phantom: PhantomData })
     }
@@ -624,6 +892,22 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_len_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<u64> {
+        self.storage.get_mapping_len_speculative(program_id, mapping_name)
+    }
+
+    /// Returns up to `limit` speculative keys for the given `program ID` and `mapping name`, skipping the first `start` keys.
+    fn get_keys_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<Plaintext<N>>> {
+        self.storage.get_keys_speculative(program_id, mapping_name, start, limit)
+    }
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` already exists, the method returns an error.
@@ -660,6 +944,17 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
     ) -> Result<Option<FinalizeOperation<N>>> {
         self.storage.remove_key_value(program_id, mapping_name, key)
     }
+
+    /// Appends the given `payload` as an event under the given `program ID` and `topic` for the given `transition ID`.
+    fn emit_event(
+        &self,
+        program_id: ProgramID<N>,
+        topic: Identifier<N>,
+        transition_id: N::TransitionID,
+        payload: Plaintext<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        self.storage.emit_event(program_id, topic, transition_id, payload)
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
@@ -742,6 +1037,38 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_mapping_speculative(program_id, mapping_name)
     }
 
+    /// Returns the confirmed number of entries for the given `program ID` and `mapping name`.
+    pub fn get_mapping_len_confirmed(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<u64> {
+        self.storage.get_mapping_len_confirmed(program_id, mapping_name)
+    }
+
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    pub fn get_mapping_len_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<u64> {
+        self.storage.get_mapping_len_speculative(program_id, mapping_name)
+    }
+
+    /// Returns up to `limit` confirmed keys for the given `program ID` and `mapping name`, skipping the first `start` keys.
+    pub fn get_keys_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<Plaintext<N>>> {
+        self.storage.get_keys_confirmed(program_id, mapping_name, start, limit)
+    }
+
+    /// Returns up to `limit` speculative keys for the given `program ID` and `mapping name`, skipping the first `start` keys.
+    pub fn get_keys_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<Plaintext<N>>> {
+        self.storage.get_keys_speculative(program_id, mapping_name, start, limit)
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     pub fn get_value_confirmed(
         &self,
@@ -762,6 +1089,26 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the confirmed events for the given `program ID`, `topic`, and `transition ID`, in emission order.
+    pub fn get_events_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        topic: Identifier<N>,
+        transition_id: N::TransitionID,
+    ) -> Result<Vec<Plaintext<N>>> {
+        self.storage.get_events_confirmed(program_id, topic, transition_id)
+    }
+
+    /// Returns the speculative events for the given `program ID`, `topic`, and `transition ID`, in emission order.
+    pub fn get_events_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        topic: Identifier<N>,
+        transition_id: N::TransitionID,
+    ) -> Result<Vec<Plaintext<N>>> {
+        self.storage.get_events_speculative(program_id, topic, transition_id)
+    }
+
     /// Returns the confirmed checksum of the finalize store.
     pub fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         self.storage.get_checksum_confirmed()
@@ -1144,6 +1491,118 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mapping_len_backfill() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize storage with a mapping that has entries, but is missing its number of entries.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        program_memory.program_id_map().insert(program_id, IndexSet::from([mapping_name])).unwrap();
+        for item in 0..3u8 {
+            let key = Plaintext::from_str(&format!("{item}u8")).unwrap();
+            let value = Value::from_str("1u64").unwrap();
+            program_memory.key_value_map().insert((program_id, mapping_name), key, value).unwrap();
+        }
+        assert!(program_memory.mapping_len_map().get_confirmed(&(program_id, mapping_name)).unwrap().is_none());
+
+        // Initialize the finalize store, which backfills the number of entries.
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 3);
+
+        // Ensure the mapping can be updated.
+        let key = Plaintext::from_str("3u8").unwrap();
+        let value = Value::from_str("2u64").unwrap();
+        finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 4);
+        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value).unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 4);
+        finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+        finalize_store.remove_key_value(program_id, mapping_name, &Plaintext::from_str("0u8").unwrap()).unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_mapping_len_and_keys() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Ensure the length of an un-initialized mapping is an error.
+        assert!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).is_err());
+        assert!(finalize_store.get_keys_confirmed(program_id, mapping_name, 0, 1).is_err());
+
+        // Now, initialize the mapping.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 0);
+
+        // Insert the list of keys and values.
+        for item in 0..100 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 100);
+
+        // Ensure updating an existing key does not change the length, while updating a new key does.
+        let value = Value::from_str("0u64").unwrap();
+        finalize_store
+            .update_key_value(program_id, mapping_name, Plaintext::from_str("0field").unwrap(), value.clone())
+            .unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 100);
+        finalize_store
+            .update_key_value(program_id, mapping_name, Plaintext::from_str("100field").unwrap(), value)
+            .unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 101);
+
+        // Ensure removing a key decrements the length, and removing a missing key does not.
+        finalize_store.remove_key_value(program_id, mapping_name, &Plaintext::from_str("100field").unwrap()).unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 100);
+        finalize_store.remove_key_value(program_id, mapping_name, &Plaintext::from_str("100field").unwrap()).unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 100);
+
+        // Ensure the pages of keys are disjoint, and cover all of the keys.
+        let all_keys = finalize_store.get_keys_confirmed(program_id, mapping_name, 0, 1000).unwrap();
+        assert_eq!(all_keys.len(), 100);
+        let pages = (0..100)
+            .step_by(7)
+            .flat_map(|start| finalize_store.get_keys_confirmed(program_id, mapping_name, start, 7).unwrap());
+        assert_eq!(pages.collect::<Vec<_>>(), all_keys);
+        assert!(finalize_store.get_keys_confirmed(program_id, mapping_name, 100, 7).unwrap().is_empty());
+
+        // Ensure the speculative length and keys reflect an atomic batch, and are reverted when it is aborted.
+        finalize_store.start_atomic();
+        let key = Plaintext::from_str("100field").unwrap();
+        finalize_store
+            .insert_key_value(program_id, mapping_name, key.clone(), Value::from_str("0u64").unwrap())
+            .unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 100);
+        assert_eq!(finalize_store.get_mapping_len_speculative(program_id, mapping_name).unwrap(), 101);
+        let speculative_keys = finalize_store.get_keys_speculative(program_id, mapping_name, 0, 1000).unwrap();
+        assert_eq!(speculative_keys.len(), 101);
+        assert!(speculative_keys.contains(&key));
+        finalize_store.abort_atomic();
+        assert_eq!(finalize_store.get_mapping_len_speculative(program_id, mapping_name).unwrap(), 100);
+
+        // Ensure replacing the mapping counts the distinct keys.
+        let entries = [0, 1, 1, 2]
+            .into_iter()
+            .map(|item| (Plaintext::from_str(&format!("{item}field")).unwrap(), Value::from_str("0u64").unwrap()))
+            .collect();
+        finalize_store.replace_mapping(program_id, mapping_name, entries).unwrap();
+        assert_eq!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).unwrap(), 3);
+        assert_eq!(finalize_store.get_keys_confirmed(program_id, mapping_name, 0, 1000).unwrap().len(), 3);
+
+        // Ensure removing the mapping removes its length.
+        finalize_store.remove_mapping(program_id, mapping_name).unwrap();
+        assert!(finalize_store.get_mapping_len_confirmed(program_id, mapping_name).is_err());
+        assert!(finalize_store.storage.mapping_len_map().get_confirmed(&(program_id, mapping_name)).unwrap().is_none());
+    }
+
     #[test]
     fn test_remove_program() {
         // Initialize a program ID and mapping name.
@@ -1268,6 +1727,131 @@ mod tests {
         check_initialize_update_remove(&finalize_store, program_id, mapping_name);
     }
 
+    #[test]
+    fn test_emit_event() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program ID and topics.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let topic = Identifier::from_str("transfer").unwrap();
+        let other_topic = Identifier::from_str("deposit").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Ensure there are no events.
+        let transition_id: <CurrentNetwork as Network>::TransitionID = Uniform::rand(rng);
+        assert!(finalize_store.get_events_confirmed(program_id, topic, transition_id).unwrap().is_empty());
+
+        // Emit two events from the same transition.
+        let first = Plaintext::from_str("1u64").unwrap();
+        let second = Plaintext::from_str("2u64").unwrap();
+        let first_operation = finalize_store.emit_event(program_id, topic, transition_id, first.clone()).unwrap();
+        let second_operation = finalize_store.emit_event(program_id, topic, transition_id, second.clone()).unwrap();
+
+        // Ensure the finalize operations share the topic ID, but not the event ID.
+        let FinalizeOperation::EmitEvent(first_topic_id, first_event_id) = first_operation else {
+            panic!("Expected an 'EmitEvent' finalize operation");
+        };
+        let FinalizeOperation::EmitEvent(second_topic_id, second_event_id) = second_operation else {
+            panic!("Expected an 'EmitEvent' finalize operation");
+        };
+        assert_eq!(first_topic_id, second_topic_id);
+        assert_ne!(first_event_id, second_event_id);
+
+        // Ensure an identical payload is stored as a separate event.
+        let third_operation = finalize_store.emit_event(program_id, topic, transition_id, first.clone()).unwrap();
+        let FinalizeOperation::EmitEvent(_, third_event_id) = third_operation else {
+            panic!("Expected an 'EmitEvent' finalize operation");
+        };
+        assert_ne!(first_event_id, third_event_id);
+
+        // Ensure each event is stored under its own index.
+        for (index, payload) in [first.clone(), second.clone(), first.clone()].iter().enumerate() {
+            let key = (transition_id, index as u16);
+            let event = finalize_store.storage.event_map().get_value_confirmed(&(program_id, topic), &key).unwrap();
+            assert_eq!(event.as_deref(), Some(payload));
+        }
+        // Ensure the events are retrieved in emission order.
+        let events = finalize_store.get_events_confirmed(program_id, topic, transition_id).unwrap();
+        assert_eq!(events, vec![first.clone(), second, first]);
+        // Ensure the other topic has no events.
+        assert!(finalize_store.get_events_confirmed(program_id, other_topic, transition_id).unwrap().is_empty());
+
+        // Ensure an emitted event is discarded when the atomic batch is aborted.
+        let other_transition_id = Uniform::rand(rng);
+        finalize_store.start_atomic();
+        let payload = Plaintext::from_str("3u64").unwrap();
+        finalize_store.emit_event(program_id, other_topic, other_transition_id, payload).unwrap();
+        let events = finalize_store.get_events_speculative(program_id, other_topic, other_transition_id).unwrap();
+        assert_eq!(events.len(), 1);
+        finalize_store.abort_atomic();
+        assert!(finalize_store.get_events_confirmed(program_id, other_topic, other_transition_id).unwrap().is_empty());
+
+        // Ensure removing the program removes its events.
+        let payload = Plaintext::from_str("4u64").unwrap();
+        finalize_store.emit_event(program_id, other_topic, other_transition_id, payload).unwrap();
+        finalize_store.initialize_mapping(program_id, Identifier::from_str("account").unwrap()).unwrap();
+        finalize_store.remove_program(&program_id).unwrap();
+        assert!(finalize_store.get_events_confirmed(program_id, topic, transition_id).unwrap().is_empty());
+        assert!(finalize_store.get_events_confirmed(program_id, other_topic, other_transition_id).unwrap().is_empty());
+        assert!(finalize_store.storage.event_topic_map().get_confirmed(&program_id).unwrap().is_none());
+    }
+
+    /// Emits interleaved events from several transitions, and returns the events of each transition.
+    fn check_events_order<F: FinalizeStorage<CurrentNetwork>>(
+        finalize_store: &FinalizeStore<CurrentNetwork, F>,
+        transition_ids: &[<CurrentNetwork as Network>::TransitionID],
+    ) -> Vec<Vec<Plaintext<CurrentNetwork>>> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let topic = Identifier::from_str("transfer").unwrap();
+
+        // Emit the events, interleaving the transitions.
+        for round in 0..4u64 {
+            for (i, transition_id) in transition_ids.iter().enumerate() {
+                let payload = Plaintext::from_str(&format!("{}u64", round * 10 + i as u64)).unwrap();
+                finalize_store.emit_event(program_id, topic, *transition_id, payload).unwrap();
+            }
+        }
+
+        // Retrieve the events of each transition, which must be in emission order.
+        transition_ids
+            .iter()
+            .enumerate()
+            .map(|(i, transition_id)| {
+                let events = finalize_store.get_events_confirmed(program_id, topic, *transition_id).unwrap();
+                let expected = (0..4u64)
+                    .map(|round| Plaintext::from_str(&format!("{}u64", round * 10 + i as u64)).unwrap())
+                    .collect::<Vec<_>>();
+                assert_eq!(events, expected);
+                events
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_events_order() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transition IDs.
+        let transition_ids = (0..8).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+
+        // Retrieve the events from the memory backend.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        let events = check_events_order(&finalize_store, &transition_ids);
+
+        // Ensure the events from the RocksDB backend are in the same order.
+        #[cfg(feature = "rocks")]
+        {
+            let temp_dir = tempfile::tempdir().expect("Failed to open temporary directory").into_path();
+            let program_rocksdb = crate::helpers::rocksdb::FinalizeDB::open_testing(temp_dir, None).unwrap();
+            let finalize_store = FinalizeStore::from(program_rocksdb).unwrap();
+            assert_eq!(check_events_order(&finalize_store, &transition_ids), events);
+        }
+        #[cfg(not(feature = "rocks"))]
+        assert_eq!(events.len(), transition_ids.len());
+    }
+
     /// If you want to customize the DB size, run:
     /// ```ignore
     /// NUM_ITEMS=100000 cargo test test_finalize_timings -- --nocapture
@@ -218,10 +218,15 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_deployment_edition(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
+        // Ensure the edition is the latest edition of the program.
+        // Note: Upgrades must be removed in the reverse order they were inserted.
+        if self.get_edition(&program_id)? != Some(edition) {
+            bail!("Failed to remove program '{program_id}' (edition {edition}), as it is not the latest edition")
+        }
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -231,8 +236,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Remove the edition, or restore the previous edition if this deployment was an upgrade.
+            match edition > N::EDITION {
+                true => self.edition_map().insert(program_id, edition - 1)?,
+                false => self.edition_map().remove(&program_id)?,
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -309,6 +317,29 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the edition of the deployment for the given `transaction ID`.
+    fn get_deployment_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => return Ok(None),
+        };
+        // Retrieve the latest edition.
+        let latest_edition = match self.get_edition(&program_id)? {
+            Some(edition) => edition,
+            None => bail!("Failed to get the edition for program '{program_id}'"),
+        };
+        // Search the editions of the program, starting from the latest edition.
+        for edition in (N::EDITION..=latest_edition).rev() {
+            if let Some(candidate_id) = self.reverse_id_map().get_confirmed(&(program_id, edition))? {
+                if cow_to_copied!(candidate_id) == *transaction_id {
+                    return Ok(Some(edition));
+                }
+            }
+        }
+        bail!("Failed to find the edition of program '{program_id}' for transaction '{transaction_id}'")
+    }
+
     /// Returns the program for the given `program ID`.
     fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -395,7 +426,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_deployment_edition(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to get the edition for program '{program_id}'"),
         };
@@ -442,8 +473,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             return Ok(None);
         }
 
-        // TODO (raychu86): Consider program upgrades and edition changes.
-        // Retrieve the edition.
+        // Retrieve the latest edition.
+        // Note: Upgrades are gated on the owner address, so the latest owner shares the address of all prior owners.
         let edition = match self.get_edition(program_id)? {
             Some(edition) => edition,
             None => return Ok(None),
@@ -469,9 +500,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
         };
 
-        // Retrieve the owner.
-        let owner = match self.get_owner(deployment.program_id())? {
-            Some(owner) => owner,
+        // Retrieve the owner of the deployed edition.
+        let owner = match self.owner_map().get_confirmed(&(*deployment.program_id(), deployment.edition()))? {
+            Some(owner) => cow_to_copied!(owner),
             None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
         };
 
@@ -575,6 +606,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_edition(program_id)
     }
 
+    /// Returns the edition of the deployment for the given `transaction ID`.
+    pub fn get_deployment_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        self.storage.get_deployment_edition(transaction_id)
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         self.storage.get_program_id(transaction_id)
@@ -585,6 +621,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_program(program_id)
     }
 
+    /// Returns the owner of the latest edition for the given `program ID`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.get_owner(program_id)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
@@ -712,6 +753,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_insert_get_remove_upgrade() {
+        let rng = &mut TestRng::default();
+
+        // Sample the original deployment transaction.
+        let transaction_0 = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let (owner, deployment) = match &transaction_0 {
+            Transaction::Deploy(_, owner, deployment, _) => (*owner, deployment.clone()),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let program_id = *deployment.program_id();
+
+        // Construct an upgrade of the deployment, with the next edition.
+        let upgrade = Deployment::new(
+            deployment.edition() + 1,
+            deployment.program().clone(),
+            deployment.verifying_keys().clone(),
+        )
+        .unwrap();
+        let upgrade_id = upgrade.to_deployment_id().unwrap();
+        let fee = ledger_test_helpers::sample_fee_private(upgrade_id, rng);
+        let transaction_1 = Transaction::from_deployment(owner, upgrade, fee).unwrap();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentMemory::open(fee_store).unwrap();
+
+        // Insert the original deployment and its upgrade.
+        deployment_store.insert(&transaction_0).unwrap();
+        deployment_store.insert(&transaction_1).unwrap();
+
+        // Ensure the latest edition is the upgrade.
+        assert_eq!(Some(deployment.edition() + 1), deployment_store.get_edition(&program_id).unwrap());
+        assert_eq!(
+            Some(transaction_1.id()),
+            deployment_store.find_transaction_id_from_program_id(&program_id).unwrap()
+        );
+        // Ensure both deployment transactions are retrievable.
+        assert_eq!(Some(transaction_0.clone()), deployment_store.get_transaction(&transaction_0.id()).unwrap());
+        assert_eq!(Some(transaction_1.clone()), deployment_store.get_transaction(&transaction_1.id()).unwrap());
+
+        // Ensure the original deployment cannot be removed before its upgrade.
+        assert!(deployment_store.remove(&transaction_0.id()).is_err());
+
+        // Remove the upgrade, and ensure the original edition is restored.
+        deployment_store.remove(&transaction_1.id()).unwrap();
+        assert_eq!(Some(deployment.edition()), deployment_store.get_edition(&program_id).unwrap());
+        assert_eq!(None, deployment_store.get_transaction(&transaction_1.id()).unwrap());
+        assert_eq!(Some(transaction_0.clone()), deployment_store.get_transaction(&transaction_0.id()).unwrap());
+
+        // Remove the original deployment.
+        deployment_store.remove(&transaction_0.id()).unwrap();
+        assert_eq!(None, deployment_store.get_edition(&program_id).unwrap());
+    }
+
     #[test]
     fn test_find_transaction_id() {
         let rng = &mut TestRng::default();
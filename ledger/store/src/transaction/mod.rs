@@ -365,11 +365,9 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         // Retrieve the edition.
         match transaction_type {
             TransactionType::Deploy => {
-                // Retrieve the program ID.
-                let program_id = self.storage.deployment_store().get_program_id(transaction_id)?;
-                // Return the edition.
-                match program_id {
-                    Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
+                // Retrieve the edition.
+                match self.storage.deployment_store().get_deployment_edition(transaction_id)? {
+                    Some(edition) => Ok(Some(edition)),
                     None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
                 }
             }
@@ -16,7 +16,7 @@ use crate::{Process, Stack, StackProgramTypes};
 
 use console::{
     prelude::*,
-    program::{FinalizeType, Identifier, LiteralType, PlaintextType},
+    program::{FinalizeType, Identifier, LiteralType, PlaintextType, Register},
 };
 use ledger_block::{Deployment, Execution};
 use synthesizer_program::{CastType, Command, Finalize, Instruction, Operand, StackProgram};
@@ -91,6 +91,11 @@ fn execution_storage_cost<N: Network>(size_in_bytes: u64) -> u64 {
 const CAST_BASE_COST: u64 = 500;
 const CAST_PER_BYTE_COST: u64 = 30;
 
+/// The finalize cost for each dynamic access (i.e. `r0[r1]`) in the operands of a command.
+const DYNAMIC_ACCESS_COST: u64 = 500;
+
+const ECDSA_VERIFY_BASE_COST: u64 = 50_000;
+
 const HASH_BASE_COST: u64 = 10_000;
 const HASH_PER_BYTE_COST: u64 = 30;
 
@@ -103,9 +108,15 @@ const HASH_PSD_PER_BYTE_COST: u64 = 75;
 const MAPPING_BASE_COST: u64 = 10_000;
 const MAPPING_PER_BYTE_COST: u64 = 10;
 
+/// The finalize cost for each mapping entry that may be visited by `mapping.keys`.
+const MAPPING_PER_ENTRY_COST: u64 = 100;
+
 const SET_BASE_COST: u64 = 10_000;
 const SET_PER_BYTE_COST: u64 = 100;
 
+const STRING_BASE_COST: u64 = 500;
+const STRING_PER_BYTE_COST: u64 = 10;
+
 /// A helper function to determine the plaintext type in bytes.
 fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &PlaintextType<N>) -> Result<u64> {
     match plaintext_type {
@@ -133,6 +144,12 @@ fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &Plaint
             // Return the size of the array.
             Ok(num_elements.saturating_mul(size_of_element))
         }
+        PlaintextType::Option(option_type) => {
+            // Compute the size of the option value.
+            let size_of_value = plaintext_size_in_bytes(stack, option_type.element_type())?;
+            // Return the size of the option, including its flag.
+            Ok(size_of_value.saturating_add(1))
+        }
     }
 }
 
@@ -166,16 +183,68 @@ fn cost_in_size<'a, N: Network>(
     Ok(base_cost.saturating_add(byte_multiplier.saturating_mul(size_of_operands)))
 }
 
+/// A helper function to compute the cost of verifying a Merkle path, which performs one hash for the leaf,
+/// and one hash for each level of the path: (base_cost * (depth + 1)) + (byte_multiplier * size_of_operands).
+fn cost_merkle_verify<N: Network>(
+    stack: &Stack<N>,
+    finalize: &Finalize<N>,
+    operands: &[Operand<N>],
+    byte_multiplier: u64,
+    base_cost: u64,
+) -> Result<u64> {
+    // Ensure the instruction has a path operand.
+    ensure!(operands.len() == 4, "'merkle.verify' must contain 4 operands");
+    // Retrieve the finalize types.
+    let finalize_types = stack.get_finalize_types(finalize.name())?;
+    // Determine the depth of the Merkle path.
+    let depth = match finalize_types.get_type_from_operand(stack, &operands[3])? {
+        FinalizeType::Plaintext(PlaintextType::Array(array_type)) => **array_type.length() as u64,
+        _ => bail!("'merkle.verify' expects the path to be an array"),
+    };
+    // Compute the cost.
+    cost_in_size(stack, finalize, operands, byte_multiplier, base_cost.saturating_mul(depth.saturating_add(1)))
+}
+
+/// A helper function to compute the cost of a multi-scalar multiplication, which performs one scalar multiplication
+/// for each term: (10_000 * num_terms).
+fn cost_msm<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, operands: &[Operand<N>]) -> Result<u64> {
+    // Ensure the instruction has a groups operand.
+    ensure!(operands.len() == 2, "'msm' must contain 2 operands");
+    // Retrieve the finalize types.
+    let finalize_types = stack.get_finalize_types(finalize.name())?;
+    // Determine the number of terms.
+    let num_terms = match finalize_types.get_type_from_operand(stack, &operands[0])? {
+        FinalizeType::Plaintext(PlaintextType::Array(array_type)) => **array_type.length() as u64,
+        _ => bail!("'msm' expects the groups to be an array"),
+    };
+    // Compute the cost.
+    Ok(10_000u64.saturating_mul(num_terms))
+}
+
 /// Returns the the cost of a command in a finalize scope.
 pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, command: &Command<N>) -> Result<u64> {
-    match command {
+    // Determine the number of dynamic accesses in the operands of the command.
+    let num_dynamic_accesses = command
+        .operands()
+        .iter()
+        .map(|operand| match operand {
+            Operand::Register(Register::Access(_, path)) => path.iter().filter(|access| access.is_dynamic()).count(),
+            _ => 0,
+        })
+        .sum::<usize>() as u64;
+    // Compute the cost of the dynamic accesses.
+    let dynamic_access_cost = num_dynamic_accesses.saturating_mul(DYNAMIC_ACCESS_COST);
+
+    let cost = match command {
         Command::Instruction(Instruction::Abs(_)) => Ok(500),
         Command::Instruction(Instruction::AbsWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Add(_)) => Ok(500),
+        Command::Instruction(Instruction::AddChecked(_)) => Ok(500),
         Command::Instruction(Instruction::AddWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::And(_)) => Ok(500),
         Command::Instruction(Instruction::AssertEq(_)) => Ok(500),
         Command::Instruction(Instruction::AssertNeq(_)) => Ok(500),
+        Command::Instruction(Instruction::AssertWithCode(_)) => Ok(500),
         Command::Instruction(Instruction::Async(_)) => bail!("'async' is not supported in finalize"),
         Command::Instruction(Instruction::Call(_)) => bail!("'call' is not supported in finalize"),
         Command::Instruction(Instruction::Cast(cast)) => match cast.cast_type() {
@@ -224,16 +293,27 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
             // Retrieve the price by the operand type.
             match finalize_types.get_type_from_operand(stack, &div.operands()[0])? {
                 FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)) => Ok(1_500),
+                FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Fixed)) => Ok(1_000),
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'div' does not support arrays"),
                 FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'div' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Option(_)) => bail!("'div' does not support options"),
                 FinalizeType::Future(_) => bail!("'div' does not support futures"),
             }
         }
+        Command::Instruction(Instruction::DivRounded(_)) => Ok(1_000),
         Command::Instruction(Instruction::DivWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Double(_)) => Ok(500),
+        Command::Instruction(Instruction::ECDSAVerifySecp256k1(_)) => Ok(ECDSA_VERIFY_BASE_COST),
+        Command::Instruction(Instruction::ECDSAVerifySecp256k1Keccak256(ecdsa)) => {
+            // The message is hashed with Keccak-256, before verifying the signature.
+            ensure!(ecdsa.operands().len() == 3, "'ecdsa.verify' must contain exactly 3 operands");
+            cost_in_size(stack, finalize, &ecdsa.operands()[2..], HASH_PER_BYTE_COST, ECDSA_VERIFY_BASE_COST)
+        }
+        Command::Instruction(Instruction::End(_)) => bail!("'end' is not supported in finalize"),
         Command::Instruction(Instruction::GreaterThan(_)) => Ok(500),
         Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(500),
+        Command::Instruction(Instruction::GroupGen(_)) => Ok(10_000),
         Command::Instruction(Instruction::HashBHP256(hash)) => {
             cost_in_size(stack, finalize, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
@@ -288,11 +368,29 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::HashManyPSD8(_)) => {
             bail!("`hash_many.psd8` is not supported in finalize")
         }
+        Command::Instruction(Instruction::HashToGroupPSD2(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashToGroupPSD4(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashToGroupPSD8(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
         Command::Instruction(Instruction::Inv(_)) => Ok(2_500),
         Command::Instruction(Instruction::IsEq(_)) => Ok(500),
         Command::Instruction(Instruction::IsNeq(_)) => Ok(500),
+        Command::Instruction(Instruction::IsSome(_)) => Ok(500),
+        Command::Instruction(Instruction::LeadingZeros(_)) => Ok(500),
         Command::Instruction(Instruction::LessThan(_)) => Ok(500),
         Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(500),
+        Command::Instruction(Instruction::MerkleVerifyBHP(merkle)) => {
+            cost_merkle_verify(stack, finalize, merkle.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::MerkleVerifyPSD(merkle)) => {
+            cost_merkle_verify(stack, finalize, merkle.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::MSM(msm)) => cost_msm(stack, finalize, msm.operands()),
         Command::Instruction(Instruction::Modulo(_)) => Ok(500),
         Command::Instruction(Instruction::Mul(mul)) => {
             // Ensure `mul` has exactly two operands.
@@ -303,18 +401,25 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
             match finalize_types.get_type_from_operand(stack, &mul.operands()[0])? {
                 FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Group)) => Ok(10_000),
                 FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)) => Ok(10_000),
+                FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Fixed)) => Ok(1_000),
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'mul' does not support arrays"),
                 FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'mul' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Option(_)) => bail!("'mul' does not support options"),
                 FinalizeType::Future(_) => bail!("'mul' does not support futures"),
             }
         }
+        Command::Instruction(Instruction::MulChecked(_)) => Ok(500),
+        Command::Instruction(Instruction::MulRounded(_)) => Ok(1_000),
         Command::Instruction(Instruction::MulWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Nand(_)) => Ok(500),
         Command::Instruction(Instruction::Neg(_)) => Ok(500),
         Command::Instruction(Instruction::Nor(_)) => Ok(500),
         Command::Instruction(Instruction::Not(_)) => Ok(500),
+        Command::Instruction(Instruction::OptionNone(_)) => Ok(500),
+        Command::Instruction(Instruction::OptionSome(_)) => Ok(500),
         Command::Instruction(Instruction::Or(_)) => Ok(500),
+        Command::Instruction(Instruction::PopCount(_)) => Ok(500),
         Command::Instruction(Instruction::Pow(pow)) => {
             // Ensure `pow` has at least one operand.
             ensure!(!pow.operands().is_empty(), "'pow' must contain at least 1 operand");
@@ -326,12 +431,16 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'pow' does not support arrays"),
                 FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'pow' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Option(_)) => bail!("'pow' does not support options"),
                 FinalizeType::Future(_) => bail!("'pow' does not support futures"),
             }
         }
         Command::Instruction(Instruction::PowWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Rem(_)) => Ok(500),
         Command::Instruction(Instruction::RemWrapped(_)) => Ok(500),
+        Command::Instruction(Instruction::Repeat(_)) => bail!("'repeat' is not supported in finalize"),
+        Command::Instruction(Instruction::RotateLeft(_)) => Ok(500),
+        Command::Instruction(Instruction::RotateRight(_)) => Ok(500),
         Command::Instruction(Instruction::SignVerify(sign)) => {
             cost_in_size(stack, finalize, sign.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
         }
@@ -341,20 +450,34 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::ShrWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Square(_)) => Ok(500),
         Command::Instruction(Instruction::SquareRoot(_)) => Ok(2_500),
+        Command::Instruction(Instruction::StringConcat(concat)) => {
+            cost_in_size(stack, finalize, concat.operands(), STRING_PER_BYTE_COST, STRING_BASE_COST)
+        }
+        Command::Instruction(Instruction::StringLength(_)) => Ok(500),
         Command::Instruction(Instruction::Sub(_)) => Ok(500),
+        Command::Instruction(Instruction::SubChecked(_)) => Ok(500),
         Command::Instruction(Instruction::SubWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Ternary(_)) => Ok(500),
+        Command::Instruction(Instruction::TrailingZeros(_)) => Ok(500),
+        Command::Instruction(Instruction::Unwrap(_)) => Ok(500),
+        Command::Instruction(Instruction::UnwrapOr(_)) => Ok(500),
         Command::Instruction(Instruction::Xor(_)) => Ok(500),
         Command::Await(_) => Ok(500),
         Command::Contains(command) => {
             cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
         }
+        Command::Emit(command) => cost_in_size(stack, finalize, [command.payload()], SET_PER_BYTE_COST, SET_BASE_COST),
         Command::Get(command) => {
             cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
         }
         Command::GetOrUse(command) => {
             cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
         }
+        Command::MappingLen(_) => Ok(MAPPING_BASE_COST),
+        Command::MappingKeys(command) => MAPPING_PER_ENTRY_COST
+            .checked_mul((**command.start() as u64).saturating_add(**command.limit() as u64))
+            .and_then(|cost| cost.checked_add(MAPPING_BASE_COST))
+            .ok_or(anyhow!("The cost computation overflowed for '{command}'")),
         Command::RandChaCha(_) => Ok(25_000),
         Command::Remove(_) => Ok(MAPPING_BASE_COST),
         Command::Set(command) => {
@@ -362,7 +485,10 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         }
         Command::BranchEq(_) | Command::BranchNeq(_) => Ok(500),
         Command::Position(_) => Ok(100),
-    }
+    }?;
+
+    // Return the cost of the command, including its dynamic accesses.
+    cost.checked_add(dynamic_access_cost).ok_or(anyhow!("The cost computation overflowed for '{command}'"))
 }
 
 /// Returns the minimum number of microcredits required to run the finalize.
@@ -16,6 +16,7 @@ use super::*;
 
 impl<N: Network> Process<N> {
     /// Deploys the given program ID, if it does not exist.
+    /// If the program exists, the deployment is an upgrade of the program to the next edition.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -25,7 +26,15 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::deploy");
 
         // Compute the stack.
-        let stack = Stack::new(self, program)?;
+        let stack = match self.get_stack(program.id()) {
+            Ok(existing) => {
+                // Determine the next edition.
+                let edition = existing.edition().checked_add(1).ok_or_else(|| anyhow!("Edition overflow"))?;
+                // Compute the stack for the upgrade.
+                Stack::upgrade(self, program, edition)?
+            }
+            Err(_) => Stack::new(self, program)?,
+        };
         lap!(timer, "Compute the stack");
 
         // Return the deployment.
@@ -44,7 +53,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        // Note: If the program does not exist, the deployment may be the latest edition of an upgraded program.
+        let stack = match self.contains_program(deployment.program_id()) {
+            true => Stack::upgrade(self, deployment.program(), deployment.edition())?,
+            false => Stack::load(self, deployment.program(), deployment.edition())?,
+        };
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -54,10 +67,34 @@ impl<N: Network> Process<N> {
         lap!(timer, "Insert the verifying keys");
 
         // Add the stack to the process.
-        self.add_stack(stack);
+        // Note: An upgraded stack also rebuilds the stacks of the programs that import it.
+        match self.contains_program(deployment.program_id()) {
+            true => self.upgrade_stack(stack)?,
+            false => self.add_stack(stack),
+        }
 
         finish!(timer);
 
         Ok(())
     }
+
+    /// Returns the stack for the given deployment.
+    /// If the program already exists, the deployment must be a valid upgrade of the existing program.
+    #[inline]
+    pub(crate) fn get_deployment_stack(&self, deployment: &Deployment<N>) -> Result<Stack<N>> {
+        match self.contains_program(deployment.program_id()) {
+            true => Stack::upgrade(self, deployment.program(), deployment.edition()),
+            false => {
+                // Ensure the deployment is for the initial edition.
+                ensure!(
+                    deployment.edition() == N::EDITION,
+                    "Program '{}' must be deployed with edition {}, found edition {}",
+                    deployment.program_id(),
+                    N::EDITION,
+                    deployment.edition()
+                );
+                Stack::new(self, deployment.program())
+            }
+        }
+    }
 }
@@ -36,4 +36,35 @@ impl<N: Network> Process<N> {
 
         response
     }
+
+    /// Evaluates a program function on the given request, recording a snapshot of the registers
+    /// for each instruction evaluated in the function, its closures, and its child functions.
+    ///
+    /// The debug trace is returned even if the evaluation fails, in which case the failed steps record the error.
+    #[inline]
+    pub fn evaluate_with_trace<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> (Result<Response<N>>, DebugTrace<N>) {
+        let timer = timer!("Process::evaluate_with_trace");
+
+        // Initialize the debug trace.
+        let debug_trace = Arc::new(RwLock::new(DebugTrace::new()));
+
+        // Evaluate the function, recording each step into the debug trace.
+        let response = authorization.peek_next().and_then(|request| {
+            // Retrieve the stack.
+            let stack = self.get_stack(request.program_id())?;
+            // Evaluate the function.
+            stack.evaluate_function::<A>(CallStack::debug(authorization, debug_trace.clone())?, None)
+        });
+        lap!(timer, "Evaluate the function");
+
+        // Retrieve the debug trace.
+        let debug_trace = debug_trace.read().clone();
+
+        finish!(timer);
+
+        (response, debug_trace)
+    }
 }
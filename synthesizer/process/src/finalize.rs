@@ -14,7 +14,8 @@
 
 use super::*;
 use console::program::{FinalizeType, Future, Register};
-use synthesizer_program::{Await, FinalizeRegistersState, Operand};
+use ledger_block::RejectionReason;
+use synthesizer_program::{Await, CommandTrait, FinalizeRegistersState, Operand};
 use utilities::try_vm_runtime;
 
 use std::collections::HashSet;
@@ -33,8 +34,11 @@ impl<N: Network> Process<N> {
     ) -> Result<(Stack<N>, Vec<FinalizeOperation<N>>)> {
         let timer = timer!("Process::finalize_deployment");
 
+        // Retrieve the existing program, if this deployment is an upgrade.
+        let existing_program = self.get_program(deployment.program_id()).ok().cloned();
+
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.get_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -62,6 +66,12 @@ impl<N: Network> Process<N> {
             let program_id = deployment.program_id();
             // Iterate over the mappings.
             for mapping in deployment.program().mappings().values() {
+                // Skip the mapping if it exists from a prior edition, so that its entries are preserved.
+                if let Some(existing_program) = &existing_program {
+                    if existing_program.contains_mapping(mapping.name()) {
+                        continue;
+                    }
+                }
                 // Initialize the mapping.
                 finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
             }
@@ -85,31 +95,15 @@ impl<N: Network> Process<N> {
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("Program::finalize_execution");
 
-        // Ensure the execution contains transitions.
-        ensure!(!execution.is_empty(), "There are no transitions in the execution");
-
-        // Ensure the number of transitions matches the program function.
-        // Retrieve the root transition (without popping it).
-        let transition = execution.peek()?;
-        // Retrieve the stack.
-        let stack = self.get_stack(transition.program_id())?;
-        // Ensure the number of calls matches the number of transitions.
-        let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
-        ensure!(
-            number_of_calls == execution.len(),
-            "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-            execution.len()
-        );
+        // Retrieve the root transition, its stack, and the call graph.
+        let (transition, stack, call_graph) = self.prepare_finalize_execution(execution)?;
         lap!(timer, "Verify the number of transitions");
 
-        // Construct the call graph.
-        let call_graph = self.construct_call_graph(execution)?;
-
         atomic_batch_scope!(store, {
             // Finalize the root transition.
             // Note that this will result in all the remaining transitions being finalized, since the number
             // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph, None)?;
 
             /* Finalize the fee. */
 
@@ -127,6 +121,49 @@ impl<N: Network> Process<N> {
         })
     }
 
+    /// Finalizes the execution without persisting its changes, recording a snapshot of the registers
+    /// for each command evaluated in the finalize scopes of the execution.
+    ///
+    /// The debug trace is returned even if the finalize scope fails, in which case the failed step records the error.
+    /// Note: The fee is not finalized in the dry run.
+    /// This method should **only** be called by `VM::finalize_dry_run()`, which holds the atomic lock.
+    #[inline]
+    pub fn finalize_dry_run<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        execution: &Execution<N>,
+    ) -> (Result<Vec<FinalizeOperation<N>>>, DebugTrace<N>) {
+        let timer = timer!("Process::finalize_dry_run");
+
+        // Initialize the debug trace.
+        let mut debug_trace = DebugTrace::new();
+
+        // Ensure the finalize store is not in an atomic batch, so that the dry run can be discarded.
+        if store.is_atomic_in_progress() {
+            return (Err(anyhow!("Cannot dry-run 'finalize' while an atomic batch is in progress")), debug_trace);
+        }
+
+        // Start an atomic batch, so that the finalize operations can be discarded.
+        store.start_atomic();
+        // Finalize the root transition, recording each step into the debug trace.
+        let result = self.prepare_finalize_execution(execution).and_then(|(transition, stack, call_graph)| {
+            finalize_transition(state, store, stack, transition, call_graph, Some(&mut debug_trace))
+        });
+        // Discard the finalize operations.
+        store.abort_atomic();
+        lap!(timer, "Finalize the execution");
+
+        // Record the error on the failed step, if the finalize scope failed.
+        if let Err(error) = &result {
+            debug_trace.fail_last(error);
+        }
+
+        finish!(timer);
+
+        (result, debug_trace)
+    }
+
     /// Finalizes the fee.
     /// This method assumes the given fee **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
@@ -149,6 +186,33 @@ impl<N: Network> Process<N> {
             result
         })
     }
+
+    /// Returns the root transition, its stack, and the call graph for the given execution.
+    fn prepare_finalize_execution<'a>(
+        &'a self,
+        execution: &'a Execution<N>,
+    ) -> Result<(&'a Transition<N>, &'a Arc<Stack<N>>, HashMap<N::TransitionID, Vec<N::TransitionID>>)> {
+        // Ensure the execution contains transitions.
+        ensure!(!execution.is_empty(), "There are no transitions in the execution");
+
+        // Ensure the number of transitions matches the program function.
+        // Retrieve the root transition (without popping it).
+        let transition = execution.peek()?;
+        // Retrieve the stack.
+        let stack = self.get_stack(transition.program_id())?;
+        // Ensure the number of calls matches the number of transitions.
+        let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
+        ensure!(
+            number_of_calls == execution.len(),
+            "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
+            execution.len()
+        );
+
+        // Construct the call graph.
+        let call_graph = self.construct_call_graph(execution)?;
+
+        Ok((transition, stack, call_graph))
+    }
 }
 
 /// Finalizes the given fee transition.
@@ -164,7 +228,7 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     call_graph.insert(*fee.transition_id(), Vec::new());
 
     // Finalize the transition.
-    match finalize_transition(state, store, stack, fee, call_graph) {
+    match finalize_transition(state, store, stack, fee, call_graph, None) {
         // If the evaluation succeeds, return the finalize operations.
         Ok(finalize_operations) => Ok(finalize_operations),
         // If the evaluation fails, bail and return the error.
@@ -179,6 +243,7 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     stack: &Stack<N>,
     transition: &Transition<N>,
     call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
+    mut debug_trace: Option<&mut DebugTrace<N>>,
 ) -> Result<Vec<FinalizeOperation<N>>> {
     // Retrieve the program ID.
     let program_id = transition.program_id();
@@ -224,30 +289,37 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         while counter < finalize.commands().len() {
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+            // Record the operands, if a debug trace is being recorded.
+            let position = debug_trace.as_deref_mut().map(|debug_trace| {
+                let scope = DebugScope::Finalize(*finalize.name());
+                debug_trace.start(stack, &registers, scope, counter, command.opcode(), &command.operands())
+            });
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
-                    let result = try_vm_runtime!(|| branch_to(counter, branch_eq, finalize, stack, &registers));
+                    let result =
+                        try_vm_runtime!(|| branch_to(counter, branch_eq, finalize.positions(), stack, &registers));
                     match result {
                         Ok(Ok(new_counter)) => {
                             counter = new_counter;
                         }
                         // If the evaluation fails, bail and return the error.
-                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        Ok(Err(error)) => return Err(command_error(stack, finalize, counter, command, Some(error))),
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(_) => return Err(command_error(stack, finalize, counter, command, None)),
                     }
                 }
                 Command::BranchNeq(branch_neq) => {
-                    let result = try_vm_runtime!(|| branch_to(counter, branch_neq, finalize, stack, &registers));
+                    let result =
+                        try_vm_runtime!(|| branch_to(counter, branch_neq, finalize.positions(), stack, &registers));
                     match result {
                         Ok(Ok(new_counter)) => {
                             counter = new_counter;
                         }
                         // If the evaluation fails, bail and return the error.
-                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        Ok(Err(error)) => return Err(command_error(stack, finalize, counter, command, Some(error))),
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(_) => return Err(command_error(stack, finalize, counter, command, None)),
                     }
                 }
                 Command::Await(await_) => {
@@ -278,9 +350,11 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         match try_vm_runtime!(|| setup_await(state, await_, stack, &registers, child_transition_id)) {
                             Ok(Ok(callee_state)) => callee_state,
                             // If the evaluation fails, bail and return the error.
-                            Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                            Ok(Err(error)) => {
+                                return Err(command_error(stack, finalize, counter, command, Some(error)));
+                            }
                             // If the evaluation fails, bail and return the error.
-                            Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                            Err(_) => return Err(command_error(stack, finalize, counter, command, None)),
                         };
 
                     // Increment the call counter.
@@ -308,9 +382,13 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         // If the evaluation succeeds with no operation, continue.
                         Ok(Ok(None)) => {}
                         // If the evaluation fails, bail and return the error.
-                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        Ok(Err(error)) => return Err(command_error(stack, finalize, counter, command, Some(error))),
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(_) => return Err(command_error(stack, finalize, counter, command, None)),
+                    }
+                    // Record the destinations, if a debug trace is being recorded.
+                    if let (Some(debug_trace), Some(position)) = (debug_trace.as_deref_mut(), position) {
+                        debug_trace.complete(position, stack, &registers, command.destinations(), None);
                     }
                     counter += 1;
                 }
@@ -334,6 +412,66 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     Ok(finalize_operations)
 }
 
+/// The error returned when a command in a finalize scope fails to evaluate.
+pub struct FinalizeError<N: Network> {
+    /// The reason the finalize scope failed.
+    reason: RejectionReason<N>,
+    /// The error message.
+    message: String,
+}
+
+impl<N: Network> FinalizeError<N> {
+    /// Returns the reason the finalize scope failed.
+    pub const fn reason(&self) -> &RejectionReason<N> {
+        &self.reason
+    }
+}
+
+impl<N: Network> Debug for FinalizeError<N> {
+    /// Prints the finalize error as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for FinalizeError<N> {
+    /// Prints the finalize error as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl<N: Network> std::error::Error for FinalizeError<N> {}
+
+/// Returns the error for the command at `counter` that failed to evaluate in the given finalize scope.
+fn command_error<N: Network>(
+    stack: &Stack<N>,
+    finalize: &Finalize<N>,
+    counter: usize,
+    command: &Command<N>,
+    error: Option<Error>,
+) -> Error {
+    // Construct the error message.
+    let message = match error {
+        Some(error) => format!("'finalize' failed to evaluate command ({command}): {error}"),
+        None => format!("'finalize' failed to evaluate command ({command})"),
+    };
+    // Retrieve the failure code, if the command is an `assert` with a code.
+    let code = match command {
+        Command::Instruction(Instruction::AssertWithCode(assert)) => Some(assert.code().to_string()),
+        _ => None,
+    };
+    // Construct the rejection reason.
+    let reason = u16::try_from(counter).map_err(Error::from).and_then(|command_index| {
+        RejectionReason::new(*stack.program_id(), *finalize.name(), command_index, command.opcode().to_string(), code)
+    });
+    // Return the error, with the rejection reason if it could be constructed.
+    match reason {
+        Ok(reason) => FinalizeError { reason, message }.into(),
+        Err(_) => anyhow!(message),
+    }
+}
+
 // A helper struct to track the execution of a finalize block.
 struct FinalizeState<'a, N: Network> {
     // A counter for the index of the commands.
@@ -413,10 +551,10 @@ fn setup_await<'a, N: Network>(
 
 // A helper function that returns the index to branch to.
 #[inline]
-fn branch_to<N: Network, const VARIANT: u8>(
+pub(crate) fn branch_to<N: Network, const VARIANT: u8>(
     counter: usize,
     branch: &Branch<N, VARIANT>,
-    finalize: &Finalize<N>,
+    positions: &HashMap<Identifier<N>, usize>,
     stack: &Stack<N>,
     registers: &FinalizeRegisters<N>,
 ) -> Result<usize> {
@@ -425,7 +563,7 @@ fn branch_to<N: Network, const VARIANT: u8>(
     let second = registers.load(stack, branch.second())?;
 
     // A helper to get the index corresponding to a position.
-    let get_position_index = |position: &Identifier<N>| match finalize.positions().get(position) {
+    let get_position_index = |position: &Identifier<N>| match positions.get(position) {
         Some(index) if *index > counter => Ok(*index),
         Some(_) => bail!("Cannot branch to an earlier position '{position}' in the program"),
         None => bail!("The position '{position}' does not exist."),
@@ -452,6 +590,7 @@ mod tests {
         helpers::memory::{BlockMemory, FinalizeMemory},
         BlockStore,
     };
+    use synthesizer_program::FinalizeStoreTrait;
 
     type CurrentNetwork = console::network::MainnetV0;
     type CurrentAleo = circuit::network::AleoV0;
@@ -518,4 +657,202 @@ function compute:
         // Ensure the program exists.
         assert!(process.contains_program(program.id()));
     }
+
+    #[test]
+    fn test_finalize_deployment_upgrade() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new upgradable program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+upgrade owner;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+
+        // Initialize a new process.
+        let mut process = Process::load().unwrap();
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+        // Deploy the program.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        assert_eq!(deployment.edition(), CurrentNetwork::EDITION);
+        // Finalize the deployment.
+        let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+        let (stack, _) =
+            process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+        process.add_stack(stack);
+
+        // Store an entry in the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("aleo1qr2ha4pfs5l28aze88yn6fhleeythklkczrule2v838uwj65n5gqxt9djx").unwrap();
+        let value = Value::from_str("100u64").unwrap();
+        finalize_store.insert_key_value(*program.id(), mapping_name, key.clone(), value.clone()).unwrap();
+
+        // Initialize the upgraded program, which adds a mapping and a function.
+        let upgraded_program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+upgrade owner;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+mapping totals:
+    key as u8.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u64.private;
+    mul r0 r0 into r1;
+    output r1 as u64.public;
+
+function twice:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+
+        // Deploy the upgrade.
+        let upgrade = process.deploy::<CurrentAleo, _>(&upgraded_program, rng).unwrap();
+        assert_eq!(upgrade.edition(), CurrentNetwork::EDITION + 1);
+        // Verify the upgrade.
+        process.verify_deployment::<CurrentAleo, _>(&upgrade, rng).unwrap();
+        // Ensure the initial edition cannot be redeployed.
+        assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
+
+        // Finalize the upgrade.
+        let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+        let (stack, _) =
+            process.finalize_deployment(sample_finalize_state(2), &finalize_store, &upgrade, &fee).unwrap();
+        process.add_stack(stack);
+
+        // Ensure the process contains the upgraded program.
+        let stack = process.get_stack(program.id()).unwrap();
+        assert_eq!(stack.edition(), CurrentNetwork::EDITION + 1);
+        assert_eq!(stack.program(), &upgraded_program);
+        assert!(stack.contains_verifying_key(&Identifier::from_str("twice").unwrap()));
+        // Ensure the existing mapping entry is preserved, and the new mapping is initialized.
+        assert_eq!(finalize_store.get_value_speculative(*program.id(), mapping_name, &key).unwrap(), Some(value));
+        assert!(
+            finalize_store.contains_mapping_confirmed(program.id(), &Identifier::from_str("totals").unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_upgrade_is_checked() {
+        // A helper to add a program to a process, and return the result of upgrading it to the candidate program.
+        let check_upgrade = |program: &str, candidate: &str| {
+            let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+            let candidate = Program::<CurrentNetwork>::from_str(candidate).unwrap();
+            // Add the program to the process.
+            let mut process = Process::load().unwrap();
+            process.add_program(&program).unwrap();
+            // Attempt to compute the stack for the upgrade.
+            Stack::upgrade(&process, &candidate, CurrentNetwork::EDITION + 1)
+        };
+
+        let program = r"
+program testing.aleo;
+
+upgrade owner;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u64.private;
+    output r0 as u64.public;";
+
+        // Ensure a valid upgrade succeeds.
+        assert!(check_upgrade(program, program).is_ok());
+        // Ensure an immutable program cannot be upgraded.
+        assert!(check_upgrade(&program.replace("upgrade owner;", ""), program).is_err());
+        // Ensure a mapping cannot change its type.
+        assert!(check_upgrade(program, &program.replace("value as u64.public", "value as u128.public")).is_err());
+        // Ensure a mapping cannot be removed.
+        assert!(
+            check_upgrade(
+                program,
+                &program.replace("mapping account:\n    key as address.public;\n    value as u64.public;\n", "")
+            )
+            .is_err()
+        );
+
+        // Ensure the upgrade must be to the next edition.
+        let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+        assert!(Stack::upgrade(&process, &program, CurrentNetwork::EDITION).is_err());
+        assert!(Stack::upgrade(&process, &program, CurrentNetwork::EDITION + 2).is_err());
+
+        // Add a program that imports the program.
+        let dependent = Program::<CurrentNetwork>::from_str(
+            r"
+import testing.aleo;
+
+program dependent.aleo;
+
+function compute:
+    input r0 as u64.private;
+    call testing.aleo/compute r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+        process.add_program(&dependent).unwrap();
+
+        // Ensure an imported function cannot change its signature.
+        let candidate = program.to_string().replace("output r0 as u64.public", "output r0 as u64.private");
+        let candidate = Program::<CurrentNetwork>::from_str(&candidate).unwrap();
+        assert!(Stack::upgrade(&process, &candidate, CurrentNetwork::EDITION + 1).is_err());
+        let candidate = program.to_string().replace("r0 as u64", "r0 as u32");
+        let candidate = Program::<CurrentNetwork>::from_str(&candidate).unwrap();
+        assert!(Stack::upgrade(&process, &candidate, CurrentNetwork::EDITION + 1).is_err());
+        // Ensure an imported function cannot be removed.
+        let candidate = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+upgrade owner;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function other:
+    input r0 as u64.private;
+    output r0 as u64.public;",
+        )
+        .unwrap();
+        assert!(Stack::upgrade(&process, &candidate, CurrentNetwork::EDITION + 1).is_err());
+
+        // Ensure an upgrade that preserves the signatures of an imported program succeeds.
+        let candidate =
+            program.to_string().replace("output r0 as u64.public", "add r0 r0 into r1;\n    output r1 as u64.public");
+        let candidate = Program::<CurrentNetwork>::from_str(&candidate).unwrap();
+        let stack = Stack::upgrade(&process, &candidate, CurrentNetwork::EDITION + 1).unwrap();
+        process.upgrade_stack(stack).unwrap();
+        // Ensure the dependent program is rebuilt against the upgraded program.
+        let dependent_stack = process.get_stack(dependent.id()).unwrap();
+        let external_stack = dependent_stack.get_external_stack(program.id()).unwrap();
+        assert_eq!(external_stack.edition(), CurrentNetwork::EDITION + 1);
+        assert_eq!(external_stack.program(), &candidate);
+    }
 }
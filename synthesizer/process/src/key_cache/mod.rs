@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, program::Identifier, types::Field};
+use synthesizer_snark::{ProvingKey, VerifyingKey};
+
+use sha2::{Digest, Sha256};
+use std::{
+    io::ErrorKind,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+/// The format version of the entries in the key cache.
+const KEY_CACHE_VERSION: u8 = 2;
+/// The version of the circuits, which invalidates the entries synthesized by other releases of this crate.
+const CIRCUIT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// An on-disk cache of circuit keys, keyed by the circuit version, program checksum, function name, and network ID.
+#[derive(Clone, Debug)]
+pub struct KeyCache<N: Network> {
+    /// The directory of the key cache.
+    directory: PathBuf,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> KeyCache<N> {
+    /// Opens the key cache in the given directory, creating the directory if it does not exist.
+    pub fn open<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        // Create the directory, if it does not exist.
+        let directory = directory.as_ref().to_path_buf();
+        std::fs::create_dir_all(&directory)?;
+        // Return the key cache.
+        Ok(Self { directory, _phantom: PhantomData })
+    }
+
+    /// Returns the directory of the key cache.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the cached proving and verifying key for the given program checksum and function name, if they exist.
+    ///
+    /// If the cached entry fails its integrity checks, the entry is removed and `None` is returned,
+    /// so that the keys are synthesized and stored again.
+    /// Note: A failure to read or remove an entry is not fatal, and is treated as a cache miss.
+    pub fn load_keys(
+        &self,
+        checksum: &Field<N>,
+        function_name: &Identifier<N>,
+    ) -> Option<(ProvingKey<N>, VerifyingKey<N>)> {
+        // Retrieve the path of the entry.
+        let path = self.path(checksum, function_name);
+        // Read the entry, if it exists.
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(error) if error.kind() == ErrorKind::NotFound => return None,
+            Err(error) => {
+                eprintln!("Failed to read the cached keys for '{function_name}' - {error}");
+                return None;
+            }
+        };
+        // Check the integrity of the entry, and read the keys.
+        match Self::read_entry(&bytes, checksum, function_name) {
+            Ok(keys) => Some(keys),
+            Err(_) => {
+                // Remove the invalid entry.
+                if let Err(error) = std::fs::remove_file(&path) {
+                    eprintln!("Failed to remove the invalid cached keys for '{function_name}' - {error}");
+                }
+                None
+            }
+        }
+    }
+
+    /// Stores the proving and verifying key for the given program checksum and function name.
+    pub fn store_keys(
+        &self,
+        checksum: &Field<N>,
+        function_name: &Identifier<N>,
+        proving_key: &ProvingKey<N>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        // Retrieve the path of the entry.
+        let path = self.path(checksum, function_name);
+        // Construct the entry.
+        let bytes = Self::write_entry(checksum, function_name, proving_key, verifying_key)?;
+        // Write the entry to a temporary file, and move it into place, so that a partial entry is never read.
+        let temporary_path = path.with_extension("tmp");
+        std::fs::write(&temporary_path, bytes)?;
+        std::fs::rename(&temporary_path, &path)?;
+        Ok(())
+    }
+}
+
+impl<N: Network> KeyCache<N> {
+    /// Returns the path of the entry for the given program checksum and function name.
+    fn path(&self, checksum: &Field<N>, function_name: &Identifier<N>) -> PathBuf {
+        self.directory.join(format!("{}-{CIRCUIT_VERSION}-{checksum}-{function_name}.keys", N::ID))
+    }
+
+    /// Returns the entry for the given keys, as the format version, circuit version, network ID, program checksum,
+    /// function name, and the sizes and SHA-256 digest of the keys, followed by the proving and verifying key.
+    fn write_entry(
+        checksum: &Field<N>,
+        function_name: &Identifier<N>,
+        proving_key: &ProvingKey<N>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<Vec<u8>> {
+        // Serialize the keys.
+        let proving_key_bytes = proving_key.to_bytes_le()?;
+        let verifying_key_bytes = verifying_key.to_bytes_le()?;
+
+        let mut bytes = Vec::new();
+        // Write the header.
+        KEY_CACHE_VERSION.write_le(&mut bytes)?;
+        u8::try_from(CIRCUIT_VERSION.len())?.write_le(&mut bytes)?;
+        bytes.extend_from_slice(CIRCUIT_VERSION.as_bytes());
+        N::ID.write_le(&mut bytes)?;
+        checksum.write_le(&mut bytes)?;
+        function_name.write_le(&mut bytes)?;
+        // Write the sizes and digest of the keys.
+        u64::try_from(proving_key_bytes.len())?.write_le(&mut bytes)?;
+        u64::try_from(verifying_key_bytes.len())?.write_le(&mut bytes)?;
+        let digest = Sha256::new().chain_update(&proving_key_bytes).chain_update(&verifying_key_bytes).finalize();
+        bytes.extend_from_slice(&digest);
+        // Write the keys.
+        bytes.extend_from_slice(&proving_key_bytes);
+        bytes.extend_from_slice(&verifying_key_bytes);
+        Ok(bytes)
+    }
+
+    /// Returns the keys from the given entry, if the entry matches the circuit version, program checksum,
+    /// function name, and network ID, and the keys match their sizes and digest.
+    fn read_entry(
+        bytes: &[u8],
+        checksum: &Field<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<(ProvingKey<N>, VerifyingKey<N>)> {
+        let mut reader = bytes;
+        // Read the header.
+        let version = u8::read_le(&mut reader)?;
+        ensure!(version == KEY_CACHE_VERSION, "Invalid key cache entry version ({version})");
+        let circuit_version_len = u8::read_le(&mut reader)? as usize;
+        ensure!(reader.len() >= circuit_version_len, "Key cache entry is missing the circuit version");
+        let (circuit_version, remaining) = reader.split_at(circuit_version_len);
+        ensure!(circuit_version == CIRCUIT_VERSION.as_bytes(), "Key cache entry is for a different circuit version");
+        reader = remaining;
+        let network_id = u16::read_le(&mut reader)?;
+        ensure!(network_id == N::ID, "Key cache entry is for network {network_id}, expected network {}", N::ID);
+        let candidate_checksum = Field::<N>::read_le(&mut reader)?;
+        ensure!(&candidate_checksum == checksum, "Key cache entry is for a different program checksum");
+        let candidate_function_name = Identifier::<N>::read_le(&mut reader)?;
+        ensure!(&candidate_function_name == function_name, "Key cache entry is for '{candidate_function_name}'");
+        // Read the sizes and digest of the keys.
+        let proving_key_size = usize::try_from(u64::read_le(&mut reader)?)?;
+        let verifying_key_size = usize::try_from(u64::read_le(&mut reader)?)?;
+        ensure!(reader.len() >= 32, "Key cache entry is missing the key digest");
+        let (digest, keys_bytes) = reader.split_at(32);
+        // Ensure the keys match their sizes and digest.
+        ensure!(
+            proving_key_size.checked_add(verifying_key_size) == Some(keys_bytes.len()),
+            "Key cache entry has invalid key sizes"
+        );
+        ensure!(Sha256::digest(keys_bytes).as_slice() == digest, "Key cache entry has an invalid digest");
+        // Read the keys.
+        let (proving_key_bytes, verifying_key_bytes) = keys_bytes.split_at(proving_key_size);
+        Ok((ProvingKey::read_le(proving_key_bytes)?, VerifyingKey::read_le(verifying_key_bytes)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use console::network::MainnetV0;
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_key_cache() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Open the key cache.
+        let directory = tempfile::tempdir().unwrap();
+        let key_cache = KeyCache::<CurrentNetwork>::open(directory.path()).unwrap();
+
+        // Synthesize the keys, which stores them in the key cache.
+        let mut process = Process::load_with_key_cache(Some(key_cache.clone())).unwrap();
+        process.add_program(&program).unwrap();
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+        let expected_proving_key = process.get_proving_key(program.id(), function_name).unwrap();
+        let expected_verifying_key = process.get_verifying_key(program.id(), function_name).unwrap();
+
+        // Ensure a new process loads the proving and verifying key from the key cache.
+        let mut process = Process::load_with_key_cache(Some(key_cache.clone())).unwrap();
+        process.add_program(&program).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+        assert!(stack.contains_proving_key(&function_name));
+        assert!(stack.contains_verifying_key(&function_name));
+        let candidate_proving_key = process.get_proving_key(program.id(), function_name).unwrap();
+        let candidate_verifying_key = process.get_verifying_key(program.id(), function_name).unwrap();
+        assert_eq!(expected_proving_key.to_bytes_le().unwrap(), candidate_proving_key.to_bytes_le().unwrap());
+        assert_eq!(expected_verifying_key, candidate_verifying_key);
+
+        // Ensure an entry is not loaded for a different function name.
+        let checksum = program.checksum().unwrap();
+        let path = key_cache.path(&checksum, &function_name);
+        let other_function_name = Identifier::from_str("other").unwrap();
+        let other_path = key_cache.path(&checksum, &other_function_name);
+        std::fs::copy(&path, &other_path).unwrap();
+        assert!(key_cache.load_keys(&checksum, &other_function_name).is_none());
+        assert!(!other_path.exists());
+
+        // Ensure an entry is not loaded for a different circuit version.
+        let bytes = std::fs::read(&path).unwrap();
+        let mut tampered = bytes.clone();
+        tampered[2] ^= 1;
+        std::fs::write(&path, tampered).unwrap();
+        assert!(key_cache.load_keys(&checksum, &function_name).is_none());
+        assert!(!path.exists());
+
+        // Ensure an unreadable entry, which also cannot be removed, is treated as a cache miss.
+        std::fs::create_dir(&path).unwrap();
+        assert!(key_cache.load_keys(&checksum, &function_name).is_none());
+        let mut process = Process::load_with_key_cache(Some(key_cache.clone())).unwrap();
+        process.add_program(&program).unwrap();
+        assert!(!process.get_stack(program.id()).unwrap().contains_proving_key(&function_name));
+        std::fs::remove_dir(&path).unwrap();
+
+        // Ensure a corrupted entry is removed, and not loaded.
+        let mut tampered = bytes;
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        std::fs::write(&path, tampered).unwrap();
+        let mut process = Process::load_with_key_cache(Some(key_cache)).unwrap();
+        process.add_program(&program).unwrap();
+        assert!(!process.get_stack(program.id()).unwrap().contains_proving_key(&function_name));
+        assert!(!path.exists());
+
+        // Ensure the keys are still synthesized if they cannot be stored in the key cache.
+        directory.close().unwrap();
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+        assert!(stack.contains_proving_key(&function_name));
+        assert!(stack.contains_verifying_key(&function_name));
+    }
+}
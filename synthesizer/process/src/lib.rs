@@ -21,6 +21,15 @@
 mod cost;
 pub use cost::*;
 
+mod finalize;
+pub use finalize::*;
+
+mod key_cache;
+pub use key_cache::*;
+
+mod proving_request;
+pub use proving_request::*;
+
 mod stack;
 pub use stack::*;
 
@@ -34,10 +43,11 @@ mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
-mod finalize;
 mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
+mod verify_proving_request;
+mod view;
 
 #[cfg(test)]
 mod tests;
@@ -66,7 +76,7 @@ use synthesizer_program::{
 use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::{collections::HashMap, sync::Arc};
 
@@ -79,6 +89,8 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The on-disk cache of circuit keys, if enabled.
+    key_cache: Option<KeyCache<N>>,
 }
 
 impl<N: Network> Process<N> {
@@ -88,7 +100,8 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process =
+            Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new(), key_cache: None };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -134,16 +147,53 @@ impl<N: Network> Process<N> {
         // Add the stack to the process.
         self.stacks.insert(*stack.program_id(), Arc::new(stack));
     }
+
+    /// Replaces the stack of an existing program with the given upgraded stack,
+    /// and rebuilds the stacks of the programs that import it, directly or transitively.
+    /// If a rebuilt stack fails to type-check, the process is left unchanged.
+    #[inline]
+    pub fn upgrade_stack(&mut self, stack: Stack<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
+        // Ensure the program exists in the process.
+        ensure!(self.contains_program(&program_id), "Cannot upgrade program '{program_id}', as it does not exist");
+
+        // Prepare the upgraded process.
+        let mut process = self.clone();
+        process.add_stack(stack);
+        // Rebuild the stacks that import an upgraded or rebuilt stack.
+        // Note: The stacks are ordered such that every import precedes the programs that import it.
+        let mut upgraded = IndexSet::from([program_id]);
+        for (dependent_id, dependent) in self.stacks.iter() {
+            if dependent.program().imports().keys().any(|import| upgraded.contains(import)) {
+                let rebuilt = Stack::rebuild(&process, dependent).map_err(|e| {
+                    anyhow!("Program '{dependent_id}' is incompatible with the upgrade of '{program_id}' - {e}")
+                })?;
+                process.add_stack(rebuilt);
+                upgraded.insert(*dependent_id);
+            }
+        }
+
+        // Replace the process with the upgraded process.
+        *self = process;
+        Ok(())
+    }
 }
 
 impl<N: Network> Process<N> {
     /// Initializes a new process.
     #[inline]
     pub fn load() -> Result<Self> {
+        Self::load_with_key_cache(None)
+    }
+
+    /// Initializes a new process, which loads and stores the circuit keys of its programs in the given key cache.
+    #[inline]
+    pub fn load_with_key_cache(key_cache: Option<KeyCache<N>>) -> Result<Self> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new(), key_cache };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -181,7 +231,8 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process =
+            Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new(), key_cache: None };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -202,12 +253,28 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the on-disk cache of circuit keys, if enabled.
+    #[inline]
+    pub const fn key_cache(&self) -> Option<&KeyCache<N>> {
+        self.key_cache.as_ref()
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
         self.stacks.contains_key(program_id)
     }
 
+    /// Returns the program IDs of the programs that import the given program ID.
+    #[inline]
+    pub fn get_dependents(&self, program_id: &ProgramID<N>) -> Vec<ProgramID<N>> {
+        self.stacks
+            .iter()
+            .filter(|(_, stack)| stack.program().contains_import(program_id))
+            .map(|(dependent_id, _)| *dependent_id)
+            .collect()
+    }
+
     /// Returns the stack for the given program ID.
     #[inline]
     pub fn get_stack(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Arc<Stack<N>>> {
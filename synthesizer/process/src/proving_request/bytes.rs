@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ProvingRequest<N> {
+    /// Reads the proving request from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid proving request version"));
+        }
+
+        // Read the authorization.
+        let authorization = Authorization::read_le(&mut reader)?;
+        // Read the fee authorization.
+        let fee_authorization = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(Authorization::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid fee authorization variant '{variant}'"))),
+        };
+        // Read the state root.
+        let state_root = N::StateRoot::read_le(&mut reader)?;
+        // Read the number of state paths.
+        let num_state_paths = u16::read_le(&mut reader)?;
+        // Read the state paths.
+        let state_paths =
+            (0..num_state_paths).map(|_| StatePath::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the number of programs.
+        let num_programs = u16::read_le(&mut reader)?;
+        // Read the programs.
+        let programs = (0..num_programs).map(|_| Program::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Return the new proving request.
+        Self::new(authorization, fee_authorization, state_root, state_paths, programs).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for ProvingRequest<N> {
+    /// Writes the proving request to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the authorization.
+        self.authorization.write_le(&mut writer)?;
+        // Write the fee authorization.
+        match &self.fee_authorization {
+            None => 0u8.write_le(&mut writer)?,
+            Some(fee_authorization) => {
+                1u8.write_le(&mut writer)?;
+                fee_authorization.write_le(&mut writer)?;
+            }
+        }
+        // Write the state root.
+        self.state_root.write_le(&mut writer)?;
+        // Write the number of state paths.
+        u16::try_from(self.state_paths.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the state paths.
+        self.state_paths.iter().try_for_each(|state_path| state_path.write_le(&mut writer))?;
+        // Write the number of programs.
+        u16::try_from(self.programs.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the programs.
+        self.programs.iter().try_for_each(|program| program.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new proving request.
+        let expected = crate::proving_request::test_helpers::sample_proving_request(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, ProvingRequest::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::Authorization;
+use console::{
+    network::prelude::*,
+    program::{Literal, Plaintext, ProgramID, StatePath, Value},
+};
+use ledger_query::StaticQuery;
+use synthesizer_program::Program;
+
+/// A bundle of the authorizations, state paths, and programs required to prove an execution.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProvingRequest<N: Network> {
+    /// The authorization for the execution.
+    authorization: Authorization<N>,
+    /// The authorization for the fee, if one is required.
+    fee_authorization: Option<Authorization<N>>,
+    /// The global state root of the state paths.
+    state_root: N::StateRoot,
+    /// The state paths for the records consumed by the authorizations.
+    state_paths: Vec<StatePath<N>>,
+    /// The programs called by the execution, excluding `credits.aleo`.
+    programs: Vec<Program<N>>,
+}
+
+impl<N: Network> ProvingRequest<N> {
+    /// Initializes a new proving request.
+    pub fn new(
+        authorization: Authorization<N>,
+        fee_authorization: Option<Authorization<N>>,
+        state_root: N::StateRoot,
+        state_paths: Vec<StatePath<N>>,
+        programs: Vec<Program<N>>,
+    ) -> Result<Self> {
+        // Ensure the authorization is not empty.
+        ensure!(!authorization.is_empty(), "The proving request must contain an authorization");
+        // Ensure the authorization is not for a fee.
+        ensure!(
+            !(authorization.is_fee_private() || authorization.is_fee_public()),
+            "The proving request must contain an execution authorization, found a fee authorization"
+        );
+        // Ensure the fee authorization is for a fee, and for the execution.
+        if let Some(fee_authorization) = &fee_authorization {
+            ensure!(
+                fee_authorization.is_fee_private() || fee_authorization.is_fee_public(),
+                "The proving request must contain a fee authorization for 'fee_private' or 'fee_public'"
+            );
+            // Retrieve the execution ID, which is the last input of the fee request.
+            let fee_request = fee_authorization.peek_next()?;
+            let execution_id = Value::Plaintext(Plaintext::from(Literal::Field(authorization.to_execution_id()?)));
+            ensure!(
+                fee_request.inputs().last() == Some(&execution_id),
+                "The fee authorization in the proving request is not for the execution"
+            );
+        }
+        // Ensure the state paths are for the state root.
+        for state_path in &state_paths {
+            ensure!(
+                state_path.global_state_root() == state_root,
+                "The state paths in the proving request must be for the state root '{state_root}'"
+            );
+        }
+        // Ensure the programs are unique.
+        ensure!(
+            !has_duplicates(programs.iter().map(|program| program.id())),
+            "The proving request contains duplicate programs"
+        );
+        // Return the proving request.
+        Ok(Self { authorization, fee_authorization, state_root, state_paths, programs })
+    }
+
+    /// Returns the authorization for the execution.
+    pub const fn authorization(&self) -> &Authorization<N> {
+        &self.authorization
+    }
+
+    /// Returns the authorization for the fee, if one is required.
+    pub const fn fee_authorization(&self) -> Option<&Authorization<N>> {
+        self.fee_authorization.as_ref()
+    }
+
+    /// Returns the global state root of the state paths.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the state paths for the records consumed by the authorizations.
+    pub fn state_paths(&self) -> &[StatePath<N>] {
+        &self.state_paths
+    }
+
+    /// Returns the programs called by the execution.
+    pub fn programs(&self) -> &[Program<N>] {
+        &self.programs
+    }
+
+    /// Returns the program for the given program ID, if it is in the proving request.
+    pub fn get_program(&self, program_id: &ProgramID<N>) -> Option<&Program<N>> {
+        self.programs.iter().find(|program| program.id() == program_id)
+    }
+
+    /// Returns the query for the state root and state paths in the proving request.
+    pub fn to_query(&self) -> Result<StaticQuery<N>> {
+        StaticQuery::new(self.state_root, self.state_paths.iter().cloned())
+    }
+
+    /// Returns the authorizations for the execution and the fee.
+    pub fn into_authorizations(self) -> (Authorization<N>, Option<Authorization<N>>) {
+        (self.authorization, self.fee_authorization)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::Process;
+    use console::account::{Address, PrivateKey};
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a sample proving request.
+    pub fn sample_proving_request(rng: &mut TestRng) -> ProvingRequest<CurrentNetwork> {
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Compute the authorization.
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, "credits.aleo", "transfer_public", inputs.iter(), rng)
+            .unwrap();
+        // Compute the fee authorization.
+        let execution_id = authorization.to_execution_id().unwrap();
+        let fee_authorization =
+            process.authorize_fee_public::<CurrentAleo, _>(&private_key, 1_000_000, 0, execution_id, rng).unwrap();
+
+        // Sample a program.
+        let program = Program::from_str(
+            r"
+program proving_request.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+
+        ProvingRequest::new(authorization, Some(fee_authorization), Uniform::rand(rng), vec![], vec![program]).unwrap()
+    }
+}
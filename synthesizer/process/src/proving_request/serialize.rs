@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for ProvingRequest<N> {
+    /// Serializes the proving request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut proving_request = serializer.serialize_struct("ProvingRequest", 5)?;
+                proving_request.serialize_field("authorization", &self.authorization)?;
+                proving_request.serialize_field("fee_authorization", &self.fee_authorization)?;
+                proving_request.serialize_field("state_root", &self.state_root)?;
+                proving_request.serialize_field("state_paths", &self.state_paths)?;
+                proving_request.serialize_field("programs", &self.programs)?;
+                proving_request.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProvingRequest<N> {
+    /// Deserializes the proving request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the proving request from a string into a value.
+                let mut proving_request = serde_json::Value::deserialize(deserializer)?;
+                // Recover the proving request.
+                Self::new(
+                    // Retrieve the authorization.
+                    DeserializeExt::take_from_value::<D>(&mut proving_request, "authorization")?,
+                    // Retrieve the fee authorization.
+                    DeserializeExt::take_from_value::<D>(&mut proving_request, "fee_authorization")?,
+                    // Retrieve the state root.
+                    DeserializeExt::take_from_value::<D>(&mut proving_request, "state_root")?,
+                    // Retrieve the state paths.
+                    DeserializeExt::take_from_value::<D>(&mut proving_request, "state_paths")?,
+                    // Retrieve the programs.
+                    DeserializeExt::take_from_value::<D>(&mut proving_request, "programs")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proving request"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the proving request.
+        let expected = crate::proving_request::test_helpers::sample_proving_request(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, ProvingRequest::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the proving request.
+        let expected = crate::proving_request::test_helpers::sample_proving_request(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, ProvingRequest::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
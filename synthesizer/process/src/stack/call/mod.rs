@@ -12,7 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{stack::Address, CallStack, Registers, RegistersCall, StackEvaluate, StackExecute};
+use crate::{
+    stack::Address,
+    Authorization,
+    CallStack,
+    DeferredCall,
+    Registers,
+    RegistersCall,
+    StackEvaluate,
+    StackExecute,
+};
 use aleo_std::prelude::{finish, lap, timer};
 use console::{
     account::Field,
@@ -349,8 +358,8 @@ impl<N: Network> CallTrait<N> for Call<N> {
                     CallStack::Evaluate(..) => {
                         bail!("Cannot 'execute' a function in 'evaluate' mode.")
                     }
-                    // If the circuit is in execute mode, then evaluate and execute the instructions.
-                    CallStack::Execute(authorization, ..) => {
+                    // If the circuit is in execute mode, then evaluate the instructions, and defer their execution.
+                    CallStack::Execute(authorization, trace) => {
                         // Retrieve the next request (without popping it).
                         let request = authorization.peek_next()?;
                         // Ensure the inputs match the original inputs.
@@ -359,20 +368,35 @@ impl<N: Network> CallTrait<N> for Call<N> {
                             Ok(())
                         })?;
 
-                        // Evaluate the function, and load the outputs.
+                        // Evaluate the function on a replica of the authorization, and load the outputs.
+                        let replica = authorization.replicate();
                         let console_response =
-                            substack.evaluate_function::<A>(registers.call_stack().replicate(), console_caller)?;
-                        // Execute the request.
-                        let response =
-                            substack.execute_function::<A, R>(registers.call_stack(), console_caller, root_tvk, rng)?;
-                        // Ensure the values are equal.
-                        if console_response.outputs() != response.outputs() {
-                            #[cfg(debug_assertions)]
-                            eprintln!("\n{:#?} != {:#?}\n", console_response.outputs(), response.outputs());
-                            bail!("Function '{}' outputs do not match in a 'call' instruction.", function.name())
+                            substack.evaluate_function::<A>(CallStack::evaluate(replica.clone())?, console_caller)?;
+
+                        // Split off the requests for this call (including its nested calls) from the authorization.
+                        let num_requests = authorization.len().saturating_sub(replica.len());
+                        let call_authorization = Authorization::new(authorization.next()?);
+                        for _ in 1..num_requests {
+                            call_authorization.push(authorization.next()?);
                         }
+
+                        // Defer the execution of the request, so that it is synthesized alongside its sibling calls.
+                        //
+                        // Note: The caller is synthesized with the *evaluated* outputs of the callee.
+                        // The consistency between the caller and the callee relies on the comparison of the
+                        // executed outputs against these evaluated outputs in `Stack::execute_deferred_calls`.
+                        // That comparison runs after the assignment of the caller is ejected, but before the
+                        // transition of the caller is inserted, so a mismatch aborts the execution without
+                        // adding the transition of the caller to the trace.
+                        trace.write().defer_call(DeferredCall {
+                            authorization: call_authorization,
+                            console_caller,
+                            root_tvk,
+                            outputs: console_response.outputs().to_vec(),
+                        })?;
+
                         // Return the request and response.
-                        (request, response)
+                        (request, console_response)
                     }
                 }
             };
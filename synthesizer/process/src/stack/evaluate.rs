@@ -36,6 +36,9 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
         }
 
+        // Retrieve the debug trace, if one is being recorded.
+        let debug_trace = call_stack.debug_trace();
+
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(closure.name())?.clone());
         // Set the transition signer.
@@ -54,9 +57,21 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in self.get_instructions(closure.name())?.iter().enumerate() {
+            // Record the operands, if a debug trace is being recorded.
+            let position = debug_trace.as_ref().map(|debug_trace| {
+                let scope = DebugScope::Closure(*closure.name());
+                debug_trace.write().start(self, &registers, scope, index, instruction.opcode(), instruction.operands())
+            });
+            // Evaluate the instruction.
+            let result = instruction.evaluate(self, &mut registers);
+            // Record the destinations, if a debug trace is being recorded.
+            if let (Some(debug_trace), Some(position)) = (&debug_trace, position) {
+                let destinations = instruction.destinations();
+                debug_trace.write().complete(position, self, &registers, destinations, result.as_ref().err());
+            }
             // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
+            if let Err(error) = result {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
         }
@@ -80,10 +95,24 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Signer => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.signer()?)))),
                     // If the operand is the caller, retrieve the caller from the registers.
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
+                    // If the operand is the program address, convert the program ID of this stack into an address.
+                    Operand::SelfAddress => {
+                        Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.program_id().to_address()?))))
+                    }
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
                     // If the operand is the network id, throw an error.
                     Operand::NetworkID => bail!("Cannot retrieve the network ID from a closure scope."),
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => bail!("Cannot retrieve the block timestamp from a closure scope."),
+                    // If the operand is the previous block hash, throw an error.
+                    Operand::PreviousBlockHash => {
+                        bail!("Cannot retrieve the previous block hash from a closure scope.")
+                    }
+                    // If the operand is a constant, retrieve the value of the constant.
+                    Operand::Constant(name) => {
+                        Ok(Value::Plaintext(Plaintext::from(self.program().get_constant(name)?.literal())))
+                    }
                 }
             })
             .collect();
@@ -107,7 +136,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
-            CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
+            CallStack::Evaluate(authorization, ..) => (authorization.next()?, call_stack),
             // If the evaluation is performed in the `Execute` mode, create a new `Evaluate` mode.
             // This is done to ensure that evaluation during execution is performed consistently.
             CallStack::Execute(authorization, _) => {
@@ -115,7 +144,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                 // This way, the authorization remains unmodified in this 'evaluate' scope.
                 let authorization = authorization.replicate();
                 let request = authorization.next()?;
-                let call_stack = CallStack::Evaluate(authorization);
+                let call_stack = CallStack::Evaluate(authorization, None);
                 (request, call_stack)
             }
             _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` in `evaluate_function`."),
@@ -154,6 +183,9 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         }
         lap!(timer, "Perform input checks");
 
+        // Retrieve the debug trace, if one is being recorded.
+        let debug_trace = call_stack.debug_trace();
+
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(function.name())?.clone());
         // Set the transition signer.
@@ -177,7 +209,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Evaluate the instructions.
         // Note: We handle the `call` instruction separately, as it requires special handling.
-        for instruction in function.instructions() {
+        for (index, instruction) in self.get_instructions(function.name())?.iter().enumerate() {
+            // Record the operands, if a debug trace is being recorded.
+            let position = debug_trace.as_ref().map(|debug_trace| {
+                let scope = DebugScope::Function(*function.name());
+                debug_trace.write().start(self, &registers, scope, index, instruction.opcode(), instruction.operands())
+            });
             // Evaluate the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
@@ -185,6 +222,11 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                 // Otherwise, evaluate the instruction normally.
                 _ => instruction.evaluate(self, &mut registers),
             };
+            // Record the destinations, if a debug trace is being recorded.
+            if let (Some(debug_trace), Some(position)) = (&debug_trace, position) {
+                let destinations = instruction.destinations();
+                debug_trace.write().complete(position, self, &registers, destinations, result.as_ref().err());
+            }
             // If the evaluation fails, bail and return the error.
             if let Err(error) = result {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
@@ -213,10 +255,24 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Signer => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.signer()?)))),
                     // If the operand is the caller, retrieve the caller from the registers.
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
+                    // If the operand is the program address, convert the program ID of this stack into an address.
+                    Operand::SelfAddress => {
+                        Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.program_id().to_address()?))))
+                    }
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
                     // If the operand is the network id, throw an error.
                     Operand::NetworkID => bail!("Cannot retrieve the network ID from a function scope."),
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => bail!("Cannot retrieve the block timestamp from a function scope."),
+                    // If the operand is the previous block hash, throw an error.
+                    Operand::PreviousBlockHash => {
+                        bail!("Cannot retrieve the previous block hash from a function scope.")
+                    }
+                    // If the operand is a constant, retrieve the value of the constant.
+                    Operand::Constant(name) => {
+                        Ok(Value::Plaintext(Plaintext::from(self.program().get_constant(name)?.literal())))
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -14,6 +14,8 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+
 impl<N: Network> StackExecute<N> for Stack<N> {
     /// Executes a program closure on the given inputs.
     ///
@@ -67,7 +69,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Execute the instructions.
-        for instruction in closure.instructions() {
+        for instruction in self.get_instructions(closure.name())? {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
@@ -111,6 +113,12 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::Caller => Ok(circuit::Value::Plaintext(circuit::Plaintext::from(
                         circuit::Literal::Address(registers.caller_circuit()?),
                     ))),
+                    // If the operand is the program address, convert the program ID of this stack into an address.
+                    Operand::SelfAddress => {
+                        Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::Address(
+                            circuit::Address::new(circuit::Mode::Constant, self.program_id().to_address()?),
+                        ))))
+                    }
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a closure scope")
@@ -119,6 +127,21 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::NetworkID => {
                         bail!("Illegal operation: cannot retrieve the network id in a closure scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a closure scope")
+                    }
+                    // If the operand is the previous block hash, throw an error.
+                    Operand::PreviousBlockHash => {
+                        bail!("Illegal operation: cannot retrieve the previous block hash in a closure scope")
+                    }
+                    // If the operand is a constant, inject the value of the constant as a constant.
+                    Operand::Constant(name) => {
+                        Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::new(
+                            circuit::Mode::Constant,
+                            self.program().get_constant(name)?.literal().clone(),
+                        ))))
+                    }
                 }
             })
             .collect();
@@ -280,9 +303,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
+        // Initialize the list of instruction metrics.
+        let mut instruction_metrics = Vec::new();
+        // Determine if the function calls are executed as their own transitions, and thus record their own metrics.
+        let records_callee_metrics =
+            matches!(registers.call_stack(), CallStack::Execute(..) | CallStack::PackageRun(..));
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in self.get_instructions(function.name())?.iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
@@ -298,12 +326,33 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 }
             }
 
-            // Execute the instruction.
-            let result = match instruction {
-                // If the instruction is a `call` instruction, we need to handle it separately.
-                Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
-                // Otherwise, execute the instruction normally.
-                _ => instruction.execute(self, &mut registers),
+            // Determine if the instruction is a function call.
+            let is_function_call = match instruction {
+                Instruction::Call(call) => call.is_function_call(self)?,
+                _ => false,
+            };
+
+            // Execute the instruction, and count the constraints and variables it synthesizes.
+            let (result, num_constraints, num_variables) = match instruction {
+                // A function call swaps out the circuit to synthesize the callee, so it is counted outside of a scope.
+                Instruction::Call(call) if is_function_call => {
+                    let (num_constraints, num_variables) = (A::num_constraints(), A::num_variables());
+                    let result = CallTrait::execute(call, self, &mut registers, rng);
+                    let num_constraints = A::num_constraints().saturating_sub(num_constraints);
+                    let num_variables = A::num_variables().saturating_sub(num_variables);
+                    (result, num_constraints, num_variables)
+                }
+                // Otherwise, execute the instruction in its own scope.
+                _ => A::scope(index.to_string(), || {
+                    let result = match instruction {
+                        // If the instruction is a `call` instruction, we need to handle it separately.
+                        Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
+                        // Otherwise, execute the instruction normally.
+                        _ => instruction.execute(self, &mut registers),
+                    };
+                    let (num_constants, num_public, num_private, num_constraints, _) = A::count_in_scope();
+                    (result, num_constraints, num_constants + num_public + num_private)
+                }),
             };
             // If the execution fails, bail and return the error.
             if let Err(error) = result {
@@ -311,12 +360,19 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             }
 
             // If the instruction was a function call, then set the tracker to `true`.
-            if let Instruction::Call(call) = instruction {
-                // Check if the call is a function call.
-                if call.is_function_call(self)? {
-                    contains_function_call = true;
-                }
-            }
+            contains_function_call |= is_function_call;
+
+            // Record the instruction metrics.
+            instruction_metrics.push(InstructionMetrics {
+                index,
+                opcode: match instruction {
+                    Instruction::Call(call) => format!("call {}", call.operator()),
+                    _ => instruction.opcode().to_string(),
+                },
+                num_constraints,
+                num_variables,
+                has_callee_metrics: is_function_call && records_callee_metrics,
+            });
         }
         lap!(timer, "Execute the instructions");
 
@@ -346,6 +402,12 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::Caller => Ok(circuit::Value::Plaintext(circuit::Plaintext::from(
                         circuit::Literal::Address(registers.caller_circuit()?),
                     ))),
+                    // If the operand is the program address, convert the program ID of this stack into an address.
+                    Operand::SelfAddress => {
+                        Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::Address(
+                            circuit::Address::new(circuit::Mode::Constant, self.program_id().to_address()?),
+                        ))))
+                    }
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a function scope")
@@ -354,6 +416,21 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::NetworkID => {
                         bail!("Illegal operation: cannot retrieve the network id in a function scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a function scope")
+                    }
+                    // If the operand is the previous block hash, throw an error.
+                    Operand::PreviousBlockHash => {
+                        bail!("Illegal operation: cannot retrieve the previous block hash in a function scope")
+                    }
+                    // If the operand is a constant, inject the value of the constant as a constant.
+                    Operand::Constant(name) => {
+                        Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::new(
+                            circuit::Mode::Constant,
+                            self.program().get_constant(name)?.literal().clone(),
+                        ))))
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -457,6 +534,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                instructions: instruction_metrics,
             };
             // Add the assignment to the assignments.
             assignments.write().push((assignment, metrics));
@@ -479,8 +557,15 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                instructions: instruction_metrics,
             };
 
+            // Synthesize the deferred calls of this function, and add their transitions to the trace.
+            // Note: This ensures the transitions of the callees precede the transition of the caller.
+            let deferred_calls = trace.write().take_deferred_calls();
+            self.execute_deferred_calls::<A, R>(deferred_calls, trace, rng)?;
+            lap!(timer, "Execute the deferred calls");
+
             // Add the transition to the trace.
             trace.write().insert_transition(
                 console_request.input_ids(),
@@ -499,6 +584,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                instructions: instruction_metrics,
             };
             // Add the assignment to the assignments.
             assignments.write().push((assignment, metrics));
@@ -513,6 +599,72 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Synthesizes the given deferred calls concurrently, and adds their transitions to the trace, in call order.
+    fn execute_deferred_calls<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        deferred_calls: Vec<DeferredCall<N>>,
+        trace: &Arc<RwLock<Trace<N>>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // If there are no deferred calls, return early.
+        if deferred_calls.is_empty() {
+            return Ok(());
+        }
+
+        // Initialize an RNG for each deferred call.
+        let rngs = (0..deferred_calls.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        // Synthesize each deferred call (and its nested calls) into its own trace.
+        let traces = cfg_into_iter!(deferred_calls)
+            .zip_eq(rngs)
+            .map(|(deferred_call, mut rng)| {
+                // Retrieve the request, and the substack.
+                let request = deferred_call.authorization.peek_next()?;
+                let substack = self.get_external_stack(request.program_id())?;
+
+                // Initialize the trace, and the call stack.
+                let call_trace = Arc::new(RwLock::new(Trace::new()));
+                let call_stack = CallStack::execute(deferred_call.authorization, call_trace.clone())?;
+
+                // Eject the existing circuit, as this thread may be in the midst of synthesizing another circuit.
+                let r1cs = A::eject_r1cs_and_reset();
+                // Execute the request.
+                let response = substack.execute_function::<A, _>(
+                    call_stack,
+                    deferred_call.console_caller,
+                    deferred_call.root_tvk,
+                    &mut rng,
+                );
+                // Reset the circuit, as a failed request leaves its partially-synthesized circuit behind.
+                A::reset();
+                // Inject the existing circuit.
+                A::inject_r1cs(r1cs);
+                let response = response?;
+
+                // Ensure the values are equal.
+                if deferred_call.outputs != response.outputs() {
+                    #[cfg(debug_assertions)]
+                    eprintln!("\n{:#?} != {:#?}\n", deferred_call.outputs, response.outputs());
+                    bail!("Function '{}' outputs do not match in a 'call' instruction.", request.function_name())
+                }
+
+                // Extract the trace.
+                match Arc::try_unwrap(call_trace) {
+                    Ok(call_trace) => Ok(call_trace.into_inner()),
+                    Err(_) => {
+                        bail!("Failed to extract the trace for '{}/{}'", request.program_id(), request.function_name())
+                    }
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Add the transitions to the trace, in call order.
+        let mut trace = trace.write();
+        for call_trace in traces {
+            trace.append(call_trace)?;
+        }
+        Ok(())
+    }
+
     /// Prints the current state of the circuit.
     #[cfg(debug_assertions)]
     pub(crate) fn log_circuit<A: circuit::Aleo<Network = N>, S: Into<String>>(scope: S) {
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::*;
-use console::program::FinalizeType;
+use console::program::{Access, FinalizeType};
 
 impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
     /// Loads the value of a given operand from the registers.
@@ -37,6 +37,10 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::Signer => bail!("Forbidden operation: Cannot use 'self.signer' in 'finalize'"),
             // If the operand is the caller, throw an error.
             Operand::Caller => bail!("Forbidden operation: Cannot use 'self.caller' in 'finalize'"),
+            // If the operand is the program address, load the address of the current program.
+            Operand::SelfAddress => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Address(stack.program_id().to_address()?))));
+            }
             // If the operand is the block height, load the block height.
             Operand::BlockHeight => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
@@ -45,6 +49,19 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::NetworkID => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U16(U16::new(N::ID)))));
             }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
+            // If the operand is the previous block hash, load the previous block hash.
+            Operand::PreviousBlockHash => {
+                let previous_block_hash = Field::from_bytes_le(self.state.previous_block_hash())?;
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Field(previous_block_hash))));
+            }
+            // If the operand is a constant, load the value of the constant.
+            Operand::Constant(name) => {
+                return Ok(Value::Plaintext(Plaintext::from(stack.program().get_constant(name)?.literal())));
+            }
         };
 
         // Retrieve the value.
@@ -55,7 +72,24 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             // If the register is a locator, then return the plaintext value.
             Register::Locator(..) => value.clone(),
             // If the register is a register access, then load the specific plaintext value.
-            Register::Access(_, ref path) => value.find(path)?,
+            Register::Access(_, ref path) => {
+                // Resolve any dynamic accesses into indices, using the values in the registers.
+                let path = path
+                    .iter()
+                    .map(|access| match access {
+                        Access::Dynamic(locator) => match self.registers.get(locator) {
+                            Some(Value::Plaintext(Plaintext::Literal(Literal::U32(index), _))) => {
+                                Ok(Access::Index(*index))
+                            }
+                            Some(value) => bail!("Index 'r{locator}' in '{register}' must be a 'u32', found '{value}'"),
+                            None => bail!("Index 'r{locator}' in '{register}' does not exist"),
+                        },
+                        access => Ok(*access),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                // Note: This halts if a (dynamic) index is out of bounds.
+                value.find(&path)?
+            }
         };
 
         // Retrieve the type of the register.
@@ -51,7 +51,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Step 2. Check the commands are well-formed. Make sure all the input futures are awaited.
         for command in finalize.commands() {
             // Check the command opcode, operands, and destinations.
-            finalize_types.check_command(stack, finalize, command)?;
+            finalize_types.check_command(stack, finalize.name(), finalize.positions(), command)?;
 
             // If the command is an `await`, add the future to the set of consumed futures.
             if let Command::Await(await_) = command {
@@ -75,6 +75,65 @@ impl<N: Network> FinalizeTypes<N> {
 
         Ok(finalize_types)
     }
+
+    /// Initializes a new instance of `FinalizeTypes` for the given view.
+    /// Checks that the given view is well-formed for the given stack.
+    #[inline]
+    pub(super) fn initialize_view_types(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        view: &View<N>,
+    ) -> Result<Self> {
+        // Initialize a map of registers to their types.
+        let mut view_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+
+        // Step 1. Check the inputs are well-formed.
+        for input in view.inputs() {
+            // Check the input register type.
+            view_types.check_input(
+                stack,
+                input.register(),
+                &FinalizeType::Plaintext(input.plaintext_type().clone()),
+            )?;
+        }
+
+        // Step 2. Check the commands are well-formed.
+        for command in view.commands() {
+            // Ensure the command only reads from the program state.
+            match command {
+                Command::Await(..) => bail!("Forbidden operation: View '{}' cannot invoke an 'await'", view.name()),
+                Command::RandChaCha(..) => bail!("Forbidden operation: View '{}' cannot invoke a 'rand'", view.name()),
+                Command::Set(..) | Command::Remove(..) | Command::Emit(..) => {
+                    bail!("Forbidden operation: View '{}' cannot invoke a 'set', 'remove', or 'emit'", view.name())
+                }
+                _ => (),
+            }
+            // Check the command opcode, operands, and destinations.
+            view_types.check_command(stack, view.name(), view.positions(), command)?;
+        }
+
+        // Step 3. Check the outputs are well-formed.
+        for output in view.outputs() {
+            // Retrieve the output plaintext type.
+            let plaintext_type = output.plaintext_type();
+            // Ensure the output type is defined in the program.
+            match plaintext_type {
+                PlaintextType::Literal(..) => (),
+                PlaintextType::Struct(struct_name) => RegisterTypes::check_struct(stack, struct_name)?,
+                PlaintextType::Array(array_type) => RegisterTypes::check_array(stack, array_type)?,
+                PlaintextType::Option(option_type) => RegisterTypes::check_option(stack, option_type)?,
+            };
+            // Ensure the operand type and the output type match.
+            let operand_type = view_types.get_type_from_operand(stack, output.operand())?;
+            ensure!(
+                operand_type == FinalizeType::Plaintext(plaintext_type.clone()),
+                "Output '{}' in view '{}' does not match the expected output type '{plaintext_type}'.",
+                output.operand(),
+                view.name()
+            );
+        }
+
+        Ok(view_types)
+    }
 }
 
 impl<N: Network> FinalizeTypes<N> {
@@ -143,6 +202,9 @@ impl<N: Network> FinalizeTypes<N> {
                 RegisterTypes::check_struct(stack, struct_name)?
             }
             FinalizeType::Plaintext(PlaintextType::Array(array_type)) => RegisterTypes::check_array(stack, array_type)?,
+            FinalizeType::Plaintext(PlaintextType::Option(option_type)) => {
+                RegisterTypes::check_option(stack, option_type)?
+            }
             FinalizeType::Future(..) => (),
         };
 
@@ -162,20 +224,24 @@ impl<N: Network> FinalizeTypes<N> {
     fn check_command(
         &mut self,
         stack: &(impl StackMatches<N> + StackProgram<N>),
-        finalize: &Finalize<N>,
+        name: &Identifier<N>,
+        positions: &HashMap<Identifier<N>, usize>,
         command: &Command<N>,
     ) -> Result<()> {
         match command {
-            Command::Instruction(instruction) => self.check_instruction(stack, finalize.name(), instruction)?,
+            Command::Instruction(instruction) => self.check_instruction(stack, name, instruction)?,
             Command::Await(await_) => self.check_await(stack, await_)?,
             Command::Contains(contains) => self.check_contains(stack, contains)?,
+            Command::Emit(emit) => self.check_emit(stack, emit)?,
             Command::Get(get) => self.check_get(stack, get)?,
             Command::GetOrUse(get_or_use) => self.check_get_or_use(stack, get_or_use)?,
-            Command::RandChaCha(rand_chacha) => self.check_rand_chacha(stack, finalize.name(), rand_chacha)?,
-            Command::Remove(remove) => self.check_remove(stack, finalize.name(), remove)?,
-            Command::Set(set) => self.check_set(stack, finalize.name(), set)?,
-            Command::BranchEq(branch_eq) => self.check_branch(stack, finalize, branch_eq)?,
-            Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, branch_neq)?,
+            Command::MappingLen(mapping_len) => self.check_mapping_len(stack, mapping_len)?,
+            Command::MappingKeys(mapping_keys) => self.check_mapping_keys(stack, mapping_keys)?,
+            Command::RandChaCha(rand_chacha) => self.check_rand_chacha(stack, name, rand_chacha)?,
+            Command::Remove(remove) => self.check_remove(stack, name, remove)?,
+            Command::Set(set) => self.check_set(stack, name, set)?,
+            Command::BranchEq(branch_eq) => self.check_branch(stack, positions, branch_eq)?,
+            Command::BranchNeq(branch_neq) => self.check_branch(stack, positions, branch_neq)?,
             // Note that the `Position`s are checked for uniqueness when constructing `Finalize`.
             Command::Position(_) => (),
         }
@@ -206,7 +272,7 @@ impl<N: Network> FinalizeTypes<N> {
     fn check_branch<const VARIANT: u8>(
         &mut self,
         stack: &(impl StackMatches<N> + StackProgram<N>),
-        finalize: &Finalize<N>,
+        positions: &HashMap<Identifier<N>, usize>,
         branch: &Branch<N, VARIANT>,
     ) -> Result<()> {
         // Get the type of the first operand.
@@ -233,7 +299,7 @@ impl<N: Network> FinalizeTypes<N> {
         );
         // Check that the `Position` has been defined.
         ensure!(
-            finalize.positions().get(branch.position()).is_some(),
+            positions.get(branch.position()).is_some(),
             "Command '{}' expects a defined position to jump to. Found undefined position '{}'",
             Branch::<N, VARIANT>::opcode(),
             branch.position()
@@ -241,15 +307,15 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
-    /// Ensures the given `contains` command is well-formed.
+    /// Returns the key and value types of the mapping referenced by the given operator.
+    /// If the mapping is external and declared by the program, its declared types are used,
+    /// otherwise the types are resolved from the external program.
     #[inline]
-    fn check_contains(
-        &mut self,
+    fn get_mapping_types(
         stack: &(impl StackMatches<N> + StackProgram<N>),
-        contains: &Contains<N>,
-    ) -> Result<()> {
-        // Retrieve the mapping.
-        let mapping = match contains.mapping() {
+        mapping: &CallOperator<N>,
+    ) -> Result<(PlaintextType<N>, PlaintextType<N>)> {
+        match mapping {
             CallOperator::Locator(locator) => {
                 // Retrieve the program ID.
                 let program_id = locator.program_id();
@@ -261,9 +327,18 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Locator '{locator}' does not reference an external mapping.");
                 }
                 // Ensure the current program contains an import for this external program.
-                if !stack.program().imports().keys().contains(program_id) {
+                if !stack.program().contains_import(program_id) {
                     bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
                 }
+                // If the external mapping is declared, return its declared types.
+                // Note that the declared types are checked against the external program when the stack is initialized.
+                if stack.program().contains_external_mapping(locator) {
+                    let external_mapping = stack.program().get_external_mapping(locator)?;
+                    return Ok((
+                        external_mapping.key().plaintext_type().clone(),
+                        external_mapping.value().plaintext_type().clone(),
+                    ));
+                }
                 // Retrieve the program.
                 let external = stack.get_external_program(program_id)?;
                 // Ensure the mapping exists in the program.
@@ -271,20 +346,30 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
                 }
                 // Retrieve the mapping from the program.
-                external.get_mapping(mapping_name)?
+                let mapping = external.get_mapping(mapping_name)?;
+                Ok((mapping.key().plaintext_type().clone(), mapping.value().plaintext_type().clone()))
             }
             CallOperator::Resource(mapping_name) => {
-                // Ensure the declared mapping in `contains` is defined in the current program.
+                // Ensure the mapping is defined in the current program.
                 if !stack.program().contains_mapping(mapping_name) {
                     bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
                 }
                 // Retrieve the mapping from the program.
-                stack.program().get_mapping(mapping_name)?
+                let mapping = stack.program().get_mapping(mapping_name)?;
+                Ok((mapping.key().plaintext_type().clone(), mapping.value().plaintext_type().clone()))
             }
-        };
+        }
+    }
 
-        // Get the mapping key type.
-        let mapping_key_type = mapping.key().plaintext_type();
+    /// Ensures the given `contains` command is well-formed.
+    #[inline]
+    fn check_contains(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        contains: &Contains<N>,
+    ) -> Result<()> {
+        // Retrieve the key type of the mapping.
+        let (mapping_key_type, _) = Self::get_mapping_types(stack, contains.mapping())?;
         // Retrieve the register type of the key.
         let key_type = match self.get_type_from_operand(stack, contains.key())? {
             // If the register is a plaintext type, return it.
@@ -293,7 +378,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `contains` command"),
         };
         // Check that the key type in the mapping matches the key type in the instruction.
-        if *mapping_key_type != key_type {
+        if mapping_key_type != key_type {
             bail!(
                 "Key type in `contains` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
             )
@@ -310,45 +395,8 @@ impl<N: Network> FinalizeTypes<N> {
     /// Ensures the given `get` command is well-formed.
     #[inline]
     fn check_get(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), get: &Get<N>) -> Result<()> {
-        // Retrieve the mapping.
-        let mapping = match get.mapping() {
-            CallOperator::Locator(locator) => {
-                // Retrieve the program ID.
-                let program_id = locator.program_id();
-                // Retrieve the mapping_name.
-                let mapping_name = locator.resource();
-
-                // Ensure the locator does not reference the current program.
-                if stack.program_id() == program_id {
-                    bail!("Locator '{locator}' does not reference an external mapping.");
-                }
-                // Ensure the current program contains an import for this external program.
-                if !stack.program().imports().keys().contains(program_id) {
-                    bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
-                }
-                // Retrieve the program.
-                let external = stack.get_external_program(program_id)?;
-                // Ensure the mapping exists in the program.
-                if !external.contains_mapping(mapping_name) {
-                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
-                }
-                // Retrieve the mapping from the program.
-                external.get_mapping(mapping_name)?
-            }
-            CallOperator::Resource(mapping_name) => {
-                // Ensure the declared mapping in `get` is defined in the current program.
-                if !stack.program().contains_mapping(mapping_name) {
-                    bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
-                }
-                // Retrieve the mapping from the program.
-                stack.program().get_mapping(mapping_name)?
-            }
-        };
-
-        // Get the mapping key type.
-        let mapping_key_type = mapping.key().plaintext_type();
-        // Get the mapping value type.
-        let mapping_value_type = mapping.value().plaintext_type();
+        // Retrieve the key and value types of the mapping.
+        let (mapping_key_type, mapping_value_type) = Self::get_mapping_types(stack, get.mapping())?;
         // Retrieve the register type of the key.
         let key_type = match self.get_type_from_operand(stack, get.key())? {
             // If the register is a plaintext type, return it.
@@ -357,7 +405,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `get` command"),
         };
         // Check that the key type in the mapping matches the key type in the instruction.
-        if *mapping_key_type != key_type {
+        if mapping_key_type != key_type {
             bail!("Key type in `get` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'.")
         }
         // Get the destination register.
@@ -365,7 +413,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Ensure the destination register is a locator (and does not reference an access).
         ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
         // Insert the destination register.
-        self.add_destination(destination, FinalizeType::Plaintext(mapping_value_type.clone()))?;
+        self.add_destination(destination, FinalizeType::Plaintext(mapping_value_type))?;
         Ok(())
     }
 
@@ -376,45 +424,8 @@ impl<N: Network> FinalizeTypes<N> {
         stack: &(impl StackMatches<N> + StackProgram<N>),
         get_or_use: &GetOrUse<N>,
     ) -> Result<()> {
-        // Retrieve the mapping.
-        let mapping = match get_or_use.mapping() {
-            CallOperator::Locator(locator) => {
-                // Retrieve the program ID.
-                let program_id = locator.program_id();
-                // Retrieve the mapping_name.
-                let mapping_name = locator.resource();
-
-                // Ensure the locator does not reference the current program.
-                if stack.program_id() == program_id {
-                    bail!("Locator '{locator}' does not reference an external mapping.");
-                }
-                // Ensure the current program contains an import for this external program.
-                if !stack.program().imports().keys().contains(program_id) {
-                    bail!("External program '{locator}' is not imported by '{program_id}'.");
-                }
-                // Retrieve the program.
-                let external = stack.get_external_program(program_id)?;
-                // Ensure the mapping exists in the program.
-                if !external.contains_mapping(mapping_name) {
-                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
-                }
-                // Retrieve the mapping from the program.
-                external.get_mapping(mapping_name)?
-            }
-            CallOperator::Resource(mapping_name) => {
-                // Ensure the declared mapping in `get.or_use` is defined in the current program.
-                if !stack.program().contains_mapping(mapping_name) {
-                    bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
-                }
-                // Retrieve the mapping from the program.
-                stack.program().get_mapping(mapping_name)?
-            }
-        };
-
-        // Get the mapping key type.
-        let mapping_key_type = mapping.key().plaintext_type();
-        // Get the mapping value type.
-        let mapping_value_type = mapping.value().plaintext_type();
+        // Retrieve the key and value types of the mapping.
+        let (mapping_key_type, mapping_value_type) = Self::get_mapping_types(stack, get_or_use.mapping())?;
         // Retrieve the register type of the key.
         let key_type = match self.get_type_from_operand(stack, get_or_use.key())? {
             // If the register is a plaintext type, return it.
@@ -423,7 +434,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `get.or_use` command"),
         };
         // Check that the key type in the mapping matches the key type.
-        if *mapping_key_type != key_type {
+        if mapping_key_type != key_type {
             bail!(
                 "Key type in `get.or_use` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
             )
@@ -436,7 +447,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A default value cannot be a future"),
         };
         // Check that the value type in the mapping matches the default value type.
-        if mapping_value_type != &default_value_type {
+        if mapping_value_type != default_value_type {
             bail!(
                 "Default value type in `get.or_use` '{default_value_type}' does not match the value type in the mapping '{mapping_value_type}'."
             )
@@ -450,6 +461,58 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `mapping.len` command is well-formed.
+    #[inline]
+    fn check_mapping_len(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        mapping_len: &MappingLen<N>,
+    ) -> Result<()> {
+        // Ensure the mapping is defined.
+        Self::get_mapping_types(stack, mapping_len.mapping())?;
+
+        // Get the destination register.
+        let destination = mapping_len.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)))?;
+        Ok(())
+    }
+
+    /// Ensures the given `mapping.keys` command is well-formed.
+    #[inline]
+    fn check_mapping_keys(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        mapping_keys: &MappingKeys<N>,
+    ) -> Result<()> {
+        // Retrieve the key type of the mapping.
+        let (mapping_key_type, _) = Self::get_mapping_types(stack, mapping_keys.mapping())?;
+        // Retrieve the register type of the default key.
+        let default_key_type = match self.get_type_from_operand(stack, mapping_keys.default())? {
+            // If the register is a plaintext type, return it.
+            FinalizeType::Plaintext(plaintext_type) => plaintext_type,
+            // If the register is a future, throw an error.
+            FinalizeType::Future(..) => bail!("A default key cannot be a future"),
+        };
+        // Check that the key type in the mapping matches the default key type.
+        if mapping_key_type != default_key_type {
+            bail!(
+                "Default key type in `mapping.keys` '{default_key_type}' does not match the key type in the mapping '{mapping_key_type}'."
+            )
+        }
+        // Construct the type of the page of keys.
+        let page_type = PlaintextType::Array(ArrayType::new(default_key_type, vec![*mapping_keys.limit()])?);
+        // Get the destination register.
+        let destination = mapping_keys.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, FinalizeType::Plaintext(page_type))?;
+        Ok(())
+    }
+
     /// Ensure the given `rand.chacha` command is well-formed.
     #[inline]
     fn check_rand_chacha(
@@ -558,6 +621,18 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `emit` command is well-formed.
+    #[inline]
+    fn check_emit(&self, stack: &(impl StackMatches<N> + StackProgram<N>), emit: &Emit<N>) -> Result<()> {
+        // Ensure the payload is a plaintext type.
+        match self.get_type_from_operand(stack, emit.payload())? {
+            // If the register is a plaintext type, the payload is well-formed.
+            FinalizeType::Plaintext(..) => Ok(()),
+            // If the register is a future, throw an error.
+            FinalizeType::Future(..) => bail!("A future cannot be used as a payload in an `emit` command"),
+        }
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -628,6 +703,10 @@ impl<N: Network> FinalizeTypes<N> {
                     matches!(instruction, Instruction::AssertNeq(..)),
                     "Instruction '{instruction}' is not for opcode '{opcode}'."
                 ),
+                "assert" => ensure!(
+                    matches!(instruction, Instruction::AssertWithCode(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
             Opcode::Async => {
@@ -671,7 +750,15 @@ impl<N: Network> FinalizeTypes<N> {
                             // Ensure that the array type is valid.
                             RegisterTypes::check_array(stack, array_type)?;
                             // Ensure the operand types match the element type.
-                            self.matches_array(stack, instruction.operands(), array_type)?;
+                            // Note: A single string operand is cast into its bytes, which is checked by the cast operation.
+                            let string_type = FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::String));
+                            match instruction.operands() {
+                                [operand] if self.get_type_from_operand(stack, operand)? == string_type => (),
+                                operands => self.matches_array(stack, operands, array_type)?,
+                            }
+                        }
+                        CastType::Plaintext(PlaintextType::Option(..)) => {
+                            bail!("Illegal operation: Cannot cast to an option, use 'some' or 'none' instead.")
                         }
                         CastType::Record(..) => {
                             bail!("Illegal operation: Cannot cast to a record.")
@@ -708,6 +795,10 @@ impl<N: Network> FinalizeTypes<N> {
                 bail!("Fatal error: Cannot check command '{opcode}' as an instruction in 'finalize {finalize_name}'.")
             }
             Opcode::Commit(opcode) => RegisterTypes::check_commit_opcode(opcode, instruction)?,
+            Opcode::ECDSA(opcode) => RegisterTypes::check_ecdsa_opcode(opcode, instruction)?,
+            Opcode::End => {
+                bail!("Instruction 'end' is not allowed in 'finalize'");
+            }
             Opcode::Hash(opcode) => RegisterTypes::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -720,6 +811,13 @@ impl<N: Network> FinalizeTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
+            Opcode::Group(opcode) => RegisterTypes::check_group_opcode(opcode, instruction)?,
+            Opcode::Merkle(opcode) => RegisterTypes::check_merkle_opcode(opcode, instruction)?,
+            Opcode::Option(opcode) => RegisterTypes::check_option_opcode(opcode, instruction)?,
+            Opcode::Overflowing(opcode) => RegisterTypes::check_overflowing_opcode(opcode, instruction)?,
+            Opcode::Repeat => {
+                bail!("Instruction 'repeat' is not allowed in 'finalize'");
+            }
             Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
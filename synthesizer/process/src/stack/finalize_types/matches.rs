@@ -68,8 +68,8 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{plaintext_type}' in the operand '{operand}'.",
                     )
                 }
-                // Ensure the program ID type (address) matches the member type.
-                Operand::ProgramID(..) => {
+                // Ensure the program ID and program address types (address) match the member type.
+                Operand::ProgramID(..) | Operand::SelfAddress => {
                     // Retrieve the program ID type.
                     let program_ref_type = PlaintextType::Literal(LiteralType::Address);
                     // Ensure the program ID type matches the member type.
@@ -106,6 +106,36 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{network_id_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = PlaintextType::Literal(LiteralType::I64);
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        &block_timestamp_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the previous block hash type (field) matches the member type.
+                Operand::PreviousBlockHash => {
+                    // Retrieve the previous block hash type.
+                    let previous_block_hash_type = PlaintextType::Literal(LiteralType::Field);
+                    // Ensure the previous block hash type matches the member type.
+                    ensure!(
+                        &previous_block_hash_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{previous_block_hash_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the constant type matches the member type.
+                Operand::Constant(name) => {
+                    // Retrieve the constant type.
+                    let constant_type = PlaintextType::Literal(stack.program().get_constant(name)?.literal_type());
+                    // Ensure the constant type matches the member type.
+                    ensure!(
+                        &constant_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{constant_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -161,8 +191,8 @@ impl<N: Network> FinalizeTypes<N> {
                         array_type.next_element_type()
                     )
                 }
-                // Ensure the program ID type (address) matches the member type.
-                Operand::ProgramID(..) => {
+                // Ensure the program ID and program address types (address) match the member type.
+                Operand::ProgramID(..) | Operand::SelfAddress => {
                     // Retrieve the program ID type.
                     let program_ref_type = PlaintextType::Literal(LiteralType::Address);
                     // Ensure the program ID type matches the member type.
@@ -198,6 +228,39 @@ impl<N: Network> FinalizeTypes<N> {
                         array_type.next_element_type()
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = PlaintextType::Literal(LiteralType::I64);
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        &block_timestamp_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
+                // Ensure the previous block hash type (field) matches the member type.
+                Operand::PreviousBlockHash => {
+                    // Retrieve the previous block hash type.
+                    let previous_block_hash_type = PlaintextType::Literal(LiteralType::Field);
+                    // Ensure the previous block hash type matches the member type.
+                    ensure!(
+                        &previous_block_hash_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{previous_block_hash_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
+                // Ensure the constant type matches the member type.
+                Operand::Constant(name) => {
+                    // Retrieve the constant type.
+                    let constant_type = PlaintextType::Literal(stack.program().get_constant(name)?.literal_type());
+                    // Ensure the constant type matches the member type.
+                    ensure!(
+                        &constant_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{constant_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
             }
         }
         Ok(())
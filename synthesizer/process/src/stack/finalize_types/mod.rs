@@ -39,11 +39,14 @@ use synthesizer_program::{
     CastType,
     Command,
     Contains,
+    Emit,
     Finalize,
     Get,
     GetOrUse,
     Instruction,
     InstructionTrait,
+    MappingKeys,
+    MappingLen,
     Opcode,
     Operand,
     Program,
@@ -52,11 +55,12 @@ use synthesizer_program::{
     Set,
     StackMatches,
     StackProgram,
+    View,
     MAX_ADDITIONAL_SEEDS,
 };
 
 use indexmap::IndexMap;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct FinalizeTypes<N: Network> {
@@ -76,6 +80,13 @@ impl<N: Network> FinalizeTypes<N> {
         Self::initialize_finalize_types(stack, finalize)
     }
 
+    /// Initializes a new instance of `FinalizeTypes` for the given view.
+    /// Checks that the given view is well-formed for the given stack.
+    #[inline]
+    pub fn from_view(stack: &(impl StackMatches<N> + StackProgram<N>), view: &View<N>) -> Result<Self> {
+        Self::initialize_view_types(stack, view)
+    }
+
     /// Returns `true` if the given register exists.
     pub fn contains(&self, register: &Register<N>) -> bool {
         // Retrieve the register locator.
@@ -99,11 +110,18 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(match operand {
             Operand::Literal(literal) => FinalizeType::Plaintext(PlaintextType::from(literal.to_type())),
             Operand::Register(register) => self.get_type(stack, register)?,
-            Operand::ProgramID(_) => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            Operand::ProgramID(_) | Operand::SelfAddress => {
+                FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Address))
+            }
             Operand::Signer => bail!("'self.signer' is not a valid operand in a finalize context."),
             Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
             Operand::BlockHeight => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
             Operand::NetworkID => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U16)),
+            Operand::BlockTimestamp => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::I64)),
+            Operand::PreviousBlockHash => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)),
+            Operand::Constant(name) => {
+                FinalizeType::Plaintext(PlaintextType::from(stack.program().get_constant(name)?.literal_type()))
+            }
         })
     }
 
@@ -153,6 +171,10 @@ impl<N: Network> FinalizeTypes<N> {
                 (FinalizeRefType::Plaintext(PlaintextType::Literal(..)), _) => {
                     bail!("'{register}' references a literal.")
                 }
+                // Ensure the plaintext type is not an option, as an option must be unwrapped before it is accessed.
+                (FinalizeRefType::Plaintext(PlaintextType::Option(..)), _) => {
+                    bail!("'{register}' references an option, which must be unwrapped first.")
+                }
                 // Access the member on the path to output the register type.
                 (FinalizeRefType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(identifier)) => {
                     // Retrieve the member type from the struct and check that it exists.
@@ -172,6 +194,17 @@ impl<N: Network> FinalizeTypes<N> {
                         false => bail!("Index out of bounds"),
                     }
                 }
+                // Access the element at a dynamic index to output the register type.
+                // Note: The index is bounds-checked when the register is loaded.
+                (FinalizeRefType::Plaintext(PlaintextType::Array(array_type)), Access::Dynamic(locator)) => {
+                    // Ensure the index is a `u32`.
+                    match self.get_type(stack, &Register::Locator(*locator))? {
+                        FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)) => (),
+                        index_type => bail!("Index 'r{locator}' in '{register}' must be a 'u32', found '{index_type}'"),
+                    }
+                    // Retrieve the element type and update `finalize_type` for the next iteration.
+                    finalize_type = FinalizeRefType::Plaintext(array_type.next_element_type());
+                }
                 // Access the input to the future to output the register type and check that it is in bounds.
                 (FinalizeRefType::Future(locator), Access::Index(index)) => {
                     // Retrieve the associated function.
@@ -199,9 +232,9 @@ impl<N: Network> FinalizeTypes<N> {
                         None => bail!("Index out of bounds"),
                     }
                 }
-                (FinalizeRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
+                (FinalizeRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..) | Access::Dynamic(..))
                 | (FinalizeRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
-                | (FinalizeRefType::Future(..), Access::Member(..)) => {
+                | (FinalizeRefType::Future(..), Access::Member(..) | Access::Dynamic(..)) => {
                     bail!("Invalid access `{access}`")
                 }
             }
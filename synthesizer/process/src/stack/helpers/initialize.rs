@@ -15,21 +15,24 @@
 use super::*;
 
 impl<N: Network> Stack<N> {
-    /// Initializes a new stack, given the process and program.
+    /// Initializes a new stack, given the process, program, and edition.
     #[inline]
-    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
         // Construct the stack for the program.
         let mut stack = Self {
             program: program.clone(),
             external_stacks: Default::default(),
+            instructions: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            key_cache: None,
             number_of_calls: Default::default(),
             finalize_costs: Default::default(),
             program_depth: 0,
+            edition,
         };
 
         // Add all the imports into the stack.
@@ -49,6 +52,29 @@ impl<N: Network> Stack<N> {
                 "Program depth exceeds the maximum allowed call depth"
             );
         }
+        // Ensure the external mappings match their definitions in the imported programs.
+        for (locator, external_mapping) in program.external_mappings() {
+            // Retrieve the mapping from the external program.
+            let mapping = stack
+                .get_external_program(locator.program_id())?
+                .get_mapping(locator.resource())
+                .map_err(|_| anyhow!("External mapping '{locator}' is not defined."))?;
+            // Ensure the declared key type matches.
+            ensure!(
+                mapping.key() == external_mapping.key(),
+                "External mapping '{locator}' declares key type '{}', but its key type is '{}'",
+                external_mapping.key().plaintext_type(),
+                mapping.key().plaintext_type()
+            );
+            // Ensure the declared value type matches.
+            ensure!(
+                mapping.value() == external_mapping.value(),
+                "External mapping '{locator}' declares value type '{}', but its value type is '{}'",
+                external_mapping.value().plaintext_type(),
+                mapping.value().plaintext_type()
+            );
+        }
+
         // Add the program closures to the stack.
         for closure in program.closures().values() {
             // Add the closure to the stack.
@@ -96,6 +122,27 @@ impl<N: Network> Stack<N> {
             stack.finalize_costs.insert(*function.name(), finalize_cost);
         }
 
+        // Add the program views to the stack.
+        for view in program.views().values() {
+            // Add the view to the stack.
+            stack.insert_view(view)?;
+        }
+
+        // If the key cache is enabled, load the cached proving and verifying keys.
+        if let Some(key_cache) = process.key_cache() {
+            // Compute the program checksum.
+            let checksum = program.checksum()?;
+            // Load the cached keys for each function, if they exist.
+            for function_name in program.functions().keys() {
+                if let Some((proving_key, verifying_key)) = key_cache.load_keys(&checksum, function_name) {
+                    stack.insert_proving_key(function_name, proving_key)?;
+                    stack.insert_verifying_key(function_name, verifying_key)?;
+                }
+            }
+            // Set the key cache.
+            stack.key_cache = Some((key_cache.clone(), checksum));
+        }
+
         // Return the stack.
         Ok(stack)
     }
@@ -127,9 +174,12 @@ impl<N: Network> Stack<N> {
         // Ensure the closure name is not already added.
         ensure!(!self.register_types.contains_key(name), "Closure '{name}' already exists");
 
+        // Unroll the instructions.
+        let instructions = Self::unroll_instructions(closure.instructions())?;
         // Compute the register types.
-        let register_types = RegisterTypes::from_closure(self, closure)?;
-        // Add the closure name and register types to the stack.
+        let register_types = RegisterTypes::from_closure(self, closure, &instructions)?;
+        // Add the closure name, instructions, and register types to the stack.
+        self.instructions.insert(*name, instructions);
         self.register_types.insert(*name, register_types);
         // Return success.
         Ok(())
@@ -143,9 +193,12 @@ impl<N: Network> Stack<N> {
         // Ensure the function name is not already added.
         ensure!(!self.register_types.contains_key(name), "Function '{name}' already exists");
 
+        // Unroll the instructions.
+        let instructions = Self::unroll_instructions(function.instructions())?;
         // Compute the register types.
-        let register_types = RegisterTypes::from_function(self, function)?;
-        // Add the function name and register types to the stack.
+        let register_types = RegisterTypes::from_function(self, function, &instructions)?;
+        // Add the function name, instructions, and register types to the stack.
+        self.instructions.insert(*name, instructions);
         self.register_types.insert(*name, register_types);
 
         // If the function contains a finalize, insert it.
@@ -158,4 +211,20 @@ impl<N: Network> Stack<N> {
         // Return success.
         Ok(())
     }
+
+    /// Adds the given view name and finalize types to the stack.
+    #[inline]
+    fn insert_view(&mut self, view: &View<N>) -> Result<()> {
+        // Retrieve the view name.
+        let name = view.name();
+        // Ensure the view name is not already added.
+        ensure!(!self.finalize_types.contains_key(name), "View '{name}' already exists");
+
+        // Compute the finalize types.
+        let finalize_types = FinalizeTypes::from_view(self, view)?;
+        // Add the view name and finalize types to the stack.
+        self.finalize_types.insert(*name, finalize_types);
+        // Return success.
+        Ok(())
+    }
 }
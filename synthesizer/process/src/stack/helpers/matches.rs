@@ -200,6 +200,8 @@ impl<N: Network> Stack<N> {
                 Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected literal, found struct"),
                 // If `plaintext` is an array, this is a mismatch.
                 Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected literal, found array"),
+                // If `plaintext` is an option, this is a mismatch.
+                Plaintext::Option(..) => bail!("'{plaintext_type}' is invalid: expected literal, found option"),
             },
             PlaintextType::Struct(struct_name) => {
                 // Ensure the struct name is valid.
@@ -220,6 +222,7 @@ impl<N: Network> Stack<N> {
                     Plaintext::Literal(..) => bail!("'{struct_name}' is invalid: expected struct, found literal"),
                     Plaintext::Struct(members, ..) => members,
                     Plaintext::Array(..) => bail!("'{struct_name}' is invalid: expected struct, found array"),
+                    Plaintext::Option(..) => bail!("'{struct_name}' is invalid: expected struct, found option"),
                 };
 
                 let num_members = members.len();
@@ -280,6 +283,21 @@ impl<N: Network> Stack<N> {
                     }
                     Ok(())
                 }
+                // If `plaintext` is an option, this is a mismatch.
+                Plaintext::Option(..) => bail!("'{plaintext_type}' is invalid: expected array, found option"),
+            },
+            PlaintextType::Option(option_type) => match plaintext {
+                // If `plaintext` is a literal, this is a mismatch.
+                Plaintext::Literal(..) => bail!("'{plaintext_type}' is invalid: expected option, found literal"),
+                // If `plaintext` is a struct, this is a mismatch.
+                Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected option, found struct"),
+                // If `plaintext` is an array, this is a mismatch.
+                Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected option, found array"),
+                // If `plaintext` is an option, its value must match the element type.
+                // Note that a `none` option still carries a placeholder value, which must also match.
+                Plaintext::Option(_, value, ..) => {
+                    self.matches_plaintext_internal(value, option_type.element_type(), depth + 1)
+                }
             },
         }
     }
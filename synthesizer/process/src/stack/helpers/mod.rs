@@ -18,3 +18,4 @@ mod initialize;
 mod matches;
 mod sample;
 mod synthesize;
+mod unroll;
@@ -148,6 +148,15 @@ impl<N: Network> Stack<N> {
 
                 Plaintext::Array(elements, Default::default())
             }
+            // Sample an option.
+            PlaintextType::Option(option_type) => {
+                // Sample whether the option is `some`.
+                let is_some = Boolean::new(rng.gen());
+                // Sample the value of the option.
+                let value = self.sample_plaintext_internal(option_type.element_type(), depth + 1, rng)?;
+
+                Plaintext::Option(is_some, Box::new(value), Default::default())
+            }
         };
         // Return the plaintext.
         Ok(plaintext)
@@ -97,6 +97,14 @@ impl<N: Network> Stack<N> {
 
         // Synthesize the proving and verifying key.
         let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
+        // If the key cache is enabled, store the proving and verifying key.
+        // Note: A failure to store the keys is not fatal, as the keys are synthesized again when next required.
+        if let Some((key_cache, checksum)) = &self.key_cache {
+            if let Err(error) = key_cache.store_keys(checksum, function_name, &proving_key, &verifying_key) {
+                let program_id = self.program_id();
+                eprintln!("Failed to store the keys for '{program_id}/{function_name}' in the key cache - {error}");
+            }
+        }
         // Insert the proving key.
         self.insert_proving_key(function_name, proving_key)?;
         // Insert the verifying key.
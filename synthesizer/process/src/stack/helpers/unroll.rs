@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the given instructions, with each `repeat` block unrolled into a flat list of instructions.
+    ///
+    /// Let `k` be the number of destination registers in a block, and `s` be its first destination register.
+    /// In iteration `i` of the block, every register at or above `s` is offset by `i * k`, and each carried
+    /// register is rebound to its carried destination in iteration `i - 1`. As such, the instructions following
+    /// the block read the outputs of the last iteration at `s + (n - 1) * k`, and the next free register is `s + n * k`.
+    #[inline]
+    pub(crate) fn unroll_instructions(instructions: &[Instruction<N>]) -> Result<Vec<Instruction<N>>> {
+        // Initialize the list of unrolled instructions.
+        let mut unrolled = Vec::with_capacity(instructions.len());
+
+        let mut instructions = instructions.iter();
+        while let Some(instruction) = instructions.next() {
+            match instruction {
+                Instruction::Repeat(repeat) => {
+                    // Collect the instructions in the block, up to the matching `end` instruction.
+                    let mut block = Vec::new();
+                    loop {
+                        match instructions.next() {
+                            Some(Instruction::End(..)) => break,
+                            Some(Instruction::Repeat(..)) => bail!("Nested 'repeat' blocks are not supported"),
+                            Some(instruction @ (Instruction::Async(..) | Instruction::Call(..))) => {
+                                bail!("Instruction '{instruction}' is not allowed in a 'repeat' block")
+                            }
+                            Some(instruction) => block.push(instruction),
+                            None => bail!("A 'repeat' block must be closed by an 'end' instruction"),
+                        }
+                    }
+
+                    // Retrieve the destination registers of the block.
+                    let destinations = block.iter().flat_map(|instruction| instruction.destinations()).collect_vec();
+                    // Ensure the block contains at least one destination register.
+                    let Some(first) = destinations.first() else {
+                        bail!("A 'repeat' block must contain at least one destination register")
+                    };
+                    // Determine the first destination register of the block.
+                    let start = first.locator();
+                    // Determine the number of destination registers in the block.
+                    let num_destinations = destinations.len() as u64;
+
+                    // Ensure each carried register is defined before the block, and is carried into one of its destinations.
+                    let carried = repeat.carried_registers().iter().zip_eq(repeat.carried_destinations());
+                    for (register, destination) in carried {
+                        ensure!(
+                            register.locator() < start,
+                            "Carried register '{register}' must be defined before the 'repeat' block"
+                        );
+                        ensure!(
+                            destinations.contains(destination),
+                            "Carried destination '{destination}' must be a destination register of the 'repeat' block"
+                        );
+                    }
+
+                    // Ensure the unrolled block does not exceed the maximum number of instructions.
+                    let num_iterations = **repeat.iterations() as usize;
+                    let num_unrolled = block.len().saturating_mul(num_iterations);
+                    ensure!(
+                        unrolled.len().saturating_add(num_unrolled) <= N::MAX_INSTRUCTIONS,
+                        "Cannot unroll more than {} instructions",
+                        N::MAX_INSTRUCTIONS
+                    );
+
+                    // Unroll the block.
+                    for iteration in 0..num_iterations as u64 {
+                        // Offset each destination in the block, by the number of destinations in prior iterations.
+                        let offset = iteration * num_destinations;
+                        let map = |locator: u64| {
+                            // If the register is a destination of the block, offset it to the current iteration.
+                            if locator >= start {
+                                return locator + offset;
+                            }
+                            // If the register is carried, rebind it to its carried destination in the previous iteration.
+                            match repeat.carried_registers().iter().position(|register| register.locator() == locator) {
+                                Some(index) if iteration > 0 => {
+                                    repeat.carried_destinations()[index].locator() + offset - num_destinations
+                                }
+                                _ => locator,
+                            }
+                        };
+                        unrolled.extend(block.iter().map(|instruction| instruction.map_registers(&map)));
+                    }
+                }
+                Instruction::End(..) => bail!("Instruction 'end' must be preceded by a 'repeat' instruction"),
+                _ => unrolled.push(instruction.clone()),
+            }
+        }
+
+        // Ensure the unrolled instructions do not exceed the maximum number of instructions.
+        ensure!(unrolled.len() <= N::MAX_INSTRUCTIONS, "Cannot unroll more than {} instructions", N::MAX_INSTRUCTIONS);
+
+        Ok(unrolled)
+    }
+}
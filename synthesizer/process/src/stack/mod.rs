@@ -36,7 +36,18 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{cost_in_microcredits, traits::*, CallMetrics, Process, Trace};
+use crate::{
+    cost_in_microcredits,
+    traits::*,
+    CallMetrics,
+    DebugScope,
+    DebugTrace,
+    DeferredCall,
+    InstructionMetrics,
+    KeyCache,
+    Process,
+    Trace,
+};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -61,10 +72,10 @@ use console::{
         Value,
         ValueType,
     },
-    types::{Field, Group},
+    types::{Boolean, Field, Group},
 };
 use ledger_block::{Deployment, Transition};
-use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
+use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program, View};
 use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -82,7 +93,7 @@ pub enum CallStack<N: Network> {
     Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>, Option<u64>, Option<u64>),
-    Evaluate(Authorization<N>),
+    Evaluate(Authorization<N>, Option<Arc<RwLock<DebugTrace<N>>>>),
     Execute(Authorization<N>, Arc<RwLock<Trace<N>>>),
     PackageRun(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
 }
@@ -90,7 +101,12 @@ pub enum CallStack<N: Network> {
 impl<N: Network> CallStack<N> {
     /// Initializes a call stack as `Self::Evaluate`.
     pub fn evaluate(authorization: Authorization<N>) -> Result<Self> {
-        Ok(CallStack::Evaluate(authorization))
+        Ok(CallStack::Evaluate(authorization, None))
+    }
+
+    /// Initializes a call stack as `Self::Evaluate`, recording each evaluated step into the given debug trace.
+    pub fn debug(authorization: Authorization<N>, debug_trace: Arc<RwLock<DebugTrace<N>>>) -> Result<Self> {
+        Ok(CallStack::Evaluate(authorization, Some(debug_trace)))
     }
 
     /// Initializes a call stack as `Self::Execute`.
@@ -118,7 +134,10 @@ impl<N: Network> CallStack<N> {
                     *variable_limit,
                 )
             }
-            CallStack::Evaluate(authorization) => CallStack::Evaluate(authorization.replicate()),
+            CallStack::Evaluate(authorization, debug_trace) => CallStack::Evaluate(
+                authorization.replicate(),
+                debug_trace.as_ref().map(|debug_trace| Arc::new(RwLock::new(debug_trace.read().clone()))),
+            ),
             CallStack::Execute(authorization, trace) => {
                 CallStack::Execute(authorization.replicate(), Arc::new(RwLock::new(trace.read().clone())))
             }
@@ -135,7 +154,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::Synthesize(requests, ..)
             | CallStack::CheckDeployment(requests, ..)
             | CallStack::PackageRun(requests, ..) => requests.push(request),
-            CallStack::Evaluate(authorization) => authorization.push(request),
+            CallStack::Evaluate(authorization, ..) => authorization.push(request),
             CallStack::Execute(authorization, ..) => authorization.push(request),
         }
         Ok(())
@@ -150,7 +169,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::PackageRun(requests, ..) => {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.next(),
+            CallStack::Evaluate(authorization, ..) => authorization.next(),
             CallStack::Execute(authorization, ..) => authorization.next(),
         }
     }
@@ -164,10 +183,18 @@ impl<N: Network> CallStack<N> {
             | CallStack::PackageRun(requests, ..) => {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.peek_next(),
+            CallStack::Evaluate(authorization, ..) => authorization.peek_next(),
             CallStack::Execute(authorization, ..) => authorization.peek_next(),
         }
     }
+
+    /// Returns the debug trace, if the call stack is recording one.
+    pub fn debug_trace(&self) -> Option<Arc<RwLock<DebugTrace<N>>>> {
+        match self {
+            CallStack::Evaluate(_, debug_trace) => debug_trace.clone(),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -176,9 +203,11 @@ pub struct Stack<N: Network> {
     program: Program<N>,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The mapping of closure and function names to their unrolled instructions.
+    instructions: IndexMap<Identifier<N>, Vec<Instruction<N>>>,
     /// The mapping of closure and function names to their register types.
     register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
-    /// The mapping of finalize names to their register types.
+    /// The mapping of finalize and view names to their register types.
     finalize_types: IndexMap<Identifier<N>, FinalizeTypes<N>>,
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
@@ -186,12 +215,16 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The on-disk cache of circuit keys and the program checksum, if the key cache is enabled.
+    key_cache: Option<(KeyCache<N>, Field<N>)>,
     /// The mapping of function names to the number of calls.
     number_of_calls: IndexMap<Identifier<N>, usize>,
     /// The mapping of function names to finalize cost.
     finalize_costs: IndexMap<Identifier<N>, u64>,
     /// The program depth.
     program_depth: usize,
+    /// The edition of the program.
+    edition: u16,
 }
 
 impl<N: Network> Stack<N> {
@@ -202,6 +235,98 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Return the stack.
+        Self::load(process, program, N::EDITION)
+    }
+
+    /// Initializes a new stack for the given edition of an existing program, if it is a valid upgrade.
+    ///
+    /// An upgrade is valid if the existing program is upgradable, the edition is the next edition,
+    /// and the existing mappings, structs, and records are unchanged. If other programs import the
+    /// existing program, the existing function signatures must also be unchanged, and the importing
+    /// programs must type-check against the upgraded program.
+    #[inline]
+    pub fn upgrade(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Retrieve the existing stack.
+        let existing = process.get_stack(program_id)?;
+        // Ensure the existing program is upgradable.
+        ensure!(existing.program().upgrade_policy().is_upgradable(), "Program '{program_id}' is not upgradable");
+        // Ensure the edition is the next edition.
+        ensure!(
+            existing.edition().checked_add(1) == Some(edition),
+            "Program '{program_id}' must be upgraded to edition {}, found edition {edition}",
+            existing.edition().saturating_add(1)
+        );
+        // Ensure the existing mappings are preserved, so that their entries remain well-typed.
+        for (name, mapping) in existing.program().mappings() {
+            match program.mappings().get(name) {
+                Some(candidate) => ensure!(
+                    candidate == mapping,
+                    "Upgrade of '{program_id}' cannot change the type of mapping '{name}'"
+                ),
+                None => bail!("Upgrade of '{program_id}' cannot remove mapping '{name}'"),
+            }
+        }
+        // Ensure the existing structs are preserved, as they may define the types of mapping entries.
+        for (name, struct_) in existing.program().structs() {
+            ensure!(
+                program.structs().get(name) == Some(struct_),
+                "Upgrade of '{program_id}' cannot change or remove struct '{name}'"
+            );
+        }
+        // Ensure the existing records are preserved, so that existing records remain spendable.
+        for (name, record) in existing.program().records() {
+            ensure!(
+                program.records().get(name) == Some(record),
+                "Upgrade of '{program_id}' cannot change or remove record '{name}'"
+            );
+        }
+        // If no other program imports the existing program, return the stack.
+        if process.get_dependents(program_id).is_empty() {
+            return Self::load(process, program, edition);
+        }
+        // Ensure the existing function signatures are preserved, as the importing programs may call them.
+        for (name, function) in existing.program().functions() {
+            let Some(candidate) = program.functions().get(name) else {
+                bail!("Upgrade of '{program_id}' cannot remove function '{name}', as '{program_id}' is imported")
+            };
+            ensure!(
+                candidate.input_types() == function.input_types()
+                    && candidate.output_types() == function.output_types()
+                    && candidate.finalize_logic().map(|finalize| finalize.input_types())
+                        == function.finalize_logic().map(|finalize| finalize.input_types()),
+                "Upgrade of '{program_id}' cannot change the signature of function '{name}', as '{program_id}' is imported"
+            );
+        }
+        // Compute the stack.
+        let stack = Self::load(process, program, edition)?;
+        // Ensure the importing programs type-check against the upgraded program.
+        process.clone().upgrade_stack(stack.clone())?;
+        // Return the stack.
+        Ok(stack)
+    }
+
+    /// Rebuilds the given stack against the stacks of its imports in the given process.
+    /// The proving and verifying keys are retained, as the circuits of a program only depend on the
+    /// signatures of the functions it calls, which an upgrade of an imported program must preserve.
+    #[inline]
+    pub(crate) fn rebuild(process: &Process<N>, stack: &Stack<N>) -> Result<Self> {
+        // Initialize the stack against the current imports.
+        let rebuilt = Stack::initialize(process, stack.program(), stack.edition())?;
+        // Retain the proving and verifying keys.
+        rebuilt.proving_keys.write().extend(stack.proving_keys.read().clone());
+        rebuilt.verifying_keys.write().extend(stack.verifying_keys.read().clone());
+        // Return the stack.
+        Ok(rebuilt)
+    }
+
+    /// Initializes a new stack for the given edition of a program, without checking if the program already exists.
+    #[inline]
+    pub(crate) fn load(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program contains functions.
         ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
 
@@ -216,7 +341,7 @@ impl<N: Network> Stack<N> {
         ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
 
         // Return the stack.
-        Stack::initialize(process, program)
+        Stack::initialize(process, program, edition)
     }
 }
 
@@ -365,6 +490,19 @@ impl<N: Network> StackProgramTypes<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns the edition of the program.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
+    /// Returns the unrolled instructions for the given closure or function name.
+    #[inline]
+    pub fn get_instructions(&self, name: &Identifier<N>) -> Result<&[Instruction<N>]> {
+        // Retrieve the unrolled instructions.
+        self.instructions.get(name).map(Vec::as_slice).ok_or_else(|| anyhow!("Instructions for '{name}' do not exist"))
+    }
+
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
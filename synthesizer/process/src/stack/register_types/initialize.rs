@@ -21,6 +21,7 @@ impl<N: Network> RegisterTypes<N> {
     pub(super) fn initialize_closure_types(
         stack: &(impl StackMatches<N> + StackProgram<N>),
         closure: &Closure<N>,
+        instructions: &[Instruction<N>],
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
         let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
@@ -32,7 +33,7 @@ impl<N: Network> RegisterTypes<N> {
         }
 
         // Step 2. Check the instructions are well-formed.
-        for instruction in closure.instructions() {
+        for instruction in instructions {
             // Ensure the closure contains no async instructions.
             ensure!(instruction.opcode() != Opcode::Async, "An 'async' instruction is not allowed in closures");
             // Ensure the closure contains no call instructions.
@@ -62,6 +63,7 @@ impl<N: Network> RegisterTypes<N> {
     pub(super) fn initialize_function_types(
         stack: &(impl StackMatches<N> + StackProgram<N>),
         function: &Function<N>,
+        instructions: &[Instruction<N>],
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
         let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
@@ -85,7 +87,7 @@ impl<N: Network> RegisterTypes<N> {
         // - All `call` instructions must precede any `async` instruction.
 
         let mut async_ = None;
-        for instruction in function.instructions() {
+        for instruction in instructions {
             // Check the instruction opcode, operands, and destinations.
             register_types.check_instruction(stack, function.name(), instruction)?;
             // Additional validation.
@@ -267,6 +269,7 @@ impl<N: Network> RegisterTypes<N> {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
+            RegisterType::Plaintext(PlaintextType::Option(option_type)) => Self::check_option(stack, option_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -319,6 +322,7 @@ impl<N: Network> RegisterTypes<N> {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
+            RegisterType::Plaintext(PlaintextType::Option(option_type)) => Self::check_option(stack, option_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -415,6 +419,10 @@ impl<N: Network> RegisterTypes<N> {
                     matches!(instruction, Instruction::AssertNeq(..)),
                     "Instruction '{instruction}' is not for opcode '{opcode}'."
                 ),
+                "assert" => ensure!(
+                    matches!(instruction, Instruction::AssertWithCode(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
             Opcode::Async => {
@@ -532,7 +540,12 @@ impl<N: Network> RegisterTypes<N> {
                             // Ensure that the array type is valid.
                             RegisterTypes::check_array(stack, array_type)?;
                             // Ensure the operand types match the element type.
-                            self.matches_array(stack, instruction.operands(), array_type)?;
+                            // Note: A single string operand is cast into its bytes, which is checked by the cast operation.
+                            let string_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String));
+                            match instruction.operands() {
+                                [operand] if self.get_type_from_operand(stack, operand)? == string_type => (),
+                                operands => self.matches_array(stack, operands, array_type)?,
+                            }
                         }
                         CastType::Record(record_name) => {
                             // Ensure the record type is defined in the program.
@@ -544,6 +557,9 @@ impl<N: Network> RegisterTypes<N> {
                             // Ensure the operand types match the record type.
                             self.matches_record(stack, instruction.operands(), record_type)?;
                         }
+                        CastType::Plaintext(PlaintextType::Option(..)) => {
+                            bail!("Illegal operation: Cannot cast to an option, use 'some' or 'none' instead.")
+                        }
                         CastType::ExternalRecord(_locator) => {
                             bail!("Illegal operation: Cannot cast to an external record.")
                         }
@@ -576,6 +592,10 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Instruction '{instruction}' cannot invoke command '{opcode}'.");
             }
             Opcode::Commit(opcode) => Self::check_commit_opcode(opcode, instruction)?,
+            Opcode::ECDSA(opcode) => Self::check_ecdsa_opcode(opcode, instruction)?,
+            Opcode::End => {
+                bail!("Instruction '{instruction}' must be unrolled before it is checked.")
+            }
             Opcode::Hash(opcode) => Self::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -588,6 +608,13 @@ impl<N: Network> RegisterTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
+            Opcode::Group(opcode) => Self::check_group_opcode(opcode, instruction)?,
+            Opcode::Merkle(opcode) => Self::check_merkle_opcode(opcode, instruction)?,
+            Opcode::Option(opcode) => Self::check_option_opcode(opcode, instruction)?,
+            Opcode::Overflowing(opcode) => Self::check_overflowing_opcode(opcode, instruction)?,
+            Opcode::Repeat => {
+                bail!("Instruction '{instruction}' must be unrolled before it is checked.")
+            }
             Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
@@ -664,6 +691,7 @@ impl<N: Network> RegisterTypes<N> {
                 PlaintextType::Literal(..) => (),
                 PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name)?,
                 PlaintextType::Array(array_type) => Self::check_array(stack, array_type)?,
+                PlaintextType::Option(option_type) => Self::check_option(stack, option_type)?,
             }
         }
         Ok(())
@@ -684,6 +712,19 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensure the element type of the option is defined in the program.
+    pub(crate) fn check_option(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        option_type: &OptionType<N>,
+    ) -> Result<()> {
+        match option_type.element_type() {
+            PlaintextType::Literal(..) => Ok(()),
+            PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name),
+            PlaintextType::Array(array_type) => Self::check_array(stack, array_type),
+            PlaintextType::Option(..) => bail!("Option '{option_type}' in '{}' is malformed.", stack.program_id()),
+        }
+    }
+
     /// Ensures the opcode is a valid opcode and corresponds to the `commit` instruction.
     #[inline]
     pub(crate) fn check_commit_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
@@ -718,6 +759,124 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensures the opcode is a valid opcode and corresponds to the `ecdsa.verify` instruction.
+    #[inline]
+    pub(crate) fn check_ecdsa_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
+        // Ensure the instruction is the correct one.
+        match opcode {
+            "ecdsa.verify.secp256k1" => ensure!(
+                matches!(instruction, Instruction::ECDSAVerifySecp256k1(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "ecdsa.verify.secp256k1.keccak256" => ensure!(
+                matches!(instruction, Instruction::ECDSAVerifySecp256k1Keccak256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        }
+        Ok(())
+    }
+
+    /// Ensures the opcode is a valid opcode and corresponds to a group instruction.
+    #[inline]
+    pub(crate) fn check_group_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
+        // Ensure the instruction is the correct one.
+        match opcode {
+            "group.gen" => ensure!(
+                matches!(instruction, Instruction::GroupGen(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "msm" => ensure!(
+                matches!(instruction, Instruction::MSM(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_to_group.psd2" => ensure!(
+                matches!(instruction, Instruction::HashToGroupPSD2(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_to_group.psd4" => ensure!(
+                matches!(instruction, Instruction::HashToGroupPSD4(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_to_group.psd8" => ensure!(
+                matches!(instruction, Instruction::HashToGroupPSD8(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        }
+        Ok(())
+    }
+
+    /// Ensures the opcode is a valid opcode and corresponds to the `merkle.verify` instruction.
+    #[inline]
+    pub(crate) fn check_merkle_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
+        // Ensure the instruction is the correct one.
+        match opcode {
+            "merkle.verify.bhp" => ensure!(
+                matches!(instruction, Instruction::MerkleVerifyBHP(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "merkle.verify.psd" => ensure!(
+                matches!(instruction, Instruction::MerkleVerifyPSD(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        }
+        Ok(())
+    }
+
+    /// Ensures the opcode is a valid opcode and corresponds to an option instruction.
+    #[inline]
+    pub(crate) fn check_option_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
+        // Ensure the instruction is the correct one.
+        match opcode {
+            "some" => ensure!(
+                matches!(instruction, Instruction::OptionSome(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "none" => ensure!(
+                matches!(instruction, Instruction::OptionNone(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "is.some" => ensure!(
+                matches!(instruction, Instruction::IsSome(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "unwrap" => ensure!(
+                matches!(instruction, Instruction::Unwrap(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "unwrap.or" => ensure!(
+                matches!(instruction, Instruction::UnwrapOr(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        }
+        Ok(())
+    }
+
+    /// Ensures the opcode is a valid opcode and corresponds to a `checked` arithmetic instruction.
+    #[inline]
+    pub(crate) fn check_overflowing_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
+        // Ensure the instruction is the correct one.
+        match opcode {
+            "add.checked" => ensure!(
+                matches!(instruction, Instruction::AddChecked(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "sub.checked" => ensure!(
+                matches!(instruction, Instruction::SubChecked(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "mul.checked" => ensure!(
+                matches!(instruction, Instruction::MulChecked(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        }
+        Ok(())
+    }
+
     /// Ensures the opcode is a valid opcode and corresponds to the `hash` instruction.
     #[inline]
     pub(crate) fn check_hash_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
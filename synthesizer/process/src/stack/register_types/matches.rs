@@ -74,8 +74,8 @@ impl<N: Network> RegisterTypes<N> {
                         }
                     }
                 }
-                // Ensure the program ID, signer, and caller types (address) match the member type.
-                Operand::ProgramID(..) | Operand::Signer | Operand::Caller => {
+                // Ensure the program ID, signer, caller, and program address types (address) match the member type.
+                Operand::ProgramID(..) | Operand::Signer | Operand::Caller | Operand::SelfAddress => {
                     // Retrieve the operand type.
                     let operand_type = PlaintextType::Literal(LiteralType::Address);
                     // Ensure the operand type matches the member type.
@@ -92,6 +92,24 @@ impl<N: Network> RegisterTypes<N> {
                 Operand::NetworkID => bail!(
                     "Struct member '{struct_name}.{member_name}' cannot be from a network ID in a non-finalize scope"
                 ),
+                // If the operand is a block timestamp type, throw an error.
+                Operand::BlockTimestamp => bail!(
+                    "Struct member '{struct_name}.{member_name}' cannot be from a block timestamp in a non-finalize scope"
+                ),
+                // If the operand is a previous block hash type, throw an error.
+                Operand::PreviousBlockHash => bail!(
+                    "Struct member '{struct_name}.{member_name}' cannot be from a previous block hash in a non-finalize scope"
+                ),
+                // Ensure the constant type matches the member type.
+                Operand::Constant(name) => {
+                    // Retrieve the constant type.
+                    let constant_type = PlaintextType::Literal(stack.program().get_constant(name)?.literal_type());
+                    // Ensure the constant type matches the member type.
+                    ensure!(
+                        &constant_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{constant_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -153,8 +171,8 @@ impl<N: Network> RegisterTypes<N> {
                         }
                     }
                 }
-                // Ensure the program ID type, signer type, and caller types (address) match the element type.
-                Operand::ProgramID(..) | Operand::Signer | Operand::Caller => {
+                // Ensure the program ID, signer, caller, and program address types (address) match the element type.
+                Operand::ProgramID(..) | Operand::Signer | Operand::Caller | Operand::SelfAddress => {
                     // Retrieve the operand type.
                     let operand_type = PlaintextType::Literal(LiteralType::Address);
                     // Ensure the operand type matches the element type.
@@ -168,6 +186,25 @@ impl<N: Network> RegisterTypes<N> {
                 Operand::BlockHeight => bail!("Array element cannot be from a block height in a non-finalize scope"),
                 // If the operand is a network ID type, throw an error.
                 Operand::NetworkID => bail!("Array element cannot be from a network ID in a non-finalize scope"),
+                // If the operand is a block timestamp type, throw an error.
+                Operand::BlockTimestamp => {
+                    bail!("Array element cannot be from a block timestamp in a non-finalize scope")
+                }
+                // If the operand is a previous block hash type, throw an error.
+                Operand::PreviousBlockHash => {
+                    bail!("Array element cannot be from a previous block hash in a non-finalize scope")
+                }
+                // Ensure the constant type matches the element type.
+                Operand::Constant(name) => {
+                    // Retrieve the constant type.
+                    let constant_type = PlaintextType::Literal(stack.program().get_constant(name)?.literal_type());
+                    // Ensure the constant type matches the element type.
+                    ensure!(
+                        &constant_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{constant_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
             }
         }
         Ok(())
@@ -224,6 +261,10 @@ impl<N: Network> RegisterTypes<N> {
                 // They must hold all necessary state in storage instead.
                 bail!("Forbidden operation: Cannot cast a program ID ('{program_id}') as a record owner")
             }
+            Operand::SelfAddress => {
+                // Note: Programs are not allowed to own any records (see above).
+                bail!("Forbidden operation: Cannot cast 'self.address' as a record owner")
+            }
             Operand::Signer | Operand::Caller => {
                 // No-op.
             }
@@ -233,6 +274,18 @@ impl<N: Network> RegisterTypes<N> {
             Operand::NetworkID => {
                 bail!("Forbidden operation: Cannot cast a network ID as a record owner")
             }
+            Operand::BlockTimestamp => {
+                bail!("Forbidden operation: Cannot cast a block timestamp as a record owner")
+            }
+            Operand::PreviousBlockHash => {
+                bail!("Forbidden operation: Cannot cast a previous block hash as a record owner")
+            }
+            Operand::Constant(name) => {
+                ensure!(
+                    stack.program().get_constant(name)?.literal_type() == LiteralType::Address,
+                    "Casting to a record requires the first operand to be an address"
+                )
+            }
         }
 
         // Ensure the operand types match the record entry types.
@@ -272,8 +325,8 @@ impl<N: Network> RegisterTypes<N> {
                                 }
                             }
                         }
-                        // Ensure the program ID, signer, and caller types (address) match the entry type.
-                        Operand::ProgramID(..) | Operand::Signer | Operand::Caller => {
+                        // Ensure the program ID, signer, caller, and program address types (address) match the entry type.
+                        Operand::ProgramID(..) | Operand::Signer | Operand::Caller | Operand::SelfAddress => {
                             // Retrieve the operand type.
                             let operand_type = &PlaintextType::Literal(LiteralType::Address);
                             // Ensure the operand type matches the entry type.
@@ -294,6 +347,29 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a network ID in the operand '{operand}'."
                             )
                         }
+                        // Fail if the operand is a block timestamp.
+                        Operand::BlockTimestamp => {
+                            bail!(
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a block timestamp in the operand '{operand}'."
+                            )
+                        }
+                        // Fail if the operand is a previous block hash.
+                        Operand::PreviousBlockHash => {
+                            bail!(
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a previous block hash in the operand '{operand}'."
+                            )
+                        }
+                        // Ensure the constant type matches the entry type.
+                        Operand::Constant(name) => {
+                            // Retrieve the constant type.
+                            let constant_type =
+                                &PlaintextType::Literal(stack.program().get_constant(name)?.literal_type());
+                            // Ensure the constant type matches the entry type.
+                            ensure!(
+                                constant_type == plaintext_type,
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{constant_type}' in the operand '{operand}'.",
+                            )
+                        }
                     }
                 }
             }
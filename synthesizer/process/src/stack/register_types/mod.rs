@@ -23,6 +23,7 @@ use console::{
         EntryType,
         Identifier,
         LiteralType,
+        OptionType,
         PlaintextType,
         RecordType,
         Register,
@@ -57,18 +58,26 @@ pub struct RegisterTypes<N: Network> {
 }
 
 impl<N: Network> RegisterTypes<N> {
-    /// Initializes a new instance of `RegisterTypes` for the given closure.
+    /// Initializes a new instance of `RegisterTypes` for the given closure and its unrolled instructions.
     /// Checks that the given closure is well-formed for the given stack.
     #[inline]
-    pub fn from_closure(stack: &(impl StackMatches<N> + StackProgram<N>), closure: &Closure<N>) -> Result<Self> {
-        Self::initialize_closure_types(stack, closure)
+    pub fn from_closure(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        closure: &Closure<N>,
+        instructions: &[Instruction<N>],
+    ) -> Result<Self> {
+        Self::initialize_closure_types(stack, closure, instructions)
     }
 
-    /// Initializes a new instance of `RegisterTypes` for the given function.
+    /// Initializes a new instance of `RegisterTypes` for the given function and its unrolled instructions.
     /// Checks that the given function is well-formed for the given stack.
     #[inline]
-    pub fn from_function(stack: &(impl StackMatches<N> + StackProgram<N>), function: &Function<N>) -> Result<Self> {
-        Self::initialize_function_types(stack, function)
+    pub fn from_function(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        function: &Function<N>,
+        instructions: &[Instruction<N>],
+    ) -> Result<Self> {
+        Self::initialize_function_types(stack, function, instructions)
     }
 
     /// Returns `true` if the given register exists.
@@ -94,11 +103,18 @@ impl<N: Network> RegisterTypes<N> {
         Ok(match operand {
             Operand::Literal(literal) => RegisterType::Plaintext(PlaintextType::from(literal.to_type())),
             Operand::Register(register) => self.get_type(stack, register)?,
-            Operand::ProgramID(_) | Operand::Signer | Operand::Caller => {
+            Operand::ProgramID(_) | Operand::Signer | Operand::Caller | Operand::SelfAddress => {
                 RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address))
             }
             Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
             Operand::NetworkID => bail!("'network.id' is not a valid operand in a non-finalize context."),
+            Operand::BlockTimestamp => bail!("'block.timestamp' is not a valid operand in a non-finalize context."),
+            Operand::PreviousBlockHash => {
+                bail!("'block.previous_hash' is not a valid operand in a non-finalize context.")
+            }
+            Operand::Constant(name) => {
+                RegisterType::Plaintext(PlaintextType::from(stack.program().get_constant(name)?.literal_type()))
+            }
         })
     }
 
@@ -125,6 +141,11 @@ impl<N: Network> RegisterTypes<N> {
             Register::Access(_, path) => {
                 // Ensure the path is valid.
                 ensure!(!path.is_empty(), "Register '{register}' references no accesses.");
+                // Ensure the path does not contain a dynamic access.
+                ensure!(
+                    !path.iter().any(Access::is_dynamic),
+                    "Register '{register}' contains a dynamic access, which is only supported in 'finalize'"
+                );
                 // Output the path.
                 path
             }
@@ -161,7 +182,7 @@ impl<N: Network> RegisterTypes<N> {
                     // Retrieve the path name.
                     let path_name = match access {
                         Access::Member(path_name) => path_name,
-                        Access::Index(_) => bail!("Attempted to index into a record"),
+                        Access::Index(_) | Access::Dynamic(_) => bail!("Attempted to index into a record"),
                     };
                     // Retrieve the entry type from the record.
                     match stack.program().get_record(record_name)?.entries().get(path_name) {
@@ -185,7 +206,7 @@ impl<N: Network> RegisterTypes<N> {
                     // Retrieve the path name.
                     let path_name = match access {
                         Access::Member(path_name) => path_name,
-                        Access::Index(_) => bail!("Attempted to index into an external record"),
+                        Access::Index(_) | Access::Dynamic(_) => bail!("Attempted to index into an external record"),
                     };
                     // Retrieve the entry type from the external record.
                     match stack.get_external_record(locator)?.entries().get(path_name) {
@@ -206,6 +227,10 @@ impl<N: Network> RegisterTypes<N> {
                 (RegisterRefType::Plaintext(PlaintextType::Literal(..)), _) => {
                     bail!("'{register}' references a literal.")
                 }
+                // Ensure the plaintext type is not an option, as an option must be unwrapped before it is accessed.
+                (RegisterRefType::Plaintext(PlaintextType::Option(..)), _) => {
+                    bail!("'{register}' references an option, which must be unwrapped first.")
+                }
                 // Traverse the path to output the register type.
                 (RegisterRefType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(identifier)) => {
                     // Retrieve the member type from the struct.
@@ -251,7 +276,8 @@ impl<N: Network> RegisterTypes<N> {
                 }
                 (RegisterRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
                 | (RegisterRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
-                | (RegisterRefType::Future(..), Access::Member(..)) => {
+                | (RegisterRefType::Future(..), Access::Member(..))
+                | (_, Access::Dynamic(..)) => {
                     bail!("Invalid access `{access}`")
                 }
             }
@@ -36,10 +36,22 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Operand::Signer => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.signer()?)))),
             // If the operand is the caller, load the value of the caller.
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
+            // If the operand is the program address, load the address of the current program.
+            Operand::SelfAddress => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Address(stack.program_id().to_address()?))));
+            }
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
             // If the operand is the network ID, throw an error.
             Operand::NetworkID => bail!("Cannot load the network ID in a non-finalize context"),
+            // If the operand is the block timestamp, throw an error.
+            Operand::BlockTimestamp => bail!("Cannot load the block timestamp in a non-finalize context"),
+            // If the operand is the previous block hash, throw an error.
+            Operand::PreviousBlockHash => bail!("Cannot load the previous block hash in a non-finalize context"),
+            // If the operand is a constant, load the value of the constant.
+            Operand::Constant(name) => {
+                return Ok(Value::Plaintext(Plaintext::from(stack.program().get_constant(name)?.literal())));
+            }
         };
 
         // Retrieve the stack value.
@@ -121,10 +133,26 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
                     self.caller_circuit()?,
                 ))));
             }
+            // If the operand is the program address, load the address of the current program.
+            Operand::SelfAddress => {
+                return Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::constant(
+                    Literal::Address(stack.program_id().to_address()?),
+                ))));
+            }
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
             // If the operand is the network ID, throw an error.
             Operand::NetworkID => bail!("Cannot load the network ID in a non-finalize context"),
+            // If the operand is the block timestamp, throw an error.
+            Operand::BlockTimestamp => bail!("Cannot load the block timestamp in a non-finalize context"),
+            // If the operand is the previous block hash, throw an error.
+            Operand::PreviousBlockHash => bail!("Cannot load the previous block hash in a non-finalize context"),
+            // If the operand is a constant, load the value of the constant.
+            Operand::Constant(name) => {
+                return Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::constant(
+                    stack.program().get_constant(name)?.literal().clone(),
+                ))));
+            }
         };
 
         // Retrieve the circuit value.
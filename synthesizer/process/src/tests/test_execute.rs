@@ -14,7 +14,12 @@
 
 use crate::{
     traits::{StackEvaluate, StackExecute},
+    Authorization,
+    CallMetrics,
     CallStack,
+    DebugScope,
+    DeferredCall,
+    FinalizeError,
     Process,
     Stack,
     Trace,
@@ -24,7 +29,7 @@ use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, MainnetV0},
     program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
-    types::{Field, U64},
+    types::{Field, I64, U64},
 };
 use ledger_block::{Fee, Transaction};
 use ledger_query::Query;
@@ -47,7 +52,7 @@ type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
 pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+    FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32], [0u8; 32])
 }
 
 /// Samples a valid fee for the given process, block store, and finalize store.
@@ -477,6 +482,128 @@ output r1 as token.record;",
     assert_eq!(expected, candidate[0]);
 }
 
+#[test]
+fn test_program_evaluate_and_execute_repeat() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program repeat.aleo;
+
+// r1 + 8 * r0
+closure accumulate:
+    input r0 as field;
+    input r1 as field;
+    repeat 8u32 carry r1 into r2;
+    add r0 r1 into r2;
+    end;
+    output r9 as field;
+
+// The fourth Fibonacci step from (r0, r1)
+closure fibonacci:
+    input r0 as field;
+    input r1 as field;
+    repeat 4u32 carry r0 r1 into r3 r2;
+    add r0 r1 into r2;
+    cast r1 into r3 as field;
+    end;
+    output r8 as field;
+
+// (r1 + 8 * r0) * 2^4 + fibonacci(r0, r1)
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    call accumulate r0 r1 into r2;
+    repeat 4u32 carry r2 into r3;
+    double r2 into r3;
+    end;
+    call fibonacci r0 r1 into r7;
+    add r6 r7 into r8;
+    output r8 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Ensure the program round-trips through bytes and strings.
+    assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le().unwrap()).unwrap());
+    assert_eq!(program, Program::from_str(&program.to_string()).unwrap());
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("2field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Declare the expected output value.
+    let expected = Value::from_str("325field").unwrap();
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+    assert_eq!(expected, candidate[0]);
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+    assert_eq!(expected, candidate[0]);
+}
+
+#[test]
+fn test_program_repeat_is_well_formed() {
+    // Ensure the given function body fails to be added to a process.
+    let check_fails = |body: &str| {
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program repeat.aleo;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+{body}"
+        ))
+        .unwrap();
+        assert!(Process::<CurrentNetwork>::load().unwrap().add_program(&program).is_err());
+    };
+
+    // Ensure a block must be closed by an `end` instruction.
+    check_fails("    repeat 2u32;\n    add r0 r1 into r2;\n    output r2 as field.private;");
+    // Ensure an `end` instruction must be preceded by a `repeat` instruction.
+    check_fails("    add r0 r1 into r2;\n    end;\n    output r2 as field.private;");
+    // Ensure blocks may not be nested.
+    check_fails(
+        "    repeat 2u32;\n    repeat 2u32;\n    add r0 r1 into r2;\n    end;\n    end;\n    output r4 as field.private;",
+    );
+    // Ensure a block must contain at least one destination register.
+    check_fails("    repeat 2u32;\n    assert.eq r0 r1;\n    end;\n    output r1 as field.private;");
+    // Ensure a carried register must be defined before the block.
+    check_fails("    repeat 2u32 carry r2 into r2;\n    add r0 r1 into r2;\n    end;\n    output r3 as field.private;");
+    // Ensure a carried register must be carried into a destination of the block.
+    check_fails("    repeat 2u32 carry r1 into r4;\n    add r0 r1 into r2;\n    end;\n    output r3 as field.private;");
+    // Ensure the unrolled instructions may not exceed the maximum number of instructions.
+    check_fails(
+        "    repeat 65535u32 carry r1 into r3;\n    add r0 r1 into r2;\n    add r2 r1 into r3;\n    end;\n    output r3 as field.private;",
+    );
+    // Ensure the registers following a block refer to the last iteration.
+    check_fails("    repeat 2u32 carry r1 into r2;\n    add r0 r1 into r2;\n    end;\n    output r4 as field.private;");
+}
+
 #[test]
 fn test_process_execute_transfer_public_to_private() {
     // Initialize a new program.
@@ -1192,6 +1319,94 @@ function transfer:
     // assert_eq!(215810, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_execute_call_metrics_per_instruction() {
+    // Initialize a new program.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program child.aleo;
+
+function square:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program.
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import child.aleo;
+
+program parent.aleo;
+
+closure square:
+    input r0 as field;
+    mul r0 r0 into r1;
+    output r1 as field;
+
+function main:
+    input r0 as field.private;
+    call square r0 into r1;
+    call child.aleo/square r1 into r2;
+    mul r1 r2 into r3;
+    output r3 as field.private;",
+    )
+    .unwrap();
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let function_name = Identifier::from_str("main").unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Execute the request.
+    let (_response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Ensure the callee metrics are recorded before the caller metrics.
+    let call_metrics = trace.call_metrics();
+    assert_eq!(call_metrics.len(), 2);
+    assert_eq!(call_metrics[0].program_id, *program0.id());
+    assert_eq!(call_metrics[1].program_id, *program1.id());
+
+    // Ensure the constraints are attributed to each instruction.
+    let instructions = &call_metrics[1].instructions;
+    assert_eq!(instructions.len(), 3);
+    assert_eq!(instructions[0].opcode, "call square");
+    assert_eq!(instructions[0].num_constraints, 1);
+    assert!(!instructions[0].has_callee_metrics);
+    assert_eq!(instructions[1].opcode, "call child.aleo/square");
+    assert!(instructions[1].num_constraints > 0);
+    assert!(instructions[1].has_callee_metrics);
+    assert_eq!(instructions[2].opcode, "mul");
+    assert_eq!(instructions[2].num_constraints, 1);
+    assert_eq!(instructions[2].num_variables, 1);
+    let num_instruction_constraints = instructions.iter().map(|instruction| instruction.num_constraints).sum::<u64>();
+    assert!(num_instruction_constraints <= call_metrics[1].num_function_constraints);
+
+    // Ensure the folded stacks nest the callee under the call instruction.
+    let folded_stacks = CallMetrics::to_folded_stacks(call_metrics);
+    let lines = folded_stacks.lines().collect::<Vec<_>>();
+    assert!(lines.iter().all(|line| line.starts_with("parent.aleo/main;")));
+    assert!(lines.contains(&"parent.aleo/main;0:call square 1"));
+    assert!(lines.contains(&"parent.aleo/main;1:call child.aleo/square;child.aleo/square;0:mul 1"));
+    assert!(lines.contains(&"parent.aleo/main;2:mul 1"));
+    assert!(lines[0].starts_with("parent.aleo/main;request "));
+    assert!(lines[lines.len() - 1].starts_with("parent.aleo/main;response "));
+}
+
 #[test]
 fn test_process_execute_and_finalize_get_add_set() {
     // Initialize a new program.
@@ -2138,6 +2353,244 @@ fn test_complex_execution_order() {
     process.verify_execution(&execution).unwrap();
 }
 
+#[test]
+fn test_process_execute_sibling_calls_with_local_record() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function burn:
+    input r0 as token.record;
+    add r0.amount 0u64 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import token.aleo;
+
+program wrapper.aleo;
+
+function mint_and_burn:
+    input r0 as u64.private;
+    call token.aleo/mint self.caller r0 into r1;
+    call token.aleo/burn r1 into r2;
+    call token.aleo/mint self.caller r2 into r3;
+    output r3 as token.aleo/token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let function_name = Identifier::from_str("mint_and_burn").unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 4);
+
+    // Retrieve the transition IDs from the authorization, in the order they finish.
+    let expected_ids = authorization.transitions().keys().copied().collect::<Vec<_>>();
+
+    // Execute the request.
+    let (_response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Ensure the sibling calls are included in the same order as in the authorization.
+    let candidate_ids = trace.transitions().iter().map(|transition| *transition.id()).collect::<Vec<_>>();
+    assert_eq!(candidate_ids, expected_ids);
+    assert_eq!(trace.call_metrics().len(), 4);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace, which requires the minted record to be found locally.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("wrapper", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_execute_call_with_mismatched_outputs() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program callee.aleo;
+
+function double:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import callee.aleo;
+
+program caller.aleo;
+
+function main:
+    input r0 as u64.private;
+    call callee.aleo/double r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let function_name = Identifier::from_str("main").unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 2);
+
+    // Construct a call to the callee, whose evaluated outputs diverge from the outputs of its execution.
+    let replica = authorization.replicate();
+    let request = replica.next().unwrap();
+    let deferred_call = DeferredCall {
+        authorization: Authorization::new(replica.next().unwrap()),
+        console_caller: Some(*program1.id()),
+        root_tvk: Some(*request.tvk()),
+        outputs: vec![Value::from_str("11u64").unwrap()],
+    };
+
+    // Initialize the trace with the divergent call, so that it is synthesized alongside the call of the caller.
+    let trace = Arc::new(RwLock::new(Trace::new()));
+    trace.write().defer_call(deferred_call).unwrap();
+    let call_stack = CallStack::execute(authorization, trace.clone()).unwrap();
+
+    // Ensure the execution fails, as the outputs of the callee do not match.
+    let stack = process.get_stack(program1.id()).unwrap();
+    let error = stack.execute_function::<CurrentAleo, _>(call_stack, None, None, rng).unwrap_err();
+    assert!(error.to_string().contains("outputs do not match"), "{error}");
+    // Ensure the error surfaces before any transition is inserted, including the transition of the caller.
+    assert!(trace.read().transitions().is_empty());
+    assert!(trace.read().call_metrics().is_empty());
+}
+
+#[test]
+fn test_process_execute_call_with_failed_callee() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program callee.aleo;
+
+function double:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import callee.aleo;
+
+program caller.aleo;
+
+function main:
+    input r0 as u64.private;
+    call callee.aleo/double r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let function_name = Identifier::from_str("main").unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 2);
+
+    // Construct a call to the callee with an incorrect root transition view key,
+    // so that the circuit of the callee is synthesized, but is not satisfied.
+    let replica = authorization.replicate();
+    let _request = replica.next().unwrap();
+    let deferred_call = DeferredCall {
+        authorization: Authorization::new(replica.next().unwrap()),
+        console_caller: Some(*program1.id()),
+        root_tvk: Some(Field::rand(rng)),
+        outputs: vec![Value::from_str("10u64").unwrap()],
+    };
+
+    // Initialize the trace with the failing call, so that it is synthesized alongside the call of the caller.
+    let trace = Arc::new(RwLock::new(Trace::new()));
+    trace.write().defer_call(deferred_call).unwrap();
+    let call_stack = CallStack::execute(authorization, trace.clone()).unwrap();
+
+    // Ensure the execution returns an error, instead of panicking on the partially-synthesized circuit of the callee.
+    let stack = process.get_stack(program1.id()).unwrap();
+    let error = stack.execute_function::<CurrentAleo, _>(call_stack, None, None, rng).unwrap_err();
+    assert!(error.to_string().contains("is not satisfied"), "{error}");
+    assert!(trace.read().transitions().is_empty());
+
+    // Ensure the process can still execute the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("10u64").unwrap()]);
+}
+
 #[test]
 fn test_process_execute_and_finalize_get_set_with_struct() {
     // Initialize a new program.
@@ -2260,42 +2713,46 @@ finalize compute:
 }
 
 #[test]
-fn test_process_execute_and_verify_call_to_closure() {
+fn test_process_execute_and_finalize_ecdsa_verify() {
     // Initialize a new program.
     let (string, program) = Program::<CurrentNetwork>::parse(
         r"
 program testing.aleo;
 
-// (a + (a + b)) + (a + b) == (3a + 2b)
-closure execute:
-    input r0 as field;
-    input r1 as field;
-    add r0 r1 into r2;
-    add r0 r2 into r3;
-    add r2 r3 into r4;
-    output r4 as field;
-    output r3 as field;
-    output r2 as field;
-
-closure check_not_equal:
-    input r0 as field;
-    input r1 as field;
-    assert.neq r0 r1;
-
-function compute:
-    input r0 as field.private;
-    input r1 as field.public;
-    call check_not_equal r0 r1;
-    call execute r0 r1 into r2 r3 r4;
-    output r2 as field.private;
-    output r3 as field.private;
-    output r4 as field.private;",
+function verify:
+    input r0 as [[u8; 13u32]; 5u32].public;
+    input r1 as [u8; 20u32].public;
+    input r2 as [u8; 11u32].public;
+    async verify r0 r1 r2 into r3;
+    output r3 as testing.aleo/verify.future;
+
+finalize verify:
+    input r0 as [[u8; 13u32]; 5u32].public;
+    input r1 as [u8; 20u32].public;
+    input r2 as [u8; 11u32].public;
+    ecdsa.verify.secp256k1.keccak256 r0 r1 r2 into r3;
+    assert.eq r3 true;
+",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
     // Declare the function name.
-    let function_name = Identifier::from_str("compute").unwrap();
+    let function_name = Identifier::from_str("verify").unwrap();
+
+    // Declare the signature, signer (as an Ethereum address), and message.
+    let signature = Value::<CurrentNetwork>::from_str("[[211u8, 241u8, 111u8, 34u8, 110u8, 132u8, 250u8, 162u8, 190u8, 188u8, 244u8, 49u8, 79u8], [9u8, 166u8, 248u8, 90u8, 15u8, 134u8, 16u8, 56u8, 198u8, 66u8, 66u8, 78u8, 117u8], [199u8, 0u8, 29u8, 104u8, 56u8, 129u8, 96u8, 156u8, 175u8, 181u8, 179u8, 52u8, 49u8], [239u8, 8u8, 3u8, 144u8, 59u8, 239u8, 241u8, 225u8, 18u8, 155u8, 225u8, 48u8, 59u8], [101u8, 254u8, 183u8, 49u8, 145u8, 86u8, 138u8, 74u8, 254u8, 201u8, 244u8, 129u8, 28u8]]").unwrap();
+    let signer = Value::<CurrentNetwork>::from_str("[74u8, 98u8, 49u8, 102u8, 35u8, 173u8, 69u8, 127u8, 2u8, 205u8, 197u8, 217u8, 151u8, 222u8, 214u8, 122u8, 56u8, 62u8, 197u8, 105u8]").unwrap();
+    // The message is "hello world".
+    let message = Value::<CurrentNetwork>::from_str(
+        "[104u8, 101u8, 108u8, 108u8, 111u8, 32u8, 119u8, 111u8, 114u8, 108u8, 100u8]",
+    )
+    .unwrap();
+    // The message is "hello worle".
+    let tampered = Value::<CurrentNetwork>::from_str(
+        "[104u8, 101u8, 108u8, 108u8, 111u8, 32u8, 119u8, 111u8, 114u8, 108u8, 101u8]",
+    )
+    .unwrap();
 
     // Initialize the RNG.
     let rng = &mut TestRng::default();
@@ -2306,120 +2763,159 @@ function compute:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
 
     // Reset the process.
-    let process = crate::test_helpers::sample_process(&program);
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
 
-    // Declare the input value.
-    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
-    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
-
-    // Authorize the function call.
-    let authorization = process
-        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
-        .unwrap();
-    assert_eq!(authorization.len(), 1);
+    for (message, is_valid) in [(message, true), (tampered, false)] {
+        // Authorize the function call.
+        let inputs = [signature.clone(), signer.clone(), message];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
 
-    let r2 = Value::from_str("19field").unwrap();
-    let r3 = Value::from_str("11field").unwrap();
-    let r4 = Value::from_str("8field").unwrap();
+        // Execute the request.
+        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
 
-    // Check again to make sure we didn't modify the authorization before calling `evaluate`.
-    assert_eq!(authorization.len(), 1);
+        // Prepare the trace.
+        trace.prepare(Query::from(&block_store)).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
 
-    // Compute the output value.
-    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(3, candidate.len());
-    assert_eq!(r2, candidate[0]);
-    assert_eq!(r3, candidate[1]);
-    assert_eq!(r4, candidate[2]);
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
 
-    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-    assert_eq!(authorization.len(), 1);
+        // Finalize the execution, which only succeeds if the signature is valid.
+        let result = process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None);
+        assert_eq!(result.is_ok(), is_valid);
+    }
+}
 
-    // Execute the request.
-    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(3, candidate.len());
-    assert_eq!(r2, candidate[0]);
-    assert_eq!(r3, candidate[1]);
-    assert_eq!(r4, candidate[2]);
+#[test]
+fn test_process_execute_ecdsa_verify() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
 
-    // Initialize a new block store.
-    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
-    // Prepare the trace.
-    trace.prepare(Query::from(block_store)).unwrap();
-    // Prove the execution.
-    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+function verify:
+    input r0 as [[u8; 13u32]; 5u32].private;
+    input r1 as [u8; 20u32].public;
+    input r2 as [u8; 11u32].private;
+    ecdsa.verify.secp256k1.keccak256 r0 r1 r2 into r3;
+    output r3 as boolean.public;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Verify the execution.
-    process.verify_execution(&execution).unwrap();
-}
+    // Declare the function name.
+    let function_name = Identifier::from_str("verify").unwrap();
+
+    // Declare the signature, signer (as an Ethereum address), and message.
+    let signature = Value::<CurrentNetwork>::from_str("[[211u8, 241u8, 111u8, 34u8, 110u8, 132u8, 250u8, 162u8, 190u8, 188u8, 244u8, 49u8, 79u8], [9u8, 166u8, 248u8, 90u8, 15u8, 134u8, 16u8, 56u8, 198u8, 66u8, 66u8, 78u8, 117u8], [199u8, 0u8, 29u8, 104u8, 56u8, 129u8, 96u8, 156u8, 175u8, 181u8, 179u8, 52u8, 49u8], [239u8, 8u8, 3u8, 144u8, 59u8, 239u8, 241u8, 225u8, 18u8, 155u8, 225u8, 48u8, 59u8], [101u8, 254u8, 183u8, 49u8, 145u8, 86u8, 138u8, 74u8, 254u8, 201u8, 244u8, 129u8, 28u8]]").unwrap();
+    let signer = Value::<CurrentNetwork>::from_str("[74u8, 98u8, 49u8, 102u8, 35u8, 173u8, 69u8, 127u8, 2u8, 205u8, 197u8, 217u8, 151u8, 222u8, 214u8, 122u8, 56u8, 62u8, 197u8, 105u8]").unwrap();
+    // The message is "hello world".
+    let message = Value::<CurrentNetwork>::from_str(
+        "[104u8, 101u8, 108u8, 108u8, 111u8, 32u8, 119u8, 111u8, 114u8, 108u8, 100u8]",
+    )
+    .unwrap();
+    // The message is "hello worle".
+    let tampered = Value::<CurrentNetwork>::from_str(
+        "[104u8, 101u8, 108u8, 108u8, 111u8, 32u8, 119u8, 111u8, 114u8, 108u8, 101u8]",
+    )
+    .unwrap();
 
-#[test]
-fn test_process_deploy_credits_program() {
+    // Initialize the RNG.
     let rng = &mut TestRng::default();
 
-    // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
-
     // Construct the process.
-    let process = Process::load().unwrap();
+    let process = crate::test_helpers::sample_process(&program);
 
-    // Fetch the credits program
-    let program = Program::credits().unwrap();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
 
-    // Create a deployment for the credits.aleo program.
-    let deployment = empty_process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    for (message, is_valid) in [(message, true), (tampered, false)] {
+        // Authorize the function call.
+        let inputs = [signature.clone(), signer.clone(), message];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
 
-    // Ensure the deployment is valid on the empty process.
-    empty_process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
-    // Ensure the deployment is not valid on the standard process.
-    assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
+        // Execute the request, and check the output matches the validity of the signature.
+        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(candidate[0], Value::from_str(&is_valid.to_string()).unwrap());
 
-    // Create a new `credits.aleo` program.
-    let program = Program::from_str(
+        // Prepare the trace.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        trace.prepare(Query::from(&block_store)).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+    }
+}
+
+#[test]
+fn test_process_execute_and_finalize_dynamic_index() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
         r"
-program credits.aleo;
+program testing.aleo;
 
-record token:
-    owner as address.private;
-    amount as u64.private;
+mapping entries:
+    key as u32.public;
+    value as u64.public;
 
-function compute:
-    input r0 as u32.private;
-    add r0 r0 into r1;
-    output r1 as u32.public;",
+function select:
+    input r0 as [u64; 4u32].public;
+    input r1 as u32.public;
+    async select r0 r1 into r2;
+    output r2 as testing.aleo/select.future;
+
+finalize select:
+    input r0 as [u64; 4u32].public;
+    input r1 as u32.public;
+    set r0[r1] into entries[r1];
+",
     )
     .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Create a deployment for the credits.aleo program.
-    let deployment = empty_process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
-
-    // Ensure the deployment is valid on the empty process.
-    empty_process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
-    // Ensure the deployment is not valid on the standard process.
-    assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
-}
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("entries").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("select").unwrap();
 
-#[test]
-fn test_process_zero_input_zero_output_executions() {
     // Initialize the RNG.
     let rng = &mut TestRng::default();
 
-    // Create a new program with a function that has no inputs or outputs.
-    let function_name = Identifier::from_str("zero_inputs_zero_outputs").unwrap();
-    let program = Program::from_str(&format!(
-        r"
-program testing.aleo;
-function {function_name}:
-    add 0u8 1u8 into r0;",
-    ))
-    .unwrap();
-
     // Reset the process.
     let mut process = Process::load().unwrap();
 
@@ -2442,206 +2938,1807 @@ function {function_name}:
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
 
-    // Create an execution for the `zero_inputs_zero_outputs` function.
-    let execution_1 = {
+    // Declare the input array.
+    let array = Value::<CurrentNetwork>::from_str("[10u64, 20u64, 30u64, 40u64]").unwrap();
+
+    for (index, expected) in [("2u32", Some("30u64")), ("4u32", None)] {
         // Authorize the function call.
-        let inputs = Vec::<Value<CurrentNetwork>>::new();
+        let inputs = [array.clone(), Value::<CurrentNetwork>::from_str(index).unwrap()];
         let authorization = process
-            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), &function_name, inputs.iter(), rng)
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
             .unwrap();
+        assert_eq!(authorization.len(), 1);
 
         // Execute the request.
-        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-        assert_eq!(response.outputs().len(), 0);
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
 
         // Prepare the trace.
-        trace.prepare(Query::from(block_store.clone())).unwrap();
+        trace.prepare(Query::from(&block_store)).unwrap();
         // Prove the execution.
-        trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap()
-    };
-    assert_eq!(execution_1.len(), 1);
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
 
-    // Create a subsequent execution for the `zero_inputs_zero_outputs` function.
-    let execution_2 = {
-        // Authorize the function call.
-        let inputs = Vec::<Value<CurrentNetwork>>::new();
-        let authorization = process
-            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), &function_name, inputs.iter(), rng)
-            .unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
 
-        // Execute the request.
-        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-        assert_eq!(response.outputs().len(), 0);
+        // Finalize the execution, which only succeeds if the index is in bounds.
+        let result = process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None);
+        assert_eq!(result.is_ok(), expected.is_some());
 
-        // Prepare the trace.
-        trace.prepare(Query::from(block_store)).unwrap();
-        // Prove the execution.
-        trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap()
-    };
-    assert_eq!(execution_2.len(), 1);
+        // Check that the element is stored as expected.
+        let key = Plaintext::from_str(index).unwrap();
+        let candidate = finalize_store.get_value_speculative(*program_id, mapping_name, &key).unwrap();
+        assert_eq!(candidate, expected.map(|value| Value::from_str(value).unwrap()));
+    }
 
-    // Ensure that the transitions are unique.
-    assert_ne!(execution_1.peek().unwrap().id(), execution_2.peek().unwrap().id());
-    assert_ne!(execution_1.to_execution_id().unwrap(), execution_2.to_execution_id().unwrap());
+    // Ensure a dynamic index is rejected outside of a finalize scope.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program dynamic.aleo;
+
+function select:
+    input r0 as [u64; 4u32].public;
+    input r1 as u32.public;
+    add r0[r1] 1u64 into r2;
+    output r2 as u64.public;",
+    )
+    .unwrap();
+    assert!(Process::<CurrentNetwork>::load().unwrap().add_program(&program).is_err());
 }
 
 #[test]
-fn test_long_import_chain() {
+fn test_process_execute_and_finalize_block_timestamp_and_previous_hash() {
     // Initialize a new program.
-    let program = Program::<CurrentNetwork>::from_str(
+    let (string, program) = Program::<CurrentNetwork>::parse(
         r"
-    program test0.aleo;
-    function c:",
+program testing.aleo;
+
+mapping timestamps:
+    key as u32.public;
+    value as i64.public;
+
+mapping hashes:
+    key as u32.public;
+    value as field.public;
+
+function record_block:
+    async record_block into r0;
+    output r0 as testing.aleo/record_block.future;
+
+finalize record_block:
+    set block.timestamp into timestamps[block.height];
+    set block.previous_hash into hashes[block.height];
+",
     )
     .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program);
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("record_block").unwrap();
 
-    // Add `MAX_PROGRAM_DEPTH` programs to the process.
-    for i in 1..=CurrentNetwork::MAX_PROGRAM_DEPTH {
-        println!("Adding program {i}");
-        // Initialize a new program.
-        let program = Program::from_str(&format!(
-            "
-        import test{}.aleo;
-        program test{}.aleo;
-        function c:",
-            i - 1,
-            i
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs: [Value<CurrentNetwork>; 0] = [];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(&block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Construct the finalize state with a block timestamp and previous block hash.
+    let block_timestamp = 1_700_000_000i64;
+    let previous_block_hash = Field::<CurrentNetwork>::rand(rng);
+    let state = FinalizeGlobalState::from(
+        2,
+        2,
+        block_timestamp,
+        previous_block_hash.to_bytes_le().unwrap().try_into().unwrap(),
+        [0u8; 32],
+    );
+
+    // Finalize the execution.
+    process.finalize_execution(state, &finalize_store, &execution, None).unwrap();
+
+    // Check that the block timestamp and previous block hash are stored as expected.
+    let key = Plaintext::from_str("2u32").unwrap();
+    let timestamp = finalize_store
+        .get_value_speculative(*program_id, Identifier::from_str("timestamps").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(timestamp, Value::from(Literal::I64(I64::new(block_timestamp))));
+    let hash = finalize_store
+        .get_value_speculative(*program_id, Identifier::from_str("hashes").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(hash, Value::from(Literal::Field(previous_block_hash)));
+}
+
+#[test]
+fn test_process_execute_and_finalize_self_address() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping addresses:
+    key as boolean.public;
+    value as address.public;
+
+function store_address:
+    assert.eq self.address testing.aleo;
+    async store_address self.address into r0;
+    output self.address as address.public;
+    output r0 as testing.aleo/store_address.future;
+
+finalize store_address:
+    input r0 as address.public;
+    assert.eq r0 self.address;
+    set self.address into addresses[true];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("store_address").unwrap();
+    // Compute the expected program address.
+    let program_address = program_id.to_address().unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs: [Value<CurrentNetwork>; 0] = [];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Check that the output is the program address.
+    assert_eq!(response.outputs()[0], Value::from(Literal::Address(program_address)));
+
+    // Prepare the trace.
+    trace.prepare(Query::from(&block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the program address is stored as expected.
+    let value = finalize_store
+        .get_value_speculative(
+            *program_id,
+            Identifier::from_str("addresses").unwrap(),
+            &Plaintext::from_str("true").unwrap(),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(value, Value::from(Literal::Address(program_address)));
+}
+
+#[test]
+fn test_process_execute_and_finalize_mapping_len_and_keys() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping members:
+    key as u8.public;
+    value as boolean.public;
+
+mapping tallies:
+    key as u8.public;
+    value as u32.public;
+
+mapping pages:
+    key as u8.public;
+    value as [u8; 4u32].public;
+
+function tally:
+    async tally into r0;
+    output r0 as testing.aleo/tally.future;
+
+finalize tally:
+    set true into members[3u8];
+    set true into members[1u8];
+    set true into members[2u8];
+    mapping.len members limit 16u32 into r0;
+    mapping.keys members from 1u32 limit 4u32 or_use 0u8 into r1;
+    set r0 into tallies[0u8];
+    set r1 into pages[0u8];
+
+function prune:
+    async prune into r0;
+    output r0 as testing.aleo/prune.future;
+
+finalize prune:
+    remove members[2u8];
+    set false into members[1u8];
+    mapping.len members limit 16u32 into r0;
+    mapping.keys members from 0u32 limit 4u32 or_use 0u8 into r1;
+    set r0 into tallies[1u8];
+    set r1 into pages[1u8];
+
+function overflow:
+    async overflow into r0;
+    output r0 as testing.aleo/overflow.future;
+
+finalize overflow:
+    mapping.len members limit 2u32 into r0;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Prepare a verified execution of the given function.
+    let mut execute = |function_name: &str| {
+        // Authorize the function call.
+        let inputs: [Value<CurrentNetwork>; 0] = [];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(&block_store)).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+        execution
+    };
+    let tally = execute("tally");
+    let prune = execute("prune");
+    let overflow = execute("overflow");
+
+    // Finalize the tally.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &tally, None).unwrap();
+
+    // Check that the number of members is tallied.
+    let key = Plaintext::from_str("0u8").unwrap();
+    let count = finalize_store
+        .get_value_speculative(*program_id, Identifier::from_str("tallies").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(count, Value::from_str("3u32").unwrap());
+    // Check that the page is ordered by key, starting from the second key, and padded with the default key.
+    let page = finalize_store
+        .get_value_speculative(*program_id, Identifier::from_str("pages").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(page, Value::from_str("[2u8, 3u8, 0u8, 0u8]").unwrap());
+
+    // Finalize the pruning.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &prune, None).unwrap();
+
+    // Check that the removal is counted, and that the update of an existing key is not.
+    let key = Plaintext::from_str("1u8").unwrap();
+    let count = finalize_store
+        .get_value_speculative(*program_id, Identifier::from_str("tallies").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(count, Value::from_str("2u32").unwrap());
+    // Check that the page skips the removed key.
+    let page = finalize_store
+        .get_value_speculative(*program_id, Identifier::from_str("pages").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(page, Value::from_str("[1u8, 3u8, 0u8, 0u8]").unwrap());
+
+    // Check that counting more entries than the limit fails.
+    assert!(process.finalize_execution(sample_finalize_state(1), &finalize_store, &overflow, None).is_err());
+}
+
+#[test]
+fn test_process_execute_and_verify_call_to_closure() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+// (a + (a + b)) + (a + b) == (3a + 2b)
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    add r2 r3 into r4;
+    output r4 as field;
+    output r3 as field;
+    output r2 as field;
+
+closure check_not_equal:
+    input r0 as field;
+    input r1 as field;
+    assert.neq r0 r1;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    call check_not_equal r0 r1;
+    call execute r0 r1 into r2 r3 r4;
+    output r2 as field.private;
+    output r3 as field.private;
+    output r4 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    let r2 = Value::from_str("19field").unwrap();
+    let r3 = Value::from_str("11field").unwrap();
+    let r4 = Value::from_str("8field").unwrap();
+
+    // Check again to make sure we didn't modify the authorization before calling `evaluate`.
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(3, candidate.len());
+    assert_eq!(r2, candidate[0]);
+    assert_eq!(r3, candidate[1]);
+    assert_eq!(r4, candidate[2]);
+
+    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(3, candidate.len());
+    assert_eq!(r2, candidate[0]);
+    assert_eq!(r3, candidate[1]);
+    assert_eq!(r4, candidate[2]);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_deploy_credits_program() {
+    let rng = &mut TestRng::default();
+
+    // Initialize an empty process without the `credits` program.
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        key_cache: None,
+    };
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Fetch the credits program
+    let program = Program::credits().unwrap();
+
+    // Create a deployment for the credits.aleo program.
+    let deployment = empty_process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+    // Ensure the deployment is valid on the empty process.
+    empty_process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Ensure the deployment is not valid on the standard process.
+    assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
+
+    // Create a new `credits.aleo` program.
+    let program = Program::from_str(
+        r"
+program credits.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+    )
+    .unwrap();
+
+    // Create a deployment for the credits.aleo program.
+    let deployment = empty_process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+    // Ensure the deployment is valid on the empty process.
+    empty_process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Ensure the deployment is not valid on the standard process.
+    assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
+}
+
+#[test]
+fn test_process_zero_input_zero_output_executions() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Create a new program with a function that has no inputs or outputs.
+    let function_name = Identifier::from_str("zero_inputs_zero_outputs").unwrap();
+    let program = Program::from_str(&format!(
+        r"
+program testing.aleo;
+function {function_name}:
+    add 0u8 1u8 into r0;",
+    ))
+    .unwrap();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Create an execution for the `zero_inputs_zero_outputs` function.
+    let execution_1 = {
+        // Authorize the function call.
+        let inputs = Vec::<Value<CurrentNetwork>>::new();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), &function_name, inputs.iter(), rng)
+            .unwrap();
+
+        // Execute the request.
+        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs().len(), 0);
+
+        // Prepare the trace.
+        trace.prepare(Query::from(block_store.clone())).unwrap();
+        // Prove the execution.
+        trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap()
+    };
+    assert_eq!(execution_1.len(), 1);
+
+    // Create a subsequent execution for the `zero_inputs_zero_outputs` function.
+    let execution_2 = {
+        // Authorize the function call.
+        let inputs = Vec::<Value<CurrentNetwork>>::new();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), &function_name, inputs.iter(), rng)
+            .unwrap();
+
+        // Execute the request.
+        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs().len(), 0);
+
+        // Prepare the trace.
+        trace.prepare(Query::from(block_store)).unwrap();
+        // Prove the execution.
+        trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap()
+    };
+    assert_eq!(execution_2.len(), 1);
+
+    // Ensure that the transitions are unique.
+    assert_ne!(execution_1.peek().unwrap().id(), execution_2.peek().unwrap().id());
+    assert_ne!(execution_1.to_execution_id().unwrap(), execution_2.to_execution_id().unwrap());
+}
+
+#[test]
+fn test_long_import_chain() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+    program test0.aleo;
+    function c:",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program);
+
+    // Add `MAX_PROGRAM_DEPTH` programs to the process.
+    for i in 1..=CurrentNetwork::MAX_PROGRAM_DEPTH {
+        println!("Adding program {i}");
+        // Initialize a new program.
+        let program = Program::from_str(&format!(
+            "
+        import test{}.aleo;
+        program test{}.aleo;
+        function c:",
+            i - 1,
+            i
+        ))
+        .unwrap();
+        // Add the program to the process.
+        process.add_program(&program).unwrap();
+    }
+
+    // Add the `MAX_PROGRAM_DEPTH + 1` program to the process, which should fail.
+    let program = Program::from_str(&format!(
+        "
+        import test{}.aleo;
+        program test{}.aleo;
+        function c:",
+        CurrentNetwork::MAX_PROGRAM_DEPTH,
+        CurrentNetwork::MAX_PROGRAM_DEPTH + 1
+    ))
+    .unwrap();
+    let result = process.add_program(&program);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_long_import_chain_with_calls() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+    program test0.aleo;
+    function c:",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program);
+
+    // Check that the number of calls, up to `Transaction::MAX_TRANSITIONS - 1`, is correct.
+    for i in 1..(Transaction::<CurrentNetwork>::MAX_TRANSITIONS - 1) {
+        println!("Adding program {}", i);
+        // Initialize a new program.
+        let program = Program::from_str(&format!(
+            "
+        import test{}.aleo;
+        program test{}.aleo;
+        function c:
+            call test{}.aleo/c;",
+            i - 1,
+            i,
+            i - 1
         ))
         .unwrap();
-        // Add the program to the process.
-        process.add_program(&program).unwrap();
-    }
+        // Add the program to the process.
+        process.add_program(&program).unwrap();
+        // Check that the number of calls is correct.
+        let stack = process.get_stack(program.id()).unwrap();
+        let number_of_calls = stack.get_number_of_calls(program.functions().into_iter().next().unwrap().0).unwrap();
+        assert_eq!(number_of_calls, i + 1);
+    }
+
+    // Check that `Transaction::MAX_TRANSITIONS - 1`-th call fails.
+    let program = Program::from_str(&format!(
+        "
+        import test{}.aleo;
+        program test{}.aleo;
+        function c:
+            call test{}.aleo/c;",
+        Transaction::<CurrentNetwork>::MAX_TRANSITIONS - 2,
+        Transaction::<CurrentNetwork>::MAX_TRANSITIONS - 1,
+        Transaction::<CurrentNetwork>::MAX_TRANSITIONS - 2
+    ))
+    .unwrap();
+    let result = process.add_program(&program);
+    assert!(result.is_err())
+}
+
+#[test]
+fn test_max_imports() {
+    // Construct the process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Add `MAX_IMPORTS` programs to the process.
+    for i in 0..CurrentNetwork::MAX_IMPORTS {
+        println!("Adding program {i}");
+        // Initialize a new program.
+        let program = Program::from_str(&format!("program test{i}.aleo; function c:")).unwrap();
+        // Add the program to the process.
+        process.add_program(&program).unwrap();
+    }
+
+    // Add a program importing all `MAX_IMPORTS` programs, which should pass.
+    let import_string =
+        (0..CurrentNetwork::MAX_IMPORTS).map(|i| format!("import test{}.aleo;", i)).collect::<Vec<_>>().join(" ");
+    let program =
+        Program::from_str(&format!("{import_string}program test{}.aleo; function c:", CurrentNetwork::MAX_IMPORTS))
+            .unwrap();
+    process.add_program(&program).unwrap();
+
+    // Attempt to construct a program importing `MAX_IMPORTS + 1` programs, which should fail.
+    let import_string =
+        (0..CurrentNetwork::MAX_IMPORTS + 1).map(|i| format!("import test{}.aleo;", i)).collect::<Vec<_>>().join(" ");
+    let result = Program::<CurrentNetwork>::from_str(&format!(
+        "{import_string}program test{}.aleo; function c:",
+        CurrentNetwork::MAX_IMPORTS + 1
+    ));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_program_exceeding_transaction_spend_limit() {
+    // Construct a finalize body whose finalize cost is excessively large.
+    let mut finalize_body = r"
+    cast  0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 into r0 as [u8; 16u32];
+    cast  r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 into r1 as [[u8; 16u32]; 16u32];
+    cast  r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 into r2 as [[[u8; 16u32]; 16u32]; 16u32];"
+        .to_string();
+    (3..500).for_each(|i| {
+        finalize_body.push_str(&format!("hash.bhp256 r2 into r{i} as field;\n"));
+    });
+    // Construct the program.
+    let program = Program::from_str(&format!(
+        r"program test_max_spend_limit.aleo;
+      function foo:
+      async foo into r0;
+      output r0 as test_max_spend_limit.aleo/foo.future;
+      finalize foo:{finalize_body}",
+    ))
+    .unwrap();
+
+    // Initialize a `Process`.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Attempt to add the program to the process, which should fail.
+    let result = process.add_program(&program);
+    assert!(result.is_err());
+
+    // Attempt to initialize a `Stack` directly with the program, which should fail.
+    let result = Stack::initialize(&process, &program, CurrentNetwork::EDITION);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_strings() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r#"
+program testing.aleo;
+
+mapping lengths:
+    key as string.public;
+    value as u32.public;
+
+function greet:
+    str.concat "hello" " world" into r0;
+    str.len r0 into r1;
+    cast r0 into r2 as [u8; 11u32];
+    cast r2 into r3 as string;
+    assert.eq r0 r3;
+    async greet r0 into r4;
+    output r0 as string.public;
+    output r1 as u32.public;
+    output r2 as [u8; 11u32].public;
+    output r4 as testing.aleo/greet.future;
+
+finalize greet:
+    input r0 as string.public;
+    str.len r0 into r1;
+    cast r0 into r2 as [u8; 11u32];
+    cast r2 into r3 as string;
+    str.concat r3 "!" into r4;
+    set r1 into lengths[r4];
+
+function mismatch:
+    async mismatch "hello world" into r0;
+    output r0 as testing.aleo/mismatch.future;
+
+finalize mismatch:
+    input r0 as string.public;
+    cast r0 into r1 as [u8; 5u32];
+    set 5u32 into lengths[r0];
+"#,
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("greet").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs: [Value<CurrentNetwork>; 0] = [];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Check the outputs.
+    assert_eq!(response.outputs()[0], Value::from_str("\"hello world\"").unwrap());
+    assert_eq!(response.outputs()[1], Value::from_str("11u32").unwrap());
+    assert_eq!(
+        response.outputs()[2],
+        Value::from_str("[104u8, 101u8, 108u8, 108u8, 111u8, 32u8, 119u8, 111u8, 114u8, 108u8, 100u8]").unwrap()
+    );
+
+    // Prepare the trace.
+    trace.prepare(Query::from(&block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the length is stored under the concatenated string.
+    let value = finalize_store
+        .get_value_speculative(
+            *program_id,
+            Identifier::from_str("lengths").unwrap(),
+            &Plaintext::from_str("\"hello world!\"").unwrap(),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(value, Value::from_str("11u32").unwrap());
+
+    // Authorize a function call, whose string does not match the length of the array in finalize.
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "mismatch", inputs.iter(), rng).unwrap();
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(&block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+    // Ensure the finalize fails.
+    assert!(process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_merkle_verify() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program airdrop.aleo;
+
+mapping claimed:
+    key as u64.public;
+    value as boolean.public;
+
+function claim:
+    input r0 as field.public;
+    input r1 as [field; 2u32].public;
+    input r2 as u64.public;
+    input r3 as [field; 2u32].public;
+    merkle.verify.psd r0 r1 r2 r3 into r4;
+    async claim r0 r1 r2 r3 into r5;
+    output r4 as boolean.public;
+    output r5 as airdrop.aleo/claim.future;
+
+finalize claim:
+    input r0 as field.public;
+    input r1 as [field; 2u32].public;
+    input r2 as u64.public;
+    input r3 as [field; 2u32].public;
+    merkle.verify.psd r0 r1 r2 r3 into r4;
+    assert.eq r4 true;
+    set true into claimed[r2];",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct a Merkle tree of depth 2, whose leaves are pairs of field elements.
+    let leaves = (0..4).map(|_| vec![Field::rand(rng), Field::rand(rng)]).collect::<Vec<_>>();
+    let tree = CurrentNetwork::merkle_tree_psd::<2>(&leaves).unwrap();
+    let root = Value::<CurrentNetwork>::from_str(&tree.root().to_string()).unwrap();
+
+    // Returns the inputs to claim the leaf at the given index, using the Merkle path for the given path index.
+    let claim_inputs = |leaf_index: usize, path_index: usize| {
+        let path = tree.prove(path_index, &leaves[path_index]).unwrap();
+        let leaf = format!("[{}, {}]", leaves[leaf_index][0], leaves[leaf_index][1]);
+        let siblings =
+            format!("[{}]", path.siblings().iter().map(|sibling| sibling.to_string()).collect::<Vec<_>>().join(", "));
+        vec![
+            root.clone(),
+            Value::from_str(&leaf).unwrap(),
+            Value::from_str(&format!("{}u64", path_index)).unwrap(),
+            Value::from_str(&siblings).unwrap(),
+        ]
+    };
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Executes `claim` with the given inputs, returning the verification output and the execution.
+    let mut execute = |inputs: Vec<Value<CurrentNetwork>>| {
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "claim", inputs.iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(&block_store)).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("airdrop", rng).unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+        (response.outputs()[0].clone(), execution)
+    };
+
+    // Claim a leaf with a valid Merkle path.
+    let (output, execution) = execute(claim_inputs(3, 3));
+    assert_eq!(output, Value::from_str("true").unwrap());
+    // Finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the leaf is marked as claimed.
+    let value = finalize_store
+        .get_value_speculative(
+            *program.id(),
+            Identifier::from_str("claimed").unwrap(),
+            &Plaintext::from_str("3u64").unwrap(),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(value, Value::from_str("true").unwrap());
+
+    // Claim a leaf with the Merkle path of a different leaf.
+    let (output, execution) = execute(claim_inputs(3, 1));
+    assert_eq!(output, Value::from_str("false").unwrap());
+    // Ensure the finalize fails.
+    assert!(process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).is_err());
+}
+
+#[test]
+fn test_process_execute_options() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program options.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+function fallback:
+    input r0 as option<u64>.private;
+    input r1 as u64.private;
+    is.some r0 into r2;
+    unwrap.or r0 r1 into r3;
+    some r3 into r4;
+    none r1 into r5;
+    output r2 as boolean.private;
+    output r3 as u64.private;
+    output r4 as option<u64>.private;
+    output r5 as option<u64>.private;
+
+function strict:
+    input r0 as option<point>.private;
+    unwrap r0 into r1;
+    output r1.x as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Ensure the program round-trips through bytes and strings.
+    assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le().unwrap()).unwrap());
+    assert_eq!(program, Program::from_str(&program.to_string()).unwrap());
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Checks that the given function evaluates and executes to the expected outputs.
+    let mut check = |function_name: &str, inputs: &[&str], expected: &[&str]| {
+        let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap()).collect::<Vec<_>>();
+        let expected = expected.iter().map(|output| Value::from_str(output).unwrap()).collect::<Vec<_>>();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+
+        // Compute the output values.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(expected, response.outputs());
+
+        // Execute the request.
+        let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(expected, response.outputs());
+    };
+
+    check("fallback", &["some(5u64)", "7u64"], &["true", "5u64", "some(5u64)", "none(0u64)"]);
+    check("fallback", &["none(0u64)", "7u64"], &["false", "7u64", "some(7u64)", "none(0u64)"]);
+    check("strict", &["some({ x: 1field, y: 2field })"], &["1field"]);
+
+    // Ensure unwrapping a `none` option fails.
+    let inputs = [Value::<CurrentNetwork>::from_str("none({ x: 0field, y: 0field })").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "strict", inputs.iter(), rng).unwrap();
+    assert!(process.evaluate::<CurrentAleo>(authorization).is_err());
+
+    // Ensure a `none` option must contain the placeholder for its type.
+    assert!(Value::<CurrentNetwork>::from_str("none(3u64)").is_err());
+}
+
+#[test]
+fn test_process_execute_group_operations() {
+    use console::types::Scalar;
+
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program groups.aleo;
+
+function combine:
+    input r0 as scalar.private;
+    input r1 as scalar.private;
+    group.gen r0 into r2;
+    group.gen r1 into r3;
+    cast r2 r3 into r4 as [group; 2u32];
+    cast r0 r1 into r5 as [scalar; 2u32];
+    msm r4 r5 into r6;
+    hash_to_group.psd2 r0 into r7;
+    hash_to_group.psd4 r5 into r8;
+    output r6 as group.private;
+    output r7 as group.private;
+    output r8 as group.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Ensure the program round-trips through bytes and strings.
+    assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le().unwrap()).unwrap());
+    assert_eq!(program, Program::from_str(&program.to_string()).unwrap());
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Prepare the inputs.
+    let first = Scalar::<CurrentNetwork>::rand(rng);
+    let second = Scalar::<CurrentNetwork>::rand(rng);
+    let inputs =
+        [Value::<CurrentNetwork>::from_str(&first.to_string()).unwrap(), Value::from_str(&second.to_string()).unwrap()];
+
+    // Compute the expected outputs.
+    let expected_msm = CurrentNetwork::g_scalar_multiply(&(first * first + second * second));
+    let expected_psd2 = match &inputs[0] {
+        Value::Plaintext(plaintext) => CurrentNetwork::hash_to_group_psd2(&plaintext.to_fields().unwrap()).unwrap(),
+        _ => unreachable!("The input is a plaintext"),
+    };
+    let array = Plaintext::<CurrentNetwork>::from_str(&format!("[{first}, {second}]")).unwrap();
+    let expected_psd4 = CurrentNetwork::hash_to_group_psd4(&array.to_fields().unwrap()).unwrap();
+    let expected = [expected_msm, expected_psd2, expected_psd4]
+        .into_iter()
+        .map(|group| Value::Plaintext(Plaintext::from(Literal::Group(group))))
+        .collect::<Vec<_>>();
+
+    // Authorize the function call.
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "combine", inputs.iter(), rng).unwrap();
+
+    // Compute the output values.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(expected, response.outputs());
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(expected, response.outputs());
+}
+
+#[test]
+fn test_process_execute_and_finalize_constants() {
+    // Initialize a program that uses constants in a closure, function, and finalize scope.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program constants.aleo;
+
+constant FEE as u64 = 5u64;
+
+constant LIMIT as u64 = 100u64;
+
+struct receipt:
+    amount as u64;
+    fee as u64;
+
+mapping totals:
+    key as boolean.public;
+    value as u64.public;
+
+closure charge:
+    input r0 as u64;
+    sub r0 FEE into r1;
+    output r1 as u64;
+
+function pay:
+    input r0 as u64.public;
+    lte r0 LIMIT into r1;
+    assert.eq r1 true;
+    call charge r0 into r2;
+    cast r2 FEE into r3 as receipt;
+    async pay r2 into r4;
+    output r3 as receipt.private;
+    output FEE as u64.public;
+    output r4 as constants.aleo/pay.future;
+
+finalize pay:
+    input r0 as u64.public;
+    get.or_use totals[true] 0u64 into r1;
+    add r1 r0 into r2;
+    add r2 FEE into r3;
+    set r3 into totals[true];
+",
+    )
+    .unwrap();
+
+    // Ensure the program round-trips through bytes and strings.
+    assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le().unwrap()).unwrap());
+    assert_eq!(program, Program::from_str(&program.to_string()).unwrap());
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Ensure a program that uses an undefined constant cannot be deployed.
+    let undefined = Program::<CurrentNetwork>::from_str(
+        &program
+            .to_string()
+            .replace("program constants.aleo;", "program undefined.aleo;")
+            .replace("sub r0 FEE into r1;", "sub r0 UNKNOWN into r1;"),
+    )
+    .unwrap();
+    assert!(process.deploy::<CurrentAleo, _>(&undefined, rng).is_err());
+
+    // Ensure a program that uses a constant of the wrong type cannot be deployed.
+    let mismatched = Program::<CurrentNetwork>::from_str(
+        &program
+            .to_string()
+            .replace("program constants.aleo;", "program mismatched.aleo;")
+            .replace("constant LIMIT as u64 = 100u64;", "constant LIMIT as u32 = 100u32;"),
+    )
+    .unwrap();
+    assert!(process.deploy::<CurrentAleo, _>(&mismatched, rng).is_err());
+
+    // Deploy the program.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("50u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, "constants.aleo", "pay", inputs.iter(), rng).unwrap();
+
+    // Execute the request, and ensure the constants are substituted in the outputs.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs()[0], Value::from_str("{ amount: 45u64, fee: 5u64 }").unwrap());
+    assert_eq!(response.outputs()[1], Value::from_str("5u64").unwrap());
+
+    // Prove, verify, and finalize the execution.
+    trace.prepare(Query::from(&block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("pay", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the total includes the constant fee.
+    let value = finalize_store
+        .get_value_speculative(
+            ProgramID::from_str("constants.aleo").unwrap(),
+            Identifier::from_str("totals").unwrap(),
+            &Plaintext::from(Literal::Boolean(console::types::Boolean::new(true))),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(value, Value::from_str("50u64").unwrap());
+
+    // Ensure the constant limit is enforced.
+    let inputs = [Value::<CurrentNetwork>::from_str("200u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, "constants.aleo", "pay", inputs.iter(), rng).unwrap();
+    assert!(process.evaluate::<CurrentAleo>(authorization).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_external_mapping_reads() {
+    // Initialize the program that owns the mapping.
+    let token = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function mint:
+    input r0 as u64.public;
+    async mint self.caller r0 into r1;
+    output r1 as token.aleo/mint.future;
+
+finalize mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    set r1 into balances[r0];
+",
+    )
+    .unwrap();
+
+    // Initialize the program that reads the external mapping.
+    let reader = Program::<CurrentNetwork>::from_str(
+        r"
+import token.aleo;
+
+import mapping token.aleo/balances:
+    key as address.public;
+    value as u64.public;
+
+program reader.aleo;
+
+mapping totals:
+    key as address.public;
+    value as u64.public;
+
+function check:
+    async check self.caller into r0;
+    output r0 as reader.aleo/check.future;
+
+finalize check:
+    input r0 as address.public;
+    get token.aleo/balances[r0] into r1;
+    get.or_use token.aleo/balances[token.aleo] 5u64 into r2;
+    add r1 r2 into r3;
+    set r3 into totals[r0];
+",
+    )
+    .unwrap();
 
-    // Add the `MAX_PROGRAM_DEPTH + 1` program to the process, which should fail.
-    let program = Program::from_str(&format!(
-        "
-        import test{}.aleo;
-        program test{}.aleo;
-        function c:",
-        CurrentNetwork::MAX_PROGRAM_DEPTH,
-        CurrentNetwork::MAX_PROGRAM_DEPTH + 1
-    ))
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Ensure the reader cannot be deployed before the program it imports.
+    assert!(process.deploy::<CurrentAleo, _>(&reader, rng).is_err());
+
+    // Deploy the token program.
+    let deployment = process.deploy::<CurrentAleo, _>(&token, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Ensure a reader that declares the wrong value type cannot be deployed.
+    let mismatched = Program::<CurrentNetwork>::from_str(
+        &reader.to_string().replace("program reader.aleo;", "program mismatched.aleo;").replacen(
+            "value as u64.public;",
+            "value as u128.public;",
+            1,
+        ),
+    )
     .unwrap();
-    let result = process.add_program(&program);
-    assert!(result.is_err());
+    let error = process.deploy::<CurrentAleo, _>(&mismatched, rng).unwrap_err();
+    assert!(error.to_string().contains("declares value type 'u128'"), "{error}");
+
+    // Deploy the reader program.
+    let deployment = process.deploy::<CurrentAleo, _>(&reader, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // A helper to execute and finalize a function.
+    let mut execute = |program_id: &str, function_name: &str, inputs: &[Value<CurrentNetwork>]| {
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+            .unwrap();
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        trace.prepare(Query::from(&block_store)).unwrap();
+        let execution = trace.prove_execution::<CurrentAleo, _>(function_name, rng).unwrap();
+        process.verify_execution(&execution).unwrap();
+        process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None)
+    };
+
+    // Ensure the read fails before the caller has a balance.
+    assert!(execute("reader.aleo", "check", &[]).is_err());
+
+    // Mint a balance for the caller.
+    execute("token.aleo", "mint", &[Value::from_str("100u64").unwrap()]).unwrap();
+    // Read the balance from the reader program.
+    execute("reader.aleo", "check", &[]).unwrap();
+
+    // Check that the total includes the caller's balance and the default value.
+    let value = finalize_store
+        .get_value_speculative(
+            ProgramID::from_str("reader.aleo").unwrap(),
+            Identifier::from_str("totals").unwrap(),
+            &Plaintext::from(Literal::Address(caller)),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(value, Value::from_str("105u64").unwrap());
 }
 
 #[test]
-fn test_long_import_chain_with_calls() {
-    // Initialize a new program.
+fn test_process_evaluate_view() {
+    // Initialize a program with views.
     let program = Program::<CurrentNetwork>::from_str(
         r"
-    program test0.aleo;
-    function c:",
+program viewer.aleo;
+
+constant THRESHOLD as u64 = 10u64;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function noop:
+    input r0 as u64.public;
+    output r0 as u64.public;
+
+view balance_of:
+    input r0 as address.public;
+    get.or_use balances[r0] 0u64 into r1;
+    gte r1 THRESHOLD into r2;
+    branch.eq r2 false to end;
+    add r1 1u64 into r3;
+    position end;
+    output r1 as u64.public;
+    output r2 as boolean.public;
+",
     )
     .unwrap();
 
-    // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program);
+    // Reset the process.
+    let mut process = Process::load().unwrap();
 
-    // Check that the number of calls, up to `Transaction::MAX_TRANSITIONS - 1`, is correct.
-    for i in 1..(Transaction::<CurrentNetwork>::MAX_TRANSITIONS - 1) {
-        println!("Adding program {}", i);
-        // Initialize a new program.
-        let program = Program::from_str(&format!(
-            "
-        import test{}.aleo;
-        program test{}.aleo;
-        function c:
-            call test{}.aleo/c;",
-            i - 1,
-            i,
-            i - 1
-        ))
+    // Ensure a view that reads an undefined mapping cannot be added.
+    let undefined = program.to_string().replace("get.or_use balances[r0]", "get.or_use unknown[r0]");
+    assert!(process.add_program(&Program::from_str(&undefined).unwrap()).is_err());
+    // Ensure a view with a mismatched output type cannot be added.
+    let mismatched = program.to_string().replace("output r2 as boolean.public;", "output r2 as u64.public;");
+    assert!(process.add_program(&Program::from_str(&mismatched).unwrap()).is_err());
+    // Ensure a view that samples randomness cannot be added.
+    let random = program.to_string().replace("position end;", "position end;\n    rand.chacha into r4 as u64;");
+    assert!(process.add_program(&Program::from_str(&random).unwrap()).is_err());
+
+    // Add the program.
+    process.add_program(&program).unwrap();
+
+    // Initialize a new finalize store, and populate the mapping.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+    let program_id = ProgramID::from_str("viewer.aleo").unwrap();
+    let mapping_name = Identifier::from_str("balances").unwrap();
+    let address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut TestRng::default()).unwrap()).unwrap();
+    finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+    finalize_store
+        .insert_key_value(
+            program_id,
+            mapping_name,
+            Plaintext::from(Literal::Address(address)),
+            Value::from_str("25u64").unwrap(),
+        )
         .unwrap();
-        // Add the program to the process.
-        process.add_program(&program).unwrap();
-        // Check that the number of calls is correct.
-        let stack = process.get_stack(program.id()).unwrap();
-        let number_of_calls = stack.get_number_of_calls(program.functions().into_iter().next().unwrap().0).unwrap();
-        assert_eq!(number_of_calls, i + 1);
-    }
 
-    // Check that `Transaction::MAX_TRANSITIONS - 1`-th call fails.
-    let program = Program::from_str(&format!(
-        "
-        import test{}.aleo;
-        program test{}.aleo;
-        function c:
-            call test{}.aleo/c;",
-        Transaction::<CurrentNetwork>::MAX_TRANSITIONS - 2,
-        Transaction::<CurrentNetwork>::MAX_TRANSITIONS - 1,
-        Transaction::<CurrentNetwork>::MAX_TRANSITIONS - 2
-    ))
-    .unwrap();
-    let result = process.add_program(&program);
-    assert!(result.is_err())
+    // Evaluate the view.
+    let state = sample_finalize_state(1);
+    let view_name = Identifier::from_str("balance_of").unwrap();
+    let inputs = [Value::from(Literal::Address(address))];
+    let outputs = process.evaluate_view(state, &finalize_store, &program_id, &view_name, &inputs).unwrap();
+    assert_eq!(outputs, vec![Value::from_str("25u64").unwrap(), Value::from_str("true").unwrap()]);
+
+    // Ensure the view does not modify the finalize store.
+    assert_eq!(finalize_store.get_mapping_confirmed(program_id, mapping_name).unwrap().len(), 1);
+
+    // Ensure the view fails with an input of the wrong type.
+    let inputs = [Value::from_str("25u64").unwrap()];
+    assert!(process.evaluate_view(state, &finalize_store, &program_id, &view_name, &inputs).is_err());
+    // Ensure the view fails with the wrong number of inputs.
+    assert!(process.evaluate_view(state, &finalize_store, &program_id, &view_name, &[]).is_err());
 }
 
 #[test]
-fn test_max_imports() {
+fn test_process_execute_checked_arithmetic() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program checked.aleo;
+
+function arithmetic:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add.checked r0 r1 into r2 r3;
+    sub.checked r0 r1 into r4 r5;
+    mul.checked r0 r1 into r6 r7;
+    output r2 as u8.private;
+    output r3 as boolean.private;
+    output r4 as u8.private;
+    output r5 as boolean.private;
+    output r6 as u8.private;
+    output r7 as boolean.private;
+
+function saturate:
+    input r0 as i8.private;
+    input r1 as i8.private;
+    add.checked r0 r1 into r2 r3;
+    lt r0 0i8 into r4;
+    ternary r4 -128i8 127i8 into r5;
+    ternary r3 r5 r2 into r6;
+    output r6 as i8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Ensure the program round-trips through bytes and strings.
+    assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le().unwrap()).unwrap());
+    assert_eq!(program, Program::from_str(&program.to_string()).unwrap());
+
+    // Ensure a program with mismatched operand types cannot be added.
+    let mut process = Process::load().unwrap();
+    let mismatched = program.to_string().replace("input r1 as u8.private;", "input r1 as u16.private;");
+    assert!(process.add_program(&Program::from_str(&mismatched).unwrap()).is_err());
+    // Ensure a program with a non-integer operand cannot be added.
+    let field = program.to_string().replace("as i8.private;", "as field.private;");
+    assert!(process.add_program(&Program::from_str(&field).unwrap()).is_err());
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
     // Construct the process.
-    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    let process = crate::test_helpers::sample_process(&program);
 
-    // Add `MAX_IMPORTS` programs to the process.
-    for i in 0..CurrentNetwork::MAX_IMPORTS {
-        println!("Adding program {i}");
-        // Initialize a new program.
-        let program = Program::from_str(&format!("program test{i}.aleo; function c:")).unwrap();
-        // Add the program to the process.
-        process.add_program(&program).unwrap();
-    }
+    // Checks that the given function evaluates and executes to the expected outputs.
+    let mut check = |function_name: &str, inputs: &[&str], expected: &[&str]| {
+        let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap()).collect::<Vec<_>>();
+        let expected = expected.iter().map(|output| Value::from_str(output).unwrap()).collect::<Vec<_>>();
 
-    // Add a program importing all `MAX_IMPORTS` programs, which should pass.
-    let import_string =
-        (0..CurrentNetwork::MAX_IMPORTS).map(|i| format!("import test{}.aleo;", i)).collect::<Vec<_>>().join(" ");
-    let program =
-        Program::from_str(&format!("{import_string}program test{}.aleo; function c:", CurrentNetwork::MAX_IMPORTS))
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
             .unwrap();
-    process.add_program(&program).unwrap();
 
-    // Attempt to construct a program importing `MAX_IMPORTS + 1` programs, which should fail.
-    let import_string =
-        (0..CurrentNetwork::MAX_IMPORTS + 1).map(|i| format!("import test{}.aleo;", i)).collect::<Vec<_>>().join(" ");
-    let result = Program::<CurrentNetwork>::from_str(&format!(
-        "{import_string}program test{}.aleo; function c:",
-        CurrentNetwork::MAX_IMPORTS + 1
-    ));
-    assert!(result.is_err());
+        // Compute the output values.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(expected, response.outputs());
+
+        // Execute the request.
+        let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(expected, response.outputs());
+    };
+
+    // Ensure the results are exact when no overflow occurs.
+    check("arithmetic", &["7u8", "3u8"], &["10u8", "false", "4u8", "false", "21u8", "false"]);
+    // Ensure an overflow wraps the result and sets the flag, instead of halting.
+    check("arithmetic", &["200u8", "100u8"], &["44u8", "true", "100u8", "false", "32u8", "true"]);
+    check("arithmetic", &["3u8", "7u8"], &["10u8", "false", "252u8", "true", "21u8", "false"]);
+    // Ensure the flag can be used to recover from an overflow.
+    check("saturate", &["100i8", "100i8"], &["127i8"]);
+    check("saturate", &["-100i8", "-100i8"], &["-128i8"]);
+    check("saturate", &["-100i8", "50i8"], &["-50i8"]);
 }
 
 #[test]
-fn test_program_exceeding_transaction_spend_limit() {
-    // Construct a finalize body whose finalize cost is excessively large.
-    let mut finalize_body = r"
-    cast  0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 0u8 into r0 as [u8; 16u32];
-    cast  r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 into r1 as [[u8; 16u32]; 16u32];
-    cast  r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 into r2 as [[[u8; 16u32]; 16u32]; 16u32];"
-        .to_string();
-    (3..500).for_each(|i| {
-        finalize_body.push_str(&format!("hash.bhp256 r2 into r{i} as field;\n"));
-    });
-    // Construct the program.
-    let program = Program::from_str(&format!(
-        r"program test_max_spend_limit.aleo;
-      function foo:
-      async foo into r0;
-      output r0 as test_max_spend_limit.aleo/foo.future;
-      finalize foo:{finalize_body}",
-    ))
+fn test_process_execute_and_finalize_assert_with_code() {
+    // Initialize a program that asserts with failure codes in a function and finalize scope.
+    let program = Program::<CurrentNetwork>::from_str(
+        r#"
+program assert_code.aleo;
+
+mapping balances:
+    key as boolean.public;
+    value as u64.public;
+
+function withdraw:
+    input r0 as u64.public;
+    lte r0 100u64 into r1;
+    assert r1 "E_LIMIT_EXCEEDED";
+    async withdraw r0 into r2;
+    output r2 as assert_code.aleo/withdraw.future;
+
+finalize withdraw:
+    input r0 as u64.public;
+    get.or_use balances[true] 10u64 into r1;
+    gte r1 r0 into r2;
+    assert r2 "E_INSUFFICIENT_BALANCE";
+    sub r1 r0 into r3;
+    set r3 into balances[true];
+"#,
+    )
     .unwrap();
 
-    // Initialize a `Process`.
-    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    // Ensure the program round-trips through bytes and strings.
+    assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le().unwrap()).unwrap());
+    assert_eq!(program, Program::from_str(&program.to_string()).unwrap());
 
-    // Attempt to add the program to the process, which should fail.
-    let result = process.add_program(&program);
-    assert!(result.is_err());
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
 
-    // Attempt to initialize a `Stack` directly with the program, which should fail.
-    let result = Stack::initialize(&process, &program);
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Ensure a program that asserts on a non-boolean cannot be deployed.
+    let non_boolean = Program::<CurrentNetwork>::from_str(
+        &program
+            .to_string()
+            .replace("program assert_code.aleo;", "program non_boolean.aleo;")
+            .replace("assert r1 \"E_LIMIT_EXCEEDED\";", "assert r0 \"E_LIMIT_EXCEEDED\";"),
+    )
+    .unwrap();
+    assert!(process.deploy::<CurrentAleo, _>(&non_boolean, rng).is_err());
+
+    // Deploy the program.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Ensure the failure code is surfaced when the function assertion fails.
+    let inputs = [Value::<CurrentNetwork>::from_str("200u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, "assert_code.aleo", "withdraw", inputs.iter(), rng)
+        .unwrap();
+    let error = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap_err();
+    assert!(error.to_string().contains("E_LIMIT_EXCEEDED"), "Unexpected error: {error}");
+    let error = process.execute::<CurrentAleo, _>(authorization, rng).unwrap_err();
+    assert!(error.to_string().contains("E_LIMIT_EXCEEDED"), "Unexpected error: {error}");
+
+    // Execute a request that passes the function assertion, but fails the finalize assertion.
+    let inputs = [Value::<CurrentNetwork>::from_str("50u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, "assert_code.aleo", "withdraw", inputs.iter(), rng)
+        .unwrap();
+    let (_response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Prove and verify the execution.
+    trace.prepare(Query::from(&block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("withdraw", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure the failure code is surfaced when the finalize assertion fails.
+    let error = process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap_err();
+    assert!(error.to_string().contains("E_INSUFFICIENT_BALANCE"), "Unexpected error: {error}");
+
+    // Ensure the rejection reason points to the failing command.
+    let reason = error.downcast_ref::<FinalizeError<CurrentNetwork>>().unwrap().reason();
+    assert_eq!(reason.program_id(), &ProgramID::from_str("assert_code.aleo").unwrap());
+    assert_eq!(reason.function_name(), &Identifier::from_str("withdraw").unwrap());
+    assert_eq!(reason.command_index(), 2);
+    assert_eq!(reason.opcode(), "assert");
+    assert_eq!(reason.code(), Some("E_INSUFFICIENT_BALANCE"));
+}
+
+#[test]
+fn test_process_evaluate_and_finalize_with_debug_trace() {
+    // Initialize a program with a closure, a function, and a finalize scope.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program debug_trace.aleo;
+
+mapping balances:
+    key as boolean.public;
+    value as u64.public;
+
+closure double:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function deposit:
+    input r0 as u64.public;
+    call double r0 into r1;
+    assert.neq r1 20u64;
+    async deposit r1 into r2;
+    output r2 as debug_trace.aleo/deposit.future;
+
+finalize deposit:
+    input r0 as u64.public;
+    get.or_use balances[true] 10u64 into r1;
+    add r1 r0 into r2;
+    assert.eq r2 100u64;
+    set r2 into balances[true];
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the program.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let program_id = ProgramID::<CurrentNetwork>::from_str("debug_trace.aleo").unwrap();
+    let function_name = Identifier::<CurrentNetwork>::from_str("deposit").unwrap();
+    let value = |string: &str| Some(Value::<CurrentNetwork>::from_str(string).unwrap());
+
+    // Evaluate a request that fails the function assertion.
+    let inputs = [Value::<CurrentNetwork>::from_str("10u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+        .unwrap();
+    let (response, debug_trace) = process.evaluate_with_trace::<CurrentAleo>(authorization);
+    assert!(response.is_err());
+
+    // Ensure the steps are recorded in order, including the steps of the closure.
+    let steps = debug_trace.steps();
+    assert_eq!(steps.len(), 3);
+    assert_eq!(steps[0].scope, DebugScope::Function(function_name));
+    assert_eq!(steps[0].opcode, "call");
+    assert_eq!(steps[0].operands, vec![value("10u64")]);
+    assert_eq!(steps[1].scope, DebugScope::Closure(Identifier::from_str("double").unwrap()));
+    assert_eq!(steps[1].opcode, "add");
+    assert_eq!(steps[1].destinations.iter().map(|(_, value)| value.clone()).collect::<Vec<_>>(), vec![
+        value("20u64").unwrap()
+    ]);
+    assert_eq!(steps[0].destinations, steps[1].destinations);
+
+    // Ensure the failed step points to the diverging register.
+    let failed_step = debug_trace.failed_step().unwrap();
+    assert_eq!(failed_step.program_id, program_id);
+    assert_eq!(failed_step.index, 1);
+    assert_eq!(failed_step.opcode, "assert.neq");
+    assert_eq!(failed_step.operands, vec![value("20u64"), value("20u64")]);
+
+    // Evaluate a request that passes the function assertion.
+    let inputs = [Value::<CurrentNetwork>::from_str("30u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+        .unwrap();
+    let (response, debug_trace) = process.evaluate_with_trace::<CurrentAleo>(authorization.replicate());
+    assert_eq!(response.unwrap().outputs().len(), 1);
+    assert_eq!(debug_trace.steps().len(), 4);
+    assert!(debug_trace.failed_step().is_none());
+
+    // Execute the request, which will fail the finalize assertion.
+    let (_response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(&block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("deposit", rng).unwrap();
+
+    // Dry-run the finalize scope.
+    let (result, debug_trace) = process.finalize_dry_run(sample_finalize_state(1), &finalize_store, &execution);
     assert!(result.is_err());
+    let steps = debug_trace.steps();
+    assert_eq!(steps.len(), 3);
+    assert!(steps.iter().all(|step| step.scope == DebugScope::Finalize(function_name)));
+    assert_eq!(steps[0].opcode, "get.or_use");
+    assert_eq!(steps[1].operands, vec![value("10u64"), value("60u64")]);
+    let failed_step = debug_trace.failed_step().unwrap();
+    assert_eq!(failed_step.index, 2);
+    assert_eq!(failed_step.opcode, "assert.eq");
+    assert_eq!(failed_step.operands, vec![value("70u64"), value("100u64")]);
+
+    // Execute a request that passes the finalize assertion.
+    let inputs = [Value::<CurrentNetwork>::from_str("45u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+        .unwrap();
+    let (_response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(&block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("deposit", rng).unwrap();
+
+    // Dry-run the finalize scope, and ensure the changes are discarded.
+    let (result, debug_trace) = process.finalize_dry_run(sample_finalize_state(1), &finalize_store, &execution);
+    assert_eq!(result.unwrap().len(), 1);
+    assert_eq!(debug_trace.steps().len(), 4);
+    assert!(debug_trace.failed_step().is_none());
+    let key = Plaintext::from_str("true").unwrap();
+    let mapping_name = Identifier::from_str("balances").unwrap();
+    assert!(finalize_store.get_value_confirmed(program_id, mapping_name, &key).unwrap().is_none());
 }
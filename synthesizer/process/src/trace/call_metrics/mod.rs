@@ -17,7 +17,9 @@ use console::{
     program::{Identifier, ProgramID},
 };
 
-#[derive(Copy, Clone, Debug)]
+use std::fmt::Write;
+
+#[derive(Clone, Debug)]
 pub struct CallMetrics<N: Network> {
     pub program_id: ProgramID<N>,
     pub function_name: Identifier<N>,
@@ -25,4 +27,69 @@ pub struct CallMetrics<N: Network> {
     pub num_request_constraints: u64,
     pub num_function_constraints: u64,
     pub num_response_constraints: u64,
+    pub instructions: Vec<InstructionMetrics>,
+}
+
+/// The constraints and variables synthesized by a single instruction in a function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionMetrics {
+    /// The index of the instruction in the (unrolled) function.
+    pub index: usize,
+    /// The opcode of the instruction, including the operator for a `call` instruction.
+    pub opcode: String,
+    /// The number of constraints synthesized by the instruction, including any closure it calls.
+    pub num_constraints: u64,
+    /// The number of variables synthesized by the instruction, including any closure it calls.
+    pub num_variables: u64,
+    /// Whether the instruction is a function call, whose callee metrics were recorded before these metrics.
+    pub has_callee_metrics: bool,
+}
+
+impl<N: Network> CallMetrics<N> {
+    /// Returns the constraints of the given calls in the folded stack format used by flamegraph tools,
+    /// where each line is a `;`-separated stack of frames followed by its number of constraints.
+    ///
+    /// The call metrics must be in the order they were recorded, with each callee before its caller.
+    pub fn to_folded_stacks(call_metrics: &[Self]) -> String {
+        // Reconstruct the call tree, by attaching the most recent pending calls as the callees of each caller.
+        let mut calls = Vec::with_capacity(call_metrics.len());
+        let mut pending = Vec::new();
+        for metrics in call_metrics {
+            let num_callees = metrics.instructions.iter().filter(|instruction| instruction.has_callee_metrics).count();
+            let callees = pending.split_off(pending.len().saturating_sub(num_callees));
+            calls.push((metrics, callees));
+            pending.push(calls.len() - 1);
+        }
+
+        // Write the folded stacks of each root call.
+        let mut output = String::new();
+        for root in pending {
+            Self::write_folded_stacks(&mut output, "", root, &calls);
+        }
+        output
+    }
+
+    /// Writes the folded stacks of the call at the given position, and of its callees, under the given prefix.
+    fn write_folded_stacks(output: &mut String, prefix: &str, position: usize, calls: &[(&Self, Vec<usize>)]) {
+        let (metrics, callees) = &calls[position];
+        let prefix = format!("{prefix}{}/{}", metrics.program_id, metrics.function_name);
+        let mut callees = callees.iter();
+
+        // Write the constraints for verifying the request.
+        let _ = writeln!(output, "{prefix};request {}", metrics.num_request_constraints);
+        // Write the constraints of each instruction, followed by the constraints of its callee.
+        for instruction in &metrics.instructions {
+            let frame = format!("{prefix};{}:{}", instruction.index, instruction.opcode);
+            if instruction.num_constraints > 0 {
+                let _ = writeln!(output, "{frame} {}", instruction.num_constraints);
+            }
+            if instruction.has_callee_metrics {
+                if let Some(callee) = callees.next() {
+                    Self::write_folded_stacks(output, &format!("{frame};"), *callee, calls);
+                }
+            }
+        }
+        // Write the constraints for verifying the response.
+        let _ = writeln!(output, "{prefix};response {}", metrics.num_response_constraints);
+    }
 }
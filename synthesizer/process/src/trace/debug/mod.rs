@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID, Register, Value},
+};
+use synthesizer_program::{Operand, RegistersLoad, StackMatches, StackProgram};
+
+/// The scope in which a debug step was recorded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DebugScope<N: Network> {
+    /// A closure, with the given name.
+    Closure(Identifier<N>),
+    /// A function, with the given name.
+    Function(Identifier<N>),
+    /// A finalize scope, for the function with the given name.
+    Finalize(Identifier<N>),
+}
+
+/// A snapshot of the registers for a single instruction or command.
+#[derive(Clone, Debug)]
+pub struct DebugStep<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The scope of the instruction or command.
+    pub scope: DebugScope<N>,
+    /// The index of the instruction or command in the scope.
+    pub index: usize,
+    /// The opcode of the instruction or command.
+    pub opcode: String,
+    /// The operand values, or `None` if an operand could not be loaded.
+    pub operands: Vec<Option<Value<N>>>,
+    /// The destination registers and their values, after the step was evaluated.
+    pub destinations: Vec<(Register<N>, Value<N>)>,
+    /// The error message, if the step failed to evaluate.
+    pub error: Option<String>,
+}
+
+/// A structured trace of the instructions and commands evaluated in a call.
+#[derive(Clone, Debug, Default)]
+pub struct DebugTrace<N: Network> {
+    /// The list of steps, in the order they were started.
+    steps: Vec<DebugStep<N>>,
+}
+
+impl<N: Network> DebugTrace<N> {
+    /// Initializes a new debug trace.
+    pub const fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Returns the list of steps.
+    pub fn steps(&self) -> &[DebugStep<N>] {
+        &self.steps
+    }
+
+    /// Returns the innermost step that failed to evaluate, if any.
+    pub fn failed_step(&self) -> Option<&DebugStep<N>> {
+        self.steps.iter().rev().find(|step| step.error.is_some())
+    }
+}
+
+impl<N: Network> DebugTrace<N> {
+    /// Starts a step with the operand values of the given instruction or command,
+    /// and returns the position of the step in the trace.
+    pub(crate) fn start(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &impl RegistersLoad<N>,
+        scope: DebugScope<N>,
+        index: usize,
+        opcode: impl Display,
+        operands: &[Operand<N>],
+    ) -> usize {
+        // Load the operand values.
+        let operands = operands.iter().map(|operand| registers.load(stack, operand).ok()).collect();
+        // Append the step.
+        self.steps.push(DebugStep {
+            program_id: *stack.program_id(),
+            scope,
+            index,
+            opcode: opcode.to_string(),
+            operands,
+            destinations: Vec::new(),
+            error: None,
+        });
+        // Return the position of the step.
+        self.steps.len() - 1
+    }
+
+    /// Completes the step at the given position with the destination values, or the error.
+    pub(crate) fn complete(
+        &mut self,
+        position: usize,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &impl RegistersLoad<N>,
+        destinations: Vec<Register<N>>,
+        error: Option<&Error>,
+    ) {
+        if let Some(step) = self.steps.get_mut(position) {
+            // Load the destination values that were assigned.
+            step.destinations = destinations
+                .into_iter()
+                .filter_map(|register| {
+                    let value = registers.load(stack, &Operand::Register(register.clone())).ok()?;
+                    Some((register, value))
+                })
+                .collect();
+            // Record the error, if one occurred.
+            step.error = error.map(|error| error.to_string());
+        }
+    }
+
+    /// Records the given error on the last step, if the step has not already recorded an error.
+    pub(crate) fn fail_last(&mut self, error: &Error) {
+        if let Some(step) = self.steps.last_mut() {
+            step.error.get_or_insert_with(|| error.to_string());
+        }
+    }
+}
@@ -15,13 +15,18 @@
 mod call_metrics;
 pub use call_metrics::*;
 
+mod debug;
+pub use debug::*;
+
 mod inclusion;
 pub use inclusion::*;
 
+use crate::Authorization;
 use circuit::Assignment;
 use console::{
     network::prelude::*,
-    program::{InputID, Locator},
+    program::{InputID, Locator, ProgramID, Value},
+    types::Field,
 };
 use ledger_block::{Execution, Fee, Transition};
 use ledger_query::QueryTrait;
@@ -38,8 +43,12 @@ pub struct Trace<N: Network> {
     transition_tasks: HashMap<Locator<N>, (ProvingKey<N>, Vec<Assignment<N::Field>>)>,
     /// A tracker for all inclusion tasks.
     inclusion_tasks: Inclusion<N>,
+    /// A list of input IDs, for each transition.
+    input_ids: Vec<Vec<InputID<N>>>,
     /// A list of call metrics.
     call_metrics: Vec<CallMetrics<N>>,
+    /// A list of function calls, whose synthesis is deferred until the caller completes.
+    deferred_calls: Vec<DeferredCall<N>>,
 
     /// A tracker for the inclusion assignments.
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
@@ -54,9 +63,11 @@ impl<N: Network> Trace<N> {
             transitions: Vec::new(),
             transition_tasks: HashMap::new(),
             inclusion_tasks: Inclusion::new(),
+            input_ids: Vec::new(),
             inclusion_assignments: OnceCell::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
+            deferred_calls: Vec::new(),
         }
     }
 
@@ -93,11 +104,75 @@ impl<N: Network> Trace<N> {
         self.transition_tasks.entry(locator).or_insert((proving_key, vec![])).1.push(assignment);
         // Insert the transition into the list.
         self.transitions.push(transition.clone());
+        // Insert the input IDs into the list.
+        self.input_ids.push(input_ids.to_vec());
         // Insert the call metrics into the list.
         self.call_metrics.push(metrics);
 
         Ok(())
     }
+
+    /// Appends the transitions of the given trace into this trace, in order.
+    pub(crate) fn append(&mut self, trace: Trace<N>) -> Result<()> {
+        // Ensure the given trace does not have pending deferred calls.
+        ensure!(trace.deferred_calls.is_empty(), "Cannot append a trace with pending deferred calls");
+
+        // Retrieve the assignments for each locator, in the order they were inserted.
+        let mut transition_tasks = trace
+            .transition_tasks
+            .into_iter()
+            .map(|(locator, (proving_key, assignments))| (locator, (proving_key, assignments.into_iter())))
+            .collect::<HashMap<_, _>>();
+
+        // Insert each transition, with its input IDs, assignment, and call metrics.
+        for ((transition, input_ids), metrics) in
+            trace.transitions.iter().zip_eq(&trace.input_ids).zip_eq(trace.call_metrics)
+        {
+            // Construct the locator.
+            let locator = Locator::new(*transition.program_id(), *transition.function_name());
+            // Retrieve the proving key and the next assignment, for the specified locator.
+            let (proving_key, assignment) = match transition_tasks.get_mut(&locator) {
+                Some((proving_key, assignments)) => match assignments.next() {
+                    Some(assignment) => (proving_key.clone(), assignment),
+                    None => bail!("Missing an assignment for '{locator}' in the trace"),
+                },
+                None => bail!("Missing the transition task for '{locator}' in the trace"),
+            };
+            // Insert the transition.
+            self.insert_transition(input_ids, transition, (proving_key, assignment), metrics)?;
+        }
+        Ok(())
+    }
+}
+
+/// A function call, whose synthesis is deferred so it may be synthesized concurrently with its siblings.
+#[derive(Clone, Debug)]
+pub(crate) struct DeferredCall<N: Network> {
+    /// The authorization, containing the request of the call and the requests of its nested calls.
+    pub(crate) authorization: Authorization<N>,
+    /// The program ID of the caller.
+    pub(crate) console_caller: Option<ProgramID<N>>,
+    /// The transition view key of the root request.
+    pub(crate) root_tvk: Option<Field<N>>,
+    /// The outputs of the call, as computed during evaluation.
+    pub(crate) outputs: Vec<Value<N>>,
+}
+
+impl<N: Network> Trace<N> {
+    /// Defers the synthesis of the given function call, until its caller completes.
+    pub(crate) fn defer_call(&mut self, deferred_call: DeferredCall<N>) -> Result<()> {
+        // Ensure the inclusion assignments and global state root have not been set.
+        ensure!(self.inclusion_assignments.get().is_none());
+        ensure!(self.global_state_root.get().is_none());
+        // Insert the deferred call into the list.
+        self.deferred_calls.push(deferred_call);
+        Ok(())
+    }
+
+    /// Removes and returns the deferred function calls, in call order.
+    pub(crate) fn take_deferred_calls(&mut self) -> Vec<DeferredCall<N>> {
+        std::mem::take(&mut self.deferred_calls)
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Process<N> {
+    /// Verifies the given proving request can be proven with this process.
+    /// This ensures the programs in the proving request match the programs in this process,
+    /// and the requests in the authorizations are signed.
+    /// Note: This does *not* check that the state root exists in the ledger.
+    #[inline]
+    pub fn verify_proving_request(&self, proving_request: &ProvingRequest<N>) -> Result<()> {
+        let timer = timer!("Process::verify_proving_request");
+
+        // Ensure the programs in the proving request match the programs in this process.
+        for program in proving_request.programs() {
+            // Retrieve the program from this process.
+            let expected = self.get_program(program.id())?;
+            // Ensure the program checksums match.
+            ensure!(
+                expected.checksum()? == program.checksum()?,
+                "Program '{}' in the proving request does not match the deployed program",
+                program.id()
+            );
+        }
+        lap!(timer, "Verify the program checksums");
+
+        // Verify the requests in the authorization, followed by the fee authorization.
+        for authorization in std::iter::once(proving_request.authorization()).chain(proving_request.fee_authorization())
+        {
+            for (index, request) in authorization.to_vec_deque().iter().enumerate() {
+                // Retrieve the program ID.
+                let program_id = request.program_id();
+                // Ensure the program is in the proving request, unless it is 'credits.aleo'.
+                ensure!(
+                    &program_id.to_string() == "credits.aleo" || proving_request.get_program(program_id).is_some(),
+                    "Program '{program_id}' is missing from the proving request"
+                );
+                // Retrieve the function from the program.
+                let function = self.get_program(program_id)?.get_function(request.function_name())?;
+                // Ensure the request is signed, where the first request is the root request.
+                ensure!(
+                    request.verify(&function.input_types(), index == 0),
+                    "Request for '{program_id}/{}' in the proving request is invalid",
+                    request.function_name()
+                );
+            }
+        }
+        finish!(timer, "Verify the requests");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::finalize::branch_to;
+use synthesizer_program::{FinalizeStoreTrait, StackMatches};
+use utilities::try_vm_runtime;
+
+impl<N: Network> Process<N> {
+    /// Evaluates the given view with the given inputs against the confirmed state in the finalize store,
+    /// and returns the outputs of the view.
+    ///
+    /// Views are read-only, so the finalize store is never modified, and no proof is computed.
+    #[inline]
+    pub fn evaluate_view<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        program_id: &ProgramID<N>,
+        view_name: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<Vec<Value<N>>> {
+        let timer = timer!("Process::evaluate_view");
+
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Retrieve the view.
+        let view = stack.program().get_view_ref(view_name)?;
+        // Ensure the number of inputs matches.
+        ensure!(
+            view.inputs().len() == inputs.len(),
+            "View '{program_id}/{view_name}' expects {} inputs, but {} were provided",
+            view.inputs().len(),
+            inputs.len()
+        );
+
+        #[cfg(debug_assertions)]
+        println!("Evaluating view for {program_id}/{view_name}...");
+
+        // Restrict the finalize store to the confirmed state.
+        let store = ConfirmedFinalizeStore(store);
+        // Initialize the registers.
+        // Note: Views are not associated with a transition, so the transition ID is set to zero.
+        let mut registers = FinalizeRegisters::new(
+            state,
+            N::TransitionID::from(Field::zero()),
+            *view_name,
+            stack.get_finalize_types(view_name)?.clone(),
+        );
+
+        // Store the inputs.
+        view.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
+            // Assign the input value to the register.
+            registers.store(stack, register, input.clone())
+        })?;
+        lap!(timer, "Store the inputs");
+
+        // Evaluate the commands.
+        let mut counter = 0;
+        while counter < view.commands().len() {
+            // Retrieve the command.
+            let command = &view.commands()[counter];
+            // Evaluate the command.
+            let result = match command {
+                Command::BranchEq(branch_eq) => {
+                    try_vm_runtime!(|| branch_to(counter, branch_eq, view.positions(), stack, &registers))
+                }
+                Command::BranchNeq(branch_neq) => {
+                    try_vm_runtime!(|| branch_to(counter, branch_neq, view.positions(), stack, &registers))
+                }
+                _ => try_vm_runtime!(|| {
+                    // Note: The commands of a view are type-checked to not produce any finalize operations.
+                    match command.finalize(stack, &store, &mut registers)? {
+                        None => Ok(counter + 1),
+                        Some(_) => bail!("A view cannot modify the program state"),
+                    }
+                }),
+            };
+            match result {
+                // If the evaluation succeeds, advance to the next command.
+                Ok(Ok(next_counter)) => counter = next_counter,
+                // If the evaluation fails, bail and return the error.
+                Ok(Err(error)) => bail!("'view' failed to evaluate command ({command}): {error}"),
+                // If the evaluation fails, bail and return the error.
+                Err(_) => bail!("'view' failed to evaluate command ({command})"),
+            }
+        }
+        lap!(timer, "Evaluate the commands");
+
+        // Load the outputs.
+        let outputs = view
+            .outputs()
+            .iter()
+            .map(|output| {
+                // Retrieve the output value.
+                let value = registers.load(stack, output.operand())?;
+                // Ensure the output value matches the output type.
+                match &value {
+                    Value::Plaintext(plaintext) => stack.matches_plaintext(plaintext, output.plaintext_type())?,
+                    _ => bail!("The output '{}' of view '{view_name}' must be a plaintext", output.operand()),
+                }
+                Ok(value)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        finish!(timer, "Load the outputs");
+
+        Ok(outputs)
+    }
+}
+
+/// A read-only view of the confirmed state in the finalize store.
+struct ConfirmedFinalizeStore<'a, N: Network, P: FinalizeStorage<N>>(&'a FinalizeStore<N, P>);
+
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for ConfirmedFinalizeStore<'_, N, P> {
+    /// Returns `true` if the given `program ID` and `mapping name` exist.
+    fn contains_mapping_confirmed(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<bool> {
+        self.0.contains_mapping_confirmed(program_id, mapping_name)
+    }
+
+    /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
+    /// Note: This method only reads from the confirmed state.
+    fn contains_key_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<bool> {
+        self.0.contains_key_confirmed(program_id, mapping_name, key)
+    }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`.
+    /// Note: This method only reads from the confirmed state.
+    fn get_value_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        self.0.get_value_confirmed(program_id, mapping_name, key)
+    }
+
+    /// Returns the number of entries for the given `program ID` and `mapping name`.
+    /// Note: This method only reads from the confirmed state.
+    fn get_mapping_len_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<u64> {
+        self.0.get_mapping_len_confirmed(program_id, mapping_name)
+    }
+
+    /// Returns up to `limit` keys for the given `program ID` and `mapping name`, skipping the first `start` keys.
+    /// Note: This method only reads from the confirmed state.
+    fn get_keys_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<Plaintext<N>>> {
+        self.0.get_keys_confirmed(program_id, mapping_name, start, limit)
+    }
+
+    /// Returns an error, as the confirmed state is read-only.
+    fn insert_key_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        _key: Plaintext<N>,
+        _value: Value<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        bail!("Cannot insert into mapping '{program_id}/{mapping_name}' from a view")
+    }
+
+    /// Returns an error, as the confirmed state is read-only.
+    fn update_key_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        _key: Plaintext<N>,
+        _value: Value<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        bail!("Cannot update mapping '{program_id}/{mapping_name}' from a view")
+    }
+
+    /// Returns an error, as the confirmed state is read-only.
+    fn remove_key_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        _key: &Plaintext<N>,
+    ) -> Result<Option<FinalizeOperation<N>>> {
+        bail!("Cannot remove from mapping '{program_id}/{mapping_name}' from a view")
+    }
+
+    /// Returns an error, as the confirmed state is read-only.
+    fn emit_event(
+        &self,
+        program_id: ProgramID<N>,
+        topic: Identifier<N>,
+        _transition_id: N::TransitionID,
+        _payload: Plaintext<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        bail!("Cannot emit event '{program_id}/{topic}' from a view")
+    }
+}
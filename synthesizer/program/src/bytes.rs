@@ -21,7 +21,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid program version"));
         }
 
@@ -31,6 +31,12 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         // Initialize the program.
         let mut program = ProgramCore::new(id).map_err(|e| error(e.to_string()))?;
 
+        // Read the upgrade policy.
+        // Note: Version 1 programs are immutable, and do not encode an upgrade policy or external mappings.
+        if version == 2 {
+            program.upgrade_policy = UpgradePolicy::read_le(&mut reader)?;
+        }
+
         // Read the number of program imports.
         let imports_len = u8::read_le(&mut reader)?;
         // Read the program imports.
@@ -38,6 +44,17 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
             program.add_import(Import::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?;
         }
 
+        if version == 2 {
+            // Read the number of external mappings.
+            let external_mappings_len = u16::read_le(&mut reader)?;
+            // Read the external mappings.
+            for _ in 0..external_mappings_len {
+                program
+                    .add_external_mapping(ExternalMapping::read_le(&mut reader)?)
+                    .map_err(|e| error(e.to_string()))?;
+            }
+        }
+
         // Read the number of components.
         let components_len = u16::read_le(&mut reader)?;
         for _ in 0..components_len {
@@ -55,6 +72,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
                 3 => program.add_closure(ClosureCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Read the function.
                 4 => program.add_function(FunctionCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the constant.
+                5 => program.add_constant(Constant::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the view.
+                6 => program.add_view(ViewCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Invalid variant.
                 _ => return Err(error(format!("Failed to parse program. Invalid component variant '{variant}'"))),
             }
@@ -69,11 +90,22 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
 {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: Immutable programs without external mappings are written as version 1,
+        // so that their bytes remain unchanged.
+        let version = match self.upgrade_policy == UpgradePolicy::default() && self.external_mappings.is_empty() {
+            true => 1u8,
+            false => 2u8,
+        };
+        version.write_le(&mut writer)?;
 
         // Write the program ID.
         self.id.write_le(&mut writer)?;
 
+        // Write the upgrade policy.
+        if version == 2 {
+            self.upgrade_policy.write_le(&mut writer)?;
+        }
+
         // Write the number of program imports.
         u8::try_from(self.imports.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
         // Write the program imports.
@@ -81,6 +113,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
             import.write_le(&mut writer)?;
         }
 
+        if version == 2 {
+            // Write the number of external mappings.
+            u16::try_from(self.external_mappings.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+            // Write the external mappings.
+            for external_mapping in self.external_mappings.values() {
+                external_mapping.write_le(&mut writer)?;
+            }
+        }
+
         // Write the number of components.
         u16::try_from(self.identifiers.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
         // Write the components.
@@ -131,6 +172,24 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
                     }
                     None => return Err(error(format!("Function '{identifier}' is not defined."))),
                 },
+                ProgramDefinition::Constant => match self.constants.get(identifier) {
+                    Some(constant) => {
+                        // Write the variant.
+                        5u8.write_le(&mut writer)?;
+                        // Write the constant.
+                        constant.write_le(&mut writer)?;
+                    }
+                    None => return Err(error(format!("Constant '{identifier}' is not defined."))),
+                },
+                ProgramDefinition::View => match self.views.get(identifier) {
+                    Some(view) => {
+                        // Write the variant.
+                        6u8.write_le(&mut writer)?;
+                        // Write the view.
+                        view.write_le(&mut writer)?;
+                    }
+                    None => return Err(error(format!("View '{identifier}' is not defined."))),
+                },
             }
         }
 
@@ -172,4 +231,122 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_upgradable() -> Result<()> {
+        let program = r"
+program token.aleo;
+
+upgrade owner;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;";
+
+        // Initialize a new program.
+        let (string, expected) = Program::<CurrentNetwork>::parse(program).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert!(expected.upgrade_policy().is_upgradable());
+
+        let expected_bytes = expected.to_bytes_le()?;
+        // Ensure the upgradable program is written as version 2.
+        assert_eq!(2, expected_bytes[0]);
+
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_constants() -> Result<()> {
+        let program = r"
+program token.aleo;
+
+constant FEE as u64 = 5u64;
+
+function compute:
+    input r0 as u64.private;
+    sub r0 FEE into r1;
+    output r1 as u64.private;";
+
+        // Initialize a new program.
+        let (string, expected) = Program::<CurrentNetwork>::parse(program).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        let expected_bytes = expected.to_bytes_le()?;
+        // Ensure the program with constants is still written as version 1.
+        assert_eq!(1, expected_bytes[0]);
+
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_views() -> Result<()> {
+        let program = r"
+program token.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;
+
+view balance_of:
+    input r0 as address.public;
+    get.or_use balances[r0] 0u64 into r1;
+    output r1 as u64.public;";
+
+        // Initialize a new program.
+        let (string, expected) = Program::<CurrentNetwork>::parse(program).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        let expected_bytes = expected.to_bytes_le()?;
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_external_mappings() -> Result<()> {
+        let program = r"
+import credits.aleo;
+
+import mapping credits.aleo/account:
+    key as address.public;
+    value as u64.public;
+
+program reader.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;";
+
+        // Initialize a new program.
+        let (string, expected) = Program::<CurrentNetwork>::parse(program).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(1, expected.external_mappings().len());
+
+        let expected_bytes = expected.to_bytes_le()?;
+        // Ensure the program with external mappings is written as version 2.
+        assert_eq!(2, expected_bytes[0]);
+
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Constant<N> {
+    /// Reads the constant from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the name.
+        let name = Identifier::<N>::read_le(&mut reader)?;
+        // Read the literal.
+        let literal = Literal::<N>::read_le(&mut reader)?;
+        // Return the new constant.
+        Ok(Self::new(name, literal))
+    }
+}
+
+impl<N: Network> ToBytes for Constant<N> {
+    /// Writes the constant to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the name.
+        self.name.write_le(&mut writer)?;
+        // Write the literal.
+        self.literal.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_constant_bytes() -> Result<()> {
+        let expected = Constant::<CurrentNetwork>::from_str("constant MAX_SUPPLY as u64 = 1000000u64;")?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Constant::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Literal, LiteralType},
+};
+
+/// A constant declaration names a literal, and is of the form `constant {name} as {type} = {literal};`.
+/// A constant may be used as an operand in any closure, function, or finalize scope of the program.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Constant<N: Network> {
+    /// The name of the constant.
+    name: Identifier<N>,
+    /// The value of the constant.
+    literal: Literal<N>,
+}
+
+impl<N: Network> Constant<N> {
+    /// Initializes a new constant with the given name and literal.
+    pub const fn new(name: Identifier<N>, literal: Literal<N>) -> Self {
+        Self { name, literal }
+    }
+
+    /// Returns the name of the constant.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the type of the constant.
+    pub fn literal_type(&self) -> LiteralType {
+        self.literal.to_type()
+    }
+
+    /// Returns the value of the constant.
+    pub const fn literal(&self) -> &Literal<N> {
+        &self.literal
+    }
+}
+
+impl<N: Network> TypeName for Constant<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "constant"
+    }
+}
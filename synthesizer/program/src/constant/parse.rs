@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for Constant<N> {
+    /// Parses a string into a constant.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'constant' keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the name from the string.
+        let (string, name) = Identifier::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the literal type from the string.
+        let (string, literal_type) = LiteralType::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "=" from the string.
+        let (string, _) = tag("=")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the literal from the string, ensuring it matches the declared type.
+        let (string, literal) =
+            map_res(Literal::parse, |literal: Literal<N>| match literal.to_type() == literal_type {
+                true => Ok(literal),
+                false => Err(error(format!("Constant '{name}' is declared as '{literal_type}', found '{literal}'"))),
+            })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the constant.
+        Ok((string, Self::new(name, literal)))
+    }
+}
+
+impl<N: Network> FromStr for Constant<N> {
+    type Err = Error;
+
+    /// Returns a constant from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Constant<N> {
+    /// Prints the constant as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Constant<N> {
+    /// Prints the constant as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {} as {} = {};", Self::type_name(), self.name, self.literal_type(), self.literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_constant_parse() -> Result<()> {
+        let constant = Constant::<CurrentNetwork>::parse("constant MAX_SUPPLY as u64 = 1000000u64;").unwrap().1;
+        assert_eq!("MAX_SUPPLY", constant.name().to_string());
+        assert_eq!(LiteralType::U64, constant.literal_type());
+        assert_eq!(Literal::from_str("1000000u64")?, *constant.literal());
+        Ok(())
+    }
+
+    #[test]
+    fn test_constant_parse_fails() {
+        // Ensure the literal must match the declared type.
+        assert!(Constant::<CurrentNetwork>::parse("constant MAX_SUPPLY as u64 = 1000000u32;").is_err());
+        // Ensure the type must be declared.
+        assert!(Constant::<CurrentNetwork>::parse("constant MAX_SUPPLY = 1000000u64;").is_err());
+        // Ensure the value must be a literal.
+        assert!(Constant::<CurrentNetwork>::parse("constant MAX_SUPPLY as u64 = r0;").is_err());
+        // Ensure the semicolon is required.
+        assert!(Constant::<CurrentNetwork>::parse("constant MAX_SUPPLY as u64 = 1000000u64").is_err());
+    }
+
+    #[test]
+    fn test_constant_display() {
+        let expected = "constant OWNER_FEE as field = 5field;";
+        let constant = Constant::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{constant}"));
+    }
+}
@@ -20,10 +20,14 @@ pub type Program<N> = crate::ProgramCore<N, Instruction<N>, Command<N>>;
 pub type Function<N> = crate::FunctionCore<N, Instruction<N>, Command<N>>;
 pub type Finalize<N> = crate::FinalizeCore<N, Command<N>>;
 pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
+pub type View<N> = crate::ViewCore<N, Command<N>>;
 
 mod closure;
 pub use closure::*;
 
+mod constant;
+pub use constant::*;
+
 pub mod finalize;
 pub use finalize::*;
 
@@ -42,6 +46,12 @@ pub use mapping::*;
 pub mod traits;
 pub use traits::*;
 
+mod upgrade;
+pub use upgrade::*;
+
+mod view;
+pub use view::*;
+
 mod bytes;
 mod parse;
 mod serialize;
@@ -59,6 +69,7 @@ use console::{
         many1,
         map,
         map_res,
+        opt,
         tag,
         take,
         Debug,
@@ -79,12 +90,14 @@ use console::{
         Sanitizer,
         Serialize,
         Serializer,
+        ToBits,
         ToBytes,
         ToBytesSerializer,
         TypeName,
         Write,
     },
-    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    program::{Identifier, Locator, PlaintextType, ProgramID, RecordType, StructType},
+    types::Field,
 };
 
 use indexmap::IndexMap;
@@ -101,16 +114,26 @@ enum ProgramDefinition {
     Closure,
     /// A program function.
     Function,
+    /// A program constant.
+    Constant,
+    /// A program view.
+    View,
 }
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
     /// The ID of the program.
     id: ProgramID<N>,
+    /// The upgrade policy of the program.
+    upgrade_policy: UpgradePolicy,
     /// A map of the declared imports for the program.
     imports: IndexMap<ProgramID<N>, Import<N>>,
+    /// A map of the declared external mappings for the program.
+    external_mappings: IndexMap<Locator<N>, ExternalMapping<N>>,
     /// A map of identifiers to their program declaration.
     identifiers: IndexMap<Identifier<N>, ProgramDefinition>,
+    /// A map of the declared constants for the program.
+    constants: IndexMap<Identifier<N>, Constant<N>>,
     /// A map of the declared mappings for the program.
     mappings: IndexMap<Identifier<N>, Mapping<N>>,
     /// A map of the declared structs for the program.
@@ -121,6 +144,8 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     closures: IndexMap<Identifier<N>, ClosureCore<N, Instruction>>,
     /// A map of the declared functions for the program.
     functions: IndexMap<Identifier<N>, FunctionCore<N, Instruction, Command>>,
+    /// A map of the declared views for the program.
+    views: IndexMap<Identifier<N>, ViewCore<N, Command>>,
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -132,13 +157,17 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
 
         Ok(Self {
             id,
+            upgrade_policy: UpgradePolicy::default(),
             imports: IndexMap::new(),
+            external_mappings: IndexMap::new(),
             identifiers: IndexMap::new(),
+            constants: IndexMap::new(),
             mappings: IndexMap::new(),
             structs: IndexMap::new(),
             records: IndexMap::new(),
             closures: IndexMap::new(),
             functions: IndexMap::new(),
+            views: IndexMap::new(),
         })
     }
 
@@ -153,11 +182,26 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.id
     }
 
+    /// Returns the upgrade policy of the program.
+    pub const fn upgrade_policy(&self) -> UpgradePolicy {
+        self.upgrade_policy
+    }
+
     /// Returns the imports in the program.
     pub const fn imports(&self) -> &IndexMap<ProgramID<N>, Import<N>> {
         &self.imports
     }
 
+    /// Returns the external mappings declared by the program.
+    pub const fn external_mappings(&self) -> &IndexMap<Locator<N>, ExternalMapping<N>> {
+        &self.external_mappings
+    }
+
+    /// Returns the constants in the program.
+    pub const fn constants(&self) -> &IndexMap<Identifier<N>, Constant<N>> {
+        &self.constants
+    }
+
     /// Returns the mappings in the program.
     pub const fn mappings(&self) -> &IndexMap<Identifier<N>, Mapping<N>> {
         &self.mappings
@@ -183,11 +227,31 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.functions
     }
 
+    /// Returns the views in the program.
+    pub const fn views(&self) -> &IndexMap<Identifier<N>, ViewCore<N, Command>> {
+        &self.views
+    }
+
+    /// Returns the checksum of the program, as the hash of its bytes.
+    pub fn checksum(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
     }
 
+    /// Returns `true` if the program declares an external mapping with the given locator.
+    pub fn contains_external_mapping(&self, locator: &Locator<N>) -> bool {
+        self.external_mappings.contains_key(locator)
+    }
+
+    /// Returns `true` if the program contains a constant with the given name.
+    pub fn contains_constant(&self, name: &Identifier<N>) -> bool {
+        self.constants.contains_key(name)
+    }
+
     /// Returns `true` if the program contains a mapping with the given name.
     pub fn contains_mapping(&self, name: &Identifier<N>) -> bool {
         self.mappings.contains_key(name)
@@ -213,6 +277,21 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         self.functions.contains_key(name)
     }
 
+    /// Returns `true` if the program contains a view with the given name.
+    pub fn contains_view(&self, name: &Identifier<N>) -> bool {
+        self.views.contains_key(name)
+    }
+
+    /// Returns the constant with the given name.
+    pub fn get_constant(&self, name: &Identifier<N>) -> Result<&Constant<N>> {
+        // Attempt to retrieve the constant.
+        let constant = self.constants.get(name).ok_or_else(|| anyhow!("Constant '{name}' is not defined."))?;
+        // Ensure the constant name matches.
+        ensure!(constant.name() == name, "Expected constant '{name}', but found constant '{}'", constant.name());
+        // Return the constant.
+        Ok(constant)
+    }
+
     /// Returns the mapping with the given name.
     pub fn get_mapping(&self, name: &Identifier<N>) -> Result<Mapping<N>> {
         // Attempt to retrieve the mapping.
@@ -223,6 +302,19 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         Ok(mapping)
     }
 
+    /// Returns the external mapping with the given locator.
+    pub fn get_external_mapping(&self, locator: &Locator<N>) -> Result<&ExternalMapping<N>> {
+        // Attempt to retrieve the external mapping.
+        let mapping = self
+            .external_mappings
+            .get(locator)
+            .ok_or_else(|| anyhow!("External mapping '{locator}' is not declared in '{}'.", self.id))?;
+        // Ensure the external mapping locator matches.
+        ensure!(mapping.locator() == locator, "Expected '{locator}', but found '{}'", mapping.locator());
+        // Return the external mapping.
+        Ok(mapping)
+    }
+
     /// Returns the struct with the given name.
     pub fn get_struct(&self, name: &Identifier<N>) -> Result<&StructType<N>> {
         // Attempt to retrieve the struct.
@@ -283,6 +375,22 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Return the function.
         Ok(function)
     }
+
+    /// Returns a reference to the view with the given name.
+    pub fn get_view_ref(&self, name: &Identifier<N>) -> Result<&ViewCore<N, Command>> {
+        // Attempt to retrieve the view.
+        let view = self.views.get(name).ok_or(anyhow!("View '{}/{name}' is not defined.", self.id))?;
+        // Ensure the view name matches.
+        ensure!(view.name() == name, "Expected view '{name}', but found view '{}'", view.name());
+        // Ensure the number of inputs is within the allowed range.
+        ensure!(view.inputs().len() <= N::MAX_INPUTS, "View exceeds maximum number of inputs");
+        // Ensure the number of commands is within the allowed range.
+        ensure!(view.commands().len() <= N::MAX_COMMANDS, "View exceeds maximum commands");
+        // Ensure the number of outputs is within the allowed range.
+        ensure!(view.outputs().len() <= N::MAX_OUTPUTS, "View exceeds maximum number of outputs");
+        // Return the view.
+        Ok(view)
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -319,6 +427,75 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         Ok(())
     }
 
+    /// Adds a new external mapping declaration to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the external mapping was previously declared.
+    /// This method will halt if the external mapping does not reference an imported program.
+    #[inline]
+    fn add_external_mapping(&mut self, mapping: ExternalMapping<N>) -> Result<()> {
+        // Retrieve the locator.
+        let locator = *mapping.locator();
+
+        // Ensure the program has not exceeded the maximum number of external mappings.
+        ensure!(
+            self.external_mappings.len() < N::MAX_MAPPINGS,
+            "Program exceeds the maximum number of external mappings"
+        );
+
+        // Ensure the external mapping does not reference the current program.
+        ensure!(locator.program_id() != self.id(), "External mapping '{locator}' references the current program.");
+        // Ensure the external mapping references an imported program.
+        ensure!(
+            self.contains_import(locator.program_id()),
+            "External mapping '{locator}' references '{}', which is not imported.",
+            locator.program_id()
+        );
+
+        // Add the external mapping to the program.
+        if self.external_mappings.insert(locator, mapping).is_some() {
+            bail!("External mapping '{locator}' is already declared.")
+        }
+        Ok(())
+    }
+
+    /// Adds a new constant to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the constant name is already in use.
+    /// This method will halt if the constant name is a reserved opcode or keyword.
+    /// This method will halt if the constant name cannot be used as an operand.
+    #[inline]
+    fn add_constant(&mut self, constant: Constant<N>) -> Result<()> {
+        // Retrieve the constant name.
+        let constant_name = *constant.name();
+
+        // Ensure the program has not exceeded the maximum number of constants.
+        ensure!(self.constants.len() < N::MAX_CONSTANTS, "Program exceeds the maximum number of constants.");
+
+        // Ensure the constant name is new.
+        ensure!(self.is_unique_name(&constant_name), "'{constant_name}' is already in use.");
+        // Ensure the constant name is not a reserved opcode.
+        ensure!(!Self::is_reserved_opcode(&constant_name.to_string()), "'{constant_name}' is a reserved opcode.");
+        // Ensure the constant name is not a reserved keyword.
+        ensure!(!Self::is_reserved_keyword(&constant_name), "'{constant_name}' is a reserved keyword.");
+        // Ensure the constant name is parsed as a constant operand (e.g. it is not a register or a literal).
+        ensure!(
+            Operand::from_str(&constant_name.to_string()).ok() == Some(Operand::Constant(constant_name)),
+            "'{constant_name}' cannot be used as a constant name."
+        );
+
+        // Add the constant name to the identifiers.
+        if self.identifiers.insert(constant_name, ProgramDefinition::Constant).is_some() {
+            bail!("'{constant_name}' already exists in the program.")
+        }
+        // Add the constant to the program.
+        if self.constants.insert(constant_name, constant).is_some() {
+            bail!("'{constant_name}' already exists in the program.")
+        }
+        Ok(())
+    }
+
     /// Adds a new mapping to the program.
     ///
     /// # Errors
@@ -397,6 +574,22 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         }
                     }
                 }
+                PlaintextType::Option(option_type) => {
+                    let struct_name = match option_type.element_type() {
+                        PlaintextType::Struct(struct_name) => Some(struct_name),
+                        PlaintextType::Array(array_type) => match array_type.base_element_type() {
+                            PlaintextType::Struct(struct_name) => Some(struct_name),
+                            _ => None,
+                        },
+                        PlaintextType::Literal(_) | PlaintextType::Option(_) => None,
+                    };
+                    if let Some(struct_name) = struct_name {
+                        // Ensure the member struct name exists in the program.
+                        if !self.structs.contains_key(struct_name) {
+                            bail!("'{struct_name}' in option '{option_type}' is not defined.")
+                        }
+                    }
+                }
             }
         }
 
@@ -454,6 +647,22 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         }
                     }
                 }
+                PlaintextType::Option(option_type) => {
+                    let struct_name = match option_type.element_type() {
+                        PlaintextType::Struct(struct_name) => Some(struct_name),
+                        PlaintextType::Array(array_type) => match array_type.base_element_type() {
+                            PlaintextType::Struct(struct_name) => Some(struct_name),
+                            _ => None,
+                        },
+                        PlaintextType::Literal(_) | PlaintextType::Option(_) => None,
+                    };
+                    if let Some(struct_name) = struct_name {
+                        // Ensure the member struct name exists in the program.
+                        if !self.structs.contains_key(struct_name) {
+                            bail!("'{struct_name}' in option '{option_type}' is not defined.")
+                        }
+                    }
+                }
             }
         }
 
@@ -561,6 +770,48 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         }
         Ok(())
     }
+
+    /// Adds a new view to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the view was previously added.
+    /// This method will halt if the view name is already in use in the program.
+    /// This method will halt if the view name is a reserved opcode or keyword.
+    /// This method will halt if the view does not contain output statements.
+    #[inline]
+    fn add_view(&mut self, view: ViewCore<N, Command>) -> Result<()> {
+        // Retrieve the view name.
+        let view_name = *view.name();
+
+        // Ensure the program has not exceeded the maximum number of views.
+        ensure!(self.views.len() < N::MAX_VIEWS, "Program exceeds the maximum number of views");
+
+        // Ensure the view name is new.
+        ensure!(self.is_unique_name(&view_name), "'{view_name}' is already in use.");
+        // Ensure the view name is not a reserved opcode.
+        ensure!(!Self::is_reserved_opcode(&view_name.to_string()), "'{view_name}' is a reserved opcode.");
+        // Ensure the view name is not a reserved keyword.
+        ensure!(!Self::is_reserved_keyword(&view_name), "'{view_name}' is a reserved keyword.");
+
+        // Ensure the number of inputs is within the allowed range.
+        ensure!(view.inputs().len() <= N::MAX_INPUTS, "View exceeds maximum number of inputs");
+        // Ensure the number of commands is within the allowed range.
+        ensure!(view.commands().len() <= N::MAX_COMMANDS, "View exceeds maximum commands");
+        // Ensure there are output statements in the view.
+        ensure!(!view.outputs().is_empty(), "Cannot evaluate a view without output statements");
+        // Ensure the number of outputs is within the allowed range.
+        ensure!(view.outputs().len() <= N::MAX_OUTPUTS, "View exceeds maximum number of outputs");
+
+        // Add the view name to the identifiers.
+        if self.identifiers.insert(view_name, ProgramDefinition::View).is_some() {
+            bail!("'{view_name}' already exists in the program.")
+        }
+        // Add the view to the program.
+        if self.views.insert(view_name, view).is_some() {
+            bail!("'{view_name}' already exists in the program.")
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -589,6 +840,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         "scalar",
         "signature",
         "string",
+        // Option
+        "option",
+        "some",
+        "none",
         // Boolean
         "true",
         "false",
@@ -615,6 +870,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         "value",
         "async",
         "finalize",
+        "view",
         // Reserved (catch all)
         "global",
         "block",
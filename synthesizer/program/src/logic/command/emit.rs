@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{FinalizeStoreTrait, RegistersLoad, StackMatches, StackProgram},
+    FinalizeOperation,
+    FinalizeRegistersState,
+    Opcode,
+    Operand,
+};
+use console::{network::prelude::*, program::Identifier};
+
+/// An emit command, e.g. `emit transfer r0;`
+/// Emits the `payload` operand as an event under `topic`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Emit<N: Network> {
+    /// The event topic.
+    topic: Identifier<N>,
+    /// The event payload.
+    payload: Operand<N>,
+}
+
+impl<N: Network> Emit<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("emit")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.payload.clone()]
+    }
+
+    /// Returns the event topic.
+    #[inline]
+    pub const fn topic(&self) -> &Identifier<N> {
+        &self.topic
+    }
+
+    /// Returns the operand containing the event payload.
+    #[inline]
+    pub const fn payload(&self) -> &Operand<N> {
+        &self.payload
+    }
+}
+
+impl<N: Network> Emit<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + FinalizeRegistersState<N>),
+    ) -> Result<FinalizeOperation<N>> {
+        // Load the payload operand as a plaintext.
+        let payload = registers.load_plaintext(stack, &self.payload)?;
+        // Store the event, and return the finalize operation.
+        store.emit_event(*stack.program_id(), self.topic, *registers.transition_id(), payload)
+    }
+}
+
+impl<N: Network> Parser for Emit<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the topic from the string.
+        let (string, topic) = Identifier::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the payload operand from the string.
+        let (string, payload) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { topic, payload }))
+    }
+}
+
+impl<N: Network> FromStr for Emit<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Emit<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Emit<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command, topic, and payload operand.
+        write!(f, "{} {} {};", Self::opcode(), self.topic, self.payload)
+    }
+}
+
+impl<N: Network> FromBytes for Emit<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the topic.
+        let topic = Identifier::read_le(&mut reader)?;
+        // Read the payload operand.
+        let payload = Operand::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { topic, payload })
+    }
+}
+
+impl<N: Network> ToBytes for Emit<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the topic.
+        self.topic.write_le(&mut writer)?;
+        // Write the payload operand.
+        self.payload.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Register};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, emit) = Emit::<CurrentNetwork>::parse("emit transfer r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(emit.topic, Identifier::from_str("transfer").unwrap());
+        assert_eq!(emit.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(emit.payload, Operand::Register(Register::Locator(1)), "The first operand is incorrect");
+
+        // Ensure a missing payload fails to parse.
+        assert!(Emit::<CurrentNetwork>::parse("emit transfer;").is_err());
+    }
+
+    #[test]
+    fn test_display_and_bytes() {
+        for string in ["emit transfer r1;", "emit deposit 100u64;", "emit total r2.amount;"] {
+            let expected = Emit::<CurrentNetwork>::from_str(string).unwrap();
+            // Check the string representation.
+            assert_eq!(string, expected.to_string());
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, Emit::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
@@ -98,13 +98,24 @@ impl<N: Network> Get<N> {
     ) -> Result<()> {
         // Determine the program ID and mapping name.
         let (program_id, mapping_name) = match self.mapping {
-            CallOperator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            CallOperator::Locator(locator) => {
+                // Ensure the external program is imported by the current program.
+                if !stack.program().contains_import(locator.program_id()) {
+                    bail!("External program '{}' is not imported by '{}'", locator.program_id(), stack.program_id());
+                }
+                (*locator.program_id(), *locator.resource())
+            }
             CallOperator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
         };
 
         // Ensure the mapping exists in storage.
         if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
-            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
+            match program_id == *stack.program_id() {
+                true => bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage"),
+                false => bail!(
+                    "External mapping '{program_id}/{mapping_name}' does not exist in storage (is '{program_id}' deployed?)"
+                ),
+            }
         }
 
         // Load the operand as a plaintext.
@@ -0,0 +1,334 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    CallOperator,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Plaintext, Register, Value},
+    types::U32,
+};
+
+/// A paged mapping keys command, e.g. `mapping.keys accounts from 0u32 limit 4u32 or_use r0 into r1;`.
+/// Retrieves up to `limit` keys in `mapping`, starting from the key at index `start`, and stores them
+/// as an array of length `limit` in `destination`. If fewer than `limit` keys remain, the array is
+/// padded with `default`.
+///
+/// The keys are ordered by their serialized byte representation, so that a page is independent
+/// of the order in which the entries were inserted. The `start` index is a constant, as the command
+/// visits `start + limit` entries in storage and is priced accordingly.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MappingKeys<N: Network> {
+    /// The mapping name.
+    mapping: CallOperator<N>,
+    /// The index of the first key.
+    start: U32<N>,
+    /// The number of keys in the page.
+    limit: U32<N>,
+    /// The default key.
+    default: Operand<N>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> MappingKeys<N> {
+    /// Initializes a new `mapping.keys` command.
+    #[inline]
+    pub fn new(
+        mapping: CallOperator<N>,
+        start: U32<N>,
+        limit: U32<N>,
+        default: Operand<N>,
+        destination: Register<N>,
+    ) -> Result<Self> {
+        // Ensure the limit is within bounds.
+        ensure!(
+            *limit > 0 && *limit as usize <= N::MAX_ARRAY_ELEMENTS,
+            "The limit of '{}' must be between 1 and {}, found {limit}",
+            Self::opcode(),
+            N::MAX_ARRAY_ELEMENTS
+        );
+        // Return the command.
+        Ok(Self { mapping, start, limit, default, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("mapping.keys")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.default.clone()]
+    }
+
+    /// Returns the mapping.
+    #[inline]
+    pub const fn mapping(&self) -> &CallOperator<N> {
+        &self.mapping
+    }
+
+    /// Returns the index of the first key.
+    #[inline]
+    pub const fn start(&self) -> &U32<N> {
+        &self.start
+    }
+
+    /// Returns the number of keys in the page.
+    #[inline]
+    pub const fn limit(&self) -> &U32<N> {
+        &self.limit
+    }
+
+    /// Returns the default key.
+    #[inline]
+    pub const fn default(&self) -> &Operand<N> {
+        &self.default
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+}
+
+impl<N: Network> MappingKeys<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match self.mapping {
+            CallOperator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            CallOperator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
+        };
+
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
+        }
+
+        // Retrieve the page of keys in the mapping, ordered by their byte representation.
+        let keys = store.get_keys_speculative(program_id, mapping_name, *self.start as usize, *self.limit as usize)?;
+
+        // Load the default key.
+        let default = registers.load_plaintext(stack, &self.default)?;
+
+        // Construct the page of keys, padding it with the default key.
+        let page = keys.into_iter().chain(std::iter::repeat(default)).take(*self.limit as usize).collect::<Vec<_>>();
+
+        // Assign the page of keys to the destination register.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::Array(page, Default::default())))?;
+
+        Ok(())
+    }
+}
+
+impl<N: Network> Parser for MappingKeys<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = CallOperator::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "from" keyword from the string.
+        let (string, _) = tag("from")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the start index from the string.
+        let (string, start) = U32::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "limit" keyword from the string.
+        let (string, _) = tag("limit")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the limit from the string.
+        let (string, limit) = U32::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "or_use" keyword from the string.
+        let (string, _) = tag("or_use")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the default key from the string.
+        let (string, default) = Operand::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        // Construct the command.
+        match Self::new(mapping, start, limit, default, destination) {
+            Ok(command) => Ok((string, command)),
+            Err(e) => map_res(fail, |_: ParserResult<Self>| Err(error(e.to_string())))(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for MappingKeys<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for MappingKeys<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for MappingKeys<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping, start index, limit, and default key.
+        write!(f, "{} from {} limit {} or_use {} into ", self.mapping, self.start, self.limit, self.default)?;
+        // Print the destination register.
+        write!(f, "{};", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for MappingKeys<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = CallOperator::read_le(&mut reader)?;
+        // Read the start index.
+        let start = U32::read_le(&mut reader)?;
+        // Read the limit.
+        let limit = U32::read_le(&mut reader)?;
+        // Read the default key.
+        let default = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Self::new(mapping, start, limit, default, destination).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for MappingKeys<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the start index.
+        self.start.write_le(&mut writer)?;
+        // Write the limit.
+        self.limit.write_le(&mut writer)?;
+        // Write the default key.
+        self.default.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Register};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, keys) =
+            MappingKeys::<CurrentNetwork>::parse("mapping.keys accounts from 8u32 limit 4u32 or_use r0 into r1;")
+                .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(keys.mapping, CallOperator::from_str("accounts").unwrap());
+        assert_eq!(keys.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(*keys.start, 8, "The start index is incorrect");
+        assert_eq!(*keys.limit, 4, "The limit is incorrect");
+        assert_eq!(keys.default, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(keys.destination, Register::Locator(1), "The destination is incorrect");
+
+        let (string, keys) = MappingKeys::<CurrentNetwork>::parse(
+            "mapping.keys credits.aleo/account from 0u32 limit 32u32 or_use self.caller into r0;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(keys.mapping, CallOperator::from_str("credits.aleo/account").unwrap());
+        assert_eq!(*keys.start, 0, "The start index is incorrect");
+        assert_eq!(*keys.limit, 32, "The limit is incorrect");
+        assert_eq!(keys.default, Operand::Caller, "The first operand is incorrect");
+        assert_eq!(keys.destination, Register::Locator(0), "The destination is incorrect");
+
+        // Ensure the start index is a constant.
+        assert!(MappingKeys::<CurrentNetwork>::parse("mapping.keys accounts from r0 limit 4u32 or_use r1 into r2;")
+            .is_err());
+        // Ensure the limit is within bounds.
+        assert!(MappingKeys::<CurrentNetwork>::parse("mapping.keys accounts from 0u32 limit 0u32 or_use r1 into r2;")
+            .is_err());
+        assert!(MappingKeys::<CurrentNetwork>::parse("mapping.keys accounts from 0u32 limit 33u32 or_use r1 into r2;")
+            .is_err());
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let (string, keys) =
+            MappingKeys::<CurrentNetwork>::parse("mapping.keys accounts from 8u32 limit 4u32 or_use r0 into r1;")
+                .unwrap();
+        assert!(string.is_empty());
+        let bytes_le = keys.to_bytes_le().unwrap();
+        let result = MappingKeys::<CurrentNetwork>::from_bytes_le(&bytes_le[..]);
+        assert_eq!(keys, result.unwrap());
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    CallOperator,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, Register, Value},
+    types::U32,
+};
+
+/// The maximum limit of a single `mapping.len` command.
+pub const MAX_MAPPING_LEN_LIMIT: u32 = 1 << 16;
+
+/// A mapping length command, e.g. `mapping.len accounts limit 1024u32 into r0;`.
+/// Counts the (`key`, `value`) entries in `mapping`, and stores the result as a `u32` in `destination`.
+/// The command fails if `mapping` contains more than `limit` entries.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MappingLen<N: Network> {
+    /// The mapping name.
+    mapping: CallOperator<N>,
+    /// The maximum number of entries.
+    limit: U32<N>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> MappingLen<N> {
+    /// Initializes a new `mapping.len` command.
+    #[inline]
+    pub fn new(mapping: CallOperator<N>, limit: U32<N>, destination: Register<N>) -> Result<Self> {
+        // Ensure the limit is within bounds.
+        ensure!(
+            *limit > 0 && *limit <= MAX_MAPPING_LEN_LIMIT,
+            "The limit of '{}' must be between 1 and {MAX_MAPPING_LEN_LIMIT}, found {limit}",
+            Self::opcode()
+        );
+        // Return the command.
+        Ok(Self { mapping, limit, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("mapping.len")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![]
+    }
+
+    /// Returns the mapping.
+    #[inline]
+    pub const fn mapping(&self) -> &CallOperator<N> {
+        &self.mapping
+    }
+
+    /// Returns the maximum number of entries.
+    #[inline]
+    pub const fn limit(&self) -> &U32<N> {
+        &self.limit
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+}
+
+impl<N: Network> MappingLen<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match self.mapping {
+            CallOperator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            CallOperator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
+        };
+
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
+        }
+
+        // Retrieve the number of entries in the mapping.
+        let num_entries = store.get_mapping_len_speculative(program_id, mapping_name)?;
+        // Ensure the number of entries does not exceed the limit.
+        if num_entries > *self.limit as u64 {
+            bail!("Mapping '{program_id}/{mapping_name}' has more than {} entries", self.limit)
+        }
+
+        // Assign the number of entries to the destination register.
+        registers.store(stack, &self.destination, Value::from(Literal::U32(U32::new(u32::try_from(num_entries)?))))?;
+
+        Ok(())
+    }
+}
+
+impl<N: Network> Parser for MappingLen<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = CallOperator::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "limit" keyword from the string.
+        let (string, _) = tag("limit")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the limit from the string.
+        let (string, limit) = U32::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        // Construct the command.
+        match Self::new(mapping, limit, destination) {
+            Ok(command) => Ok((string, command)),
+            Err(e) => map_res(fail, |_: ParserResult<Self>| Err(error(e.to_string())))(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for MappingLen<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for MappingLen<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for MappingLen<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping and limit.
+        write!(f, "{} limit {} into ", self.mapping, self.limit)?;
+        // Print the destination register.
+        write!(f, "{};", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for MappingLen<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = CallOperator::read_le(&mut reader)?;
+        // Read the limit.
+        let limit = U32::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Self::new(mapping, limit, destination).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for MappingLen<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the limit.
+        self.limit.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Register};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, len) = MappingLen::<CurrentNetwork>::parse("mapping.len accounts limit 1024u32 into r0;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(len.mapping, CallOperator::from_str("accounts").unwrap());
+        assert_eq!(len.operands().len(), 0, "The number of operands is incorrect");
+        assert_eq!(*len.limit, 1024, "The limit is incorrect");
+        assert_eq!(len.destination, Register::Locator(0), "The destination is incorrect");
+
+        let (string, len) =
+            MappingLen::<CurrentNetwork>::parse("mapping.len credits.aleo/account limit 1u32 into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(len.mapping, CallOperator::from_str("credits.aleo/account").unwrap());
+        assert_eq!(*len.limit, 1, "The limit is incorrect");
+        assert_eq!(len.destination, Register::Locator(1), "The destination is incorrect");
+
+        // Ensure the limit is within bounds.
+        assert!(MappingLen::<CurrentNetwork>::parse("mapping.len accounts limit 0u32 into r0;").is_err());
+        assert!(MappingLen::<CurrentNetwork>::parse("mapping.len accounts limit 65537u32 into r0;").is_err());
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let (string, len) = MappingLen::<CurrentNetwork>::parse("mapping.len accounts limit 1024u32 into r0;").unwrap();
+        assert!(string.is_empty());
+        let bytes_le = len.to_bytes_le().unwrap();
+        let result = MappingLen::<CurrentNetwork>::from_bytes_le(&bytes_le[..]);
+        assert_eq!(len, result.unwrap());
+    }
+}
@@ -21,12 +21,21 @@ pub use branch::*;
 mod contains;
 pub use contains::*;
 
+mod emit;
+pub use emit::*;
+
 mod get;
 pub use get::*;
 
 mod get_or_use;
 pub use get_or_use::*;
 
+mod mapping_keys;
+pub use mapping_keys::*;
+
+mod mapping_len;
+pub use mapping_len::*;
+
 mod rand_chacha;
 pub use crate::command::rand_chacha::*;
 
@@ -53,6 +62,8 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Opcode,
+    Operand,
 };
 use console::{
     network::prelude::*,
@@ -67,11 +78,18 @@ pub enum Command<N: Network> {
     Await(Await<N>),
     /// Returns true if the `key` operand is present in `mapping`, and stores the result into `destination`.
     Contains(Contains<N>),
+    /// Emits the `payload` operand as an event under `topic`.
+    Emit(Emit<N>),
     /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
     Get(Get<N>),
     /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
     /// If the key is not present, `default` is stored `destination`.
     GetOrUse(GetOrUse<N>),
+    /// Counts the entries in `mapping`, up to `limit`, and stores the result into `destination`.
+    MappingLen(MappingLen<N>),
+    /// Gets up to `limit` keys in `mapping`, starting from the `start` operand, and stores them into `destination`.
+    /// If fewer than `limit` keys remain, the remainder is padded with `default`.
+    MappingKeys(MappingKeys<N>),
     /// Generates a random value using the `rand.chacha` command and stores the result into `destination`.
     RandChaCha(RandChaCha<N>),
     /// Removes the (`key`, `value`) entry from the `mapping`.
@@ -95,10 +113,13 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             Command::Contains(contains) => vec![contains.destination().clone()],
             Command::Get(get) => vec![get.destination().clone()],
             Command::GetOrUse(get_or_use) => vec![get_or_use.destination().clone()],
+            Command::MappingLen(mapping_len) => vec![mapping_len.destination().clone()],
+            Command::MappingKeys(mapping_keys) => vec![mapping_keys.destination().clone()],
             Command::RandChaCha(rand_chacha) => vec![rand_chacha.destination().clone()],
             Command::Await(_)
             | Command::BranchEq(_)
             | Command::BranchNeq(_)
+            | Command::Emit(_)
             | Command::Position(_)
             | Command::Remove(_)
             | Command::Set(_) => vec![],
@@ -140,11 +161,53 @@ impl<N: Network> CommandTrait<N> for Command<N> {
     /// Returns `true` if the command is a write operation.
     #[inline]
     fn is_write(&self) -> bool {
-        matches!(self, Command::Set(_) | Command::Remove(_))
+        matches!(self, Command::Set(_) | Command::Remove(_) | Command::Emit(_))
     }
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the opcode of the command.
+    #[inline]
+    pub const fn opcode(&self) -> Opcode {
+        match self {
+            Command::Instruction(instruction) => instruction.opcode(),
+            Command::Await(_) => Await::<N>::opcode(),
+            Command::Contains(_) => Contains::<N>::opcode(),
+            Command::Emit(_) => Emit::<N>::opcode(),
+            Command::Get(_) => Get::<N>::opcode(),
+            Command::GetOrUse(_) => GetOrUse::<N>::opcode(),
+            Command::MappingLen(_) => MappingLen::<N>::opcode(),
+            Command::MappingKeys(_) => MappingKeys::<N>::opcode(),
+            Command::RandChaCha(_) => RandChaCha::<N>::opcode(),
+            Command::Remove(_) => Remove::<N>::opcode(),
+            Command::Set(_) => Set::<N>::opcode(),
+            Command::BranchEq(_) => BranchEq::<N>::opcode(),
+            Command::BranchNeq(_) => BranchNeq::<N>::opcode(),
+            Command::Position(_) => Position::<N>::opcode(),
+        }
+    }
+
+    /// Returns the operands of the command.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        match self {
+            Command::Instruction(instruction) => instruction.operands().to_vec(),
+            Command::Await(await_) => vec![Operand::Register(await_.register().clone())],
+            Command::Contains(contains) => contains.operands(),
+            Command::Emit(emit) => emit.operands(),
+            Command::Get(get) => get.operands(),
+            Command::GetOrUse(get_or_use) => get_or_use.operands(),
+            Command::MappingLen(mapping_len) => mapping_len.operands(),
+            Command::MappingKeys(mapping_keys) => mapping_keys.operands(),
+            Command::RandChaCha(rand_chacha) => rand_chacha.operands(),
+            Command::Remove(remove) => remove.operands(),
+            Command::Set(set) => set.operands(),
+            Command::BranchEq(branch_eq) => vec![branch_eq.first().clone(), branch_eq.second().clone()],
+            Command::BranchNeq(branch_neq) => vec![branch_neq.first().clone(), branch_neq.second().clone()],
+            Command::Position(_) => vec![],
+        }
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize(
@@ -160,10 +223,16 @@ impl<N: Network> Command<N> {
             Command::Await(_) => bail!("`await` commands cannot be finalized directly."),
             // Finalize the 'contains' command, and return no finalize operation.
             Command::Contains(contains) => contains.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'emit' command, and return the finalize operation.
+            Command::Emit(emit) => emit.finalize(stack, store, registers).map(Some),
             // Finalize the 'get' command, and return no finalize operation.
             Command::Get(get) => get.finalize(stack, store, registers).map(|_| None),
             // Finalize the 'get.or_use' command, and return no finalize operation.
             Command::GetOrUse(get_or_use) => get_or_use.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'mapping.len' command, and return no finalize operation.
+            Command::MappingLen(mapping_len) => mapping_len.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'mapping.keys' command, and return no finalize operation.
+            Command::MappingKeys(mapping_keys) => mapping_keys.finalize(stack, store, registers).map(|_| None),
             // Finalize the `rand.chacha` command, and return no finalize operation.
             Command::RandChaCha(rand_chacha) => rand_chacha.finalize(stack, registers).map(|_| None),
             // Finalize the 'remove' command, and return the finalize operation.
@@ -208,8 +277,14 @@ impl<N: Network> FromBytes for Command<N> {
             9 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
             // Read the `position` command.
             10 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `mapping.len` command.
+            11 => Ok(Self::MappingLen(MappingLen::read_le(&mut reader)?)),
+            // Read the `mapping.keys` command.
+            12 => Ok(Self::MappingKeys(MappingKeys::read_le(&mut reader)?)),
+            // Read the `emit` command.
+            13 => Ok(Self::Emit(Emit::read_le(&mut reader)?)),
             // Invalid variant.
-            11.. => Err(error(format!("Invalid command variant: {variant}"))),
+            14.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -284,6 +359,24 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::MappingLen(mapping_len) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the `mapping.len` command.
+                mapping_len.write_le(&mut writer)
+            }
+            Self::MappingKeys(mapping_keys) => {
+                // Write the variant.
+                12u8.write_le(&mut writer)?;
+                // Write the `mapping.keys` command.
+                mapping_keys.write_le(&mut writer)
+            }
+            Self::Emit(emit) => {
+                // Write the variant.
+                13u8.write_le(&mut writer)?;
+                // Write the `emit` command.
+                emit.write_le(&mut writer)
+            }
         }
     }
 }
@@ -297,8 +390,11 @@ impl<N: Network> Parser for Command<N> {
         alt((
             map(Await::parse, |await_| Self::Await(await_)),
             map(Contains::parse, |contains| Self::Contains(contains)),
+            map(Emit::parse, |emit| Self::Emit(emit)),
             map(GetOrUse::parse, |get_or_use| Self::GetOrUse(get_or_use)),
             map(Get::parse, |get| Self::Get(get)),
+            map(MappingLen::parse, |mapping_len| Self::MappingLen(mapping_len)),
+            map(MappingKeys::parse, |mapping_keys| Self::MappingKeys(mapping_keys)),
             map(RandChaCha::parse, |rand_chacha| Self::RandChaCha(rand_chacha)),
             map(Remove::parse, |remove| Self::Remove(remove)),
             map(Set::parse, |set| Self::Set(set)),
@@ -342,8 +438,11 @@ impl<N: Network> Display for Command<N> {
             Self::Instruction(instruction) => Display::fmt(instruction, f),
             Self::Await(await_) => Display::fmt(await_, f),
             Self::Contains(contains) => Display::fmt(contains, f),
+            Self::Emit(emit) => Display::fmt(emit, f),
             Self::Get(get) => Display::fmt(get, f),
             Self::GetOrUse(get_or_use) => Display::fmt(get_or_use, f),
+            Self::MappingLen(mapping_len) => Display::fmt(mapping_len, f),
+            Self::MappingKeys(mapping_keys) => Display::fmt(mapping_keys, f),
             Self::RandChaCha(rand_chacha) => Display::fmt(rand_chacha, f),
             Self::Remove(remove) => Display::fmt(remove, f),
             Self::Set(set) => Display::fmt(set, f),
@@ -389,6 +488,12 @@ mod tests {
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
 
+        // Emit
+        let expected = "emit transfer r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
         // Get
         let expected = "get object[r0] into r1;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -401,6 +506,18 @@ mod tests {
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
 
+        // MappingLen
+        let expected = "mapping.len object limit 1024u32 into r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // MappingKeys
+        let expected = "mapping.keys object from r0 limit 4u32 or_use r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
         // RandChaCha
         let expected = "rand.chacha into r1 as field;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -466,6 +583,12 @@ mod tests {
         assert_eq!(Command::Contains(Contains::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
 
+        // Emit
+        let expected = "emit transfer r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Emit(Emit::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
         // Get
         let expected = "get object[r0] into r1;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -478,6 +601,18 @@ mod tests {
         assert_eq!(Command::GetOrUse(GetOrUse::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
 
+        // MappingLen
+        let expected = "mapping.len object limit 1024u32 into r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::MappingLen(MappingLen::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // MappingKeys
+        let expected = "mapping.keys object from r0 limit 4u32 or_use r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::MappingKeys(MappingKeys::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
         // RandChaCha
         let expected = "rand.chacha into r1 as field;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
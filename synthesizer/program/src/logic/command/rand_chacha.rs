@@ -21,7 +21,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, Register, Value},
-    types::{Address, Boolean, Field, Group, Scalar, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8},
+    types::{Address, Boolean, Field, Fixed, Group, Scalar, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8},
 };
 
 use rand::SeedableRng;
@@ -129,6 +129,7 @@ impl<N: Network> RandChaCha<N> {
             LiteralType::Scalar => Literal::Scalar(Scalar::rand(&mut rng)),
             LiteralType::Signature => bail!("Cannot 'rand.chacha' into a 'signature'"),
             LiteralType::String => bail!("Cannot 'rand.chacha' into a 'string'"),
+            LiteralType::Fixed => Literal::Fixed(Fixed::rand(&mut rng)),
         };
 
         // Assign the value to the destination register.
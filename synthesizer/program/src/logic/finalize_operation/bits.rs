@@ -79,7 +79,15 @@ impl<N: Network> FromBits for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            6.. => bail!("Invalid finalize operation variant '{variant}'"),
+            6 => {
+                // Read the topic ID.
+                let topic_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the event ID.
+                let event_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Return the finalize operation.
+                Ok(Self::EmitEvent(topic_id, event_id))
+            }
+            7.. => bail!("Invalid finalize operation variant '{variant}'"),
         }
     }
 
@@ -147,7 +155,15 @@ impl<N: Network> FromBits for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            6.. => bail!("Invalid finalize operation variant '{variant}'"),
+            6 => {
+                // Read the topic ID.
+                let topic_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the event ID.
+                let event_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Return the finalize operation.
+                Ok(Self::EmitEvent(topic_id, event_id))
+            }
+            7.. => bail!("Invalid finalize operation variant '{variant}'"),
         }
     }
 }
@@ -202,6 +218,14 @@ impl<N: Network> ToBits for FinalizeOperation<N> {
                 // Write the mapping ID.
                 mapping_id.write_bits_le(vec);
             }
+            Self::EmitEvent(topic_id, event_id) => {
+                // Write the variant.
+                6u8.write_bits_le(vec);
+                // Write the topic ID.
+                topic_id.write_bits_le(vec);
+                // Write the event ID.
+                event_id.write_bits_le(vec);
+            }
         }
     }
 
@@ -254,6 +278,14 @@ impl<N: Network> ToBits for FinalizeOperation<N> {
                 // Write the mapping ID.
                 mapping_id.write_bits_be(vec);
             }
+            Self::EmitEvent(topic_id, event_id) => {
+                // Write the variant.
+                6u8.write_bits_be(vec);
+                // Write the topic ID.
+                topic_id.write_bits_be(vec);
+                // Write the event ID.
+                event_id.write_bits_be(vec);
+            }
         }
     }
 }
@@ -67,7 +67,15 @@ impl<N: Network> FromBytes for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            6.. => Err(error(format!("Failed to decode finalize operation variant {variant}"))),
+            6 => {
+                // Read the topic ID.
+                let topic_id = Field::read_le(&mut reader)?;
+                // Read the event ID.
+                let event_id = Field::read_le(&mut reader)?;
+                // Return the finalize operation.
+                Ok(Self::EmitEvent(topic_id, event_id))
+            }
+            7.. => Err(error(format!("Failed to decode finalize operation variant {variant}"))),
         }
     }
 }
@@ -122,6 +130,14 @@ impl<N: Network> ToBytes for FinalizeOperation<N> {
                 // Write the mapping ID.
                 mapping_id.write_le(&mut writer)?;
             }
+            Self::EmitEvent(topic_id, event_id) => {
+                // Write the variant.
+                6u8.write_le(&mut writer)?;
+                // Write the topic ID.
+                topic_id.write_le(&mut writer)?;
+                // Write the event ID.
+                event_id.write_le(&mut writer)?;
+            }
         }
         Ok(())
     }
@@ -37,6 +37,8 @@ pub enum FinalizeOperation<N: Network> {
     ReplaceMapping(Field<N>),
     /// Removes a mapping from the program tree, as (`mapping ID`).
     RemoveMapping(Field<N>),
+    /// Emits an event under a program topic, as (`topic ID`, `event ID`).
+    EmitEvent(Field<N>, Field<N>),
 }
 
 #[cfg(test)]
@@ -76,6 +78,11 @@ pub(crate) mod test_helpers {
         FinalizeOperation::RemoveMapping(Uniform::rand(rng))
     }
 
+    /// Samples a random `EmitEvent`.
+    pub(crate) fn sample_emit_event(rng: &mut TestRng) -> FinalizeOperation<CurrentNetwork> {
+        FinalizeOperation::EmitEvent(Uniform::rand(rng), Uniform::rand(rng))
+    }
+
     /// Samples a list of random `FinalizeOperation`.
     pub(crate) fn sample_finalize_operations() -> Vec<FinalizeOperation<CurrentNetwork>> {
         let rng = &mut TestRng::default();
@@ -93,6 +100,7 @@ pub(crate) mod test_helpers {
             sample_remove_key_value(rng),
             sample_replace_mapping(rng),
             sample_remove_mapping(rng),
+            sample_emit_event(rng),
         ]
     }
 }
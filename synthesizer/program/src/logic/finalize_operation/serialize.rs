@@ -62,6 +62,13 @@ impl<N: Network> Serialize for FinalizeOperation<N> {
                         operation.serialize_field("mapping_id", mapping_id)?;
                         operation.end()
                     }
+                    Self::EmitEvent(topic_id, event_id) => {
+                        let mut operation = serializer.serialize_struct("FinalizeOperation", 3)?;
+                        operation.serialize_field("type", "emit_event")?;
+                        operation.serialize_field("topic_id", topic_id)?;
+                        operation.serialize_field("event_id", event_id)?;
+                        operation.end()
+                    }
                 }
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -123,6 +130,14 @@ impl<'de, N: Network> Deserialize<'de> for FinalizeOperation<N> {
                         // Return the operation.
                         Self::RemoveMapping(mapping_id)
                     }
+                    Some("emit_event") => {
+                        // Deserialize the topic ID.
+                        let topic_id = DeserializeExt::take_from_value::<D>(&mut operation, "topic_id")?;
+                        // Deserialize the event ID.
+                        let event_id = DeserializeExt::take_from_value::<D>(&mut operation, "event_id")?;
+                        // Return the operation.
+                        Self::EmitEvent(topic_id, event_id)
+                    }
                     _ => return Err(de::Error::custom("Invalid finalize operation type")),
                 };
                 // Return the operation.
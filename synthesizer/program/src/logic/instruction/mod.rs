@@ -71,6 +71,8 @@ pub enum Instruction<N: Network> {
     AbsWrapped(AbsWrapped<N>),
     /// Adds `first` with `second`, storing the outcome in `destination`.
     Add(Add<N>),
+    /// Adds `first` with `second`, storing the wrapped sum in `destination`, and whether an overflow occurred in `flag`.
+    AddChecked(AddChecked<N>),
     /// Adds `first` with `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     AddWrapped(AddWrapped<N>),
     /// Performs a bitwise `and` operation on `first` and `second`, storing the outcome in `destination`.
@@ -79,6 +81,8 @@ pub enum Instruction<N: Network> {
     AssertEq(AssertEq<N>),
     /// Asserts `first` and `second` are **not** equal.
     AssertNeq(AssertNeq<N>),
+    /// Asserts `condition` is true, halting with the given failure code otherwise.
+    AssertWithCode(AssertWithCode<N>),
     /// Calls a finalize asynchronously on the operands.
     Async(Async<N>),
     /// Calls a closure or function on the operands.
@@ -101,14 +105,24 @@ pub enum Instruction<N: Network> {
     CommitPED128(CommitPED128<N>),
     /// Divides `first` by `second`, storing the outcome in `destination`.
     Div(Div<N>),
+    /// Divides `first` by `second`, rounding half away from zero, and storing the outcome in `destination`.
+    DivRounded(DivRounded<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Computes whether `signature` is a valid secp256k1 ECDSA signature for the given `verifier` and `digest`.
+    ECDSAVerifySecp256k1(ECDSAVerifySecp256k1<N>),
+    /// Computes whether `signature` is a valid secp256k1 ECDSA signature for the given `verifier` and the Keccak-256 digest of `message`.
+    ECDSAVerifySecp256k1Keccak256(ECDSAVerifySecp256k1Keccak256<N>),
+    /// Closes the block of instructions opened by the preceding `repeat` instruction.
+    End(End<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
     GreaterThanOrEqual(GreaterThanOrEqual<N>),
+    /// Multiplies the generator `G` by the scalar `first`, storing the outcome in `destination`.
+    GroupGen(GroupGen<N>),
     /// Performs a BHP hash on inputs of 256-bit chunks.
     HashBHP256(HashBHP256<N>),
     /// Performs a BHP hash on inputs of 512-bit chunks.
@@ -145,20 +159,40 @@ pub enum Instruction<N: Network> {
     HashManyPSD4(HashManyPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashManyPSD8(HashManyPSD8<N>),
+    /// Hashes `first` to a group element using Poseidon with an input rate of 2, storing the outcome in `destination`.
+    HashToGroupPSD2(HashToGroupPSD2<N>),
+    /// Hashes `first` to a group element using Poseidon with an input rate of 4, storing the outcome in `destination`.
+    HashToGroupPSD4(HashToGroupPSD4<N>),
+    /// Hashes `first` to a group element using Poseidon with an input rate of 8, storing the outcome in `destination`.
+    HashToGroupPSD8(HashToGroupPSD8<N>),
     /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
     Inv(Inv<N>),
     /// Computes whether `first` equals `second` as a boolean, storing the outcome in `destination`.
     IsEq(IsEq<N>),
     /// Computes whether `first` does **not** equals `second` as a boolean, storing the outcome in `destination`.
     IsNeq(IsNeq<N>),
+    /// Computes whether the option `first` is `some` as a boolean, storing the outcome in `destination`.
+    IsSome(IsSome<N>),
+    /// Counts the number of leading zero bits in `first`, storing the outcome in `destination`.
+    LeadingZeros(LeadingZeros<N>),
     /// Computes whether `first` is less than `second` as a boolean, storing the outcome in `destination`.
     LessThan(LessThan<N>),
     /// Computes whether `first` is less than or equal to `second` as a boolean, storing the outcome in `destination`.
     LessThanOrEqual(LessThanOrEqual<N>),
+    /// Computes whether `path` is a valid Merkle path from `leaf` to `root` using BHP hashes, storing the outcome in `destination`.
+    MerkleVerifyBHP(MerkleVerifyBHP<N>),
+    /// Computes whether `path` is a valid Merkle path from `leaf` to `root` using Poseidon hashes, storing the outcome in `destination`.
+    MerkleVerifyPSD(MerkleVerifyPSD<N>),
+    /// Computes the multi-scalar multiplication of `first` and `second`, storing the outcome in `destination`.
+    MSM(MSM<N>),
     /// Computes `first` mod `second`, storing the outcome in `destination`.
     Modulo(Modulo<N>),
     /// Multiplies `first` with `second`, storing the outcome in `destination`.
     Mul(Mul<N>),
+    /// Multiplies `first` with `second`, storing the wrapped product in `destination`, and whether an overflow occurred in `flag`.
+    MulChecked(MulChecked<N>),
+    /// Multiplies `first` with `second`, rounding half away from zero, and storing the outcome in `destination`.
+    MulRounded(MulRounded<N>),
     /// Multiplies `first` with `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     MulWrapped(MulWrapped<N>),
     /// Returns `false` if `first` and `second` are true, storing the outcome in `destination`.
@@ -169,8 +203,14 @@ pub enum Instruction<N: Network> {
     Nor(Nor<N>),
     /// Flips each bit in the representation of `first`, storing the outcome in `destination`.
     Not(Not<N>),
+    /// Wraps `first` into an option that is `none`, storing the outcome in `destination`.
+    OptionNone(OptionNone<N>),
+    /// Wraps `first` into an option that is `some`, storing the outcome in `destination`.
+    OptionSome(OptionSome<N>),
     /// Performs a bitwise `or` on `first` and `second`, storing the outcome in `destination`.
     Or(Or<N>),
+    /// Counts the number of set bits in `first`, storing the outcome in `destination`.
+    PopCount(PopCount<N>),
     /// Raises `first` to the power of `second`, storing the outcome in `destination`.
     Pow(Pow<N>),
     /// Raises `first` to the power of `second`, wrapping around at the boundary of the type, storing the outcome in `destination`.
@@ -179,6 +219,12 @@ pub enum Instruction<N: Network> {
     Rem(Rem<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, storing the remainder in `destination`.
     RemWrapped(RemWrapped<N>),
+    /// Repeats the block of instructions up to the matching `end` instruction a constant number of times.
+    Repeat(Repeat<N>),
+    /// Rotates `first` left by `second` bits, modulo the number of bits in the type, storing the outcome in `destination`.
+    RotateLeft(RotateLeft<N>),
+    /// Rotates `first` right by `second` bits, modulo the number of bits in the type, storing the outcome in `destination`.
+    RotateRight(RotateRight<N>),
     /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
     Shl(Shl<N>),
     /// Shifts `first` left by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
@@ -193,12 +239,24 @@ pub enum Instruction<N: Network> {
     Square(Square<N>),
     /// Compute the square root of 'first', storing the outcome in `destination`.
     SquareRoot(SquareRoot<N>),
+    /// Concatenates `first` with `second`, storing the outcome in `destination`.
+    StringConcat(StringConcat<N>),
+    /// Computes the number of bytes in `first`, storing the outcome in `destination`.
+    StringLength(StringLength<N>),
     /// Computes `first - second`, storing the outcome in `destination`.
     Sub(Sub<N>),
+    /// Computes `first - second`, storing the wrapped difference in `destination`, and whether an underflow occurred in `flag`.
+    SubChecked(SubChecked<N>),
     /// Computes `first - second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     SubWrapped(SubWrapped<N>),
     /// Selects `first`, if `condition` is true, otherwise selects `second`, storing the result in `destination`.
     Ternary(Ternary<N>),
+    /// Counts the number of trailing zero bits in `first`, storing the outcome in `destination`.
+    TrailingZeros(TrailingZeros<N>),
+    /// Unwraps the option `first`, storing its value in `destination`. Halts if `first` is `none`.
+    Unwrap(Unwrap<N>),
+    /// Unwraps the option `first` if it is `some`, otherwise selects `second`, storing the outcome in `destination`.
+    UnwrapOr(UnwrapOr<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
 }
@@ -300,6 +358,36 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // Note: New instructions are appended below, as the opcode index is used in the byte encoding.
+            ECDSAVerifySecp256k1,
+            ECDSAVerifySecp256k1Keccak256,
+            Repeat,
+            End,
+            StringLength,
+            StringConcat,
+            RotateLeft,
+            RotateRight,
+            PopCount,
+            LeadingZeros,
+            TrailingZeros,
+            MerkleVerifyBHP,
+            MerkleVerifyPSD,
+            OptionSome,
+            OptionNone,
+            IsSome,
+            Unwrap,
+            UnwrapOr,
+            GroupGen,
+            MSM,
+            HashToGroupPSD2,
+            HashToGroupPSD4,
+            HashToGroupPSD8,
+            AddChecked,
+            SubChecked,
+            MulChecked,
+            DivRounded,
+            MulRounded,
+            AssertWithCode,
         }}
     };
     // A variant **without** curly braces:
@@ -403,6 +491,12 @@ impl<N: Network> Instruction<N> {
         instruction!(self, |instruction| instruction.operands())
     }
 
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        instruction!(self, |instruction| Self::from(instruction.map_registers(map)))
+    }
+
     /// Evaluates the instruction.
     #[inline]
     pub fn evaluate(
@@ -469,7 +563,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            97,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
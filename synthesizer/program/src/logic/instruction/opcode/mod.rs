@@ -29,12 +29,26 @@ pub enum Opcode {
     Command(&'static str),
     /// The opcode is for a commit operation (i.e. `commit.psd4`).
     Commit(&'static str),
+    /// The opcode is for an ECDSA signature verification operation (i.e. `ecdsa.verify.secp256k1`).
+    ECDSA(&'static str),
+    /// The opcode closes a repeated block of instructions (i.e. `end`).
+    End,
+    /// The opcode is for a group operation (i.e. `group.gen`).
+    Group(&'static str),
     /// The opcode is for a hash operation (i.e. `hash.psd4`).
     Hash(&'static str),
     /// The opcode is for an 'is' operation (i.e. `is.eq`).
     Is(&'static str),
     /// The opcode is for a literal operation (i.e. `add`).
     Literal(&'static str),
+    /// The opcode is for a Merkle path verification operation (i.e. `merkle.verify.psd`).
+    Merkle(&'static str),
+    /// The opcode is for an option operation (i.e. `unwrap`).
+    Option(&'static str),
+    /// The opcode is for an arithmetic operation that flags an overflow (i.e. `add.checked`).
+    Overflowing(&'static str),
+    /// The opcode opens a repeated block of instructions (i.e. `repeat`).
+    Repeat,
     /// The opcode is for signature verification (i.e. `sign.verify`).
     Sign,
 }
@@ -51,9 +65,16 @@ impl Deref for Opcode {
             Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
+            Opcode::ECDSA(opcode) => opcode,
+            Opcode::End => &"end",
+            Opcode::Group(opcode) => opcode,
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
+            Opcode::Merkle(opcode) => opcode,
+            Opcode::Option(opcode) => opcode,
+            Opcode::Overflowing(opcode) => opcode,
+            Opcode::Repeat => &"repeat",
             Opcode::Sign => &"sign.verify",
         }
     }
@@ -76,9 +97,16 @@ impl Display for Opcode {
             Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
+            Self::ECDSA(opcode) => write!(f, "{opcode}"),
+            Self::End => write!(f, "{}", self.deref()),
+            Self::Group(opcode) => write!(f, "{opcode}"),
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
+            Self::Merkle(opcode) => write!(f, "{opcode}"),
+            Self::Option(opcode) => write!(f, "{opcode}"),
+            Self::Overflowing(opcode) => write!(f, "{opcode}"),
+            Self::Repeat => write!(f, "{}", self.deref()),
             Self::Sign => write!(f, "{}", self.deref()),
         }
     }
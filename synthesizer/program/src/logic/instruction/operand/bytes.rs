@@ -24,6 +24,10 @@ impl<N: Network> FromBytes for Operand<N> {
             4 => Ok(Self::Caller),
             5 => Ok(Self::BlockHeight),
             6 => Ok(Self::NetworkID),
+            7 => Ok(Self::BlockTimestamp),
+            8 => Ok(Self::PreviousBlockHash),
+            9 => Ok(Self::SelfAddress),
+            10 => Ok(Self::Constant(Identifier::read_le(&mut reader)?)),
             variant => Err(error(format!("Failed to deserialize operand variant {variant}"))),
         }
     }
@@ -48,6 +52,13 @@ impl<N: Network> ToBytes for Operand<N> {
             Self::Caller => 4u8.write_le(&mut writer),
             Self::BlockHeight => 5u8.write_le(&mut writer),
             Self::NetworkID => 6u8.write_le(&mut writer),
+            Self::BlockTimestamp => 7u8.write_le(&mut writer),
+            Self::PreviousBlockHash => 8u8.write_le(&mut writer),
+            Self::SelfAddress => 9u8.write_le(&mut writer),
+            Self::Constant(name) => {
+                10u8.write_le(&mut writer)?;
+                name.write_le(&mut writer)
+            }
         }
     }
 }
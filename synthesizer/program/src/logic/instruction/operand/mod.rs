@@ -17,7 +17,7 @@ mod parse;
 
 use console::{
     network::prelude::*,
-    program::{Literal, ProgramID, Register},
+    program::{Identifier, Literal, ProgramID, Register},
     types::Group,
 };
 
@@ -37,12 +37,22 @@ pub enum Operand<N: Network> {
     /// The operand is the caller address.
     /// Note: This variant is only accessible in the `function` scope.
     Caller,
+    /// The operand is the address of the current program.
+    SelfAddress,
     /// The operand is the block height.
     /// Note: This variant is only accessible in the `finalize` scope.
     BlockHeight,
     /// The operand is the network ID.
     /// Note: This variant is only accessible in the `finalize` scope.
     NetworkID,
+    /// The operand is the block timestamp.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockTimestamp,
+    /// The operand is the previous block hash.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    PreviousBlockHash,
+    /// The operand is a constant declared in the program.
+    Constant(Identifier<N>),
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
@@ -77,6 +87,17 @@ impl<N: Network> From<&Register<N>> for Operand<N> {
     }
 }
 
+impl<N: Network> Operand<N> {
+    /// Returns the operand, with its register locator (if any) remapped by the given function.
+    #[inline]
+    pub fn map_register(&self, map: impl Fn(u64) -> u64) -> Self {
+        match self {
+            Operand::Register(register) => Operand::Register(register.map_locator(map)),
+            operand => operand.clone(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
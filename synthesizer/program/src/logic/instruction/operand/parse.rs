@@ -25,13 +25,22 @@ impl<N: Network> Parser for Operand<N> {
             map(tag("group::GEN"), |_| Self::Literal(Literal::Group(Group::generator()))),
             map(tag("self.signer"), |_| Self::Signer),
             map(tag("self.caller"), |_| Self::Caller),
+            map(tag("self.address"), |_| Self::SelfAddress),
             map(tag("block.height"), |_| Self::BlockHeight),
             map(tag("network.id"), |_| Self::NetworkID),
+            map(tag("block.timestamp"), |_| Self::BlockTimestamp),
+            map(tag("block.previous_hash"), |_| Self::PreviousBlockHash),
             // Note that `Operand::ProgramID`s must be parsed before `Operand::Literal`s, since a program ID can be implicitly parsed as a literal address.
             // This ensures that the string representation of a program uses the `Operand::ProgramID` variant.
             map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
             map(Literal::parse, |literal| Self::Literal(literal)),
             map(Register::parse, |register| Self::Register(register)),
+            // Note that `Operand::Constant`s must be parsed last, since literals and registers are also identifiers.
+            // The `into` and `as` keywords are excluded, as they terminate the operands of an instruction.
+            map_res(Identifier::parse, |identifier: Identifier<N>| match identifier.to_string().as_str() {
+                "into" | "as" => Err(error(format!("'{identifier}' is not a valid operand"))),
+                _ => Ok(Self::Constant(identifier)),
+            }),
         ))(string)
     }
 }
@@ -75,10 +84,18 @@ impl<N: Network> Display for Operand<N> {
             Self::Signer => write!(f, "self.signer"),
             // Prints the identifier for the caller, i.e. self.caller
             Self::Caller => write!(f, "self.caller"),
+            // Prints the identifier for the program address, i.e. self.address
+            Self::SelfAddress => write!(f, "self.address"),
             // Prints the identifier for the block height, i.e. block.height
             Self::BlockHeight => write!(f, "block.height"),
             // Prints the identifier for the network ID, i.e. network.id
             Self::NetworkID => write!(f, "network.id"),
+            // Prints the identifier for the block timestamp, i.e. block.timestamp
+            Self::BlockTimestamp => write!(f, "block.timestamp"),
+            // Prints the identifier for the previous block hash, i.e. block.previous_hash
+            Self::PreviousBlockHash => write!(f, "block.previous_hash"),
+            // Prints the name of the constant, i.e. MAX_SUPPLY
+            Self::Constant(name) => Display::fmt(name, f),
         }
     }
 }
@@ -110,15 +127,31 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(Operand::Caller, operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("self.address").unwrap().1;
+        assert_eq!(Operand::SelfAddress, operand);
+
         let operand = Operand::<CurrentNetwork>::parse("block.height").unwrap().1;
         assert_eq!(Operand::BlockHeight, operand);
 
         let operand = Operand::<CurrentNetwork>::parse("network.id").unwrap().1;
         assert_eq!(Operand::NetworkID, operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("block.timestamp").unwrap().1;
+        assert_eq!(Operand::BlockTimestamp, operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("block.previous_hash").unwrap().1;
+        assert_eq!(Operand::PreviousBlockHash, operand);
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(Operand::Literal(Literal::Group(Group::generator())), operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("MAX_SUPPLY").unwrap().1;
+        assert_eq!(Operand::Constant(Identifier::from_str("MAX_SUPPLY")?), operand);
+
+        // Ensure the keywords that terminate the operands are not parsed as constants.
+        assert!(Operand::<CurrentNetwork>::parse("into").is_err());
+        assert!(Operand::<CurrentNetwork>::parse("as").is_err());
+
         // Sanity check a failure case.
         let (remainder, operand) = Operand::<CurrentNetwork>::parse("1field.private").unwrap();
         assert_eq!(Operand::Literal(Literal::from_str("1field")?), operand);
@@ -147,6 +180,18 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(format!("{operand}"), "self.caller");
 
+        let operand = Operand::<CurrentNetwork>::parse("self.address").unwrap().1;
+        assert_eq!(format!("{operand}"), "self.address");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.timestamp").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.timestamp");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.previous_hash").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.previous_hash");
+
+        let operand = Operand::<CurrentNetwork>::parse("MAX_SUPPLY").unwrap().1;
+        assert_eq!(format!("{operand}"), "MAX_SUPPLY");
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(
             format!("{operand}"),
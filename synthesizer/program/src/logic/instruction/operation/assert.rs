@@ -73,6 +73,12 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![]
     }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self { operands: self.operands.iter().map(|operand| operand.map_register(map)).collect() }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
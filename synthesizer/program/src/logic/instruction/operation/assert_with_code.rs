@@ -0,0 +1,322 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+};
+
+/// Asserts `condition` is `true`, halting with the given failure `code` otherwise.
+///
+/// The failure code is surfaced in the error returned on evaluation, execution, and finalization,
+/// so callers can determine *why* an assertion failed, e.g. `assert r0 "E_INSUFFICIENT_BALANCE";`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AssertWithCode<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The failure code.
+    code: String,
+}
+
+impl<N: Network> AssertWithCode<N> {
+    /// The maximum number of bytes in a failure code.
+    pub const MAX_CODE_BYTES: usize = 64;
+
+    /// Initializes a new `assert` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, code: String) -> Result<Self> {
+        // Sanity check that the operands is exactly one input.
+        ensure!(operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Ensure the failure code is well-formed.
+        ensure!(Self::is_valid_code(&code), "Invalid failure code \"{code}\" in '{}'", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, code })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Assert("assert")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly one input.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the failure code.
+    #[inline]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![]
+    }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            code: self.code.clone(),
+        }
+    }
+
+    /// Returns `true` if the given failure code is non-empty, within `MAX_CODE_BYTES`,
+    /// and consists only of ASCII alphanumeric characters, `_`, `-`, and `.`.
+    fn is_valid_code(code: &str) -> bool {
+        !code.is_empty()
+            && code.len() <= Self::MAX_CODE_BYTES
+            && code.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    }
+}
+
+impl<N: Network> AssertWithCode<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the condition.
+        let condition = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Literal(Literal::Boolean(condition), ..)) => condition,
+            _ => bail!("Instruction '{}' expects a boolean condition", Self::opcode()),
+        };
+
+        // Assert the condition.
+        if !*condition {
+            bail!("'{}' failed with code \"{}\"", Self::opcode(), self.code)
+        }
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersLoadCircuit<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the condition.
+        let condition = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Boolean(condition), ..)) => {
+                condition
+            }
+            _ => bail!("Instruction '{}' expects a boolean condition", Self::opcode()),
+        };
+
+        // Assert the condition.
+        A::assert(condition);
+        Ok(())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the condition is a boolean.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean)) {
+            bail!(
+                "Instruction '{}' expects a boolean condition. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        Ok(vec![])
+    }
+}
+
+impl<N: Network> Parser for AssertWithCode<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the condition from the string.
+        let (string, condition) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the opening quote from the string.
+        let (string, _) = char('"')(string)?;
+        // Parse the failure code from the string.
+        let (string, code) =
+            map_res(recognize(many1(alt((alphanumeric1, tag("_"), tag("-"), tag("."))))), |code: &str| {
+                match code.len() <= Self::MAX_CODE_BYTES {
+                    true => Ok(code.to_string()),
+                    false => Err(error(format!("Failure code exceeds the maximum of {} bytes", Self::MAX_CODE_BYTES))),
+                }
+            })(string)?;
+        // Parse the closing quote from the string.
+        let (string, _) = char('"')(string)?;
+
+        Ok((string, Self { operands: vec![condition], code }))
+    }
+}
+
+impl<N: Network> FromStr for AssertWithCode<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for AssertWithCode<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for AssertWithCode<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} \"{}\"", Self::opcode(), self.operands[0], self.code)
+    }
+}
+
+impl<N: Network> FromBytes for AssertWithCode<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the condition.
+        let condition = Operand::read_le(&mut reader)?;
+        // Read the number of bytes in the failure code.
+        let num_bytes = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of bytes is within bounds.
+        if num_bytes > Self::MAX_CODE_BYTES {
+            return Err(error(format!("Failure code exceeds the maximum of {} bytes", Self::MAX_CODE_BYTES)));
+        }
+        // Read the failure code.
+        let mut bytes = vec![0u8; num_bytes];
+        reader.read_exact(&mut bytes)?;
+        let code = String::from_utf8(bytes).map_err(|e| error(e.to_string()))?;
+
+        // Return the operation.
+        Self::new(vec![condition], code).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for AssertWithCode<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the condition.
+        self.operands[0].write_le(&mut writer)?;
+        // Write the number of bytes in the failure code.
+        u8::try_from(self.code.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the failure code.
+        writer.write_all(self.code.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, assert) = AssertWithCode::<CurrentNetwork>::parse("assert r0 \"E_INSUFFICIENT_BALANCE\"").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(assert.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(assert.operands[0], Operand::Register(Register::Locator(0)), "The operand is incorrect");
+        assert_eq!(assert.code(), "E_INSUFFICIENT_BALANCE", "The failure code is incorrect");
+
+        // Ensure the opcode does not consume other assert instructions.
+        assert!(AssertWithCode::<CurrentNetwork>::parse("assert.eq r0 r1").is_err());
+        // Ensure the failure code is well-formed.
+        assert!(AssertWithCode::<CurrentNetwork>::parse("assert r0 \"\"").is_err());
+        assert!(AssertWithCode::<CurrentNetwork>::parse("assert r0 \"has space\"").is_err());
+        assert!(AssertWithCode::<CurrentNetwork>::parse("assert r0 E_MISSING_QUOTES").is_err());
+        assert!(AssertWithCode::<CurrentNetwork>::parse(&format!("assert r0 \"{}\"", "E".repeat(65))).is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let expected = AssertWithCode::<CurrentNetwork>::from_str("assert r3 \"ERR-42.a\"").unwrap();
+        let bytes = expected.to_bytes_le().unwrap();
+        let candidate = AssertWithCode::<CurrentNetwork>::from_bytes_le(&bytes).unwrap();
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+        assert_eq!("assert r3 \"ERR-42.a\"", candidate.to_string());
+    }
+}
@@ -66,6 +66,16 @@ impl<N: Network> Async<N> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            function_name: self.function_name,
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destination: self.destination.map_locator(map),
+        }
+    }
 }
 
 impl<N: Network> Async<N> {
@@ -144,6 +144,16 @@ impl<N: Network> Call<N> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         self.destinations.clone()
     }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operator: self.operator.clone(),
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destinations: self.destinations.iter().map(|destination| destination.map_locator(map)).collect(),
+        }
+    }
 }
 
 impl<N: Network> Call<N> {
@@ -45,7 +45,7 @@ use console::{
         Value,
         ValueType,
     },
-    types::Field,
+    types::{Field, StringType, U8},
 };
 
 use indexmap::IndexMap;
@@ -191,6 +191,16 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
         vec![self.destination.clone()]
     }
 
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destination: self.destination.map_locator(map),
+            cast_type: self.cast_type.clone(),
+        }
+    }
+
     /// Returns the cast type.
     #[inline]
     pub const fn cast_type(&self) -> &CastType<N> {
@@ -242,6 +252,10 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                         1 => literal.cast_lossy(*literal_type)?,
                         2.. => unreachable!("Invalid cast variant"),
                     },
+                    // If the operand is an array of bytes, cast it into a string.
+                    Value::Plaintext(plaintext @ Plaintext::Array(..)) if *literal_type == LiteralType::String => {
+                        Self::cast_bytes_to_string(plaintext)?
+                    }
                     _ => bail!("Casting to a literal requires a literal"),
                 };
                 registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(value)))
@@ -252,6 +266,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Option(..)) => {
+                bail!("Illegal operation: Cannot cast to an option, use 'some' or 'none' instead.")
+            }
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -387,6 +404,22 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                         1 => literal.cast_lossy(*literal_type)?,
                         2.. => unreachable!("Invalid cast variant"),
                     },
+                    // If the operand is an array of bytes, cast it into a string.
+                    circuit::Value::Plaintext(plaintext @ circuit::Plaintext::Array(elements, ..))
+                        if *literal_type == LiteralType::String =>
+                    {
+                        // Ensure the bytes form a valid string.
+                        Self::cast_bytes_to_string(&plaintext.eject_value())?;
+                        // Retrieve the bytes.
+                        let bytes = elements
+                            .iter()
+                            .map(|element| match element {
+                                circuit::Plaintext::Literal(circuit::Literal::U8(byte), ..) => Ok(byte.clone()),
+                                _ => bail!("Casting to a string requires an array of 'u8' elements"),
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        circuit::Literal::String(circuit::StringType::from_bytes(&bytes))
+                    }
                     _ => bail!("Casting to a literal requires a literal"),
                 };
                 registers.store_circuit(
@@ -448,6 +481,23 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(struct_))
             }
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
+                // If the operand is a string, cast it into an array of bytes.
+                if let [circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::String(string), ..))] =
+                    inputs.as_slice()
+                {
+                    // Retrieve the bytes as array elements.
+                    let elements = string
+                        .to_bytes()
+                        .into_iter()
+                        .map(|byte| circuit::Plaintext::from(circuit::Literal::U8(byte)))
+                        .collect::<Vec<_>>();
+                    // Ensure the array matches the array type.
+                    let array = circuit::Plaintext::Array(elements, Default::default());
+                    Self::check_string_to_bytes(stack, &array.eject_value(), array_type)?;
+                    // Store the array.
+                    return registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array));
+                }
+
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_ARRAY_ELEMENTS {
                     bail!("Casting to an array requires at least {} operand(s)", N::MIN_ARRAY_ELEMENTS)
@@ -492,6 +542,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 // Store the array.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
             }
+            CastType::Plaintext(PlaintextType::Option(..)) => {
+                bail!("Illegal operation: Cannot cast to an option, use 'some' or 'none' instead.")
+            }
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -624,6 +677,10 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                         1 => literal.cast_lossy(*literal_type)?,
                         2.. => unreachable!("Invalid cast variant"),
                     },
+                    // If the operand is an array of bytes, cast it into a string.
+                    Value::Plaintext(plaintext @ Plaintext::Array(..)) if *literal_type == LiteralType::String => {
+                        Self::cast_bytes_to_string(plaintext)?
+                    }
                     _ => bail!("Casting to a literal requires a literal"),
                 };
                 registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(value)))
@@ -634,6 +691,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Option(..)) => {
+                bail!("Illegal operation: Cannot cast to an option, use 'some' or 'none' instead.")
+            }
             CastType::Record(_record_name) => {
                 bail!("Illegal operation: Cannot cast to a record in a finalize block.")
             }
@@ -677,8 +737,24 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     input_types[0]
                 );
             }
-            CastType::Plaintext(PlaintextType::Literal(..)) => {
+            CastType::Plaintext(PlaintextType::Literal(literal_type)) => {
                 ensure!(input_types.len() == 1, "Casting to a literal requires exactly 1 operand");
+                // If the operand is an array, ensure it is an array of bytes that is cast into a string.
+                if let RegisterType::Plaintext(PlaintextType::Array(array_type)) = &input_types[0] {
+                    ensure!(
+                        *literal_type == LiteralType::String,
+                        "Casting an array to a literal is only supported for 'string', found '{literal_type}'"
+                    );
+                    ensure!(
+                        array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8),
+                        "Casting to a string requires an array of 'u8' elements, found '{array_type}'"
+                    );
+                    ensure!(
+                        **array_type.length() <= N::MAX_STRING_BYTES,
+                        "Casting to a string cannot exceed {} bytes, found '{array_type}'",
+                        N::MAX_STRING_BYTES
+                    );
+                }
             }
             CastType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
@@ -727,6 +803,16 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 }
             }
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
+                // If the operand is a string, ensure it is cast into an array of bytes.
+                // Note: The length of the string is checked against the length of the array at runtime.
+                if let [RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String))] = input_types {
+                    ensure!(
+                        array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8),
+                        "Casting a string requires an array of 'u8' elements, found '{array_type}'"
+                    );
+                    return Ok(vec![RegisterType::Plaintext(PlaintextType::Array(array_type.clone()))]);
+                }
+
                 // Ensure the input types length is at least the minimum.
                 if input_types.len() < N::MIN_ARRAY_ELEMENTS {
                     bail!("Casting to an array requires at least {} operand(s)", N::MIN_ARRAY_ELEMENTS)
@@ -775,6 +861,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     }
                 }
             }
+            CastType::Plaintext(PlaintextType::Option(..)) => {
+                bail!("Illegal operation: Cannot cast to an option, use 'some' or 'none' instead.")
+            }
             CastType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(record_name)?;
@@ -909,6 +998,18 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
         array_type: &ArrayType<N>,
         inputs: Vec<Value<N>>,
     ) -> Result<()> {
+        // If the operand is a string, cast it into an array of bytes.
+        if let [Value::Plaintext(Plaintext::Literal(Literal::String(string), ..))] = inputs.as_slice() {
+            // Retrieve the bytes as array elements.
+            let elements =
+                string.as_bytes().iter().map(|byte| Plaintext::from(Literal::U8(U8::new(*byte)))).collect::<Vec<_>>();
+            // Ensure the array matches the array type.
+            let array = Plaintext::Array(elements, Default::default());
+            Self::check_string_to_bytes(stack, &array, array_type)?;
+            // Store the array.
+            return registers.store(stack, &self.destination, Value::Plaintext(array));
+        }
+
         // Ensure that there is at least one operand.
         if inputs.len() < N::MIN_ARRAY_ELEMENTS {
             bail!("Casting to an array requires at least {} operand", N::MIN_ARRAY_ELEMENTS)
@@ -949,6 +1050,54 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
         // Store the array.
         registers.store(stack, &self.destination, Value::Plaintext(array))
     }
+
+    /// A helper method to ensure the bytes of a string match the given array type.
+    fn check_string_to_bytes(
+        stack: &impl StackMatches<N>,
+        array: &Plaintext<N>,
+        array_type: &ArrayType<N>,
+    ) -> Result<()> {
+        // Ensure the number of bytes in the string matches the length of the array.
+        if let Plaintext::Array(elements, ..) = array {
+            ensure!(
+                elements.len() == **array_type.length() as usize,
+                "Casting a string of {} bytes into the array {array_type} requires exactly {} bytes",
+                elements.len(),
+                array_type.length()
+            );
+        }
+        // Ensure the array matches the array type.
+        stack.matches_plaintext(array, &PlaintextType::Array(array_type.clone()))
+    }
+
+    /// A helper method to cast an array of bytes into a string.
+    fn cast_bytes_to_string(plaintext: &Plaintext<N>) -> Result<Literal<N>> {
+        // Ensure the cast is not lossy.
+        ensure!(VARIANT == CastVariant::Cast as u8, "`cast.lossy` does not support casting to a string");
+        // Retrieve the bytes.
+        let bytes = match plaintext {
+            Plaintext::Array(elements, ..) => elements
+                .iter()
+                .map(|element| match element {
+                    Plaintext::Literal(Literal::U8(byte), ..) => Ok(**byte),
+                    _ => bail!("Casting to a string requires an array of 'u8' elements"),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Casting to a string requires an array of 'u8' elements"),
+        };
+        // Ensure the string is within the allowed capacity.
+        ensure!(
+            bytes.len() <= N::MAX_STRING_BYTES as usize,
+            "Casting to a string cannot exceed {} bytes, found {} bytes",
+            N::MAX_STRING_BYTES,
+            bytes.len()
+        );
+        // Ensure the bytes are valid UTF-8.
+        let string =
+            String::from_utf8(bytes).map_err(|error| anyhow!("Casting to a string requires UTF-8: {error}"))?;
+        // Return the string.
+        Ok(Literal::String(StringType::new(&string)))
+    }
 }
 
 impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
@@ -987,7 +1136,8 @@ impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
         let max_operands = match cast_type {
             CastType::GroupXCoordinate
             | CastType::GroupYCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Option(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
@@ -1035,7 +1185,8 @@ impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
         let max_operands = match self.cast_type {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Option(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
@@ -1080,7 +1231,8 @@ impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
         let max_operands = match cast_type {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Option(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
@@ -1101,7 +1253,8 @@ impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
         let max_operands = match self.cast_type {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Option(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
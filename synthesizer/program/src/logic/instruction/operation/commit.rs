@@ -102,6 +102,16 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         vec![self.destination.clone()]
     }
 
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destination: self.destination.map_locator(map),
+            destination_type: self.destination_type,
+        }
+    }
+
     /// Returns the destination register type.
     #[inline]
     pub const fn destination_type(&self) -> LiteralType {
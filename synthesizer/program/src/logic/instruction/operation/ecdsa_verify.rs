@@ -0,0 +1,458 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    algorithms::ECDSASecp256k1,
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Computes whether `signature` is a valid secp256k1 ECDSA signature for the given `verifier` and 32-byte `digest`.
+pub type ECDSAVerifySecp256k1<N> = ECDSAVerify<N, { ECDSAVariant::Secp256k1 as u8 }>;
+/// Computes whether `signature` is a valid secp256k1 ECDSA signature for the given `verifier` and the Keccak-256 digest of `message`.
+pub type ECDSAVerifySecp256k1Keccak256<N> = ECDSAVerify<N, { ECDSAVariant::Secp256k1Keccak256 as u8 }>;
+
+/// Which digest to verify the ECDSA signature against.
+enum ECDSAVariant {
+    /// The message is a prehashed 32-byte digest.
+    Secp256k1,
+    /// The message is hashed with Keccak-256.
+    Secp256k1Keccak256,
+}
+
+/// Returns the number of bytes in the given register type,
+/// if it is a (possibly multi-dimensional) array of `u8` elements.
+fn num_bytes<N: Network>(register_type: &RegisterType<N>) -> Option<usize> {
+    /// Returns the number of bytes in the given plaintext type.
+    fn num_bytes_internal<N: Network>(plaintext_type: &PlaintextType<N>) -> Option<usize> {
+        match plaintext_type {
+            PlaintextType::Literal(LiteralType::U8) => Some(1),
+            PlaintextType::Array(array_type) => {
+                (**array_type.length() as usize).checked_mul(num_bytes_internal(array_type.next_element_type())?)
+            }
+            _ => None,
+        }
+    }
+    match register_type {
+        RegisterType::Plaintext(plaintext_type @ PlaintextType::Array(..)) => num_bytes_internal(plaintext_type),
+        _ => None,
+    }
+}
+
+/// Returns the bytes of the given value, which must be a (possibly multi-dimensional) array of `u8` elements.
+/// The elements of a multi-dimensional array are flattened in order.
+fn to_bytes<N: Network>(value: &Value<N>) -> Result<Vec<u8>> {
+    /// Appends the bytes of the given plaintext to the given vector.
+    fn to_bytes_internal<N: Network>(plaintext: &Plaintext<N>, bytes: &mut Vec<u8>) -> Result<()> {
+        match plaintext {
+            Plaintext::Literal(Literal::U8(byte), _) => bytes.push(**byte),
+            Plaintext::Array(elements, _) => {
+                elements.iter().try_for_each(|element| to_bytes_internal(element, bytes))?;
+            }
+            _ => bail!("Expected an array of 'u8' elements, found '{plaintext}'"),
+        }
+        Ok(())
+    }
+    match value {
+        Value::Plaintext(plaintext @ Plaintext::Array(..)) => {
+            let mut bytes = Vec::new();
+            to_bytes_internal(plaintext, &mut bytes)?;
+            Ok(bytes)
+        }
+        _ => bail!("Expected an array of 'u8' elements, found '{value}'"),
+    }
+}
+
+/// Returns the bytes of the given circuit value, which must be a (possibly multi-dimensional) array of `u8` elements.
+/// The elements of a multi-dimensional array are flattened in order.
+fn to_bytes_circuit<A: circuit::Aleo>(value: &circuit::Value<A>) -> Result<Vec<circuit::U8<A>>> {
+    use circuit::{Literal, Plaintext, Value};
+
+    /// Appends the bytes of the given plaintext to the given vector.
+    fn to_bytes_internal<A: circuit::Aleo>(plaintext: &Plaintext<A>, bytes: &mut Vec<circuit::U8<A>>) -> Result<()> {
+        match plaintext {
+            Plaintext::Literal(Literal::U8(byte), _) => bytes.push(byte.clone()),
+            Plaintext::Array(elements, _) => {
+                elements.iter().try_for_each(|element| to_bytes_internal(element, bytes))?;
+            }
+            _ => bail!("Expected an array of 'u8' elements"),
+        }
+        Ok(())
+    }
+    match value {
+        Value::Plaintext(plaintext @ Plaintext::Array(..)) => {
+            let mut bytes = Vec::new();
+            to_bytes_internal(plaintext, &mut bytes)?;
+            Ok(bytes)
+        }
+        _ => bail!("Expected an array of 'u8' elements"),
+    }
+}
+
+/// Computes whether `signature` is a valid ECDSA signature for the given `verifier` and message.
+///
+/// The operands are byte arrays (i.e. `[u8; N]`, or nested arrays of `u8`, which are flattened in order):
+///   - `signature` is `r || s` (64 bytes) or `r || s || v` (65 bytes), with `r` and `s` in big-endian.
+///   - `verifier` is a compressed (33 bytes) or uncompressed (65 bytes) public key, or an Ethereum address (20 bytes).
+///   - `message` is a 32-byte digest, or for the Keccak-256 variant, the message bytes to be hashed.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ECDSAVerify<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> ECDSAVerify<N, VARIANT> {
+    /// Initializes a new `ecdsa.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::ECDSA("ecdsa.verify.secp256k1"),
+            1 => Opcode::ECDSA("ecdsa.verify.secp256k1.keccak256"),
+            2.. => panic!("Invalid 'ecdsa.verify' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destination: self.destination.map_locator(map),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ECDSAVerify<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = to_bytes(&registers.load(stack, &self.operands[0])?)?;
+        let verifier = to_bytes(&registers.load(stack, &self.operands[1])?)?;
+        let message = to_bytes(&registers.load(stack, &self.operands[2])?)?;
+
+        // Verify the signature.
+        let is_valid = match VARIANT {
+            0 => ECDSASecp256k1::verify_digest(&signature, &verifier, &message)?,
+            1 => ECDSASecp256k1::verify_keccak256(&signature, &verifier, &message)?,
+            2.. => bail!("Invalid 'ecdsa.verify' variant: {VARIANT}"),
+        };
+        let output = Literal::Boolean(Boolean::new(is_valid));
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[0])?)?;
+        let verifier = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[1])?)?;
+        let message = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[2])?)?;
+
+        // Ensure the inputs are well-formed, as the circuit halts otherwise.
+        ensure!(ECDSASecp256k1::is_valid_signature_size(signature.len()), "Invalid ECDSA signature size");
+        ensure!(ECDSASecp256k1::is_valid_verifier_size(verifier.len()), "Invalid ECDSA verifier size");
+        ensure!(
+            verifier.len() != ECDSASecp256k1::ETH_ADDRESS_SIZE_IN_BYTES
+                || signature.len() == ECDSASecp256k1::RECOVERABLE_SIGNATURE_SIZE_IN_BYTES,
+            "An ECDSA signature must contain a recovery ID to be verified against an Ethereum address"
+        );
+
+        // Verify the signature.
+        let output = match VARIANT {
+            0 => {
+                ensure!(message.len() == ECDSASecp256k1::DIGEST_SIZE_IN_BYTES, "Invalid ECDSA digest size");
+                circuit::algorithms::ECDSASecp256k1::verify_digest(&signature, &verifier, &message)
+            }
+            1 => circuit::algorithms::ECDSASecp256k1::verify_keccak256(&signature, &verifier, &message),
+            2.. => bail!("Invalid 'ecdsa.verify' variant: {VARIANT}"),
+        };
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Boolean(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the first operand is a signature of 64 or 65 bytes.
+        let signature_size = match num_bytes(&input_types[0]) {
+            Some(num_bytes) if ECDSASecp256k1::is_valid_signature_size(num_bytes) => num_bytes,
+            _ => bail!(
+                "Instruction '{}' expects the first input to be a 64 or 65 byte array. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            ),
+        };
+
+        // Ensure the second operand is a public key of 33 or 65 bytes, or an Ethereum address of 20 bytes.
+        match num_bytes(&input_types[1]) {
+            Some(ECDSASecp256k1::ETH_ADDRESS_SIZE_IN_BYTES) => ensure!(
+                signature_size == ECDSASecp256k1::RECOVERABLE_SIGNATURE_SIZE_IN_BYTES,
+                "Instruction '{}' expects a 65 byte signature to verify against an Ethereum address",
+                Self::opcode()
+            ),
+            Some(num_bytes) if ECDSASecp256k1::is_valid_verifier_size(num_bytes) => (),
+            _ => bail!(
+                "Instruction '{}' expects the second input to be a 20, 33, or 65 byte array. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            ),
+        }
+
+        // Ensure the third operand is a digest of 32 bytes, or for the Keccak-256 variant, a byte array.
+        match (VARIANT, num_bytes(&input_types[2])) {
+            (0, Some(ECDSASecp256k1::DIGEST_SIZE_IN_BYTES)) | (1, Some(_)) => (),
+            (0, _) => bail!(
+                "Instruction '{}' expects the third input to be a 32 byte array. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            ),
+            _ => bail!(
+                "Instruction '{}' expects the third input to be a byte array. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            ),
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for ECDSAVerify<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for ECDSAVerify<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for ECDSAVerify<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for ECDSAVerify<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for ECDSAVerify<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for ECDSAVerify<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, ecdsa) =
+            ECDSAVerifySecp256k1::<CurrentNetwork>::parse("ecdsa.verify.secp256k1 r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(ecdsa.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(ecdsa.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(ecdsa.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(ecdsa.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(ecdsa.destination, Register::Locator(3), "The destination register is incorrect");
+
+        let (string, ecdsa) =
+            ECDSAVerifySecp256k1Keccak256::<CurrentNetwork>::parse("ecdsa.verify.secp256k1.keccak256 r0 r1 r2 into r3")
+                .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(ecdsa.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(ecdsa.destination, Register::Locator(3), "The destination register is incorrect");
+
+        // Ensure the digest variant does not parse the Keccak-256 variant.
+        assert!(
+            ECDSAVerifySecp256k1::<CurrentNetwork>::parse("ecdsa.verify.secp256k1.keccak256 r0 r1 r2 into r3").is_err()
+        );
+    }
+
+    #[test]
+    fn test_num_bytes() {
+        let register_type = |string: &str| RegisterType::<CurrentNetwork>::from_str(string).unwrap();
+
+        assert_eq!(num_bytes(&register_type("[u8; 20u32]")), Some(20));
+        assert_eq!(num_bytes(&register_type("[u8; 32u32]")), Some(32));
+        assert_eq!(num_bytes(&register_type("[[u8; 32u32]; 2u32]")), Some(64));
+        assert_eq!(num_bytes(&register_type("[[u8; 13u32]; 5u32]")), Some(65));
+        assert_eq!(num_bytes(&register_type("[u16; 32u32]")), None);
+        assert_eq!(num_bytes(&register_type("u8")), None);
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let value = Value::<CurrentNetwork>::from_str("[[1u8, 2u8], [3u8, 4u8]]").unwrap();
+        assert_eq!(to_bytes(&value).unwrap(), vec![1, 2, 3, 4]);
+
+        let value = Value::<CurrentNetwork>::from_str("[1u16, 2u16]").unwrap();
+        assert!(to_bytes(&value).is_err());
+        let value = Value::<CurrentNetwork>::from_str("1u8").unwrap();
+        assert!(to_bytes(&value).is_err());
+    }
+}
@@ -0,0 +1,531 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Group,
+};
+
+/// The maximum number of terms in a multi-scalar multiplication.
+pub const MAX_MSM_TERMS: u32 = 32;
+
+/// Multiplies the generator `G` by the scalar `first`, storing the outcome in `destination`.
+pub type GroupGen<N> = GroupInstruction<N, { GroupVariant::GroupGen as u8 }>;
+/// Computes the multi-scalar multiplication of `first` and `second`, storing the outcome in `destination`.
+pub type MSM<N> = GroupInstruction<N, { GroupVariant::MSM as u8 }>;
+/// Hashes `first` to a group element using Poseidon with an input rate of 2, storing the outcome in `destination`.
+pub type HashToGroupPSD2<N> = GroupInstruction<N, { GroupVariant::HashToGroupPSD2 as u8 }>;
+/// Hashes `first` to a group element using Poseidon with an input rate of 4, storing the outcome in `destination`.
+pub type HashToGroupPSD4<N> = GroupInstruction<N, { GroupVariant::HashToGroupPSD4 as u8 }>;
+/// Hashes `first` to a group element using Poseidon with an input rate of 8, storing the outcome in `destination`.
+pub type HashToGroupPSD8<N> = GroupInstruction<N, { GroupVariant::HashToGroupPSD8 as u8 }>;
+
+/// Which group operation is performed.
+enum GroupVariant {
+    GroupGen,
+    MSM,
+    HashToGroupPSD2,
+    HashToGroupPSD4,
+    HashToGroupPSD8,
+}
+
+/// Returns the elements of the given value, which must be an array of literals of the given type.
+fn to_literals<N: Network>(value: Value<N>, literal_type: LiteralType) -> Result<Vec<Literal<N>>> {
+    match value {
+        Value::Plaintext(Plaintext::Array(elements, _)) => elements
+            .into_iter()
+            .map(|element| match element {
+                Plaintext::Literal(literal, _) if literal.to_type() == literal_type => Ok(literal),
+                _ => bail!("Expected a '{literal_type}' element, found '{element}'"),
+            })
+            .collect(),
+        _ => bail!("Expected an array of '{literal_type}' elements, found '{value}'"),
+    }
+}
+
+/// Returns the elements of the given circuit value, which must be an array of literals.
+fn to_literals_circuit<A: circuit::Aleo>(value: circuit::Value<A>) -> Result<Vec<circuit::Literal<A>>> {
+    match value {
+        circuit::Value::Plaintext(circuit::Plaintext::Array(elements, _)) => elements
+            .into_iter()
+            .map(|element| match element {
+                circuit::Plaintext::Literal(literal, _) => Ok(literal),
+                _ => bail!("Expected a literal element"),
+            })
+            .collect(),
+        _ => bail!("Expected an array of literal elements"),
+    }
+}
+
+/// Returns the length of the given register type, if it is an array of the given literal type.
+fn array_length<N: Network>(register_type: &RegisterType<N>, literal_type: LiteralType) -> Option<u32> {
+    match register_type {
+        RegisterType::Plaintext(PlaintextType::Array(array_type))
+            if *array_type.next_element_type() == PlaintextType::Literal(literal_type) =>
+        {
+            Some(**array_type.length())
+        }
+        _ => None,
+    }
+}
+
+/// Performs a group operation, where:
+///   - `group.gen` multiplies the generator `G` by the scalar `first`. Note that `group.gen 1scalar` returns `G`.
+///   - `msm` computes `first[0] * second[0] + ... + first[n - 1] * second[n - 1]`, where `first` is an array of
+///     `group` elements, and `second` is an array of `scalar` elements of the same length, which is at most 32.
+///   - `hash_to_group.psd{2,4,8}` hashes `first`, which may be any plaintext, to a group element.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GroupInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> GroupInstruction<N, VARIANT> {
+    /// Initializes a new group instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(
+            operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operands",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Group("group.gen"),
+            1 => Opcode::Group("msm"),
+            2 => Opcode::Group("hash_to_group.psd2"),
+            3 => Opcode::Group("hash_to_group.psd4"),
+            4 => Opcode::Group("hash_to_group.psd8"),
+            5.. => panic!("Invalid group instruction opcode"),
+        }
+    }
+
+    /// Returns the number of operands of the instruction.
+    #[inline]
+    const fn num_operands() -> usize {
+        match VARIANT {
+            1 => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check the number of operands.
+        debug_assert!(
+            self.operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operands",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destination: self.destination.map_locator(map),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> GroupInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => match registers.load_literal(stack, &self.operands[0])? {
+                Literal::Scalar(scalar) => N::g_scalar_multiply(&scalar),
+                _ => bail!("Instruction '{}' expects the first operand to be a scalar.", Self::opcode()),
+            },
+            1 => {
+                // Retrieve the groups and scalars.
+                let groups = to_literals(registers.load(stack, &self.operands[0])?, LiteralType::Group)?;
+                let scalars = to_literals(registers.load(stack, &self.operands[1])?, LiteralType::Scalar)?;
+                ensure!(groups.len() == scalars.len(), "Expected the same number of groups and scalars");
+                // Compute the multi-scalar multiplication.
+                groups
+                    .into_iter()
+                    .zip_eq(scalars)
+                    .map(|terms| match terms {
+                        (Literal::Group(group), Literal::Scalar(scalar)) => Ok(group * scalar),
+                        _ => bail!("Instruction '{}' expects groups and scalars", Self::opcode()),
+                    })
+                    .try_fold(Group::<N>::zero(), |sum, term| Ok::<_, Error>(sum + term?))?
+            }
+            2..=4 => {
+                // Retrieve the input as field elements.
+                let input = match registers.load(stack, &self.operands[0])? {
+                    Value::Plaintext(plaintext) => plaintext.to_fields()?,
+                    _ => bail!("Instruction '{}' expects the first operand to be a plaintext.", Self::opcode()),
+                };
+                match VARIANT {
+                    2 => N::hash_to_group_psd2(&input)?,
+                    3 => N::hash_to_group_psd4(&input)?,
+                    _ => N::hash_to_group_psd8(&input)?,
+                }
+            }
+            5.. => bail!("Invalid group variant: {VARIANT}"),
+        };
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Group(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::traits::ToFields;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => match registers.load_literal_circuit(stack, &self.operands[0])? {
+                circuit::Literal::Scalar(scalar) => A::g_scalar_multiply(&scalar),
+                _ => bail!("Instruction '{}' expects the first operand to be a scalar.", Self::opcode()),
+            },
+            1 => {
+                // Retrieve the groups and scalars.
+                let groups = to_literals_circuit(registers.load_circuit(stack, &self.operands[0])?)?;
+                let scalars = to_literals_circuit(registers.load_circuit(stack, &self.operands[1])?)?;
+                ensure!(groups.len() == scalars.len(), "Expected the same number of groups and scalars");
+                // Compute the multi-scalar multiplication.
+                let terms = groups
+                    .into_iter()
+                    .zip_eq(scalars)
+                    .map(|terms| match terms {
+                        (circuit::Literal::Group(group), circuit::Literal::Scalar(scalar)) => Ok(group * scalar),
+                        _ => bail!("Instruction '{}' expects groups and scalars", Self::opcode()),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                match terms.into_iter().reduce(|sum, term| sum + term) {
+                    Some(sum) => sum,
+                    None => bail!("Instruction '{}' expects at least one term", Self::opcode()),
+                }
+            }
+            2..=4 => {
+                // Retrieve the input as field elements.
+                let input = match registers.load_circuit(stack, &self.operands[0])? {
+                    circuit::Value::Plaintext(plaintext) => plaintext.to_fields(),
+                    _ => bail!("Instruction '{}' expects the first operand to be a plaintext.", Self::opcode()),
+                };
+                match VARIANT {
+                    2 => A::hash_to_group_psd2(&input),
+                    3 => A::hash_to_group_psd4(&input),
+                    _ => A::hash_to_group_psd8(&input),
+                }
+            }
+            5.. => bail!("Invalid group variant: {VARIANT}"),
+        };
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Group(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                Self::num_operands(),
+                input_types.len()
+            )
+        }
+
+        match VARIANT {
+            // Ensure the operand is a scalar.
+            0 => {
+                if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)) {
+                    bail!(
+                        "Instruction '{}' expects the first input to be a 'scalar'. Found input of type '{}'",
+                        Self::opcode(),
+                        input_types[0]
+                    )
+                }
+            }
+            // Ensure the operands are a group array and a scalar array of the same length.
+            1 => {
+                let num_groups = array_length(&input_types[0], LiteralType::Group);
+                let num_scalars = array_length(&input_types[1], LiteralType::Scalar);
+                match (num_groups, num_scalars) {
+                    (Some(num_groups), Some(num_scalars)) if num_groups == num_scalars => {
+                        ensure!(num_groups <= MAX_MSM_TERMS, "Expected at most {MAX_MSM_TERMS} terms, found {num_groups}")
+                    }
+                    _ => bail!(
+                        "Instruction '{}' expects a 'group' array and a 'scalar' array of the same length. Found inputs of type '{}' and '{}'",
+                        Self::opcode(),
+                        input_types[0],
+                        input_types[1]
+                    ),
+                }
+            }
+            // Ensure the operand is a plaintext.
+            2..=4 => {
+                if !matches!(input_types[0], RegisterType::Plaintext(..)) {
+                    bail!(
+                        "Instruction '{}' expects the first input to be a plaintext. Found input of type '{}'",
+                        Self::opcode(),
+                        input_types[0]
+                    )
+                }
+            }
+            5.. => bail!("Invalid group variant: {VARIANT}"),
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Group))])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for GroupInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (mut string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        for _ in 0..Self::num_operands() {
+            // Parse the whitespace from the string.
+            let (next, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            let (next, operand) = Operand::parse(next)?;
+            // Add the operand to the operands.
+            operands.push(operand);
+            string = next;
+        }
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for GroupInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for GroupInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for GroupInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for GroupInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        // Read the operands.
+        for _ in 0..Self::num_operands() {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for GroupInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(error(format!(
+                "The number of operands must be {}, found {}",
+                Self::num_operands(),
+                self.operands.len()
+            )));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, types::Scalar};
+
+    type CurrentNetwork = MainnetV0;
+
+    fn check_parse<const VARIANT: u8>() {
+        let num_operands = GroupInstruction::<CurrentNetwork, VARIANT>::num_operands();
+        let operands = (0..num_operands).map(|i| format!("r{i}")).collect::<Vec<_>>().join(" ");
+        let string =
+            format!("{} {operands} into r{num_operands}", GroupInstruction::<CurrentNetwork, VARIANT>::opcode());
+        let (remainder, instruction) = GroupInstruction::<CurrentNetwork, VARIANT>::parse(&string).unwrap();
+        assert!(remainder.is_empty(), "Parser did not consume all of the string: '{remainder}'");
+        assert_eq!(instruction.operands.len(), num_operands, "The number of operands is incorrect");
+        for (i, operand) in instruction.operands.iter().enumerate() {
+            assert_eq!(*operand, Operand::Register(Register::Locator(i as u64)), "Operand {i} is incorrect");
+        }
+        assert_eq!(instruction.destination, Register::Locator(num_operands as u64), "The destination is incorrect");
+        assert_eq!(string, instruction.to_string());
+    }
+
+    #[test]
+    fn test_parse() {
+        check_parse::<{ GroupVariant::GroupGen as u8 }>();
+        check_parse::<{ GroupVariant::MSM as u8 }>();
+        check_parse::<{ GroupVariant::HashToGroupPSD2 as u8 }>();
+        check_parse::<{ GroupVariant::HashToGroupPSD4 as u8 }>();
+        check_parse::<{ GroupVariant::HashToGroupPSD8 as u8 }>();
+
+        // Ensure the number of operands is checked.
+        assert!(MSM::<CurrentNetwork>::from_str("msm r0 into r1").is_err());
+        assert!(GroupGen::<CurrentNetwork>::from_str("group.gen r0 r1 into r2").is_err());
+    }
+
+    #[test]
+    fn test_array_length() -> Result<()> {
+        let groups = RegisterType::<CurrentNetwork>::from_str("[group; 4u32]")?;
+        let scalars = RegisterType::<CurrentNetwork>::from_str("[scalar; 4u32]")?;
+        let scalar = RegisterType::<CurrentNetwork>::from_str("scalar")?;
+
+        assert_eq!(array_length(&groups, LiteralType::Group), Some(4));
+        assert_eq!(array_length(&scalars, LiteralType::Scalar), Some(4));
+        assert_eq!(array_length(&groups, LiteralType::Scalar), None);
+        assert_eq!(array_length(&scalar, LiteralType::Scalar), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_literals() -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str("[1scalar, 2scalar]")?;
+        let literals = to_literals(value.clone(), LiteralType::Scalar)?;
+        assert_eq!(literals, vec![Literal::from_str("1scalar")?, Literal::from_str("2scalar")?]);
+
+        // Ensure the element type is checked.
+        assert!(to_literals(value, LiteralType::Group).is_err());
+        // Ensure non-arrays are rejected.
+        assert!(to_literals(Value::<CurrentNetwork>::from_str("1scalar")?, LiteralType::Scalar).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_gen_one() {
+        // Ensure `group.gen 1scalar` returns the generator.
+        assert_eq!(CurrentNetwork::g_scalar_multiply(&Scalar::one()), Group::generator());
+    }
+}
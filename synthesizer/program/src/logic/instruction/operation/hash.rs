@@ -108,9 +108,11 @@ fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) ->
     !matches!(
         destination_type,
         PlaintextType::Literal(LiteralType::Boolean)
+            | PlaintextType::Literal(LiteralType::Fixed)
             | PlaintextType::Literal(LiteralType::String)
             | PlaintextType::Struct(..)
             | PlaintextType::Array(..)
+            | PlaintextType::Option(..)
     )
 }
 
@@ -188,6 +190,16 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         vec![self.destination.clone()]
     }
 
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destination: self.destination.map_locator(map),
+            destination_type: self.destination_type.clone(),
+        }
+    }
+
     /// Returns the destination register type.
     #[inline]
     pub const fn destination_type(&self) -> &PlaintextType<N> {
@@ -254,12 +266,14 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Option(..)) => bail!("Cannot hash into an option"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Option(..) => bail!("Cannot hash into an option"),
         };
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
@@ -325,12 +339,14 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Option(..)) => bail!("Cannot hash into an option"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Option(..) => bail!("Cannot hash into an option"),
         };
         // Convert the output to a stack value.
         let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
@@ -417,11 +433,11 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         let (string, destination_type) = PlaintextType::parse(string)?;
         // Ensure the destination type is allowed.
         match destination_type {
-            PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String) => {
-                map_res(fail, |_: ParserResult<Self>| {
-                    Err(error(format!("Failed to parse 'hash': '{destination_type}' is invalid")))
-                })(string)
-            }
+            PlaintextType::Literal(LiteralType::Boolean)
+            | PlaintextType::Literal(LiteralType::Fixed)
+            | PlaintextType::Literal(LiteralType::String) => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse 'hash': '{destination_type}' is invalid")))
+            })(string),
             _ => Ok((string, Self { operands, destination, destination_type })),
         }
     }
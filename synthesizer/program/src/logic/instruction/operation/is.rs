@@ -76,6 +76,15 @@ impl<N: Network, const VARIANT: u8> IsInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destination: self.destination.map_locator(map),
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> IsInstruction<N, VARIANT> {
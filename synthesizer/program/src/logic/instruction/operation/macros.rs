@@ -357,6 +357,7 @@ mod tests {
                 console::program::Literal::Scalar(console::types::Scalar::rand($rng)),
                 console::program::Literal::sample(console::program::LiteralType::Signature, $rng),
                 console::program::Literal::String(console::types::StringType::rand($rng)),
+                console::program::Literal::Fixed(console::types::Fixed::rand($rng)),
             ]
         };
     }
@@ -682,7 +683,7 @@ mod tests {
 
         // Case 1-A: Binary operation.
         // Case 1-B: Binary operation, where:
-        //   1. "ensure overflow halts" | "ensure exponentiation overflow halts" | "ensure shifting past boundary halts"
+        //   1. "ensure overflow halts" | "ensure fixed-point overflows halt" | "ensure exponentiation overflow halts" | "ensure shifting past boundary halts"
         //     - If the sampled values overflow or underflow on evaluation, ensure it halts.
         //     - If the sampled values **do not** overflow or underflow on evaluation, ensure it succeeds.
         //   2. "ensure divide by zero halts"
@@ -723,6 +724,16 @@ mod tests {
                                     4.. => ($input_b::<CurrentNetwork>::rand(&mut rng), $input_b::<CurrentNetwork>::rand(&mut rng))
                                 }
                             };
+                            (Fixed, Fixed) => {
+                                match i {
+                                    0 => ($input_a::zero(), $input_b::zero()),
+                                    1 => ($input_a::<CurrentNetwork>::rand(&mut rng), $input_b::zero()),
+                                    2 => ($input_a::zero(), $input_b::<CurrentNetwork>::rand(&mut rng)),
+                                    // Sample from the full range on even iterations, and from a range that avoids overflows otherwise.
+                                    3.. if i % 2 == 0 => ($input_a::<CurrentNetwork>::rand(&mut rng), $input_b::<CurrentNetwork>::rand(&mut rng)),
+                                    3.. => ($input_a::new(i64::rand(&mut rng) as i128), $input_b::new(i64::rand(&mut rng) as i128)),
+                                }
+                            };
                             ($lhs:ident, $rhs:ident) => {
                                 match i {
                                     0 => ($lhs::zero(), $rhs::zero()),
@@ -762,6 +773,17 @@ mod tests {
                                     _ => panic!("Unsupported test enforcement for '{}'", <$operation as $crate::Operation<_, _, _, 2>>::OPCODE),
                                 }
                             };
+                            ("ensure fixed-point overflows halt") => {
+                                let rounding = match *<$operation as $crate::Operation<_, _, _, 2>>::OPCODE {
+                                    "div" | "mul" => console::types::fixed::Rounding::TowardZero,
+                                    "div.round" | "mul.round" => console::types::fixed::Rounding::HalfAwayFromZero,
+                                    _ => panic!("Unsupported test enforcement for '{}'", <$operation as $crate::Operation<_, _, _, 2>>::OPCODE),
+                                };
+                                match *<$operation as $crate::Operation<_, _, _, 2>>::OPCODE {
+                                    "div" | "div.round" => should_succeed &= a.checked_div_with_rounding(&b, rounding).is_some(),
+                                    _ => should_succeed &= a.checked_mul_with_rounding(&b, rounding).is_some(),
+                                }
+                            };
                             ("ensure exponentiation overflows halt") => {
                                 should_succeed &= (*a).checked_pow((*b) as u32).is_some()
                             };
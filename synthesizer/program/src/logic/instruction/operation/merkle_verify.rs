@@ -0,0 +1,533 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use circuit::{
+    prelude::{FromBits as CircuitFromBits, ToBits as CircuitToBits},
+    Inject,
+};
+use console::{
+    collections::merkle_tree::MerklePath,
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Boolean, Field, U64},
+};
+
+/// Computes whether `path` is a valid Merkle path from `leaf` to `root`, using BHP hashes.
+pub type MerkleVerifyBHP<N> = MerkleVerify<N, { MerkleVariant::BHP as u8 }>;
+/// Computes whether `path` is a valid Merkle path from `leaf` to `root`, using Poseidon hashes.
+pub type MerkleVerifyPSD<N> = MerkleVerify<N, { MerkleVariant::PSD as u8 }>;
+
+/// Which hash functions the Merkle tree is built with.
+enum MerkleVariant {
+    /// The leaves are hashed with BHP-1024, and the paths with BHP-512.
+    BHP,
+    /// The leaves are hashed with Poseidon with an input rate of 4, and the paths with an input rate of 2.
+    PSD,
+}
+
+/// Invokes `$verify::<$network, VARIANT, DEPTH>` with the given arguments, for the given runtime `$depth`.
+macro_rules! verify_with_depth {
+    ($depth:expr, $verify:ident::<$network:ident>$arguments:tt) => {
+        verify_with_depth!($depth, $verify::<$network>$arguments, [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+        ])
+    };
+    ($depth:expr, $verify:ident::<$network:ident>$arguments:tt, [$($supported:literal),+]) => {
+        match $depth {
+            $($supported => $verify::<$network, VARIANT, $supported>$arguments,)+
+            depth => bail!("Instruction '{}' does not support a Merkle path of depth {depth}", Self::opcode()),
+        }
+    };
+}
+
+/// Returns `true` if the given Merkle path is valid for the given root and leaf.
+fn verify<N: Network, const VARIANT: u8, const DEPTH: u8>(
+    root: &Field<N>,
+    leaf: &[Field<N>],
+    leaf_index: U64<N>,
+    siblings: Vec<Field<N>>,
+) -> Result<bool> {
+    // Note that a leaf index that is out of bounds for the tree depth is not a valid Merkle path.
+    if (*leaf_index as u128) >= (1u128 << DEPTH) {
+        return Ok(false);
+    }
+    // Construct the Merkle path.
+    let path = MerklePath::<N, DEPTH>::try_from((leaf_index, siblings))?;
+    // Verify the Merkle path.
+    match VARIANT {
+        0 => Ok(N::verify_merkle_path_bhp(&path, root, &leaf.iter().flat_map(|field| field.to_bits_le()).collect())),
+        1 => Ok(N::verify_merkle_path_psd(&path, root, &leaf.to_vec())),
+        2.. => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+    }
+}
+
+/// Returns `true` if the given Merkle path is valid for the given root and leaf.
+fn verify_circuit<A: circuit::Aleo, const VARIANT: u8, const DEPTH: u8>(
+    root: &circuit::Field<A>,
+    leaf: &[circuit::Field<A>],
+    leaf_index: circuit::U64<A>,
+    siblings: Vec<circuit::Field<A>>,
+) -> Result<circuit::Boolean<A>> {
+    // Decompose the leaf index, and determine if it is within the bounds of the tree depth.
+    // Note that an out-of-bounds leaf index must not halt, as the number of constraints must not depend on it.
+    let mut index_bits = leaf_index.to_bits_le();
+    let is_in_bounds =
+        !index_bits[DEPTH as usize..].iter().fold(circuit::Boolean::constant(false), |acc, bit| acc | bit);
+    // Truncate the leaf index to the tree depth.
+    index_bits[DEPTH as usize..].iter_mut().for_each(|bit| *bit = circuit::Boolean::constant(false));
+    let leaf_index = circuit::U64::from_bits_le(&index_bits);
+
+    // Construct the Merkle path.
+    let path = circuit::collections::merkle_tree::MerklePath::<A, DEPTH>::try_from((leaf_index, siblings))?;
+    // Verify the Merkle path.
+    let is_valid = match VARIANT {
+        0 => A::verify_merkle_path_bhp(&path, root, &leaf.iter().flat_map(|field| field.to_bits_le()).collect()),
+        1 => A::verify_merkle_path_psd(&path, root, &leaf.to_vec()),
+        2.. => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+    };
+    Ok(is_valid & is_in_bounds)
+}
+
+/// Returns the field elements of the given value, which must be a `field` or an array of `field` elements.
+fn to_field_elements<N: Network>(value: &Value<N>) -> Result<Vec<Field<N>>> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::Field(field), _)) => Ok(vec![*field]),
+        Value::Plaintext(Plaintext::Array(elements, _)) => elements
+            .iter()
+            .map(|element| match element {
+                Plaintext::Literal(Literal::Field(field), _) => Ok(*field),
+                _ => bail!("Expected a 'field' element, found '{element}'"),
+            })
+            .collect(),
+        _ => bail!("Expected a 'field' or an array of 'field' elements, found '{value}'"),
+    }
+}
+
+/// Returns the field elements of the given circuit value, which must be a `field` or an array of `field` elements.
+fn to_field_elements_circuit<A: circuit::Aleo>(value: &circuit::Value<A>) -> Result<Vec<circuit::Field<A>>> {
+    use circuit::{Literal, Plaintext, Value};
+
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::Field(field), _)) => Ok(vec![field.clone()]),
+        Value::Plaintext(Plaintext::Array(elements, _)) => elements
+            .iter()
+            .map(|element| match element {
+                Plaintext::Literal(Literal::Field(field), _) => Ok(field.clone()),
+                _ => bail!("Expected a 'field' element"),
+            })
+            .collect(),
+        _ => bail!("Expected a 'field' or an array of 'field' elements"),
+    }
+}
+
+/// Returns the number of field elements in the given register type,
+/// if it is a `field` or an array of `field` elements.
+fn num_field_elements<N: Network>(register_type: &RegisterType<N>) -> Option<u32> {
+    let field_type = PlaintextType::Literal(LiteralType::Field);
+    match register_type {
+        RegisterType::Plaintext(plaintext_type) if *plaintext_type == field_type => Some(1),
+        RegisterType::Plaintext(PlaintextType::Array(array_type)) if *array_type.next_element_type() == field_type => {
+            Some(**array_type.length())
+        }
+        _ => None,
+    }
+}
+
+/// Computes whether `path` is a valid Merkle path from `leaf` to `root`, storing the outcome in `destination`.
+///
+/// The operands are, in order:
+///   - `root` is the `field` root of the Merkle tree.
+///   - `leaf` is a `field` or an array of `field` elements. For the BHP variant, the leaf is hashed as the
+///     concatenation of the little-endian bits of its elements.
+///   - `index` is the `u64` index of the leaf in the Merkle tree.
+///   - `path` is an array of `field` elements, containing the sibling hashes from the leaf to the root.
+///     The length of the array is the depth of the Merkle tree, which must be at most 32.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MerkleVerify<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> MerkleVerify<N, VARIANT> {
+    /// Initializes a new `merkle.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 4, "Instruction '{}' must have four operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Merkle("merkle.verify.bhp"),
+            1 => Opcode::Merkle("merkle.verify.psd"),
+            2.. => panic!("Invalid 'merkle.verify' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly four operands.
+        debug_assert!(self.operands.len() == 4, "Instruction '{}' must have four operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destination: self.destination.map_locator(map),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> MerkleVerify<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 4 {
+            bail!("Instruction '{}' expects 4 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let root = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Field(root) => root,
+            _ => bail!("Expected the first operand to be a field."),
+        };
+        let leaf = to_field_elements(&registers.load(stack, &self.operands[1])?)?;
+        let leaf_index = match registers.load_literal(stack, &self.operands[2])? {
+            Literal::U64(leaf_index) => leaf_index,
+            _ => bail!("Expected the third operand to be a u64."),
+        };
+        let siblings = to_field_elements(&registers.load(stack, &self.operands[3])?)?;
+
+        // Verify the Merkle path.
+        let is_valid = verify_with_depth!(siblings.len(), verify::<N>(&root, &leaf, leaf_index, siblings))?;
+        let output = Literal::Boolean(Boolean::new(is_valid));
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 4 {
+            bail!("Instruction '{}' expects 4 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let root = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Field(root) => root,
+            _ => bail!("Expected the first operand to be a field."),
+        };
+        let leaf = to_field_elements_circuit(&registers.load_circuit(stack, &self.operands[1])?)?;
+        let leaf_index = match registers.load_literal_circuit(stack, &self.operands[2])? {
+            circuit::Literal::U64(leaf_index) => leaf_index,
+            _ => bail!("Expected the third operand to be a u64."),
+        };
+        let siblings = to_field_elements_circuit(&registers.load_circuit(stack, &self.operands[3])?)?;
+
+        // Verify the Merkle path.
+        let output = verify_with_depth!(siblings.len(), verify_circuit::<A>(&root, &leaf, leaf_index, siblings))?;
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Boolean(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 4 {
+            bail!("Instruction '{}' expects 4 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the first operand is a field.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+            bail!(
+                "Instruction '{}' expects the first input to be a 'field'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+
+        // Ensure the second operand is a field or an array of fields.
+        if num_field_elements(&input_types[1]).is_none() {
+            bail!(
+                "Instruction '{}' expects the second input to be a 'field' or an array of 'field' elements. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+
+        // Ensure the third operand is a u64.
+        if input_types[2] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U64)) {
+            bail!(
+                "Instruction '{}' expects the third input to be a 'u64'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        // Ensure the fourth operand is an array of fields, with a length of at most 32.
+        match (&input_types[3], num_field_elements(&input_types[3])) {
+            (RegisterType::Plaintext(PlaintextType::Array(..)), Some(1..=32)) => (),
+            _ => bail!(
+                "Instruction '{}' expects the fourth input to be an array of at most 32 'field' elements. Found input of type '{}'",
+                Self::opcode(),
+                input_types[3]
+            ),
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for MerkleVerify<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the fourth operand from the string.
+        let (string, fourth) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third, fourth], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for MerkleVerify<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for MerkleVerify<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for MerkleVerify<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 4.
+        if self.operands.len() != 4 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for MerkleVerify<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(4);
+        // Read the operands.
+        for _ in 0..4 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for MerkleVerify<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 4.
+        if self.operands.len() != 4 {
+            return Err(error(format!("The number of operands must be 4, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{network::AleoV0, Eject};
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = AleoV0;
+
+    fn check_parse<const VARIANT: u8>() {
+        let string = format!("{} r0 r1 r2 r3 into r4", MerkleVerify::<CurrentNetwork, VARIANT>::opcode());
+        let (remainder, instruction) = MerkleVerify::<CurrentNetwork, VARIANT>::parse(&string).unwrap();
+        assert!(remainder.is_empty(), "Parser did not consume all of the string: '{remainder}'");
+        assert_eq!(instruction.operands.len(), 4, "The number of operands is incorrect");
+        for (i, operand) in instruction.operands.iter().enumerate() {
+            assert_eq!(*operand, Operand::Register(Register::Locator(i as u64)), "Operand {i} is incorrect");
+        }
+        assert_eq!(instruction.destination, Register::Locator(4), "The destination register is incorrect");
+        assert_eq!(string, instruction.to_string());
+    }
+
+    #[test]
+    fn test_parse() {
+        check_parse::<{ MerkleVariant::BHP as u8 }>();
+        check_parse::<{ MerkleVariant::PSD as u8 }>();
+    }
+
+    #[test]
+    fn test_evaluate_psd() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Construct a Merkle tree of depth 4 with Poseidon leaves.
+        let leaves = (0..10).map(|_| vec![Uniform::rand(&mut rng), Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+        let tree = CurrentNetwork::merkle_tree_psd::<4>(&leaves)?;
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let path = tree.prove(index, leaf)?;
+            let siblings = path.siblings().to_vec();
+            let leaf_index = path.leaf_index();
+            // Ensure a valid path is accepted.
+            assert!(verify::<CurrentNetwork, 1, 4>(tree.root(), leaf, leaf_index, siblings.clone())?);
+            // Ensure a path for the wrong leaf is rejected.
+            let wrong_leaf = vec![leaf[1], leaf[0]];
+            assert!(!verify::<CurrentNetwork, 1, 4>(tree.root(), &wrong_leaf, leaf_index, siblings.clone())?);
+            // Ensure a path against the wrong root is rejected.
+            assert!(!verify::<CurrentNetwork, 1, 4>(&Uniform::rand(&mut rng), leaf, leaf_index, siblings.clone())?);
+            // Ensure an out of bounds leaf index is rejected.
+            let out_of_bounds = U64::new(*leaf_index + 16);
+            assert!(!verify::<CurrentNetwork, 1, 4>(tree.root(), leaf, out_of_bounds, siblings.clone())?);
+
+            // Ensure the circuit matches, including for an out of bounds leaf index.
+            for leaf_index in [leaf_index, out_of_bounds] {
+                let expected = verify::<CurrentNetwork, 1, 4>(tree.root(), leaf, leaf_index, siblings.clone())?;
+                let candidate = verify_circuit::<CurrentAleo, 1, 4>(
+                    &circuit::Field::new(circuit::Mode::Public, *tree.root()),
+                    &leaf.iter().map(|field| circuit::Field::new(circuit::Mode::Private, *field)).collect::<Vec<_>>(),
+                    circuit::U64::new(circuit::Mode::Private, leaf_index),
+                    siblings.iter().map(|field| circuit::Field::new(circuit::Mode::Private, *field)).collect(),
+                )?;
+                assert_eq!(expected, candidate.eject_value());
+                <CurrentAleo as circuit::Environment>::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_bhp() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Construct a Merkle tree of depth 3 with BHP leaves, where each leaf is the bits of a single field element.
+        let leaves = (0..5).map(|_| Field::<CurrentNetwork>::rand(&mut rng)).collect::<Vec<_>>();
+        let leaf_bits = leaves.iter().map(|leaf| leaf.to_bits_le()).collect::<Vec<_>>();
+        let tree = CurrentNetwork::merkle_tree_bhp::<3>(&leaf_bits)?;
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let path = tree.prove(index, &leaf_bits[index])?;
+            let siblings = path.siblings().to_vec();
+            // Ensure a valid path is accepted.
+            assert!(verify::<CurrentNetwork, 0, 3>(tree.root(), &[*leaf], path.leaf_index(), siblings.clone())?);
+            // Ensure a path for the wrong leaf index is rejected.
+            let wrong_index = U64::new((index as u64 + 1) % 8);
+            assert!(!verify::<CurrentNetwork, 0, 3>(tree.root(), &[*leaf], wrong_index, siblings)?);
+        }
+        Ok(())
+    }
+}
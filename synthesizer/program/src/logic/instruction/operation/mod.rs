@@ -15,6 +15,9 @@
 mod assert;
 pub use assert::*;
 
+mod assert_with_code;
+pub use assert_with_code::*;
+
 mod async_;
 pub use async_::*;
 
@@ -27,6 +30,12 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod ecdsa_verify;
+pub use ecdsa_verify::*;
+
+mod group;
+pub use group::*;
+
 mod hash;
 pub use hash::*;
 
@@ -38,9 +47,24 @@ pub use literals::*;
 
 mod macros;
 
+mod merkle_verify;
+pub use merkle_verify::*;
+
+mod option;
+pub use option::*;
+
+mod overflowing;
+pub use overflowing::*;
+
+mod repeat;
+pub use repeat::*;
+
 mod sign_verify;
 pub use sign_verify::*;
 
+mod string;
+pub use string::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
@@ -95,6 +119,7 @@ pub type Add<N> = BinaryLiteral<N, AddOperation<N>>;
 crate::operation!(
     pub struct AddOperation<core::ops::Add, core::ops::Add, add, "add"> {
         (Field, Field) => Field,
+        (Fixed, Fixed) => Fixed ("ensure overflows halt"),
         (Group, Group) => Group,
         (I8, I8) => I8 ("ensure overflows halt"),
         (I16, I16) => I16 ("ensure overflows halt"),
@@ -153,6 +178,7 @@ pub type Div<N> = BinaryLiteral<N, DivOperation<N>>;
 crate::operation!(
     pub struct DivOperation<core::ops::Div, core::ops::Div, div, "div"> {
         (Field, Field) => Field ("ensure divide by zero halts"),
+        (Fixed, Fixed) => Fixed ("ensure fixed-point overflows halt", "ensure divide by zero halts"),
         (I8, I8) => I8 ("ensure overflows halt", "ensure divide by zero halts"),
         (I16, I16) => I16 ("ensure overflows halt", "ensure divide by zero halts"),
         (I32, I32) => I32 ("ensure overflows halt", "ensure divide by zero halts"),
@@ -167,6 +193,15 @@ crate::operation!(
     }
 );
 
+/// Divides `first` by `second`, rounding half away from zero, storing the outcome in `destination`.
+pub type DivRounded<N> = BinaryLiteral<N, DivRoundedOperation<N>>;
+
+crate::operation!(
+    pub struct DivRoundedOperation<console::prelude::DivRounded, circuit::traits::DivRounded, div_rounded, "div.round"> {
+        (Fixed, Fixed) => Fixed ("ensure fixed-point overflows halt", "ensure divide by zero halts"),
+    }
+);
+
 /// Divides `first` by `second`, wrapping around at the boundary of the type, storing the outcome in `destination`.
 pub type DivWrapped<N> = BinaryLiteral<N, DivWrappedOperation<N>>;
 
@@ -202,6 +237,7 @@ crate::operation!(
     pub struct GreaterThanOperation<console::prelude::Compare, circuit::traits::Compare, is_greater_than, "gt"> {
         // (Address, Address) => Boolean,
         (Field, Field) => Boolean,
+        (Fixed, Fixed) => Boolean,
         (I8, I8) => Boolean,
         (I16, I16) => Boolean,
         (I32, I32) => Boolean,
@@ -223,6 +259,7 @@ crate::operation!(
     pub struct GreaterThanOrEqualOperation<console::prelude::Compare, circuit::traits::Compare, is_greater_than_or_equal, "gte"> {
         // (Address, Address) => Boolean,
         (Field, Field) => Boolean,
+        (Fixed, Fixed) => Boolean,
         (I8, I8) => Boolean,
         (I16, I16) => Boolean,
         (I32, I32) => Boolean,
@@ -246,6 +283,24 @@ crate::operation!(
     }
 );
 
+/// Counts the number of leading zero bits in `first`, storing the outcome in `destination`.
+pub type LeadingZeros<N> = UnaryLiteral<N, LeadingZerosOperation<N>>;
+
+crate::operation!(
+    pub struct LeadingZerosOperation<console::prelude::LeadingZeros, circuit::traits::LeadingZeros, leading_zeros, "clz"> {
+        I8 => U8,
+        I16 => U8,
+        I32 => U8,
+        I64 => U8,
+        I128 => U8,
+        U8 => U8,
+        U16 => U8,
+        U32 => U8,
+        U64 => U8,
+        U128 => U8,
+    }
+);
+
 /// Computes whether `first` is less than `second` as a boolean, storing the outcome in `destination`.
 pub type LessThan<N> = BinaryLiteral<N, LessThanOperation<N>>;
 
@@ -253,6 +308,7 @@ crate::operation!(
     pub struct LessThanOperation<console::prelude::Compare, circuit::traits::Compare, is_less_than, "lt"> {
         // (Address, Address) => Boolean,
         (Field, Field) => Boolean,
+        (Fixed, Fixed) => Boolean,
         (I8, I8) => Boolean,
         (I16, I16) => Boolean,
         (I32, I32) => Boolean,
@@ -274,6 +330,7 @@ crate::operation!(
     pub struct LessThanOrEqualOperation<console::prelude::Compare, circuit::traits::Compare, is_less_than_or_equal, "lte"> {
         // (Address, Address) => Boolean,
         (Field, Field) => Boolean,
+        (Fixed, Fixed) => Boolean,
         (I8, I8) => Boolean,
         (I16, I16) => Boolean,
         (I32, I32) => Boolean,
@@ -307,6 +364,7 @@ pub type Mul<N> = BinaryLiteral<N, MulOperation<N>>;
 crate::operation!(
     pub struct MulOperation<core::ops::Mul, core::ops::Mul, mul, "mul"> {
         (Field, Field) => Field,
+        (Fixed, Fixed) => Fixed ("ensure fixed-point overflows halt"),
         (Group, Scalar) => Group,
         (Scalar, Group) => Group,
         (I8, I8) => I8 ("ensure overflows halt"),
@@ -323,6 +381,15 @@ crate::operation!(
     }
 );
 
+/// Multiplies `first` and `second`, rounding half away from zero, storing the outcome in `destination`.
+pub type MulRounded<N> = BinaryLiteral<N, MulRoundedOperation<N>>;
+
+crate::operation!(
+    pub struct MulRoundedOperation<console::prelude::MulRounded, circuit::traits::MulRounded, mul_rounded, "mul.round"> {
+        (Fixed, Fixed) => Fixed ("ensure fixed-point overflows halt"),
+    }
+);
+
 /// Multiplies `first` and `second`, wrapping around at the boundary of the type, storing the outcome in `destination`.
 pub type MulWrapped<N> = BinaryLiteral<N, MulWrappedOperation<N>>;
 
@@ -412,6 +479,24 @@ crate::operation!(
     }
 );
 
+/// Counts the number of set bits in `first`, storing the outcome in `destination`.
+pub type PopCount<N> = UnaryLiteral<N, PopCountOperation<N>>;
+
+crate::operation!(
+    pub struct PopCountOperation<console::prelude::PopCount, circuit::traits::PopCount, pop_count, "popcnt"> {
+        I8 => U8,
+        I16 => U8,
+        I32 => U8,
+        I64 => U8,
+        I128 => U8,
+        U8 => U8,
+        U16 => U8,
+        U32 => U8,
+        U64 => U8,
+        U128 => U8,
+    }
+);
+
 /// Raises `first` to the power of `second`, storing the outcome in `destination`.
 pub type Pow<N> = BinaryLiteral<N, PowOperation<N>>;
 
@@ -525,6 +610,82 @@ crate::operation!(
     }
 );
 
+/// Rotates `first` left by `second` bits, modulo the number of bits in the type, storing the outcome in `destination`.
+pub type RotateLeft<N> = BinaryLiteral<N, RotateLeftOperation<N>>;
+
+crate::operation!(
+    pub struct RotateLeftOperation<console::prelude::RotateLeft, circuit::traits::RotateLeft, rotate_left, "rotl"> {
+        (I8, U8) => I8,
+        (I8, U16) => I8,
+        (I8, U32) => I8,
+        (I16, U8) => I16,
+        (I16, U16) => I16,
+        (I16, U32) => I16,
+        (I32, U8) => I32,
+        (I32, U16) => I32,
+        (I32, U32) => I32,
+        (I64, U8) => I64,
+        (I64, U16) => I64,
+        (I64, U32) => I64,
+        (I128, U8) => I128,
+        (I128, U16) => I128,
+        (I128, U32) => I128,
+        (U8, U8) => U8,
+        (U8, U16) => U8,
+        (U8, U32) => U8,
+        (U16, U8) => U16,
+        (U16, U16) => U16,
+        (U16, U32) => U16,
+        (U32, U8) => U32,
+        (U32, U16) => U32,
+        (U32, U32) => U32,
+        (U64, U8) => U64,
+        (U64, U16) => U64,
+        (U64, U32) => U64,
+        (U128, U8) => U128,
+        (U128, U16) => U128,
+        (U128, U32) => U128,
+    }
+);
+
+/// Rotates `first` right by `second` bits, modulo the number of bits in the type, storing the outcome in `destination`.
+pub type RotateRight<N> = BinaryLiteral<N, RotateRightOperation<N>>;
+
+crate::operation!(
+    pub struct RotateRightOperation<console::prelude::RotateRight, circuit::traits::RotateRight, rotate_right, "rotr"> {
+        (I8, U8) => I8,
+        (I8, U16) => I8,
+        (I8, U32) => I8,
+        (I16, U8) => I16,
+        (I16, U16) => I16,
+        (I16, U32) => I16,
+        (I32, U8) => I32,
+        (I32, U16) => I32,
+        (I32, U32) => I32,
+        (I64, U8) => I64,
+        (I64, U16) => I64,
+        (I64, U32) => I64,
+        (I128, U8) => I128,
+        (I128, U16) => I128,
+        (I128, U32) => I128,
+        (U8, U8) => U8,
+        (U8, U16) => U8,
+        (U8, U32) => U8,
+        (U16, U8) => U16,
+        (U16, U16) => U16,
+        (U16, U32) => U16,
+        (U32, U8) => U32,
+        (U32, U16) => U32,
+        (U32, U32) => U32,
+        (U64, U8) => U64,
+        (U64, U16) => U64,
+        (U64, U32) => U64,
+        (U128, U8) => U128,
+        (U128, U16) => U128,
+        (U128, U32) => U128,
+    }
+);
+
 /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
 pub type Shl<N> = BinaryLiteral<N, ShlOperation<N>>;
 
@@ -701,6 +862,7 @@ pub type Sub<N> = BinaryLiteral<N, SubOperation<N>>;
 crate::operation!(
     pub struct SubOperation<core::ops::Sub, core::ops::Sub, sub, "sub"> {
         (Field, Field) => Field,
+        (Fixed, Fixed) => Fixed ("ensure overflows halt"),
         (Group, Group) => Group,
         (I8, I8) => I8 ("ensure overflows halt"),
         (I16, I16) => I16 ("ensure overflows halt"),
@@ -742,6 +904,7 @@ crate::operation!(
         (Boolean, Address, Address) => Address,
         (Boolean, Boolean, Boolean) => Boolean,
         (Boolean, Field, Field) => Field,
+        (Boolean, Fixed, Fixed) => Fixed,
         (Boolean, Group, Group) => Group,
         (Boolean, I8, I8) => I8,
         (Boolean, I16, I16) => I16,
@@ -759,6 +922,24 @@ crate::operation!(
     }
 );
 
+/// Counts the number of trailing zero bits in `first`, storing the outcome in `destination`.
+pub type TrailingZeros<N> = UnaryLiteral<N, TrailingZerosOperation<N>>;
+
+crate::operation!(
+    pub struct TrailingZerosOperation<console::prelude::TrailingZeros, circuit::traits::TrailingZeros, trailing_zeros, "ctz"> {
+        I8 => U8,
+        I16 => U8,
+        I32 => U8,
+        I64 => U8,
+        I128 => U8,
+        U8 => U8,
+        U16 => U8,
+        U32 => U8,
+        U64 => U8,
+        U128 => U8,
+    }
+);
+
 /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
 pub type Xor<N> = BinaryLiteral<N, XorOperation<N>>;
 
@@ -0,0 +1,487 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use circuit::{
+    prelude::{FromBits as CircuitFromBits, ToBits as CircuitToBits},
+    Eject,
+    Inject,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, OptionType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Wraps `first` into a `some` option, storing the outcome in `destination`.
+pub type OptionSome<N> = OptionOperation<N, { OptionVariant::Some as u8 }>;
+/// Wraps `first` into a `none` option, storing the outcome in `destination`.
+pub type OptionNone<N> = OptionOperation<N, { OptionVariant::None as u8 }>;
+/// Computes whether the option `first` is `some`, storing the outcome in `destination`.
+pub type IsSome<N> = OptionOperation<N, { OptionVariant::IsSome as u8 }>;
+/// Unwraps the option `first`, storing its value in `destination`. Halts if `first` is `none`.
+pub type Unwrap<N> = OptionOperation<N, { OptionVariant::Unwrap as u8 }>;
+/// Unwraps the option `first` if it is `some`, otherwise selects `second`, storing the outcome in `destination`.
+pub type UnwrapOr<N> = OptionOperation<N, { OptionVariant::UnwrapOr as u8 }>;
+
+/// Which operation is performed on an option.
+enum OptionVariant {
+    Some,
+    None,
+    IsSome,
+    Unwrap,
+    UnwrapOr,
+}
+
+/// Returns `first` if `condition` is `true`, otherwise returns `second`.
+/// Note that `first` and `second` must be of the same plaintext type.
+fn ternary_circuit<A: circuit::Aleo>(
+    condition: &circuit::Boolean<A>,
+    first: &circuit::Plaintext<A>,
+    second: &circuit::Plaintext<A>,
+) -> Result<circuit::Plaintext<A>> {
+    // Select each bit of the plaintexts.
+    // Note that the bits of two plaintexts of the same type have the same length, with the
+    // exception of strings of different lengths, which cannot be selected between in a circuit.
+    let (first, second) = (first.to_bits_le(), second.to_bits_le());
+    ensure!(first.len() == second.len(), "Cannot select between plaintexts of different sizes");
+    let bits = first
+        .iter()
+        .zip_eq(second.iter())
+        .map(|(first, second)| circuit::Boolean::ternary(condition, first, second))
+        .collect::<Vec<_>>();
+    // Recover the selected plaintext.
+    Ok(circuit::Plaintext::from_bits_le(&bits))
+}
+
+/// Performs an operation on an option, where:
+///   - `some` wraps `first` into an option that is `some`.
+///   - `none` returns an option that is `none`, for the type of `first`. The option holds the default value
+///     of the type as a placeholder, which ensures the option has the same size regardless of whether it is `some`.
+///   - `is.some` returns `true` if `first` is `some`.
+///   - `unwrap` returns the value of `first`, and halts if `first` is `none`.
+///   - `unwrap.or` returns the value of `first` if it is `some`, otherwise returns `second`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct OptionOperation<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> OptionOperation<N, VARIANT> {
+    /// Initializes a new option instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(
+            operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operands",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Option("some"),
+            1 => Opcode::Option("none"),
+            2 => Opcode::Option("is.some"),
+            3 => Opcode::Option("unwrap"),
+            4 => Opcode::Option("unwrap.or"),
+            5.. => panic!("Invalid option instruction opcode"),
+        }
+    }
+
+    /// Returns the number of operands of the instruction.
+    #[inline]
+    const fn num_operands() -> usize {
+        match VARIANT {
+            4 => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check the number of operands.
+        debug_assert!(
+            self.operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operands",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destination: self.destination.map_locator(map),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> OptionOperation<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Retrieve the first input.
+        let first = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("Instruction '{}' expects the first operand to be a plaintext.", Self::opcode()),
+        };
+
+        // Compute the output.
+        let output = match (VARIANT, first) {
+            (0, value) => Plaintext::Option(Boolean::new(true), Box::new(value), Default::default()),
+            (1, value) => Plaintext::new_none(&value)?,
+            (2, Plaintext::Option(is_some, ..)) => Plaintext::from(Literal::Boolean(is_some)),
+            (3, Plaintext::Option(is_some, value, ..)) => {
+                // Ensure the option is `some`.
+                ensure!(*is_some, "Instruction '{}' failed: the option is 'none'", Self::opcode());
+                *value
+            }
+            (4, Plaintext::Option(is_some, value, ..)) => match *is_some {
+                true => *value,
+                false => match registers.load(stack, &self.operands[1])? {
+                    Value::Plaintext(plaintext) => plaintext,
+                    _ => bail!("Instruction '{}' expects the second operand to be a plaintext.", Self::opcode()),
+                },
+            },
+            (2.., _) => bail!("Instruction '{}' expects the first operand to be an option.", Self::opcode()),
+        };
+
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Retrieve the first input.
+        let first = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("Instruction '{}' expects the first operand to be a plaintext.", Self::opcode()),
+        };
+
+        // Compute the output.
+        let output = match (VARIANT, first) {
+            (0, value) => {
+                circuit::Plaintext::Option(circuit::Boolean::constant(true), Box::new(value), Default::default())
+            }
+            // Note: The placeholder only depends on the type of `first`, so the option is a constant.
+            (1, value) => circuit::Plaintext::constant(Plaintext::new_none(&value.eject_value())?),
+            (2, circuit::Plaintext::Option(is_some, ..)) => {
+                circuit::Plaintext::from(circuit::Literal::Boolean(is_some))
+            }
+            (3, circuit::Plaintext::Option(is_some, value, ..)) => {
+                // Ensure the option is `some`.
+                A::assert(is_some);
+                *value
+            }
+            (4, circuit::Plaintext::Option(is_some, value, ..)) => {
+                let default = match registers.load_circuit(stack, &self.operands[1])? {
+                    circuit::Value::Plaintext(plaintext) => plaintext,
+                    _ => bail!("Instruction '{}' expects the second operand to be a plaintext.", Self::opcode()),
+                };
+                ternary_circuit(&is_some, &value, &default)?
+            }
+            (2.., _) => bail!("Instruction '{}' expects the first operand to be an option.", Self::opcode()),
+        };
+
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                Self::num_operands(),
+                input_types.len()
+            )
+        }
+
+        let output_type = match (VARIANT, &input_types[0]) {
+            (0 | 1, RegisterType::Plaintext(plaintext_type)) => {
+                PlaintextType::Option(OptionType::new(plaintext_type.clone())?)
+            }
+            (2, RegisterType::Plaintext(PlaintextType::Option(..))) => PlaintextType::Literal(LiteralType::Boolean),
+            (3, RegisterType::Plaintext(PlaintextType::Option(option_type))) => option_type.element_type().clone(),
+            (4, RegisterType::Plaintext(PlaintextType::Option(option_type))) => {
+                // Ensure the second operand matches the element type of the option.
+                let element_type = RegisterType::Plaintext(option_type.element_type().clone());
+                if input_types[1] != element_type {
+                    bail!(
+                        "Instruction '{}' expects the second input to be a '{element_type}'. Found input of type '{}'",
+                        Self::opcode(),
+                        input_types[1]
+                    )
+                }
+                option_type.element_type().clone()
+            }
+            (0 | 1, _) => bail!(
+                "Instruction '{}' expects the first input to be a plaintext. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            ),
+            (2.., _) => bail!(
+                "Instruction '{}' expects the first input to be an option. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            ),
+        };
+
+        Ok(vec![RegisterType::Plaintext(output_type)])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for OptionOperation<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (mut string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        for _ in 0..Self::num_operands() {
+            // Parse the whitespace from the string.
+            let (next, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            let (next, operand) = Operand::parse(next)?;
+            // Add the operand to the operands.
+            operands.push(operand);
+            string = next;
+        }
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for OptionOperation<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for OptionOperation<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for OptionOperation<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for OptionOperation<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        // Read the operands.
+        for _ in 0..Self::num_operands() {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for OptionOperation<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(error(format!(
+                "The number of operands must be {}, found {}",
+                Self::num_operands(),
+                self.operands.len()
+            )));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = AleoV0;
+
+    fn check_parse<const VARIANT: u8>() {
+        let num_operands = OptionOperation::<CurrentNetwork, VARIANT>::num_operands();
+        let operands = (0..num_operands).map(|i| format!("r{i}")).collect::<Vec<_>>().join(" ");
+        let string =
+            format!("{} {operands} into r{num_operands}", OptionOperation::<CurrentNetwork, VARIANT>::opcode());
+        let (remainder, instruction) = OptionOperation::<CurrentNetwork, VARIANT>::parse(&string).unwrap();
+        assert!(remainder.is_empty(), "Parser did not consume all of the string: '{remainder}'");
+        assert_eq!(instruction.operands.len(), num_operands, "The number of operands is incorrect");
+        for (i, operand) in instruction.operands.iter().enumerate() {
+            assert_eq!(*operand, Operand::Register(Register::Locator(i as u64)), "Operand {i} is incorrect");
+        }
+        assert_eq!(instruction.destination, Register::Locator(num_operands as u64), "The destination is incorrect");
+        assert_eq!(string, instruction.to_string());
+    }
+
+    #[test]
+    fn test_parse() {
+        check_parse::<{ OptionVariant::Some as u8 }>();
+        check_parse::<{ OptionVariant::None as u8 }>();
+        check_parse::<{ OptionVariant::IsSome as u8 }>();
+        check_parse::<{ OptionVariant::Unwrap as u8 }>();
+        check_parse::<{ OptionVariant::UnwrapOr as u8 }>();
+
+        // Ensure the number of operands is checked.
+        assert!(Unwrap::<CurrentNetwork>::from_str("unwrap r0 r1 into r2").is_err());
+        assert!(UnwrapOr::<CurrentNetwork>::from_str("unwrap.or r0 into r1").is_err());
+    }
+
+    #[test]
+    fn test_ternary_circuit() -> Result<()> {
+        let first = Plaintext::<CurrentNetwork>::from_str("{ x: 1u8, y: [true, false] }")?;
+        let second = Plaintext::<CurrentNetwork>::from_str("{ x: 2u8, y: [false, true] }")?;
+
+        for condition in [true, false] {
+            let candidate = ternary_circuit(
+                &circuit::Boolean::<CurrentAleo>::new(circuit::Mode::Private, condition),
+                &circuit::Plaintext::new(circuit::Mode::Private, first.clone()),
+                &circuit::Plaintext::new(circuit::Mode::Private, second.clone()),
+            )?;
+            let expected = match condition {
+                true => &first,
+                false => &second,
+            };
+            assert_eq!(expected.to_string(), candidate.eject_value().to_string());
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+
+        // Ensure plaintexts of different sizes cannot be selected between.
+        let second = Plaintext::<CurrentNetwork>::from_str("{ x: 2u8 }")?;
+        assert!(
+            ternary_circuit(
+                &circuit::Boolean::<CurrentAleo>::new(circuit::Mode::Private, true),
+                &circuit::Plaintext::new(circuit::Mode::Private, first),
+                &circuit::Plaintext::new(circuit::Mode::Private, second),
+            )
+            .is_err()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,373 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+};
+
+/// Adds `first` with `second`, storing the wrapped sum in `destination`, and whether an overflow occurred in `flag`.
+pub type AddChecked<N> = Overflowing<N, { OverflowingVariant::Add as u8 }>;
+/// Computes `first - second`, storing the wrapped difference in `destination`, and whether an underflow occurred in `flag`.
+pub type SubChecked<N> = Overflowing<N, { OverflowingVariant::Sub as u8 }>;
+/// Multiplies `first` with `second`, storing the wrapped product in `destination`, and whether an overflow occurred in `flag`.
+pub type MulChecked<N> = Overflowing<N, { OverflowingVariant::Mul as u8 }>;
+
+/// Which arithmetic operation is performed.
+enum OverflowingVariant {
+    Add,
+    Sub,
+    Mul,
+}
+
+/// Applies the arithmetic operation for the given `VARIANT` to two integers of the same type,
+/// returning the wrapped result and the overflow flag.
+macro_rules! overflowing {
+    ($first:expr, $second:expr, $literal:ident, { $( $variant:ident ),+ }) => {
+        match ($first, $second) {
+            $( ($literal::$variant(first), $literal::$variant(second)) => {
+                let (output, is_overflow) = match VARIANT {
+                    0 => first.add_overflowing(second),
+                    1 => first.sub_overflowing(second),
+                    2 => first.mul_overflowing(second),
+                    3.. => bail!("Invalid 'checked' variant: {VARIANT}"),
+                };
+                ($literal::$variant(output), is_overflow)
+            } ),+
+            _ => bail!("Instruction '{}' expects two integers of the same type", Self::opcode()),
+        }
+    };
+}
+
+/// Performs an integer arithmetic operation that does not halt on overflow.
+/// The wrapped result is stored in `destination`, and whether an overflow (or underflow) occurred is stored in `flag`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Overflowing<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register for the wrapped result.
+    destination: Register<N>,
+    /// The destination register for the overflow flag.
+    flag: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> Overflowing<N, VARIANT> {
+    /// Initializes a new `checked` arithmetic instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>, flag: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination, flag })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Overflowing("add.checked"),
+            1 => Opcode::Overflowing("sub.checked"),
+            2 => Opcode::Overflowing("mul.checked"),
+            3.. => panic!("Invalid 'checked' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly two operands.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination registers, for the wrapped result and the overflow flag.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone(), self.flag.clone()]
+    }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, map: &impl Fn(u64) -> u64) -> Self {
+        Self {
+            operands: self.operands.iter().map(|operand| operand.map_register(map)).collect(),
+            destination: self.destination.map_locator(map),
+            flag: self.flag.map_locator(map),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Overflowing<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let first = registers.load_literal(stack, &self.operands[0])?;
+        let second = registers.load_literal(stack, &self.operands[1])?;
+
+        // Compute the wrapped result and the overflow flag.
+        let (output, is_overflow) =
+            overflowing!(&first, &second, Literal, { I8, I16, I32, I64, I128, U8, U16, U32, U64, U128 });
+
+        // Store the outputs.
+        registers.store_literal(stack, &self.destination, output)?;
+        registers.store_literal(stack, &self.flag, Literal::Boolean(is_overflow))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::Literal;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let first = registers.load_literal_circuit(stack, &self.operands[0])?;
+        let second = registers.load_literal_circuit(stack, &self.operands[1])?;
+
+        // Compute the wrapped result and the overflow flag.
+        let (output, is_overflow) =
+            overflowing!(&first, &second, Literal, { I8, I16, I32, I64, I128, U8, U16, U32, U64, U128 });
+
+        // Store the outputs.
+        registers.store_literal_circuit(stack, &self.destination, output)?;
+        registers.store_literal_circuit(stack, &self.flag, Literal::Boolean(is_overflow))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the operands are integers of the same type.
+        let integer_type = match (&input_types[0], &input_types[1]) {
+            (
+                RegisterType::Plaintext(PlaintextType::Literal(first)),
+                RegisterType::Plaintext(PlaintextType::Literal(second)),
+            ) if first == second && is_integer_type(first) => *first,
+            _ => bail!(
+                "Instruction '{}' expects two integers of the same type. Found inputs of type '{}' and '{}'",
+                Self::opcode(),
+                input_types[0],
+                input_types[1]
+            ),
+        };
+
+        Ok(vec![
+            RegisterType::Plaintext(PlaintextType::Literal(integer_type)),
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean)),
+        ])
+    }
+}
+
+/// Returns `true` if the given literal type is an integer type.
+fn is_integer_type(literal_type: &LiteralType) -> bool {
+    matches!(
+        literal_type,
+        LiteralType::I8
+            | LiteralType::I16
+            | LiteralType::I32
+            | LiteralType::I64
+            | LiteralType::I128
+            | LiteralType::U8
+            | LiteralType::U16
+            | LiteralType::U32
+            | LiteralType::U64
+            | LiteralType::U128
+    )
+}
+
+impl<N: Network, const VARIANT: u8> Parser for Overflowing<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the flag register from the string.
+        let (string, flag) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second], destination, flag }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for Overflowing<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for Overflowing<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for Overflowing<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {} {}", self.destination, self.flag)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for Overflowing<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operands.
+        let first = Operand::read_le(&mut reader)?;
+        let second = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the flag register.
+        let flag = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands: vec![first, second], destination, flag })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for Overflowing<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the flag register.
+        self.flag.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        network::MainnetV0,
+        types::{Boolean, U8},
+    };
+
+    type CurrentNetwork = MainnetV0;
+
+    fn check_parse<const VARIANT: u8>() {
+        let string = format!("{} r0 r1 into r2 r3", Overflowing::<CurrentNetwork, VARIANT>::opcode());
+        let (remainder, instruction) = Overflowing::<CurrentNetwork, VARIANT>::parse(&string).unwrap();
+        assert!(remainder.is_empty(), "Parser did not consume all of the string: '{remainder}'");
+        assert_eq!(instruction.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(instruction.flag, Register::Locator(3), "The flag register is incorrect");
+        assert_eq!(string, instruction.to_string());
+    }
+
+    #[test]
+    fn test_parse() {
+        check_parse::<{ OverflowingVariant::Add as u8 }>();
+        check_parse::<{ OverflowingVariant::Sub as u8 }>();
+        check_parse::<{ OverflowingVariant::Mul as u8 }>();
+
+        // Ensure a missing flag register fails to parse.
+        assert!(AddChecked::<CurrentNetwork>::from_str("add.checked r0 r1 into r2").is_err());
+    }
+
+    #[test]
+    fn test_overflowing() {
+        let max = U8::<CurrentNetwork>::new(u8::MAX);
+        let one = U8::<CurrentNetwork>::new(1);
+        assert_eq!(max.add_overflowing(&one), (U8::new(0), Boolean::new(true)));
+        assert_eq!(one.add_overflowing(&one), (U8::new(2), Boolean::new(false)));
+        assert_eq!(U8::<CurrentNetwork>::new(0).sub_overflowing(&one), (U8::new(u8::MAX), Boolean::new(true)));
+        assert_eq!(max.mul_overflowing(&U8::new(2)), (U8::new(254), Boolean::new(true)));
+    }
+}
@@ -0,0 +1,532 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Register, RegisterType},
+    types::U32,
+};
+
+use core::marker::PhantomData;
+
+/// Opens a block of instructions that is repeated a constant number of times.
+/// i.e. `repeat 4u32 carry r1 r2 into r4 r5;`
+///
+/// The block is closed by an `end` instruction, and is unrolled by the stack before it is evaluated.
+/// In iteration `i`, each destination register of the block is offset by `i * k`, where `k` is the
+/// number of destination registers in the block. Each carried register (i.e. `r1` and `r2`) is read
+/// as-is by the first iteration, and is rebound to the matching carried destination (i.e. `r4` and `r5`)
+/// of the previous iteration in every subsequent iteration.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Repeat<N: Network> {
+    /// The number of iterations.
+    iterations: U32<N>,
+    /// The registers that are carried into each iteration.
+    carried_registers: Vec<Register<N>>,
+    /// The destination registers of the block that rebind the carried registers in the next iteration.
+    carried_destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> Repeat<N> {
+    /// Initializes a new `repeat` instruction.
+    #[inline]
+    pub fn new(
+        iterations: U32<N>,
+        carried_registers: Vec<Register<N>>,
+        carried_destinations: Vec<Register<N>>,
+    ) -> Result<Self> {
+        // Ensure the block is repeated at least once.
+        ensure!(*iterations > 0, "Instruction '{}' must repeat at least once", Self::opcode());
+        // Ensure the number of carried registers is within the bounds.
+        ensure!(
+            carried_registers.len() <= N::MAX_OPERANDS,
+            "Instruction '{}' can carry at most {} registers",
+            Self::opcode(),
+            N::MAX_OPERANDS
+        );
+        // Ensure each carried register has a matching carried destination.
+        ensure!(
+            carried_registers.len() == carried_destinations.len(),
+            "Instruction '{}' carries {} registers into {} destinations",
+            Self::opcode(),
+            carried_registers.len(),
+            carried_destinations.len()
+        );
+        // Ensure the carried registers and destinations are distinct register locators.
+        for registers in [&carried_registers, &carried_destinations] {
+            ensure!(
+                registers.iter().all(|register| matches!(register, Register::Locator(..))),
+                "Instruction '{}' can only carry register locators",
+                Self::opcode()
+            );
+            ensure!(
+                !has_duplicates(registers),
+                "Instruction '{}' cannot carry the same register twice",
+                Self::opcode()
+            );
+        }
+        // Return the instruction.
+        Ok(Self { iterations, carried_registers, carried_destinations })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Repeat
+    }
+
+    /// Returns the number of iterations.
+    #[inline]
+    pub const fn iterations(&self) -> &U32<N> {
+        &self.iterations
+    }
+
+    /// Returns the registers that are carried into each iteration.
+    #[inline]
+    pub fn carried_registers(&self) -> &[Register<N>] {
+        &self.carried_registers
+    }
+
+    /// Returns the destination registers of the block that rebind the carried registers in the next iteration.
+    #[inline]
+    pub fn carried_destinations(&self) -> &[Register<N>] {
+        &self.carried_destinations
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &[]
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![]
+    }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, _map: &impl Fn(u64) -> u64) -> Self {
+        self.clone()
+    }
+}
+
+impl<N: Network> Repeat<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        bail!("Instruction '{}' must be unrolled before it is evaluated", Self::opcode())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        bail!("Instruction '{}' must be unrolled before it is executed", Self::opcode())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke '{}'.", Self::opcode())
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        ensure!(
+            input_types.is_empty(),
+            "Instruction '{}' expects 0 inputs, found {}",
+            Self::opcode(),
+            input_types.len()
+        );
+        // Ensure the block is repeated at least once.
+        ensure!(*self.iterations > 0, "Instruction '{}' must repeat at least once", Self::opcode());
+        Ok(vec![])
+    }
+}
+
+impl<N: Network> Parser for Repeat<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a register from the string.
+        fn parse_register<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the register from the string.
+            Register::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the number of iterations from the string.
+        let (string, iterations) = U32::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Optionally parse the "carry" from the string.
+        let (string, (carried_registers, carried_destinations)) = match opt(tag("carry"))(string)? {
+            // If the "carry" was not parsed, return the string and no carried registers.
+            (string, None) => (string, (vec![], vec![])),
+            // If the "carry" was parsed, parse the carried registers and destinations from the string.
+            (string, Some(_)) => {
+                // Parse the carried registers from the string.
+                let (string, carried_registers) = many1(complete(parse_register))(string)?;
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the "into" from the string.
+                let (string, _) = tag("into")(string)?;
+                // Parse the carried destinations from the string.
+                let (string, carried_destinations) = many1(complete(parse_register))(string)?;
+                // Return the string and the carried registers and destinations.
+                (string, (carried_registers, carried_destinations))
+            }
+        };
+
+        // Construct the instruction, ensuring it is well-formed.
+        match Self::new(iterations, carried_registers, carried_destinations) {
+            Ok(repeat) => Ok((string, repeat)),
+            Err(e) => map_res(fail, |_: ParserResult<Self>| Err(error(e.to_string())))(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for Repeat<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Repeat<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Repeat<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of carried registers is within the bounds.
+        if self.carried_registers.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {}", Self::opcode(), self.iterations)?;
+        if !self.carried_registers.is_empty() {
+            write!(f, " carry")?;
+            self.carried_registers.iter().try_for_each(|register| write!(f, " {register}"))?;
+            write!(f, " into")?;
+            self.carried_destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for Repeat<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of iterations.
+        let iterations = U32::read_le(&mut reader)?;
+
+        // Read the number of carried registers.
+        let num_carried = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of carried registers is within the bounds.
+        if num_carried > N::MAX_OPERANDS {
+            return Err(error(format!("The number of carried registers must be <= {}", N::MAX_OPERANDS)));
+        }
+        // Read the carried registers.
+        let carried_registers = (0..num_carried).map(|_| Register::read_le(&mut reader)).collect::<IoResult<_>>()?;
+        // Read the carried destinations.
+        let carried_destinations = (0..num_carried).map(|_| Register::read_le(&mut reader)).collect::<IoResult<_>>()?;
+
+        // Return the operation.
+        Self::new(iterations, carried_registers, carried_destinations).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for Repeat<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of carried registers is within the bounds.
+        if self.carried_registers.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of carried registers must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Write the number of iterations.
+        self.iterations.write_le(&mut writer)?;
+        // Write the number of carried registers.
+        u8::try_from(self.carried_registers.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the carried registers.
+        self.carried_registers.iter().try_for_each(|register| register.write_le(&mut writer))?;
+        // Write the carried destinations.
+        self.carried_destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+/// Closes the block of instructions opened by the preceding `repeat` instruction.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct End<N: Network> {
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> End<N> {
+    /// Initializes a new `end` instruction.
+    #[inline]
+    pub fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::End
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &[]
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![]
+    }
+
+    /// Returns the instruction, with each register locator remapped by the given function.
+    #[inline]
+    pub fn map_registers(&self, _map: &impl Fn(u64) -> u64) -> Self {
+        self.clone()
+    }
+}
+
+impl<N: Network> Default for End<N> {
+    /// Initializes a new `end` instruction.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> End<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        bail!("Instruction '{}' must be unrolled before it is evaluated", Self::opcode())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        bail!("Instruction '{}' must be unrolled before it is executed", Self::opcode())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke '{}'.", Self::opcode())
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        ensure!(
+            input_types.is_empty(),
+            "Instruction '{}' expects 0 inputs, found {}",
+            Self::opcode(),
+            input_types.len()
+        );
+        Ok(vec![])
+    }
+}
+
+impl<N: Network> Parser for End<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+
+        Ok((string, Self::new()))
+    }
+}
+
+impl<N: Network> FromStr for End<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for End<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for End<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", Self::opcode())
+    }
+}
+
+impl<N: Network> FromBytes for End<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(_reader: R) -> IoResult<Self> {
+        Ok(Self::new())
+    }
+}
+
+impl<N: Network> ToBytes for End<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, _writer: W) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, repeat) = Repeat::<CurrentNetwork>::parse("repeat 4u32").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(*repeat.iterations(), U32::new(4), "The number of iterations is incorrect");
+        assert!(repeat.carried_registers().is_empty(), "The carried registers are incorrect");
+
+        let (string, repeat) = Repeat::<CurrentNetwork>::parse("repeat 4u32 carry r1 r2 into r4 r5").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(*repeat.iterations(), U32::new(4), "The number of iterations is incorrect");
+        assert_eq!(
+            repeat.carried_registers(),
+            [Register::Locator(1), Register::Locator(2)],
+            "The carried registers are incorrect"
+        );
+        assert_eq!(
+            repeat.carried_destinations(),
+            [Register::Locator(4), Register::Locator(5)],
+            "The carried destinations are incorrect"
+        );
+
+        let (string, _) = End::<CurrentNetwork>::parse("end").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Ensure a block must repeat at least once.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 0u32").is_err());
+        // Ensure the number of iterations must be a `u32`.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u64").is_err());
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4").is_err());
+        // Ensure each carried register must have a matching carried destination.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u32 carry r1 r2 into r4").is_err());
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u32 carry r1 into").is_err());
+        // Ensure a register cannot be carried twice.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u32 carry r1 r1 into r4 r5").is_err());
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u32 carry r1 r2 into r4 r4").is_err());
+        // Ensure only register locators can be carried.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u32 carry r1.owner into r4").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in ["repeat 8u32", "repeat 8u32 carry r1 into r3", "repeat 8u32 carry r0 r1 into r3 r2"] {
+            assert_eq!(expected, Repeat::<CurrentNetwork>::from_str(expected).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for string in ["repeat 8u32", "repeat 8u32 carry r1 into r3", "repeat 8u32 carry r0 r1 into r3 r2"] {
+            let expected = Repeat::<CurrentNetwork>::from_str(string).unwrap();
+            let candidate = Repeat::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+            assert_eq!(expected, candidate);
+        }
+
+        // Ensure a block that does not repeat is rejected.
+        let bytes = [0u32.to_bytes_le().unwrap(), 0u8.to_bytes_le().unwrap()].concat();
+        assert!(Repeat::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Opcode, Operation};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType},
+    types::{StringType, U32},
+};
+
+/// Computes the number of bytes in `first`, storing the outcome in `destination`.
+pub type StringLength<N> = crate::UnaryLiteral<N, StringLengthOperation<N>>;
+
+/// The implementation of the `str.len` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StringLengthOperation<N: Network>(core::marker::PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 1> for StringLengthOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("str.len");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 1]) -> Result<Literal<N>> {
+        match inputs {
+            [Literal::String(string)] => Ok(Literal::U32(U32::new(u32::try_from(string.len())?))),
+            _ => bail!("Invalid operand type for '{}', expected a 'string'", Self::OPCODE),
+        }
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 1]) -> Result<circuit::Literal<A>> {
+        match inputs {
+            [circuit::Literal::String(string)] => Ok(circuit::Literal::U32(string.length())),
+            _ => bail!("Invalid operand type for '{}', expected a 'string'", Self::OPCODE),
+        }
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 1]) -> Result<LiteralType> {
+        match inputs {
+            [LiteralType::String] => Ok(LiteralType::U32),
+            [input] => bail!("Invalid operand type for '{}', expected 'string', found '{input}'", Self::OPCODE),
+        }
+    }
+}
+
+/// Concatenates `first` with `second`, storing the outcome in `destination`.
+pub type StringConcat<N> = crate::BinaryLiteral<N, StringConcatOperation<N>>;
+
+/// The implementation of the `str.concat` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StringConcatOperation<N: Network>(core::marker::PhantomData<N>);
+
+impl<N: Network> StringConcatOperation<N> {
+    /// Ensures the concatenation of strings with the given number of bytes is within the allowed capacity.
+    fn check_size(num_bytes_a: usize, num_bytes_b: usize) -> Result<()> {
+        match num_bytes_a.checked_add(num_bytes_b) {
+            Some(num_bytes) if num_bytes <= N::MAX_STRING_BYTES as usize => Ok(()),
+            _ => bail!("'{}' cannot exceed {} bytes", Self::OPCODE, N::MAX_STRING_BYTES),
+        }
+    }
+}
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 2> for StringConcatOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("str.concat");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 2]) -> Result<Literal<N>> {
+        match inputs {
+            [Literal::String(first), Literal::String(second)] => {
+                Self::check_size(first.len(), second.len())?;
+                Ok(Literal::String(StringType::new(&format!("{}{}", &**first, &**second))))
+            }
+            _ => bail!("Invalid operand types for '{}', expected two 'string's", Self::OPCODE),
+        }
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 2]) -> Result<circuit::Literal<A>> {
+        match inputs {
+            [circuit::Literal::String(first), circuit::Literal::String(second)] => {
+                Self::check_size(first.to_bytes().len(), second.to_bytes().len())?;
+                Ok(circuit::Literal::String(first.concat(second)))
+            }
+            _ => bail!("Invalid operand types for '{}', expected two 'string's", Self::OPCODE),
+        }
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 2]) -> Result<LiteralType> {
+        match inputs {
+            [LiteralType::String, LiteralType::String] => Ok(LiteralType::String),
+            [first, second] => bail!(
+                "Invalid operand types for '{}', expected ('string', 'string'), found ('{first}', '{second}')",
+                Self::OPCODE
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{Eject, Inject, Mode};
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_string_length() {
+        for string in ["", "a", "hello world", "héllo"] {
+            let literal = Literal::<CurrentNetwork>::String(StringType::new(string));
+            let expected = Literal::U32(U32::new(u32::try_from(string.len()).unwrap()));
+            assert_eq!(StringLengthOperation::evaluate(&[literal.clone()]).unwrap(), expected);
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let candidate =
+                    StringLengthOperation::execute::<CurrentAleo>(&[circuit::Literal::new(mode, literal.clone())]);
+                assert_eq!(candidate.unwrap().eject_value(), expected);
+            }
+        }
+        assert_eq!(
+            StringLengthOperation::<CurrentNetwork>::output_type(&[LiteralType::String]).unwrap(),
+            LiteralType::U32
+        );
+        assert!(StringLengthOperation::<CurrentNetwork>::output_type(&[LiteralType::U8]).is_err());
+    }
+
+    #[test]
+    fn test_string_concat() {
+        for (first, second) in [("", ""), ("hello", " world"), ("héllo", "wörld")] {
+            let expected = Literal::String(StringType::new(&format!("{first}{second}")));
+            let first = Literal::<CurrentNetwork>::String(StringType::new(first));
+            let second = Literal::<CurrentNetwork>::String(StringType::new(second));
+            assert_eq!(StringConcatOperation::evaluate(&[first.clone(), second.clone()]).unwrap(), expected);
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let candidate = StringConcatOperation::execute::<CurrentAleo>(&[
+                    circuit::Literal::new(mode, first.clone()),
+                    circuit::Literal::new(mode, second.clone()),
+                ]);
+                assert_eq!(candidate.unwrap().eject_value(), expected);
+            }
+        }
+        assert_eq!(
+            StringConcatOperation::<CurrentNetwork>::output_type(&[LiteralType::String, LiteralType::String]).unwrap(),
+            LiteralType::String
+        );
+        assert!(StringConcatOperation::<CurrentNetwork>::output_type(&[LiteralType::String, LiteralType::U8]).is_err());
+    }
+
+    #[test]
+    fn test_string_concat_exceeds_max_bytes() {
+        let max_bytes = CurrentNetwork::MAX_STRING_BYTES as usize;
+        let first = Literal::<CurrentNetwork>::String(StringType::new(&"a".repeat(max_bytes)));
+        let second = Literal::<CurrentNetwork>::String(StringType::new("b"));
+        assert!(StringConcatOperation::evaluate(&[first.clone(), second.clone()]).is_err());
+        let candidate = StringConcatOperation::execute::<CurrentAleo>(&[
+            circuit::Literal::new(Mode::Private, first),
+            circuit::Literal::new(Mode::Private, second),
+        ]);
+        assert!(candidate.is_err());
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use super::{MapKey, MapValue};
+
+use console::{
+    network::prelude::*,
+    program::{Locator, ProgramID},
+};
+
+/// An external mapping declares the key and value types of a mapping in an imported program,
+/// and is of the form `import mapping {program_id}/{mapping_name}:`, followed by its key and value statements.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExternalMapping<N: Network> {
+    /// The locator of the external mapping.
+    locator: Locator<N>,
+    /// The key statement.
+    key: MapKey<N>,
+    /// The value statement.
+    value: MapValue<N>,
+}
+
+impl<N: Network> ExternalMapping<N> {
+    /// Initializes a new external mapping with the given locator, key statement, and value statement.
+    pub fn new(locator: Locator<N>, key: MapKey<N>, value: MapValue<N>) -> Self {
+        Self { locator, key, value }
+    }
+
+    /// Returns the locator of the external mapping.
+    pub const fn locator(&self) -> &Locator<N> {
+        &self.locator
+    }
+
+    /// Returns the program ID of the external mapping.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        self.locator.program_id()
+    }
+
+    /// Returns the key statement.
+    pub const fn key(&self) -> &MapKey<N> {
+        &self.key
+    }
+
+    /// Returns the value statement.
+    pub const fn value(&self) -> &MapValue<N> {
+        &self.value
+    }
+}
+
+impl<N: Network> TypeName for ExternalMapping<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "import mapping"
+    }
+}
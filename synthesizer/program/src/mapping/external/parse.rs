@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for ExternalMapping<N> {
+    /// Parses a string into an external mapping.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'import' keyword from the string.
+        let (string, _) = tag("import")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the 'mapping' keyword from the string.
+        let (string, _) = tag("mapping")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the locator from the string.
+        let (string, locator) = Locator::<N>::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+
+        // Parse the key statement from the string.
+        let (string, key) = MapKey::parse(string)?;
+        // Parse the value statement from the string.
+        let (string, value) = MapValue::parse(string)?;
+
+        // Return the external mapping.
+        Ok((string, Self::new(locator, key, value)))
+    }
+}
+
+impl<N: Network> FromStr for ExternalMapping<N> {
+    type Err = Error;
+
+    /// Returns an external mapping from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ExternalMapping<N> {
+    /// Prints the external mapping as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ExternalMapping<N> {
+    /// Prints the external mapping as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Write the external mapping to a string.
+        write!(f, "{} {}:", Self::type_name(), self.locator)?;
+        write!(f, "\n    {}", self.key)?;
+        write!(f, "\n    {}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_external_mapping_parse() {
+        let mapping = ExternalMapping::<CurrentNetwork>::parse(
+            r"
+import mapping credits.aleo/account:
+    key as address.public;
+    value as u64.public;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("credits.aleo/account", mapping.locator().to_string());
+        assert_eq!("address", mapping.key.plaintext_type().to_string());
+        assert_eq!("u64", mapping.value.plaintext_type().to_string());
+    }
+
+    #[test]
+    fn test_external_mapping_parse_fails() {
+        // Ensure an import statement is not parsed as an external mapping.
+        assert!(ExternalMapping::<CurrentNetwork>::parse("import credits.aleo;").is_err());
+        // Ensure the mapping must be referenced by its locator.
+        assert!(
+            ExternalMapping::<CurrentNetwork>::parse(
+                r"
+import mapping account:
+    key as address.public;
+    value as u64.public;"
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_external_mapping_display() {
+        let expected = r"import mapping credits.aleo/account:
+    key as address.public;
+    value as u64.public;";
+        let mapping = ExternalMapping::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{mapping}"),);
+    }
+}
@@ -18,6 +18,9 @@ use key::*;
 mod value;
 use value::*;
 
+mod external;
+pub use external::*;
+
 mod bytes;
 mod parse;
 
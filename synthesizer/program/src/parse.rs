@@ -22,15 +22,19 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
     fn parse(string: &str) -> ParserResult<Self> {
         // A helper to parse a program.
         enum P<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
+            K(Constant<N>),
             M(Mapping<N>),
             I(StructType<N>),
             R(RecordType<N>),
             C(ClosureCore<N, Instruction>),
             F(FunctionCore<N, Instruction, Command>),
+            V(ViewCore<N, Command>),
         }
 
         // Parse the imports from the string.
         let (string, imports) = many0(Import::parse)(string)?;
+        // Parse the external mappings from the string.
+        let (string, external_mappings) = many0(ExternalMapping::parse)(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the 'program' keyword from the string.
@@ -43,14 +47,18 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the semicolon ';' keyword from the string.
         let (string, _) = tag(";")(string)?;
+        // Parse the upgrade policy (if any) from the string.
+        let (string, upgrade_policy) = opt(UpgradePolicy::parse)(string)?;
 
         // Parse the struct or function from the string.
         let (string, components) = many1(alt((
+            map(Constant::parse, |constant| P::<N, Instruction, Command>::K(constant)),
             map(Mapping::parse, |mapping| P::<N, Instruction, Command>::M(mapping)),
             map(StructType::parse, |struct_| P::<N, Instruction, Command>::I(struct_)),
             map(RecordType::parse, |record| P::<N, Instruction, Command>::R(record)),
             map(ClosureCore::parse, |closure| P::<N, Instruction, Command>::C(closure)),
             map(FunctionCore::parse, |function| P::<N, Instruction, Command>::F(function)),
+            map(ViewCore::parse, |view| P::<N, Instruction, Command>::V(view)),
         )))(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
@@ -65,14 +73,18 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     return Err(error);
                 }
             };
+            // Set the upgrade policy.
+            program.upgrade_policy = upgrade_policy.unwrap_or_default();
             // Construct the program with the parsed components.
             for component in components.iter() {
                 let result = match component {
+                    P::K(constant) => program.add_constant(constant.clone()),
                     P::M(mapping) => program.add_mapping(mapping.clone()),
                     P::I(struct_) => program.add_struct(struct_.clone()),
                     P::R(record) => program.add_record(record.clone()),
                     P::C(closure) => program.add_closure(closure.clone()),
                     P::F(function) => program.add_function(function.clone()),
+                    P::V(view) => program.add_view(view.clone()),
                 };
 
                 match result {
@@ -93,6 +105,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     }
                 }
             }
+            // Add the external mappings (if any) to the program.
+            for external_mapping in external_mappings.iter() {
+                match program.add_external_mapping(external_mapping.clone()) {
+                    Ok(_) => (),
+                    Err(error) => {
+                        eprintln!("{error}");
+                        return Err(error);
+                    }
+                }
+            }
             // Output the program.
             Ok::<_, Error>(program)
         })(string)
@@ -149,9 +171,19 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
             program.push('\n');
         }
 
+        // Print the external mappings.
+        for external_mapping in self.external_mappings.values() {
+            program.push_str(&format!("{external_mapping}\n\n"));
+        }
+
         // Print the program name.
         program += &format!("{} {};\n\n", Self::type_name(), self.id);
 
+        // Print the upgrade policy, if the program is upgradable.
+        if self.upgrade_policy != UpgradePolicy::default() {
+            program += &format!("{}\n\n", self.upgrade_policy);
+        }
+
         for (identifier, definition) in self.identifiers.iter() {
             match definition {
                 ProgramDefinition::Mapping => match self.mappings.get(identifier) {
@@ -174,6 +206,14 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
                     Some(function) => program.push_str(&format!("{function}\n\n")),
                     None => return Err(fmt::Error),
                 },
+                ProgramDefinition::Constant => match self.constants.get(identifier) {
+                    Some(constant) => program.push_str(&format!("{constant}\n\n")),
+                    None => return Err(fmt::Error),
+                },
+                ProgramDefinition::View => match self.views.get(identifier) {
+                    Some(view) => program.push_str(&format!("{view}\n\n")),
+                    None => return Err(fmt::Error),
+                },
             }
         }
         // Remove the last newline.
@@ -259,6 +299,145 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_display_upgradable() -> Result<()> {
+        let expected = r"program to_parse.aleo;
+
+upgrade owner;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+";
+        // Parse a new program.
+        let program = Program::<CurrentNetwork>::from_str(expected)?;
+        // Ensure the program is upgradable.
+        assert_eq!(UpgradePolicy::Owner, program.upgrade_policy());
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+
+        // Ensure an explicitly immutable program prints without the upgrade policy.
+        let program = Program::<CurrentNetwork>::from_str(&expected.replace("upgrade owner;", "upgrade immutable;"))?;
+        assert_eq!(UpgradePolicy::Immutable, program.upgrade_policy());
+        assert!(!format!("{program}").contains("upgrade"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_display_constants() -> Result<()> {
+        let expected = r"program to_parse.aleo;
+
+constant MAX_SUPPLY as u64 = 1000000u64;
+
+mapping supply:
+    key as boolean.public;
+    value as u64.public;
+
+function mint:
+    input r0 as u64.public;
+    lte r0 MAX_SUPPLY into r1;
+    assert.eq r1 true;
+    async mint r0 into r2;
+    output r2 as to_parse.aleo/mint.future;
+
+finalize mint:
+    input r0 as u64.public;
+    get.or_use supply[true] 0u64 into r1;
+    add r1 r0 into r2;
+    lte r2 MAX_SUPPLY into r3;
+    assert.eq r3 true;
+    set r2 into supply[true];
+";
+        // Parse a new program.
+        let program = Program::<CurrentNetwork>::from_str(expected)?;
+        // Ensure the program contains the constant.
+        assert!(program.contains_constant(&Identifier::from_str("MAX_SUPPLY")?));
+        assert_eq!("1000000u64", program.get_constant(&Identifier::from_str("MAX_SUPPLY")?)?.literal().to_string());
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+
+        // Ensure a constant name cannot be reused.
+        let duplicate = expected.replace("mapping supply:", "constant supply as u8 = 1u8;\n\nmapping supply:");
+        assert!(Program::<CurrentNetwork>::from_str(&duplicate).is_err());
+        // Ensure a constant name cannot be a register.
+        let register = expected.replace("constant MAX_SUPPLY", "constant MAX_SUPPLY as u8 = 1u8;\nconstant r0");
+        assert!(Program::<CurrentNetwork>::from_str(&register).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_display_views() -> Result<()> {
+        let expected = r"program to_parse.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function noop:
+    input r0 as u64.public;
+    output r0 as u64.public;
+
+view balance_of:
+    input r0 as address.public;
+    get.or_use balances[r0] 0u64 into r1;
+    output r1 as u64.public;
+";
+        // Parse a new program.
+        let program = Program::<CurrentNetwork>::from_str(expected)?;
+        // Ensure the program contains the view.
+        assert!(program.contains_view(&Identifier::from_str("balance_of")?));
+        assert_eq!(1, program.get_view_ref(&Identifier::from_str("balance_of")?)?.outputs().len());
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+
+        // Ensure a view name cannot be reused.
+        let duplicate = expected.replace("view balance_of:", "view noop:");
+        assert!(Program::<CurrentNetwork>::from_str(&duplicate).is_err());
+        // Ensure a view cannot write to a mapping.
+        let write =
+            expected.replace("output r1 as u64.public;", "set r1 into balances[r0];\n    output r1 as u64.public;");
+        assert!(Program::<CurrentNetwork>::from_str(&write).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_display_external_mappings() -> Result<()> {
+        let expected = r"import credits.aleo;
+
+import mapping credits.aleo/account:
+    key as address.public;
+    value as u64.public;
+
+program to_parse.aleo;
+
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+";
+        // Parse a new program.
+        let program = Program::<CurrentNetwork>::from_str(expected)?;
+        // Ensure the program declares the external mapping.
+        assert!(program.contains_external_mapping(&Locator::from_str("credits.aleo/account")?));
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+
+        // Ensure an external mapping must reference an imported program.
+        assert!(Program::<CurrentNetwork>::from_str(&expected.replace("import credits.aleo;\n\n", "")).is_err());
+        // Ensure an external mapping cannot be declared twice.
+        let duplicate = expected.replace(
+            "program to_parse.aleo;",
+            "import mapping credits.aleo/account:\n    key as address.public;\n    value as u64.public;\n\nprogram to_parse.aleo;",
+        );
+        assert!(Program::<CurrentNetwork>::from_str(&duplicate).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_size() {
         // Define variable name for easy experimentation with program sizes.
@@ -39,6 +39,19 @@ pub trait FinalizeStoreTrait<N: Network> {
         key: &Plaintext<N>,
     ) -> Result<Option<Value<N>>>;
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_len_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<u64>;
+
+    /// Returns up to `limit` speculative keys for the given `program ID` and `mapping name`, skipping the first `start` keys.
+    /// The keys are returned in a stable order, which is the order of their serialized bytes.
+    fn get_keys_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<Plaintext<N>>>;
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` already exists, the method returns an error.
@@ -70,4 +83,13 @@ pub trait FinalizeStoreTrait<N: Network> {
         mapping_name: Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<Option<FinalizeOperation<N>>>;
+
+    /// Appends the given `payload` as an event under the given `program ID` and `topic` for the given `transition ID`.
+    fn emit_event(
+        &self,
+        program_id: ProgramID<N>,
+        topic: Identifier<N>,
+        transition_id: N::TransitionID,
+        payload: Plaintext<N>,
+    ) -> Result<FinalizeOperation<N>>;
 }
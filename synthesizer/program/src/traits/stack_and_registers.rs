@@ -197,6 +197,7 @@ pub trait RegistersLoad<N: Network> {
             Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
             Value::Plaintext(Plaintext::Struct(..))
             | Value::Plaintext(Plaintext::Array(..))
+            | Value::Plaintext(Plaintext::Option(..))
             | Value::Record(..)
             | Value::Future(..) => {
                 bail!("Operand must be a literal")
@@ -251,6 +252,7 @@ pub trait RegistersLoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
             circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal),
             circuit::Value::Plaintext(circuit::Plaintext::Struct(..))
             | circuit::Value::Plaintext(circuit::Plaintext::Array(..))
+            | circuit::Value::Plaintext(circuit::Plaintext::Option(..))
             | circuit::Value::Record(..)
             | circuit::Value::Future(..) => bail!("Operand must be a literal"),
         }
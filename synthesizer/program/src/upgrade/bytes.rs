@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl FromBytes for UpgradePolicy {
+    /// Reads the upgrade policy from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Immutable),
+            1 => Ok(Self::Owner),
+            variant => Err(error(format!("Invalid upgrade policy variant '{variant}'"))),
+        }
+    }
+}
+
+impl ToBytes for UpgradePolicy {
+    /// Writes the upgrade policy to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Immutable => 0u8.write_le(&mut writer),
+            Self::Owner => 1u8.write_le(&mut writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for expected in [UpgradePolicy::Immutable, UpgradePolicy::Owner] {
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, UpgradePolicy::read_le(&expected_bytes[..])?);
+        }
+        assert!(UpgradePolicy::read_le(&[2u8][..]).is_err());
+        Ok(())
+    }
+}
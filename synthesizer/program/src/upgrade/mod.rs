@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use console::network::prelude::*;
+
+/// An upgrade statement declares who may upgrade a program, and is of the form `upgrade {policy};`.
+/// If no upgrade statement is declared, the program is immutable.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum UpgradePolicy {
+    /// The program may not be upgraded.
+    #[default]
+    Immutable,
+    /// The program may only be upgraded by the owner of the program.
+    Owner,
+}
+
+impl UpgradePolicy {
+    /// Returns `true` if the program may be upgraded.
+    #[inline]
+    pub const fn is_upgradable(&self) -> bool {
+        matches!(self, Self::Owner)
+    }
+}
+
+impl TypeName for UpgradePolicy {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "upgrade"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_policy() -> Result<()> {
+        assert!(!UpgradePolicy::default().is_upgradable());
+        assert!(!UpgradePolicy::from_str("upgrade immutable;")?.is_upgradable());
+        assert!(UpgradePolicy::from_str("upgrade owner;")?.is_upgradable());
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Parser for UpgradePolicy {
+    /// Parses a string into an upgrade statement of the form `upgrade {policy};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the upgrade keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the policy from the string.
+        let (string, policy) =
+            alt((map(tag("immutable"), |_| Self::Immutable), map(tag("owner"), |_| Self::Owner)))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the upgrade statement.
+        Ok((string, policy))
+    }
+}
+
+impl FromStr for UpgradePolicy {
+    type Err = Error;
+
+    /// Parses a string into an upgrade statement.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl Debug for UpgradePolicy {
+    /// Prints the upgrade statement as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for UpgradePolicy {
+    /// Prints the upgrade statement as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let policy = match self {
+            Self::Immutable => "immutable",
+            Self::Owner => "owner",
+        };
+        write!(f, "{type_} {policy};", type_ = Self::type_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(UpgradePolicy::Owner, UpgradePolicy::parse("upgrade owner;")?.1);
+        assert_eq!(UpgradePolicy::Immutable, UpgradePolicy::parse("upgrade immutable;")?.1);
+        assert!(UpgradePolicy::parse("upgrade anyone;").is_err());
+        assert!(UpgradePolicy::parse("upgrade owner").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        for string in ["upgrade owner;", "upgrade immutable;"] {
+            assert_eq!(string, UpgradePolicy::from_str(string)?.to_string());
+        }
+        Ok(())
+    }
+}
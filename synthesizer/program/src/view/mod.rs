@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod input;
+use input::*;
+
+mod output;
+use output::*;
+
+mod bytes;
+mod parse;
+
+use crate::traits::CommandTrait;
+use console::{
+    network::prelude::*,
+    program::{Identifier, PlaintextType, Register},
+};
+
+use indexmap::IndexSet;
+use std::collections::HashMap;
+
+/// A view is a read-only function over the program mappings, which is evaluated
+/// directly against the confirmed on-chain state, without a proof or a transaction.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ViewCore<N: Network, Command: CommandTrait<N>> {
+    /// The name of the view.
+    name: Identifier<N>,
+    /// The input statements, added in order of the input registers.
+    /// Input assignments are ensured to match the ordering of the input statements.
+    inputs: IndexSet<Input<N>>,
+    /// The commands, in order of execution.
+    commands: Vec<Command>,
+    /// The output statements, in order of the desired output.
+    outputs: IndexSet<Output<N>>,
+    /// A mapping from `Position`s to their index in `commands`.
+    positions: HashMap<Identifier<N>, usize>,
+}
+
+impl<N: Network, Command: CommandTrait<N>> ViewCore<N, Command> {
+    /// Initializes a new view with the given name.
+    pub fn new(name: Identifier<N>) -> Self {
+        Self {
+            name,
+            inputs: IndexSet::new(),
+            commands: Vec::new(),
+            outputs: IndexSet::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Returns the name of the view.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the view inputs.
+    pub const fn inputs(&self) -> &IndexSet<Input<N>> {
+        &self.inputs
+    }
+
+    /// Returns the view input types.
+    pub fn input_types(&self) -> Vec<PlaintextType<N>> {
+        self.inputs.iter().map(|input| input.plaintext_type()).cloned().collect()
+    }
+
+    /// Returns the view commands.
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Returns the view outputs.
+    pub const fn outputs(&self) -> &IndexSet<Output<N>> {
+        &self.outputs
+    }
+
+    /// Returns the view output types.
+    pub fn output_types(&self) -> Vec<PlaintextType<N>> {
+        self.outputs.iter().map(|output| output.plaintext_type()).cloned().collect()
+    }
+
+    /// Returns the mapping of `Position`s to their index in `commands`.
+    pub const fn positions(&self) -> &HashMap<Identifier<N>, usize> {
+        &self.positions
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> ViewCore<N, Command> {
+    /// Adds the input statement to the view.
+    ///
+    /// # Errors
+    /// This method will halt if there are commands or output statements already.
+    /// This method will halt if the maximum number of inputs has been reached.
+    /// This method will halt if the input statement was previously added.
+    #[inline]
+    fn add_input(&mut self, input: Input<N>) -> Result<()> {
+        // Ensure there are no commands or output statements in memory.
+        ensure!(self.commands.is_empty(), "Cannot add inputs after commands have been added");
+        ensure!(self.outputs.is_empty(), "Cannot add inputs after outputs have been added");
+
+        // Ensure the maximum number of inputs has not been exceeded.
+        ensure!(self.inputs.len() < N::MAX_INPUTS, "Cannot add more than {} inputs", N::MAX_INPUTS);
+        // Ensure the input statement was not previously added.
+        ensure!(!self.inputs.contains(&input), "Cannot add duplicate input statement");
+
+        // Ensure the input register is a locator.
+        ensure!(matches!(input.register(), Register::Locator(..)), "Input register must be a locator");
+
+        // Insert the input statement.
+        self.inputs.insert(input);
+        Ok(())
+    }
+
+    /// Adds the given command to the view.
+    ///
+    /// # Errors
+    /// This method will halt if there are output statements already.
+    /// This method will halt if the maximum number of commands has been reached.
+    /// This method will halt if the command writes to a mapping, invokes a 'call', or casts to a record.
+    #[inline]
+    pub fn add_command(&mut self, command: Command) -> Result<()> {
+        // Ensure that there are no output statements in memory.
+        ensure!(self.outputs.is_empty(), "Cannot add commands after outputs have been added");
+
+        // Ensure the maximum number of commands has not been exceeded.
+        ensure!(self.commands.len() < N::MAX_COMMANDS, "Cannot add more than {} commands", N::MAX_COMMANDS);
+
+        // Ensure the command is not a write command.
+        ensure!(!command.is_write(), "Forbidden operation: View cannot invoke a 'set', 'remove', or 'emit'");
+        // Ensure the command is not a call instruction.
+        ensure!(!command.is_call(), "Forbidden operation: View cannot invoke a 'call'");
+        // Ensure the command is not a cast to record instruction.
+        ensure!(!command.is_cast_to_record(), "Forbidden operation: View cannot cast to a record");
+
+        // Check the destination registers.
+        for register in command.destinations() {
+            // Ensure the destination register is a locator.
+            ensure!(matches!(register, Register::Locator(..)), "Destination register must be a locator");
+        }
+
+        // Check if the command is a branch command.
+        if let Some(position) = command.branch_to() {
+            // Ensure the branch target does not reference an earlier position.
+            ensure!(!self.positions.contains_key(position), "Cannot branch to an earlier position '{position}'");
+        }
+
+        // Check if the command is a position command.
+        if let Some(position) = command.position() {
+            // Ensure the position is not yet defined.
+            ensure!(!self.positions.contains_key(position), "Cannot redefine position '{position}'");
+            // Ensure that there are less than `u8::MAX` positions.
+            ensure!(self.positions.len() < u8::MAX as usize, "Cannot add more than {} positions", u8::MAX);
+            // Insert the position.
+            self.positions.insert(*position, self.commands.len());
+        }
+
+        // Insert the command.
+        self.commands.push(command);
+        Ok(())
+    }
+
+    /// Adds the output statement to the view.
+    ///
+    /// # Errors
+    /// This method will halt if the maximum number of outputs has been reached.
+    /// This method will halt if the output statement was previously added.
+    #[inline]
+    fn add_output(&mut self, output: Output<N>) -> Result<()> {
+        // Ensure the maximum number of outputs has not been exceeded.
+        ensure!(self.outputs.len() < N::MAX_OUTPUTS, "Cannot add more than {} outputs", N::MAX_OUTPUTS);
+        // Ensure the output statement was not previously added.
+        ensure!(!self.outputs.contains(&output), "Cannot add duplicate output statement");
+
+        // Insert the output statement.
+        self.outputs.insert(output);
+        Ok(())
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> TypeName for ViewCore<N, Command> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "view"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Command, View};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_add_command() {
+        // Initialize a new view instance.
+        let name = Identifier::from_str("view_core_test").unwrap();
+        let mut view = View::<CurrentNetwork>::new(name);
+
+        // Ensure that read commands can be added.
+        let command = Command::<CurrentNetwork>::from_str("get.or_use balances[r0] 0u64 into r1;").unwrap();
+        assert!(view.add_command(command).is_ok());
+        let command = Command::<CurrentNetwork>::from_str("contains balances[r0] into r2;").unwrap();
+        assert!(view.add_command(command).is_ok());
+        let command = Command::<CurrentNetwork>::from_str("add r1 1u64 into r3;").unwrap();
+        assert!(view.add_command(command).is_ok());
+
+        // Ensure that write commands cannot be added.
+        let command = Command::<CurrentNetwork>::from_str("set r1 into balances[r0];").unwrap();
+        assert!(view.add_command(command).is_err());
+        let command = Command::<CurrentNetwork>::from_str("remove balances[r0];").unwrap();
+        assert!(view.add_command(command).is_err());
+
+        // Ensure that commands cannot be added after an output.
+        let output = Output::<CurrentNetwork>::from_str("output r1 as u64.public;").unwrap();
+        assert!(view.add_output(output).is_ok());
+        let command = Command::<CurrentNetwork>::from_str("add r1 2u64 into r4;").unwrap();
+        assert!(view.add_command(command).is_err());
+    }
+
+    #[test]
+    fn test_add_output() {
+        // Initialize a new view instance.
+        let name = Identifier::from_str("view_core_test").unwrap();
+        let mut view = View::<CurrentNetwork>::new(name);
+
+        // Ensure that an output can be added.
+        let output = Output::<CurrentNetwork>::from_str("output r0 as field.public;").unwrap();
+        assert!(view.add_output(output.clone()).is_ok());
+
+        // Ensure that adding a duplicate output will fail.
+        assert!(view.add_output(output).is_err());
+
+        // Ensure that an input cannot be added after an output.
+        let input = Input::<CurrentNetwork>::from_str("input r0 as field.public;").unwrap();
+        assert!(view.add_input(input).is_err());
+    }
+}
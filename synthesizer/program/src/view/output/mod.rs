@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use crate::Operand;
+
+use console::{network::prelude::*, program::PlaintextType};
+
+/// An output statement defines an output of a view.
+/// An output statement is of the form `output {operand} as {plaintext_type}.public;`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Output<N: Network> {
+    /// The output operand.
+    operand: Operand<N>,
+    /// The output plaintext type.
+    plaintext_type: PlaintextType<N>,
+}
+
+impl<N: Network> Output<N> {
+    /// Returns the output operand.
+    #[inline]
+    pub const fn operand(&self) -> &Operand<N> {
+        &self.operand
+    }
+
+    /// Returns the output plaintext type.
+    #[inline]
+    pub const fn plaintext_type(&self) -> &PlaintextType<N> {
+        &self.plaintext_type
+    }
+}
+
+impl<N: Network> TypeName for Output<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "output"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_output_type_name() {
+        assert_eq!(Output::<CurrentNetwork>::type_name(), "output");
+    }
+}
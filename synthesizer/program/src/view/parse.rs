@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Command: CommandTrait<N>> Parser for ViewCore<N, Command> {
+    /// Parses a string into a view.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'view' keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the view name from the string.
+        let (string, name) = Identifier::<N>::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+
+        // Parse the inputs from the string.
+        let (string, inputs) = many0(Input::parse)(string)?;
+        // Parse the commands from the string.
+        let (string, commands) = many0(Command::parse)(string)?;
+        // Parse the outputs from the string.
+        let (string, outputs) = many1(Output::parse)(string)?;
+
+        map_res(take(0usize), move |_| {
+            // Initialize a new view.
+            let mut view = Self::new(name);
+            if let Err(error) = inputs.iter().cloned().try_for_each(|input| view.add_input(input)) {
+                eprintln!("{error}");
+                return Err(error);
+            }
+            if let Err(error) = commands.iter().cloned().try_for_each(|command| view.add_command(command)) {
+                eprintln!("{error}");
+                return Err(error);
+            }
+            if let Err(error) = outputs.iter().cloned().try_for_each(|output| view.add_output(output)) {
+                eprintln!("{error}");
+                return Err(error);
+            }
+            Ok::<_, Error>(view)
+        })(string)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> FromStr for ViewCore<N, Command> {
+    type Err = Error;
+
+    /// Returns a view from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> Debug for ViewCore<N, Command> {
+    /// Prints the view as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> Display for ViewCore<N, Command> {
+    /// Prints the view as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Write the view to a string.
+        write!(f, "{} {}:", Self::type_name(), self.name)?;
+        self.inputs.iter().try_for_each(|input| write!(f, "\n    {input}"))?;
+        self.commands.iter().try_for_each(|command| write!(f, "\n    {command}"))?;
+        self.outputs.iter().try_for_each(|output| write!(f, "\n    {output}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::View;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_view_parse() {
+        let view = View::<CurrentNetwork>::parse(
+            r"
+view balance_of:
+    input r0 as address.public;
+    get.or_use balances[r0] 0u64 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("balance_of", view.name().to_string());
+        assert_eq!(1, view.inputs().len());
+        assert_eq!(1, view.commands().len());
+        assert_eq!(1, view.outputs().len());
+
+        // View with 0 inputs and 0 commands.
+        let view = View::<CurrentNetwork>::parse(
+            r"
+view height:
+    output block.height as u32.public;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("height", view.name().to_string());
+        assert_eq!(0, view.inputs().len());
+        assert_eq!(0, view.commands().len());
+        assert_eq!(1, view.outputs().len());
+    }
+
+    #[test]
+    fn test_view_parse_fails() {
+        // Ensure a view without outputs fails.
+        let result = View::<CurrentNetwork>::parse(
+            r"
+view foo:
+    input r0 as address.public;
+    get.or_use balances[r0] 0u64 into r1;",
+        );
+        assert!(result.is_err());
+
+        // Ensure a view that writes to a mapping fails.
+        let result = View::<CurrentNetwork>::parse(
+            r"
+view foo:
+    input r0 as address.public;
+    set 0u64 into balances[r0];
+    output r0 as address.public;",
+        );
+        assert!(result.is_err());
+
+        // Ensure a view with a private input fails.
+        let result = View::<CurrentNetwork>::parse(
+            r"
+view foo:
+    input r0 as address.private;
+    output r0 as address.public;",
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_view_display() {
+        let expected = r"view balance_of:
+    input r0 as address.public;
+    get.or_use balances[r0] 0u64 into r1;
+    output r1 as u64.public;";
+        let view = View::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{view}"));
+    }
+}
@@ -33,6 +33,7 @@ macro_rules! sample_literals {
             console::program::Literal::Scalar(console::types::Scalar::rand($rng)),
             console::program::Literal::sample(console::program::LiteralType::Signature, $rng),
             console::program::Literal::String(console::types::StringType::rand($rng)),
+            console::program::Literal::Fixed(console::types::Fixed::rand($rng)),
         ]
     };
 }
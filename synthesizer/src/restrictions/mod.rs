@@ -148,7 +148,9 @@ impl<N: Network> Restrictions<N> {
                                                     }
                                                 }
                                             }
-                                            Plaintext::Struct(..) | Plaintext::Array(..) => continue,
+                                            Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Option(..) => {
+                                                continue
+                                            }
                                         }
                                     }
                                     _ => continue,
@@ -167,7 +169,9 @@ impl<N: Network> Restrictions<N> {
                                                     }
                                                 }
                                             }
-                                            Plaintext::Struct(..) | Plaintext::Array(..) => continue,
+                                            Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Option(..) => {
+                                                continue
+                                            }
                                         }
                                     }
                                     _ => continue,
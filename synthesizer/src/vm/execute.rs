@@ -35,6 +35,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<Transaction<N>> {
         // Compute the authorization.
         let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        // Prepare the query.
+        let query = query.unwrap_or_else(|| Query::VM(self.block_store().clone()));
         // Determine if a fee is required.
         let is_fee_required = !authorization.is_split();
         // Determine if a priority fee is declared.
@@ -83,6 +85,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Transaction<N>> {
+        // Prepare the query.
+        let query = query.unwrap_or_else(|| Query::VM(self.block_store().clone()));
         // Compute the execution.
         let execution = self.execute_authorization_raw(execute_authorization, query.clone(), rng)?;
         // Compute the fee.
@@ -102,8 +106,42 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         rng: &mut R,
     ) -> Result<Fee<N>> {
         debug_assert!(authorization.is_fee_private() || authorization.is_fee_public(), "Expected a fee authorization");
+        // Prepare the query.
+        let query = query.unwrap_or_else(|| Query::VM(self.block_store().clone()));
         self.execute_fee_authorization_raw(authorization, query, rng)
     }
+
+    /// Returns a new execute transaction for the given proving request.
+    ///
+    /// The proving request is verified against the programs in the VM before proving,
+    /// and the execution is proven against the state root and state paths in the proving request.
+    pub fn execute_proving_request<R: Rng + CryptoRng>(
+        &self,
+        proving_request: ProvingRequest<N>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        let timer = timer!("VM::execute_proving_request");
+
+        // Ensure the proving request is valid for the programs in the VM.
+        self.process().read().verify_proving_request(&proving_request)?;
+        // Prepare the query.
+        let query = proving_request.to_query()?;
+        lap!(timer, "Verify the proving request");
+
+        // Retrieve the authorizations.
+        let (execute_authorization, fee_authorization) = proving_request.into_authorizations();
+        // Compute the execution.
+        let execution = self.execute_authorization_raw(execute_authorization, query.clone(), rng)?;
+        // Compute the fee.
+        let fee = match fee_authorization {
+            Some(authorization) => Some(self.execute_fee_authorization_raw(authorization, query, rng)?),
+            None => None,
+        };
+        finish!(timer, "Prove the proving request");
+
+        // Return the execute transaction.
+        Transaction::from_execution(execution, fee)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -113,7 +151,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     fn execute_authorization_raw<R: Rng + CryptoRng>(
         &self,
         authorization: Authorization<N>,
-        query: Option<Query<N, C::BlockStorage>>,
+        query: impl QueryTrait<N>,
         rng: &mut R,
     ) -> Result<Execution<N>> {
         let timer = timer!("VM::execute_authorization_raw");
@@ -123,13 +161,6 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let request = authorization.peek_next()?;
             Locator::new(*request.program_id(), *request.function_name()).to_string()
         };
-        // Prepare the query.
-        let query = match query {
-            Some(query) => query,
-            None => Query::VM(self.block_store().clone()),
-        };
-        lap!(timer, "Prepare the query");
-
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the authorization.
@@ -163,18 +194,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     fn execute_fee_authorization_raw<R: Rng + CryptoRng>(
         &self,
         authorization: Authorization<N>,
-        query: Option<Query<N, C::BlockStorage>>,
+        query: impl QueryTrait<N>,
         rng: &mut R,
     ) -> Result<Fee<N>> {
         let timer = timer!("VM::execute_fee_authorization_raw");
 
-        // Prepare the query.
-        let query = match query {
-            Some(query) => query,
-            None => Query::VM(self.block_store().clone()),
-        };
-        lap!(timer, "Prepare the query");
-
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the authorization.
@@ -348,7 +372,7 @@ mod tests {
 
         let authorization = vm.authorize(&caller_private_key, credits_program, function_name, inputs, rng).unwrap();
 
-        let execution = vm.execute_authorization_raw(authorization, None, rng).unwrap();
+        let execution = vm.execute_authorization_raw(authorization, Query::from(vm.block_store()), rng).unwrap();
         let (cost, _) = execution_cost(&vm.process().read(), &execution).unwrap();
         println!("Cost: {}", cost);
     }
@@ -464,6 +488,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_execute_proving_request() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Authorize the execution and the fee.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+        let authorization = vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs, rng).unwrap();
+        let execution_id = authorization.to_execution_id().unwrap();
+        let fee_authorization = vm.authorize_fee_public(&caller_private_key, 1_000_000, 0, execution_id, rng).unwrap();
+
+        // Construct the proving request, and send it to the prover as bytes.
+        let state_root = vm.block_store().current_state_root();
+        let proving_request =
+            ProvingRequest::new(authorization, Some(fee_authorization), state_root, vec![], vec![]).unwrap();
+        let proving_request = ProvingRequest::read_le(&proving_request.to_bytes_le().unwrap()[..]).unwrap();
+
+        // Ensure a proving request with a program that is not deployed is rejected.
+        let program = Program::from_str(
+            r"
+program proving_request.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+        let (authorization, fee_authorization) = proving_request.clone().into_authorizations();
+        let invalid_request =
+            ProvingRequest::new(authorization, fee_authorization, state_root, vec![], vec![program]).unwrap();
+        assert!(vm.execute_proving_request(invalid_request, rng).is_err());
+
+        // Prove the proving request, and ensure the transaction is valid.
+        let transaction = vm.execute_proving_request(proving_request, rng).unwrap();
+        assert_eq!(transaction.transitions().count(), 2);
+        assert_eq!(transaction.execution().unwrap().global_state_root(), state_root);
+        vm.check_transaction(&transaction, None, rng).unwrap();
+    }
+
     #[test]
     fn test_transfer_public_as_signer_transaction_size() {
         let rng = &mut TestRng::default();
@@ -168,6 +168,81 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         finish!(timer, "Finished real-run of finalize");
         Ok(ratified_finalize_operations)
     }
+
+    /// Finalizes the given execution without persisting its changes, and returns the debug trace of its finalize scopes.
+    ///
+    /// Note: The dry run holds the atomic lock, as it uses an atomic batch on the finalize store,
+    /// which must not interleave with speculation or finalization.
+    #[inline]
+    pub fn finalize_dry_run(
+        &self,
+        state: FinalizeGlobalState,
+        execution: &Execution<N>,
+    ) -> (Result<Vec<FinalizeOperation<N>>>, DebugTrace<N>) {
+        // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
+        // with `VM::atomic_speculate` or `VM::atomic_finalize`, which use the same finalize store.
+        let _atomic_lock = self.atomic_lock.lock();
+        // Perform the dry run of finalize.
+        self.process.read().finalize_dry_run(state, self.finalize_store(), execution)
+    }
+
+    /// Returns the given block with the rejection reasons of its rejected executions,
+    /// or `None` if no rejection reason is missing from the block.
+    ///
+    /// Note: The rejection reasons are not included in the bytes of a block, so a block received from
+    /// another node does not carry them. The missing reasons are recomputed by speculating on the block,
+    /// which must be done before the block is finalized.
+    pub(crate) fn with_rejection_reasons(
+        &self,
+        state: FinalizeGlobalState,
+        block: &Block<N>,
+    ) -> Result<Option<Block<N>>> {
+        // Determine if a rejected execution is missing its rejection reason.
+        let is_missing_reason = block.transactions().iter().any(|confirmed| match confirmed {
+            ConfirmedTransaction::RejectedExecute(_, _, rejected, _) => rejected.reason().is_none(),
+            _ => false,
+        });
+        // If no rejection reason is missing, return early.
+        if !is_missing_reason {
+            return Ok(None);
+        }
+
+        // Reconstruct the candidate ratifications and unconfirmed transactions.
+        let candidate_ratifications = block.ratifications().iter().cloned().collect::<Vec<_>>();
+        let candidate_transactions = block
+            .transactions()
+            .iter()
+            .map(|confirmed| confirmed.to_unconfirmed_transaction())
+            .collect::<Result<Vec<_>>>()?;
+        // Performs a **dry-run** over the block, to recompute the rejection reasons.
+        let (_, confirmed_transactions, _, _) = self.atomic_speculate(
+            state,
+            None,
+            candidate_ratifications,
+            block.solutions(),
+            candidate_transactions.iter(),
+        )?;
+
+        // Ensure the transactions after speculation match.
+        // Note: The comparison ignores the rejection reasons.
+        let transactions = confirmed_transactions.into_iter().collect::<Transactions<N>>();
+        if block.transactions() != &transactions {
+            bail!("The transactions after speculation do not match the transactions in the block");
+        }
+
+        // Return the block, with the speculated transactions that carry the rejection reasons.
+        Ok(Some(Block::from_unchecked(
+            block.hash(),
+            block.previous_hash(),
+            block.header().clone(),
+            block.authority().clone(),
+            block.ratifications().clone(),
+            block.solutions().clone(),
+            block.aborted_solution_ids().clone(),
+            transactions,
+            block.aborted_transaction_ids().clone(),
+        )?))
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -389,15 +464,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                     .map_err(|e| e.to_string())
                             }
                             // Construct the rejected execute transaction.
-                            Err(_error) => match fee {
+                            Err(error) => match fee {
                                 // Finalize the fee, to ensure it is valid.
                                 Some(fee) => {
                                     match process.finalize_fee(state, store, fee).and_then(|finalize| {
                                         Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
                                     }) {
                                         Ok((fee_tx, finalize)) => {
-                                            // Construct the rejected execution.
-                                            let rejected = Rejected::new_execution(execution.clone());
+                                            // Construct the rejected execution, with the reason if it is known.
+                                            let rejected = match error.downcast_ref::<FinalizeError<N>>() {
+                                                Some(error) => Rejected::new_execution_with_reason(
+                                                    execution.clone(),
+                                                    error.reason().clone(),
+                                                ),
+                                                None => Rejected::new_execution(execution.clone()),
+                                            };
                                             // Construct the rejected execute transaction.
                                             ConfirmedTransaction::rejected_execute(counter, fee_tx, rejected, finalize)
                                                 .map_err(|e| e.to_string())
@@ -749,8 +830,24 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             /* Start the commit process. */
 
             // Commit all of the stacks to the process.
+            // Note: An upgraded stack replaces the stack of its prior edition, including its verifying keys,
+            // and rebuilds the stacks of the programs that import it.
+            // If the atomic batch is aborted, the stacks are discarded and the prior edition remains in place.
             if !stacks.is_empty() {
-                stacks.into_iter().for_each(|stack| process.add_stack(stack))
+                let mut updated_process = process.clone();
+                for stack in stacks {
+                    let program_id = *stack.program_id();
+                    match updated_process.contains_program(&program_id) {
+                        true => {
+                            if let Err(error) = updated_process.upgrade_stack(stack) {
+                                // Note: This will abort the entire atomic batch.
+                                return Err(format!("Failed to upgrade the stack for '{program_id}' - {error}"));
+                            }
+                        }
+                        false => updated_process.add_stack(stack),
+                    }
+                }
+                *process = updated_process;
             }
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
@@ -1370,7 +1467,7 @@ mod tests {
         program::{Ciphertext, Entry, Record},
         types::Field,
     };
-    use ledger_block::{Block, Header, Metadata, Transaction, Transition};
+    use ledger_block::{Block, Header, Metadata, RejectionReason, Transaction, Transition};
     use ledger_committee::{MAX_DELEGATORS, MIN_VALIDATOR_STAKE};
     use ledger_store::helpers::memory::ConsensusMemory;
     use synthesizer_program::Program;
@@ -1634,6 +1731,18 @@ finalize transfer_public:
         }
     }
 
+    /// A helper method to construct the rejection reason for a `transfer_public` that underflows the balance.
+    fn transfer_public_underflow(program_id: &str) -> RejectionReason<CurrentNetwork> {
+        RejectionReason::new(
+            ProgramID::from_str(program_id).unwrap(),
+            Identifier::from_str("transfer_public").unwrap(),
+            2,
+            "sub".to_string(),
+            None,
+        )
+        .unwrap()
+    }
+
     /// Samples the validators.
     fn sample_validators<N: Network>(
         num_validators: usize,
@@ -2006,6 +2115,10 @@ finalize transfer_public:
                 confirmed_transactions[1],
                 reject(1, &transfer_10, confirmed_transactions[1].finalize_operations())
             );
+            assert_eq!(
+                confirmed_transactions[1].to_rejected().and_then(|rejected| rejected.reason()),
+                Some(&transfer_public_underflow(&program_id))
+            );
         }
 
         // Starting Balance = 20
@@ -2033,6 +2146,10 @@ finalize transfer_public:
                 confirmed_transactions[2],
                 reject(2, &transfer_20, confirmed_transactions[2].finalize_operations())
             );
+            assert_eq!(
+                confirmed_transactions[2].to_rejected().and_then(|rejected| rejected.reason()),
+                Some(&transfer_public_underflow(&program_id))
+            );
             assert_eq!(confirmed_transactions[3].transaction(), &transfer_10);
         }
     }
@@ -2045,14 +2162,20 @@ finalize transfer_public:
         let caller_private_key = test_helpers::sample_genesis_private_key(rng);
         let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
 
-        for finalize_logic in &[
-            "finalize ped_hash:
+        for (finalize_logic, opcode) in &[
+            (
+                "finalize ped_hash:
     input r0 as u128.public;
     hash.ped64 r0 into r1 as field;
     set r1 into hashes[r0];",
-            "finalize ped_hash:
+                "hash.ped64",
+            ),
+            (
+                "finalize ped_hash:
     input r0 as u128.public;
     div r0 0u128 into r1;",
+                "div",
+            ),
         ] {
             // Initialize the vm.
             let vm = test_helpers::sample_vm_with_genesis_block(rng);
@@ -2124,6 +2247,14 @@ function ped_hash:
             assert!(transaction.is_execute());
             if let Transaction::Execute(_, execution, fee) = transaction {
                 let fee_transaction = Transaction::from_fee(fee.unwrap()).unwrap();
+                let reason = RejectionReason::new(
+                    ProgramID::from_str(program_id).unwrap(),
+                    Identifier::from_str("ped_hash").unwrap(),
+                    0,
+                    opcode.to_string(),
+                    None,
+                )
+                .unwrap();
                 let expected_confirmed_transaction = ConfirmedTransaction::RejectedExecute(
                     0,
                     fee_transaction,
@@ -2133,10 +2264,165 @@ function ped_hash:
 
                 let confirmed_transaction = confirmed_transactions.iter().next().unwrap();
                 assert_eq!(confirmed_transaction, &expected_confirmed_transaction);
+                // Ensure the rejection reason identifies the failing command.
+                assert_eq!(confirmed_transaction.to_rejected().and_then(|rejected| rejected.reason()), Some(&reason));
             }
         }
     }
 
+    #[test]
+    fn test_rejection_reason_of_block_from_bytes() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key, view key, and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Create a program whose finalize always fails.
+        let program_id = "testing.aleo";
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            "
+program {program_id};
+
+function divide:
+    input r0 as u128.public;
+    async divide r0 into r1;
+    output r1 as {program_id}/divide.future;
+
+finalize divide:
+    input r0 as u128.public;
+    div r0 0u128 into r1;"
+        ))
+        .unwrap();
+
+        // Deploy the program.
+        let credits = Some(unspent_records.pop().unwrap().decrypt(&caller_view_key).unwrap());
+        let deployment_transaction = vm.deploy(&caller_private_key, &program, credits, 10, None, rng).unwrap();
+        let deployment_block =
+            sample_next_block(&vm, &caller_private_key, &[deployment_transaction], &genesis, &mut unspent_records, rng)
+                .unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Construct a block with an execution that is rejected.
+        let inputs = vec![Value::<CurrentNetwork>::from_str("1u128").unwrap()];
+        let transaction =
+            create_execution(&vm, caller_private_key, program_id, "divide", inputs, &mut unspent_records, rng);
+        let block =
+            sample_next_block(&vm, &caller_private_key, &[transaction], &deployment_block, &mut unspent_records, rng)
+                .unwrap();
+        let confirmed_transaction = block.transactions().iter().next().unwrap();
+        let expected = confirmed_transaction.to_rejected().and_then(|rejected| rejected.reason()).cloned();
+        assert!(expected.is_some());
+
+        // Round-trip the block through bytes, which does not preserve the rejection reason.
+        let block = Block::read_le(&block.to_bytes_le().unwrap()[..]).unwrap();
+        let confirmed_transaction = block.transactions().iter().next().unwrap();
+        assert!(confirmed_transaction.to_rejected().unwrap().reason().is_none());
+
+        // Add the block to the VM.
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the rejection reason is recomputed and stored.
+        let candidate = vm.block_store().get_confirmed_transaction(&confirmed_transaction.id()).unwrap().unwrap();
+        assert_eq!(candidate.to_rejected().and_then(|rejected| rejected.reason()).cloned(), expected);
+    }
+
+    #[test]
+    fn test_finalize_dry_run_during_finalize() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key, view key, and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Create a program that stores its input.
+        let program_id = "testing.aleo";
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            "
+program {program_id};
+
+mapping entries:
+    key as u128.public;
+    value as u128.public;
+
+function store:
+    input r0 as u128.public;
+    async store r0 into r1;
+    output r1 as {program_id}/store.future;
+
+finalize store:
+    input r0 as u128.public;
+    set r0 into entries[r0];"
+        ))
+        .unwrap();
+
+        // Deploy the program.
+        let credits = Some(unspent_records.pop().unwrap().decrypt(&caller_view_key).unwrap());
+        let deployment_transaction = vm.deploy(&caller_private_key, &program, credits, 10, None, rng).unwrap();
+        let deployment_block =
+            sample_next_block(&vm, &caller_private_key, &[deployment_transaction], &genesis, &mut unspent_records, rng)
+                .unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Construct a block with an execution.
+        let inputs = vec![Value::<CurrentNetwork>::from_str("1u128").unwrap()];
+        let transaction =
+            create_execution(&vm, caller_private_key, program_id, "store", inputs, &mut unspent_records, rng);
+        let execution = transaction.execution().unwrap().clone();
+        let block =
+            sample_next_block(&vm, &caller_private_key, &[transaction], &deployment_block, &mut unspent_records, rng)
+                .unwrap();
+
+        // Dry-run the execution while the block is being added, and ensure neither interferes with the other.
+        std::thread::scope(|scope| {
+            let handle = scope.spawn(|| {
+                for _ in 0..10 {
+                    let (result, debug_trace) = vm.finalize_dry_run(sample_finalize_state(2), &execution);
+                    assert_eq!(result.unwrap().len(), 1);
+                    assert!(debug_trace.failed_step().is_none());
+                }
+            });
+            vm.add_next_block(&block).unwrap();
+            handle.join().unwrap();
+        });
+
+        // Ensure the block was finalized, and the dry runs did not persist any changes.
+        let program_id = ProgramID::from_str(program_id).unwrap();
+        let mapping_name = Identifier::from_str("entries").unwrap();
+        let key = Plaintext::from_str("1u128").unwrap();
+        assert_eq!(
+            vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap(),
+            Some(Value::from_str("1u128").unwrap())
+        );
+        assert_eq!(vm.finalize_store().get_mapping_confirmed(program_id, mapping_name).unwrap().len(), 1);
+    }
+
     #[test]
     fn test_rejected_transaction_should_not_update_storage() {
         let rng = &mut TestRng::default();
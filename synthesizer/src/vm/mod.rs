@@ -20,6 +20,7 @@ mod deploy;
 mod execute;
 mod finalize;
 mod verify;
+mod view;
 
 use crate::{cast_mut_ref, cast_ref, convert, process, Restrictions};
 use console::{
@@ -46,7 +47,7 @@ use ledger_block::{
 use ledger_committee::Committee;
 use ledger_narwhal_data::Data;
 use ledger_puzzle::Puzzle;
-use ledger_query::Query;
+use ledger_query::{Query, QueryTrait};
 use ledger_store::{
     atomic_finalize,
     BlockStore,
@@ -58,7 +59,16 @@ use ledger_store::{
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{deployment_cost, execution_cost, Authorization, Process, Trace};
+use synthesizer_process::{
+    deployment_cost,
+    execution_cost,
+    Authorization,
+    DebugTrace,
+    FinalizeError,
+    Process,
+    ProvingRequest,
+    Trace,
+};
 use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
 use utilities::try_vm_runtime;
 
@@ -114,6 +124,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             transaction_store: &TransactionStore<N, T>,
             transaction_id: N::TransactionID,
         ) -> Result<Vec<(ProgramID<N>, Deployment<N>)>> {
+            // Retrieve the program ID from the transaction ID.
+            let Some(program_id) = transaction_store.get_program_id(&transaction_id)? else {
+                bail!("Deployment transaction '{transaction_id}' is not found in storage.");
+            };
+
+            // Return early if the program is already loaded.
+            if process.contains_program(&program_id) {
+                return Ok(vec![]);
+            }
+
+            // Retrieve the transaction ID of the latest edition, as the program may have been upgraded.
+            let Some(transaction_id) = transaction_store.find_transaction_id_from_program_id(&program_id)? else {
+                bail!("Transaction ID for '{program_id}' is not found in storage.");
+            };
             // Retrieve the deployment from the transaction ID.
             let deployment = match transaction_store.get_deployment(&transaction_id)? {
                 Some(deployment) => deployment,
@@ -122,12 +146,6 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Fetch the program from the deployment.
             let program = deployment.program();
-            let program_id = program.id();
-
-            // Return early if the program is already loaded.
-            if process.contains_program(program_id) {
-                return Ok(vec![]);
-            }
 
             // Prepare a vector for the deployments.
             let mut deployments = vec![];
@@ -153,7 +171,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
 
             // Once all the imports have been included, add the parent deployment.
-            deployments.push((*program_id, deployment));
+            deployments.push((program_id, deployment));
 
             Ok(deployments)
         }
@@ -401,11 +419,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
         )?;
 
+        // Attach the rejection reasons that are missing from the block, such as when the block was received as bytes.
+        let block_with_reasons = self.with_rejection_reasons(state, block)?;
+        let block = block_with_reasons.as_ref().unwrap_or(block);
+
         // Pause the atomic writes, so that both the insertion and finalization belong to a single batch.
         #[cfg(feature = "rocks")]
         self.block_store().pause_atomic_writes()?;
@@ -491,7 +514,7 @@ pub(crate) mod test_helpers {
 
     /// Samples a new finalize state.
     pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+        FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32], [0u8; 32])
     }
 
     pub(crate) fn sample_vm() -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
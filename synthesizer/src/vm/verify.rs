@@ -150,17 +150,35 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 };
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
+                // Retrieve the program ID.
+                let program_id = deployment.program_id();
                 // Ensure the edition is correct.
-                if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
-                }
-                // Ensure the program ID does not already exist in the store.
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
-                }
-                // Ensure the program does not already exist in the process.
-                if self.contains_program(deployment.program_id()) {
-                    bail!("Program ID '{}' already exists", deployment.program_id());
+                match self.transaction_store().deployment_store().get_edition(program_id)? {
+                    // If the program is new, ensure the edition is the initial edition.
+                    None => {
+                        if deployment.edition() != N::EDITION {
+                            bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
+                        }
+                        // Ensure the program does not already exist in the process.
+                        if self.contains_program(program_id) {
+                            bail!("Program ID '{program_id}' already exists");
+                        }
+                    }
+                    // If the program is deployed, ensure the deployment is an upgrade by the program owner.
+                    Some(edition) => {
+                        if edition.checked_add(1) != Some(deployment.edition()) {
+                            bail!("Invalid upgrade transaction '{id}' - expected edition {}", edition.saturating_add(1))
+                        }
+                        // Retrieve the owner of the deployed program.
+                        let Some(existing_owner) = self.transaction_store().deployment_store().get_owner(program_id)?
+                        else {
+                            bail!("Program ID '{program_id}' is already deployed")
+                        };
+                        // Ensure the upgrade is signed by the owner of the deployed program.
+                        if existing_owner.address() != owner.address() {
+                            bail!("Invalid upgrade transaction '{id}' - only the owner may upgrade '{program_id}'")
+                        }
+                    }
                 }
                 // Verify the deployment if it has not been verified before.
                 if !is_partially_verified {
@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Evaluates the view of the given program for the given inputs, and returns the outputs.
+    ///
+    /// The view is evaluated directly against the confirmed state as of the latest block,
+    /// so no proof is computed and no transaction is constructed.
+    #[inline]
+    pub fn view(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        view_name: impl TryInto<Identifier<N>>,
+        inputs: impl IntoIterator<IntoIter = impl ExactSizeIterator<Item = impl TryInto<Value<N>>>>,
+    ) -> Result<Vec<Value<N>>> {
+        let timer = timer!("VM::view");
+
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Prepare the view name.
+        let view_name = view_name.try_into().map_err(|_| anyhow!("Invalid view name"))?;
+        // Prepare the inputs.
+        let inputs = inputs
+            .into_iter()
+            .enumerate()
+            .map(|(index, input)| {
+                input.try_into().map_err(|_| anyhow!("Failed to parse input #{index} for '{program_id}/{view_name}'"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Prepare inputs");
+
+        // Construct the finalize state of the latest block.
+        let state = self.latest_finalize_state()?;
+        lap!(timer, "Construct the finalize state");
+
+        // Evaluate the view.
+        let outputs = self.process.read().evaluate_view(state, self.finalize_store(), &program_id, &view_name, &inputs);
+        finish!(timer, "Evaluate the view");
+        outputs
+    }
+
+    /// Returns the finalize state of the latest block.
+    fn latest_finalize_state(&self) -> Result<FinalizeGlobalState> {
+        // Retrieve the latest block height.
+        let height = self.block_store().current_block_height();
+        // Retrieve the latest block hash.
+        let Some(block_hash) = self.block_store().get_block_hash(height)? else {
+            bail!("Missing the block hash for block {height}")
+        };
+        // Retrieve the latest block header.
+        let Some(header) = self.block_store().get_block_header(&block_hash)? else {
+            bail!("Missing the block header for block {height}")
+        };
+        // Retrieve the previous block hash.
+        let Some(previous_hash) = self.block_store().get_previous_block_hash(height)? else {
+            bail!("Missing the previous block hash for block {height}")
+        };
+        // Construct the finalize state.
+        FinalizeGlobalState::new::<N>(
+            header.round(),
+            header.height(),
+            header.timestamp(),
+            header.cumulative_weight(),
+            header.cumulative_proof_target(),
+            previous_hash,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{
+        sample_genesis_block,
+        sample_genesis_private_key,
+        sample_next_block,
+        sample_vm,
+        CurrentNetwork,
+    };
+
+    #[test]
+    fn test_view() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&sample_genesis_block(rng)).unwrap();
+
+        // Deploy a program with views.
+        let program = Program::from_str(
+            r"
+program viewer.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function mint:
+    input r0 as u64.public;
+    async mint self.caller r0 into r1;
+    output r1 as viewer.aleo/mint.future;
+
+finalize mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];
+
+view balance_of:
+    input r0 as address.public;
+    get.or_use balances[r0] 0u64 into r1;
+    contains balances[r0] into r2;
+    output r1 as u64.public;
+    output r2 as boolean.public;
+
+view height:
+    output block.height as u32.public;",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Ensure the view reads the default value before the mapping is populated.
+        let outputs = vm.view("viewer.aleo", "balance_of", [Value::<CurrentNetwork>::from(Literal::Address(address))]);
+        assert_eq!(outputs.unwrap(), vec![Value::from_str("0u64").unwrap(), Value::from_str("false").unwrap()]);
+
+        // Mint to the caller.
+        let transaction =
+            vm.execute(&private_key, ("viewer.aleo", "mint"), ["100u64"].into_iter(), None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[transaction], rng).unwrap()).unwrap();
+
+        // Ensure the view reads the confirmed value.
+        let outputs = vm.view("viewer.aleo", "balance_of", [Value::<CurrentNetwork>::from(Literal::Address(address))]);
+        assert_eq!(outputs.unwrap(), vec![Value::from_str("100u64").unwrap(), Value::from_str("true").unwrap()]);
+
+        // Ensure the view reads the latest block height.
+        let outputs = vm.view("viewer.aleo", "height", Vec::<Value<CurrentNetwork>>::new()).unwrap();
+        assert_eq!(outputs, vec![Value::from_str(&format!("{}u32", vm.block_store().current_block_height())).unwrap()]);
+
+        // Ensure the view fails with the wrong number or type of inputs.
+        assert!(vm.view("viewer.aleo", "balance_of", Vec::<Value<CurrentNetwork>>::new()).is_err());
+        assert!(vm.view("viewer.aleo", "balance_of", ["1u64"]).is_err());
+        // Ensure an undefined view fails.
+        assert!(vm.view("viewer.aleo", "mint", ["100u64"]).is_err());
+    }
+}
@@ -530,6 +530,8 @@ fn construct_finalize_global_state<C: ConsensusStorage<CurrentNetwork>>(
     let latest_round = latest_block.round();
     // Retrieve the latest height.
     let latest_height = latest_block.height();
+    // Retrieve the latest timestamp.
+    let latest_timestamp = latest_block.timestamp();
     // Retrieve the latest cumulative weight.
     let latest_cumulative_weight = latest_block.cumulative_weight();
 
@@ -542,6 +544,7 @@ fn construct_finalize_global_state<C: ConsensusStorage<CurrentNetwork>>(
     FinalizeGlobalState::new::<CurrentNetwork>(
         next_round,
         next_height,
+        latest_timestamp,
         latest_cumulative_weight,
         0u128,
         latest_block.hash(),
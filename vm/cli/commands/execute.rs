@@ -27,6 +27,9 @@ pub struct Execute {
     /// Toggles offline mode.
     #[clap(long)]
     offline: bool,
+    /// Writes the constraints of each instruction to the specified file, as folded stacks for a flamegraph.
+    #[clap(long)]
+    profile: Option<std::path::PathBuf>,
 }
 
 impl Execute {
@@ -75,6 +78,11 @@ impl Execute {
             }
         }
 
+        // Write the constraint profile, if requested.
+        if let Some(profile) = &self.profile {
+            std::fs::write(profile, CallMetrics::to_folded_stacks(&metrics))?;
+        }
+
         // Log the metrics.
         use num_format::ToFormattedString;
 
@@ -34,6 +34,7 @@ use crate::{
     console::program::{Identifier, Locator, ProgramID, Value},
     ledger::block::Transaction,
     package::Package,
+    synthesizer::CallMetrics,
 };
 
 use anyhow::Result;
@@ -21,6 +21,9 @@ pub struct Run {
     function: Identifier<CurrentNetwork>,
     /// The function inputs.
     inputs: Vec<Value<CurrentNetwork>>,
+    /// Writes the constraints of each instruction to the specified file, as folded stacks for a flamegraph.
+    #[clap(long)]
+    profile: Option<std::path::PathBuf>,
 }
 
 impl Run {
@@ -62,6 +65,11 @@ impl Run {
             }
         }
 
+        // Write the constraint profile, if requested.
+        if let Some(profile) = &self.profile {
+            std::fs::write(profile, CallMetrics::to_folded_stacks(&metrics))?;
+        }
+
         // Log the metrics.
         use num_format::ToFormattedString;
 